@@ -63,6 +63,8 @@ pub enum LitKind {
     StrRaw(u16), // raw string delimited by `n` hash symbols
     ByteStr,
     ByteStrRaw(u16), // raw byte string delimited by `n` hash symbols
+    CStr,
+    CStrRaw(u16), // raw C string delimited by `n` hash symbols
     Err,
 }
 
@@ -94,6 +96,13 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 delim = "#".repeat(n as usize),
                 string = symbol
             )?,
+            CStr => write!(f, "c\"{}\"", symbol)?,
+            CStrRaw(n) => write!(
+                f,
+                "cr{delim}\"{string}\"{delim}",
+                delim = "#".repeat(n as usize),
+                string = symbol
+            )?,
             Integer | Float | Bool | Err => write!(f, "{}", symbol)?,
         }
 
@@ -123,6 +132,7 @@ pub fn descr(self) -> &'static str {
             Float => "float",
             Str | StrRaw(..) => "string",
             ByteStr | ByteStrRaw(..) => "byte string",
+            CStr | CStrRaw(..) => "C string",
             Err => "error",
         }
     }
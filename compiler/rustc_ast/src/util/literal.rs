@@ -4,7 +4,8 @@
 use crate::token::{self, Token};
 
 use rustc_lexer::unescape::{unescape_byte, unescape_char};
-use rustc_lexer::unescape::{unescape_byte_literal, unescape_literal, Mode};
+use rustc_lexer::unescape::{unescape_byte_literal, unescape_c_string, unescape_literal};
+use rustc_lexer::unescape::{CStrUnit, Mode};
 use rustc_span::symbol::{kw, sym, Symbol};
 use rustc_span::Span;
 
@@ -141,6 +142,26 @@ fn from_lit_token(lit: token::Lit) -> Result<LitKind, LitError> {
 
                 LitKind::ByteStr(bytes.into())
             }
+            token::CStr | token::CStrRaw(_) => {
+                let mode = if kind == token::CStr { Mode::CStr } else { Mode::RawCStr };
+                let s = symbol.as_str();
+                let mut buf = Vec::with_capacity(s.len() + 1);
+                let mut error = Ok(());
+                unescape_c_string(&s, mode, &mut |_, unescaped_unit| match unescaped_unit {
+                    Ok(CStrUnit::Byte(b)) => buf.push(b),
+                    Ok(CStrUnit::Char(c)) => {
+                        buf.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes())
+                    }
+                    Err(err) => {
+                        if err.is_fatal() {
+                            error = Err(LitError::LexerError);
+                        }
+                    }
+                });
+                error?;
+                buf.push(0);
+                LitKind::CStr(buf.into())
+            }
             token::Err => LitKind::Err(symbol),
         })
     }
@@ -167,6 +188,16 @@ pub fn to_lit_token(&self) -> token::Lit {
                     .collect::<String>();
                 (token::ByteStr, Symbol::intern(&string), None)
             }
+            LitKind::CStr(ref bytes) => {
+                // Strip the trailing nul, it is implied by the literal syntax.
+                let string = bytes[..bytes.len() - 1]
+                    .iter()
+                    .cloned()
+                    .flat_map(ascii::escape_default)
+                    .map(Into::<char>::into)
+                    .collect::<String>();
+                (token::CStr, Symbol::intern(&string), None)
+            }
             LitKind::Byte(byte) => {
                 let string: String = ascii::escape_default(byte).map(Into::<char>::into).collect();
                 (token::Byte, Symbol::intern(&string), None)
@@ -200,6 +200,10 @@ pub fn literal_to_string(lit: token::Lit) -> String {
         token::ByteStrRaw(n) => {
             format!("br{delim}\"{string}\"{delim}", delim = "#".repeat(n as usize), string = symbol)
         }
+        token::CStr => format!("c\"{}\"", symbol),
+        token::CStrRaw(n) => {
+            format!("cr{delim}\"{string}\"{delim}", delim = "#".repeat(n as usize), string = symbol)
+        }
         token::Integer | token::Float | token::Bool | token::Err => symbol.to_string(),
     };
 
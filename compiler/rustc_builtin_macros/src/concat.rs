@@ -40,6 +40,9 @@ pub fn expand_concat(
                 ast::LitKind::Byte(..) | ast::LitKind::ByteStr(..) => {
                     cx.span_err(e.span, "cannot concatenate a byte string literal");
                 }
+                ast::LitKind::CStr(..) => {
+                    cx.span_err(e.span, "cannot concatenate a C string literal");
+                }
                 ast::LitKind::Err(_) => {
                     has_errors = true;
                 }
@@ -35,8 +35,8 @@
 use regex::Regex;
 use tempfile::Builder as TempFileBuilder;
 
+use std::cell::OnceCell;
 use std::ffi::OsString;
-use std::lazy::OnceCell;
 use std::path::{Path, PathBuf};
 use std::process::{ExitStatus, Output, Stdio};
 use std::{ascii, char, env, fmt, fs, io, mem, str};
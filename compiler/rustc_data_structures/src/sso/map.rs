@@ -33,7 +33,7 @@
 //   all hasher-related
 //   try_reserve
 //   shrink_to (unstable)
-//   drain_filter (unstable)
+//   extract_if (unstable)
 //   into_keys/into_values (unstable)
 //   all raw_entry-related
 //   PartialEq/Eq (requires sorting the array)
@@ -15,7 +15,7 @@
 //   all hasher-related
 //   try_reserve
 //   shrink_to (unstable)
-//   drain_filter (unstable)
+//   extract_if (unstable)
 //   replace
 //   get_or_insert/get_or_insert_owned/get_or_insert_with (unstable)
 //   difference/symmetric_difference/intersection/union
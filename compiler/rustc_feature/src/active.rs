@@ -701,6 +701,9 @@ pub fn set(&self, features: &mut Features, span: Span) {
     /// Enables experimental inline assembly support for additional architectures.
     (active, asm_experimental_arch, "1.58.0", Some(72016), None),
 
+    /// Allows `c"foo"` literals, which have type `&'static CStr`.
+    (active, c_str_literals, "1.58.0", None, None),
+
     // -------------------------------------------------------------------------
     // feature-group-end: actual feature gates
     // -------------------------------------------------------------------------
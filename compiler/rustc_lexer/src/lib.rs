@@ -162,6 +162,10 @@ pub enum LiteralKind {
     RawStr { n_hashes: u16, err: Option<RawStrError> },
     /// "br"abc"", "br#"abc"#", "br####"ab"###"c"####", "br#"a"
     RawByteStr { n_hashes: u16, err: Option<RawStrError> },
+    /// "c"abc"", "c"abc"
+    CStr { terminated: bool },
+    /// "cr"abc"", "cr#"abc"#", "cr####"ab"###"c"####", "cr#"a"
+    RawCStr { n_hashes: u16, err: Option<RawStrError> },
 }
 
 /// Error produced validating a raw string. Represents cases like:
@@ -358,6 +362,31 @@ fn advance_token(&mut self) -> Token {
                 _ => self.ident_or_unknown_prefix(),
             },
 
+            // C string literal, raw C string literal or identifier.
+            'c' => match (self.first(), self.second()) {
+                ('"', _) => {
+                    self.bump();
+                    let terminated = self.double_quoted_string();
+                    let suffix_start = self.len_consumed();
+                    if terminated {
+                        self.eat_literal_suffix();
+                    }
+                    let kind = CStr { terminated };
+                    Literal { kind, suffix_start }
+                }
+                ('r', '"') | ('r', '#') => {
+                    self.bump();
+                    let (n_hashes, err) = self.raw_double_quoted_string(2);
+                    let suffix_start = self.len_consumed();
+                    if err.is_none() {
+                        self.eat_literal_suffix();
+                    }
+                    let kind = RawCStr { n_hashes, err };
+                    Literal { kind, suffix_start }
+                }
+                _ => self.ident_or_unknown_prefix(),
+            },
+
             // Identifier (this should be checked after other variant that can
             // start as identifier).
             c if is_id_start(c) => self.ident_or_unknown_prefix(),
@@ -247,6 +247,7 @@ fn literal_suffixes() {
 b'a'
 "a"
 b"a"
+c"a"
 1234
 0b101
 0xABC
@@ -255,6 +256,7 @@ fn literal_suffixes() {
 2us
 r###"raw"###suffix
 br###"raw"###suffix
+cr###"raw"###suffix
 "####,
         expect![[r#"
             Token { kind: Whitespace, len: 1 }
@@ -266,6 +268,8 @@ fn literal_suffixes() {
             Token { kind: Whitespace, len: 1 }
             Token { kind: Literal { kind: ByteStr { terminated: true }, suffix_start: 4 }, len: 4 }
             Token { kind: Whitespace, len: 1 }
+            Token { kind: Literal { kind: CStr { terminated: true }, suffix_start: 4 }, len: 4 }
+            Token { kind: Whitespace, len: 1 }
             Token { kind: Literal { kind: Int { base: Decimal, empty_int: false }, suffix_start: 4 }, len: 4 }
             Token { kind: Whitespace, len: 1 }
             Token { kind: Literal { kind: Int { base: Binary, empty_int: false }, suffix_start: 5 }, len: 5 }
@@ -282,6 +286,8 @@ fn literal_suffixes() {
             Token { kind: Whitespace, len: 1 }
             Token { kind: Literal { kind: RawByteStr { n_hashes: 3, err: None }, suffix_start: 13 }, len: 19 }
             Token { kind: Whitespace, len: 1 }
+            Token { kind: Literal { kind: RawCStr { n_hashes: 3, err: None }, suffix_start: 13 }, len: 19 }
+            Token { kind: Whitespace, len: 1 }
         "#]],
     )
 }
@@ -57,6 +57,9 @@ pub enum EscapeError {
     /// Non-ascii character in byte string literal.
     NonAsciiCharInByteString,
 
+    /// Nul character (escaped or not) in C string literal.
+    NulInCStr,
+
     /// After a line ending with '\', the next line contains whitespace
     /// characters that are not skipped.
     UnskippedWhitespaceWarning,
@@ -89,15 +92,49 @@ pub fn unescape_literal<F>(literal_text: &str, mode: Mode, callback: &mut F)
             // The Chars iterator moved forward.
             callback(0..(literal_text.len() - chars.as_str().len()), result);
         }
-        Mode::Str | Mode::ByteStr => unescape_str_or_byte_str(literal_text, mode, callback),
+        Mode::Str | Mode::ByteStr | Mode::CStr => {
+            unescape_str_or_byte_str(literal_text, mode, callback)
+        }
         // NOTE: Raw strings do not perform any explicit character escaping, here we
         // only translate CRLF to LF and produce errors on bare CR.
-        Mode::RawStr | Mode::RawByteStr => {
+        Mode::RawStr | Mode::RawByteStr | Mode::RawCStr => {
             unescape_raw_str_or_byte_str(literal_text, mode, callback)
         }
     }
 }
 
+/// A unit of a C string literal: either a byte written as a `\x` escape,
+/// or a character that is encoded as UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CStrUnit {
+    Byte(u8),
+    Char(char),
+}
+
+/// Takes a contents of a C string or raw C string literal (without quotes)
+/// and produces a sequence of units or errors. Nul characters are reported
+/// as [`EscapeError::NulInCStr`], since the terminating nul is implicit.
+/// Values are returned through invoking of the provided callback.
+pub fn unescape_c_string<F>(literal_text: &str, mode: Mode, callback: &mut F)
+where
+    F: FnMut(Range<usize>, Result<CStrUnit, EscapeError>),
+{
+    assert!(mode.is_c_str());
+    unescape_literal(literal_text, mode, &mut |range, result| {
+        let result = result.and_then(|c| {
+            if c == '\0' {
+                Err(EscapeError::NulInCStr)
+            } else if literal_text[range.clone()].starts_with("\\x") {
+                // Hex escapes denote bytes, which may be outside of the ASCII range.
+                Ok(CStrUnit::Byte(byte_from_char(c)))
+            } else {
+                Ok(CStrUnit::Char(c))
+            }
+        });
+        callback(range, result);
+    })
+}
+
 /// Takes a contents of a byte, byte string or raw byte string (without quotes)
 /// and produces a sequence of bytes or errors.
 /// Values are returned through invoking of the provided callback.
@@ -137,13 +174,20 @@ pub enum Mode {
     ByteStr,
     RawStr,
     RawByteStr,
+    CStr,
+    RawCStr,
 }
 
 impl Mode {
     pub fn in_single_quotes(self) -> bool {
         match self {
             Mode::Char | Mode::Byte => true,
-            Mode::Str | Mode::ByteStr | Mode::RawStr | Mode::RawByteStr => false,
+            Mode::Str
+            | Mode::ByteStr
+            | Mode::RawStr
+            | Mode::RawByteStr
+            | Mode::CStr
+            | Mode::RawCStr => false,
         }
     }
 
@@ -154,9 +198,13 @@ pub fn in_double_quotes(self) -> bool {
     pub fn is_bytes(self) -> bool {
         match self {
             Mode::Byte | Mode::ByteStr | Mode::RawByteStr => true,
-            Mode::Char | Mode::Str | Mode::RawStr => false,
+            Mode::Char | Mode::Str | Mode::RawStr | Mode::CStr | Mode::RawCStr => false,
         }
     }
+
+    pub fn is_c_str(self) -> bool {
+        matches!(self, Mode::CStr | Mode::RawCStr)
+    }
 }
 
 fn scan_escape(first_char: char, chars: &mut Chars<'_>, mode: Mode) -> Result<char, EscapeError> {
@@ -202,8 +250,9 @@ fn scan_escape(first_char: char, chars: &mut Chars<'_>, mode: Mode) -> Result<ch
 
             let value = hi * 16 + lo;
 
-            // For a byte literal verify that it is within ASCII range.
-            if !mode.is_bytes() && !is_ascii(value) {
+            // For a character literal verify that it is within ASCII range.
+            // Byte and C string literals may contain any byte.
+            if !mode.is_bytes() && !mode.is_c_str() && !is_ascii(value) {
                 return Err(EscapeError::OutOfRangeHexEscape);
             }
             let value = value as u8;
@@ -295,3 +295,43 @@ fn check(literal: &str, expected: &[(Range<usize>, Result<u8, EscapeError>)]) {
         &[(0..4, Err(EscapeError::NonAsciiCharInByteString)), (4..5, Ok(byte_from_char('a')))],
     );
 }
+
+#[test]
+fn test_unescape_c_str() {
+    fn check(literal: &str, expected: &[(Range<usize>, Result<CStrUnit, EscapeError>)]) {
+        let mut unescaped = Vec::with_capacity(literal.len());
+        unescape_c_string(literal, Mode::CStr, &mut |range, res| unescaped.push((range, res)));
+        assert_eq!(unescaped, expected);
+    }
+
+    check("a", &[(0..1, Ok(CStrUnit::Char('a')))]);
+    check("é", &[(0..2, Ok(CStrUnit::Char('é')))]);
+    check(r"\xff", &[(0..4, Ok(CStrUnit::Byte(0xff)))]);
+    check(r"\u{ff}", &[(0..6, Ok(CStrUnit::Char('\u{ff}')))]);
+    check(r"\x7f", &[(0..4, Ok(CStrUnit::Byte(0x7f)))]);
+    check(r"a\0", &[(0..1, Ok(CStrUnit::Char('a'))), (1..3, Err(EscapeError::NulInCStr))]);
+    check(r"\x00", &[(0..4, Err(EscapeError::NulInCStr))]);
+    check(r"\u{0}", &[(0..5, Err(EscapeError::NulInCStr))]);
+    check("\0", &[(0..1, Err(EscapeError::NulInCStr))]);
+}
+
+#[test]
+fn test_unescape_raw_c_str() {
+    fn check(literal: &str, expected: &[(Range<usize>, Result<CStrUnit, EscapeError>)]) {
+        let mut unescaped = Vec::with_capacity(literal.len());
+        unescape_c_string(literal, Mode::RawCStr, &mut |range, res| unescaped.push((range, res)));
+        assert_eq!(unescaped, expected);
+    }
+
+    check(
+        r"\xff",
+        &[
+            (0..1, Ok(CStrUnit::Char('\\'))),
+            (1..2, Ok(CStrUnit::Char('x'))),
+            (2..3, Ok(CStrUnit::Char('f'))),
+            (3..4, Ok(CStrUnit::Char('f'))),
+        ],
+    );
+    check("\r", &[(0..1, Err(EscapeError::BareCarriageReturnInRawString))]);
+    check("\0", &[(0..1, Err(EscapeError::NulInCStr))]);
+}
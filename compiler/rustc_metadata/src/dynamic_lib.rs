@@ -66,11 +66,10 @@ mod dl {
     // with other code (see #74469) on platforms where `dlerror` is not thread-safe.
     mod error {
         use std::ffi::CStr;
-        use std::lazy::SyncLazy;
-        use std::sync::{Mutex, MutexGuard};
+        use std::sync::{LazyLock, Mutex, MutexGuard};
 
         pub fn lock() -> MutexGuard<'static, Guard> {
-            static LOCK: SyncLazy<Mutex<Guard>> = SyncLazy::new(|| Mutex::new(Guard));
+            static LOCK: LazyLock<Mutex<Guard>> = LazyLock::new(|| Mutex::new(Guard));
             LOCK.lock().unwrap()
         }
 
@@ -345,7 +345,7 @@ fn process_command_line(&mut self) {
             // can move them to the end of the list below.
             let mut existing = self
                 .libs
-                .drain_filter(|lib| {
+                .extract_if(.., |lib| {
                     if let Some(lib_name) = lib.name {
                         if lib_name.as_str() == passed_lib.name {
                             if passed_lib.kind != NativeLibKind::Unspecified {
@@ -3,7 +3,7 @@
 use rustc_middle::mir::interpret::{
     Allocation, ConstValue, LitToConstError, LitToConstInput, Scalar,
 };
-use rustc_middle::ty::{self, ParamEnv, TyCtxt};
+use rustc_middle::ty::{self, ParamEnv, Ty, TyCtxt};
 use rustc_span::symbol::Symbol;
 use rustc_target::abi::Size;
 
@@ -22,6 +22,10 @@
         Ok(ConstValue::Scalar(Scalar::from_uint(result, width)))
     };
 
+    let is_c_str = |ty: &Ty<'tcx>| {
+        matches!(ty.kind(), ty::Adt(def, _) if Some(def.did) == tcx.lang_items().c_str())
+    };
+
     let lit = match (lit, &ty.kind()) {
         (ast::LitKind::Str(s, _), ty::Ref(_, inner_ty, _)) if inner_ty.is_str() => {
             let s = s.as_str();
@@ -40,6 +44,13 @@
             let id = tcx.allocate_bytes(data);
             ConstValue::Scalar(Scalar::from_pointer(id.into(), &tcx))
         }
+        (ast::LitKind::CStr(data), ty::Ref(_, inner_ty, _)) if is_c_str(inner_ty) => {
+            // `CStr` is a wrapper around a slice, so its metadata is the byte length
+            // including the trailing nul.
+            let allocation = Allocation::from_bytes_byte_aligned_immutable(data as &[u8]);
+            let allocation = tcx.intern_const_alloc(allocation);
+            ConstValue::Slice { data: allocation, start: 0, end: data.len() }
+        }
         (ast::LitKind::Byte(n), ty::Uint(ty::UintTy::U8)) => {
             ConstValue::Scalar(Scalar::from_uint(*n, Size::from_bytes(1)))
         }
@@ -13,6 +13,8 @@
 use rustc_span::symbol::{sym, Symbol};
 use rustc_span::{edition::Edition, BytePos, Pos, Span};
 
+use std::ops::Range;
+
 use tracing::debug;
 
 mod tokentrees;
@@ -83,9 +85,17 @@ fn next_token(&mut self) -> (Spacing, Token) {
                 return (spacing, Token::new(token::Eof, span));
             }
 
-            let token = rustc_lexer::first_token(text);
+            let mut token = rustc_lexer::first_token(text);
 
             let start = self.pos;
+            if let Some(prefix_len) = self.c_str_prefix_before_2021(&token, start) {
+                // Before Rust 2021, `c"..."` and `cr"..."` are an unknown prefix
+                // followed by a string literal, so only lex the prefix here.
+                token = rustc_lexer::Token {
+                    kind: rustc_lexer::TokenKind::UnknownPrefix,
+                    len: prefix_len,
+                };
+            }
             self.pos = self.pos + BytePos::from_usize(token.len);
 
             debug!("next_token: {:?}({:?})", token.kind, self.str_from(start));
@@ -100,6 +110,32 @@ fn next_token(&mut self) -> (Spacing, Token) {
         }
     }
 
+    /// Returns the length of the `c` or `cr` prefix if `token` is a C string
+    /// literal that is lexed in an edition without C string literals.
+    fn c_str_prefix_before_2021(
+        &self,
+        token: &rustc_lexer::Token,
+        start: BytePos,
+    ) -> Option<usize> {
+        let prefix_len = match token.kind {
+            rustc_lexer::TokenKind::Literal {
+                kind: rustc_lexer::LiteralKind::CStr { .. },
+                ..
+            } => 1,
+            rustc_lexer::TokenKind::Literal {
+                kind: rustc_lexer::LiteralKind::RawCStr { .. },
+                ..
+            } => 2,
+            _ => return None,
+        };
+        let prefix_span = self.mk_sp(start, start + BytePos::from_usize(prefix_len));
+        if prefix_span.ctxt().outer_expn_data().edition >= Edition::Edition2021 {
+            None
+        } else {
+            Some(prefix_len)
+        }
+    }
+
     /// Report a fatal lexical error with a given span.
     fn fatal_span(&self, sp: Span, m: &str) -> FatalError {
         self.sess.span_diagnostic.span_fatal(sp, m)
@@ -398,6 +434,23 @@ fn cook_lexer_literal(
                 let n = u32::from(n_hashes);
                 (token::ByteStrRaw(n_hashes), Mode::RawByteStr, 3 + n, 1 + n) // br##" "##
             }
+            rustc_lexer::LiteralKind::CStr { terminated } => {
+                if !terminated {
+                    self.sess.span_diagnostic.span_fatal_with_code(
+                        self.mk_sp(start + BytePos(1), suffix_start),
+                        "unterminated C string",
+                        error_code!(E0765),
+                    )
+                }
+                self.sess.gated_spans.gate(sym::c_str_literals, self.mk_sp(start, self.pos));
+                (token::CStr, Mode::CStr, 2, 1) // c" "
+            }
+            rustc_lexer::LiteralKind::RawCStr { n_hashes, err } => {
+                self.report_raw_str_error(start, err);
+                self.sess.gated_spans.gate(sym::c_str_literals, self.mk_sp(start, self.pos));
+                let n = u32::from(n_hashes);
+                (token::CStrRaw(n_hashes), Mode::RawCStr, 3 + n, 1 + n) // cr##" "##
+            }
             rustc_lexer::LiteralKind::Int { base, empty_int } => {
                 return if empty_int {
                     self.sess
@@ -598,26 +651,37 @@ fn validate_literal_escape(
         postfix_len: u32,
     ) {
         let lit_content = self.str_from_to(content_start, content_end);
-        unescape::unescape_literal(lit_content, mode, &mut |range, result| {
-            // Here we only check for errors. The actual unescaping is done later.
-            if let Err(err) = result {
-                let span_with_quotes = self
-                    .mk_sp(content_start - BytePos(prefix_len), content_end + BytePos(postfix_len));
-                let (start, end) = (range.start as u32, range.end as u32);
-                let lo = content_start + BytePos(start);
-                let hi = lo + BytePos(end - start);
-                let span = self.mk_sp(lo, hi);
-                emit_unescape_error(
-                    &self.sess.span_diagnostic,
-                    lit_content,
-                    span_with_quotes,
-                    span,
-                    mode,
-                    range,
-                    err,
-                );
-            }
-        });
+        let mut report_error = |range: Range<usize>, err| {
+            let span_with_quotes =
+                self.mk_sp(content_start - BytePos(prefix_len), content_end + BytePos(postfix_len));
+            let (start, end) = (range.start as u32, range.end as u32);
+            let lo = content_start + BytePos(start);
+            let hi = lo + BytePos(end - start);
+            let span = self.mk_sp(lo, hi);
+            emit_unescape_error(
+                &self.sess.span_diagnostic,
+                lit_content,
+                span_with_quotes,
+                span,
+                mode,
+                range,
+                err,
+            );
+        };
+        // Here we only check for errors. The actual unescaping is done later.
+        if mode.is_c_str() {
+            unescape::unescape_c_string(lit_content, mode, &mut |range, result| {
+                if let Err(err) = result {
+                    report_error(range, err);
+                }
+            });
+        } else {
+            unescape::unescape_literal(lit_content, mode, &mut |range, result| {
+                if let Err(err) = result {
+                    report_error(range, err);
+                }
+            });
+        }
     }
 
     fn validate_int_literal(&self, base: Base, content_start: BytePos, content_end: BytePos) {
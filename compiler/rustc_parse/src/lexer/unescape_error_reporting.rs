@@ -328,6 +328,13 @@ pub(crate) fn emit_unescape_error(
             let bottom_msg = "skipping everything up to and including this point";
             handler.struct_span_warn(span, msg).span_label(span, bottom_msg).emit();
         }
+        EscapeError::NulInCStr => {
+            assert!(mode.is_c_str());
+            handler
+                .struct_span_err(span, "null characters in C string literals are not supported")
+                .span_label(span, "C string literals are implicitly nul-terminated")
+                .emit();
+        }
     }
 }
 
@@ -390,7 +390,10 @@ fn check_too_many_raw_str_terminators(&mut self, err: &mut DiagnosticBuilder<'_>
         match (&self.prev_token.kind, &self.token.kind) {
             (
                 TokenKind::Literal(Lit {
-                    kind: LitKind::StrRaw(n_hashes) | LitKind::ByteStrRaw(n_hashes),
+                    kind:
+                        LitKind::StrRaw(n_hashes)
+                        | LitKind::ByteStrRaw(n_hashes)
+                        | LitKind::CStrRaw(n_hashes),
                     ..
                 }),
                 TokenKind::Pound,
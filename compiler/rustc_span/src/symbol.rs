@@ -386,6 +386,8 @@
         bridge,
         bswap,
         c_str,
+        c_str_alloc,
+        c_str_literals,
         c_unwind,
         c_variadic,
         call,
@@ -591,6 +593,9 @@
         env,
         eq,
         ermsb_target_feature,
+        error_alloc,
+        error_send_alloc,
+        error_send_sync_alloc,
         exact_div,
         except,
         exchange_malloc,
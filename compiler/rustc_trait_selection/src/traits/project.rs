@@ -946,7 +946,7 @@ fn opt_normalize_projection_type<'a, 'b, 'tcx>(
 
             let mut canonical =
                 SelectionContext::with_query_mode(selcx.infcx(), TraitQueryMode::Canonical);
-            result.obligations.drain_filter(|projected_obligation| {
+            result.obligations.extract_if(.., |projected_obligation| {
                 // If any global obligations always apply, considering regions, then we don't
                 // need to include them. The `is_global` check rules out inference variables,
                 // so there's no need for the caller of `opt_normalize_projection_type`
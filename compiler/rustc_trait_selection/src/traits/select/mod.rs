@@ -2634,7 +2634,7 @@ fn on_completion(
         debug!(?dfn, "on_completion");
 
         for (fresh_trait_ref, eval) in
-            self.map.borrow_mut().drain_filter(|_k, eval| eval.from_dfn >= dfn)
+            self.map.borrow_mut().extract_if(|_k, eval| eval.from_dfn >= dfn)
         {
             debug!(?fresh_trait_ref, ?eval, "on_completion");
 
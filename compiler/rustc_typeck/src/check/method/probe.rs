@@ -638,9 +638,19 @@ fn assemble_probe(&mut self, self_ty: &Canonical<'tcx, QueryResponse<'tcx, Ty<'t
 
                 self.assemble_inherent_candidates_from_object(generalized_self_ty);
                 self.assemble_inherent_impl_candidates_for_type(p.def_id());
+                for lang_def_id in [
+                    lang_items.error_alloc_impl(),
+                    lang_items.error_send_alloc_impl(),
+                    lang_items.error_send_sync_alloc_impl(),
+                ] {
+                    self.assemble_inherent_impl_for_primitive(lang_def_id);
+                }
             }
             ty::Adt(def, _) => {
                 self.assemble_inherent_impl_candidates_for_type(def.did);
+                if Some(def.did) == lang_items.c_str() {
+                    self.assemble_inherent_impl_for_primitive(lang_items.c_str_alloc_impl());
+                }
             }
             ty::Foreign(did) => {
                 self.assemble_inherent_impl_candidates_for_type(did);
@@ -420,7 +420,7 @@ fn check_gat_where_clauses(
         // case, `'me` can be `static` from `gimme_default`)
         match clauses.as_mut() {
             Some(clauses) => {
-                clauses.drain_filter(|p| !function_clauses.contains(p));
+                clauses.extract_if(|p| !function_clauses.contains(p));
             }
             None => {
                 clauses = Some(function_clauses);
@@ -435,7 +435,7 @@ fn check_gat_where_clauses(
         let written_predicates: ty::GenericPredicates<'_> =
             tcx.explicit_predicates_of(trait_item.def_id);
         let mut clauses: Vec<_> = clauses
-            .drain_filter(|clause| !written_predicates.predicates.iter().any(|p| &p.0 == clause))
+            .extract_if(|clause| !written_predicates.predicates.iter().any(|p| &p.0 == clause))
             .map(|clause| format!("{}", clause))
             .collect();
         // We sort so that order is predictable
@@ -51,13 +51,23 @@ fn visit_item(&mut self, item: &hir::Item<'_>) {
         let lang_items = self.tcx.lang_items();
         match *self_ty.kind() {
             ty::Adt(def, _) => {
-                self.check_def_id(item, def.did);
+                // `CStr` is defined in core, but its allocating methods live in alloc.
+                if Some(item.def_id.to_def_id()) != lang_items.c_str_alloc_impl() {
+                    self.check_def_id(item, def.did);
+                }
             }
             ty::Foreign(did) => {
                 self.check_def_id(item, did);
             }
             ty::Dynamic(data, ..) if data.principal_def_id().is_some() => {
-                self.check_def_id(item, data.principal_def_id().unwrap());
+                // `dyn Error` is defined in core, but its `Box`-taking methods live in alloc.
+                let impl_def_id = Some(item.def_id.to_def_id());
+                if impl_def_id != lang_items.error_alloc_impl()
+                    && impl_def_id != lang_items.error_send_alloc_impl()
+                    && impl_def_id != lang_items.error_send_sync_alloc_impl()
+                {
+                    self.check_def_id(item, data.principal_def_id().unwrap());
+                }
             }
             ty::Dynamic(..) => {
                 struct_span_err!(
@@ -70,7 +70,7 @@
 #![feature(never_type)]
 #![feature(slice_partition_dedup)]
 #![feature(control_flow_enum)]
-#![feature(hash_drain_filter)]
+#![feature(hash_extract_if)]
 #![recursion_limit = "256"]
 
 #[macro_use]
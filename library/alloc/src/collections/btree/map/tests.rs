@@ -3,11 +3,13 @@
 use super::super::testing::rng::DeterministicRng;
 use super::Entry::{Occupied, Vacant};
 use super::*;
+use crate::alloc::{AllocError, Allocator, Layout};
 use crate::boxed::Box;
 use crate::fmt::Debug;
 use crate::rc::Rc;
 use crate::string::{String, ToString};
 use crate::vec::Vec;
+use std::cell::Cell;
 use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::iter::{self, FromIterator};
@@ -15,6 +17,7 @@
 use std::ops::Bound::{self, Excluded, Included, Unbounded};
 use std::ops::RangeBounds;
 use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr::NonNull;
 use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
 
 // Capacity of a tree with a single level,
@@ -45,7 +48,7 @@ fn test_all_refs<'a, T: 'a>(dummy: &mut T, iter: impl Iterator<Item = &'a mut T>
     }
 }
 
-impl<K, V> BTreeMap<K, V> {
+impl<K, V, A: Allocator + Clone> BTreeMap<K, V, A> {
     // Panics if the map (or the code navigating it) is corrupted.
     fn check_invariants(&self) {
         if let Some(root) = &self.root {
@@ -118,9 +121,10 @@ fn compact(&mut self)
     where
         K: Ord,
     {
-        let iter = mem::take(self).into_iter();
-        let root = BTreeMap::ensure_is_owned(&mut self.root);
-        root.bulk_push(iter, &mut self.length);
+        let alloc = (*self.alloc).clone();
+        let iter = mem::replace(self, BTreeMap::new_in(alloc.clone())).into_iter();
+        let root = BTreeMap::ensure_is_owned(&mut self.root, alloc.clone());
+        root.bulk_push(iter, &mut self.length, alloc);
     }
 }
 
@@ -919,13 +923,13 @@ fn test_retain() {
     assert_eq!(map[&6], 60);
 }
 
-mod test_drain_filter {
+mod test_extract_if {
     use super::*;
 
     #[test]
     fn empty() {
         let mut map: BTreeMap<i32, i32> = BTreeMap::new();
-        map.drain_filter(|_, _| unreachable!("there's nothing to decide on"));
+        map.extract_if(|_, _| unreachable!("there's nothing to decide on"));
         assert!(map.is_empty());
         map.check();
     }
@@ -935,7 +939,7 @@ fn empty() {
     fn consumed_keeping_all() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map: BTreeMap<_, _> = pairs.collect();
-        assert!(map.drain_filter(|_, _| false).eq(iter::empty()));
+        assert!(map.extract_if(|_, _| false).eq(iter::empty()));
         map.check();
     }
 
@@ -944,7 +948,7 @@ fn consumed_keeping_all() {
     fn consumed_removing_all() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map: BTreeMap<_, _> = pairs.clone().collect();
-        assert!(map.drain_filter(|_, _| true).eq(pairs));
+        assert!(map.extract_if(|_, _| true).eq(pairs));
         assert!(map.is_empty());
         map.check();
     }
@@ -955,7 +959,7 @@ fn mutating_and_keeping() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map: BTreeMap<_, _> = pairs.collect();
         assert!(
-            map.drain_filter(|_, v| {
+            map.extract_if(|_, v| {
                 *v += 6;
                 false
             })
@@ -972,7 +976,7 @@ fn mutating_and_removing() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map: BTreeMap<_, _> = pairs.collect();
         assert!(
-            map.drain_filter(|_, v| {
+            map.extract_if(|_, v| {
                 *v += 6;
                 true
             })
@@ -986,7 +990,7 @@ fn mutating_and_removing() {
     fn underfull_keeping_all() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map: BTreeMap<_, _> = pairs.collect();
-        map.drain_filter(|_, _| false);
+        map.extract_if(|_, _| false);
         assert!(map.keys().copied().eq(0..3));
         map.check();
     }
@@ -996,7 +1000,7 @@ fn underfull_removing_one() {
         let pairs = (0..3).map(|i| (i, i));
         for doomed in 0..3 {
             let mut map: BTreeMap<_, _> = pairs.clone().collect();
-            map.drain_filter(|i, _| *i == doomed);
+            map.extract_if(|i, _| *i == doomed);
             assert_eq!(map.len(), 2);
             map.check();
         }
@@ -1007,7 +1011,7 @@ fn underfull_keeping_one() {
         let pairs = (0..3).map(|i| (i, i));
         for sacred in 0..3 {
             let mut map: BTreeMap<_, _> = pairs.clone().collect();
-            map.drain_filter(|i, _| *i != sacred);
+            map.extract_if(|i, _| *i != sacred);
             assert!(map.keys().copied().eq(sacred..=sacred));
             map.check();
         }
@@ -1017,7 +1021,7 @@ fn underfull_keeping_one() {
     fn underfull_removing_all() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map: BTreeMap<_, _> = pairs.collect();
-        map.drain_filter(|_, _| true);
+        map.extract_if(|_, _| true);
         assert!(map.is_empty());
         map.check();
     }
@@ -1026,7 +1030,7 @@ fn underfull_removing_all() {
     fn height_0_keeping_all() {
         let pairs = (0..NODE_CAPACITY).map(|i| (i, i));
         let mut map: BTreeMap<_, _> = pairs.collect();
-        map.drain_filter(|_, _| false);
+        map.extract_if(|_, _| false);
         assert!(map.keys().copied().eq(0..NODE_CAPACITY));
         map.check();
     }
@@ -1036,7 +1040,7 @@ fn height_0_removing_one() {
         let pairs = (0..NODE_CAPACITY).map(|i| (i, i));
         for doomed in 0..NODE_CAPACITY {
             let mut map: BTreeMap<_, _> = pairs.clone().collect();
-            map.drain_filter(|i, _| *i == doomed);
+            map.extract_if(|i, _| *i == doomed);
             assert_eq!(map.len(), NODE_CAPACITY - 1);
             map.check();
         }
@@ -1047,7 +1051,7 @@ fn height_0_keeping_one() {
         let pairs = (0..NODE_CAPACITY).map(|i| (i, i));
         for sacred in 0..NODE_CAPACITY {
             let mut map: BTreeMap<_, _> = pairs.clone().collect();
-            map.drain_filter(|i, _| *i != sacred);
+            map.extract_if(|i, _| *i != sacred);
             assert!(map.keys().copied().eq(sacred..=sacred));
             map.check();
         }
@@ -1057,7 +1061,7 @@ fn height_0_keeping_one() {
     fn height_0_removing_all() {
         let pairs = (0..NODE_CAPACITY).map(|i| (i, i));
         let mut map: BTreeMap<_, _> = pairs.collect();
-        map.drain_filter(|_, _| true);
+        map.extract_if(|_, _| true);
         assert!(map.is_empty());
         map.check();
     }
@@ -1065,7 +1069,7 @@ fn height_0_removing_all() {
     #[test]
     fn height_0_keeping_half() {
         let mut map: BTreeMap<_, _> = (0..16).map(|i| (i, i)).collect();
-        assert_eq!(map.drain_filter(|i, _| *i % 2 == 0).count(), 8);
+        assert_eq!(map.extract_if(|i, _| *i % 2 == 0).count(), 8);
         assert_eq!(map.len(), 8);
         map.check();
     }
@@ -1074,7 +1078,7 @@ fn height_0_keeping_half() {
     fn height_1_removing_all() {
         let pairs = (0..MIN_INSERTS_HEIGHT_1).map(|i| (i, i));
         let mut map: BTreeMap<_, _> = pairs.collect();
-        map.drain_filter(|_, _| true);
+        map.extract_if(|_, _| true);
         assert!(map.is_empty());
         map.check();
     }
@@ -1084,7 +1088,7 @@ fn height_1_removing_one() {
         let pairs = (0..MIN_INSERTS_HEIGHT_1).map(|i| (i, i));
         for doomed in 0..MIN_INSERTS_HEIGHT_1 {
             let mut map: BTreeMap<_, _> = pairs.clone().collect();
-            map.drain_filter(|i, _| *i == doomed);
+            map.extract_if(|i, _| *i == doomed);
             assert_eq!(map.len(), MIN_INSERTS_HEIGHT_1 - 1);
             map.check();
         }
@@ -1095,7 +1099,7 @@ fn height_1_keeping_one() {
         let pairs = (0..MIN_INSERTS_HEIGHT_1).map(|i| (i, i));
         for sacred in 0..MIN_INSERTS_HEIGHT_1 {
             let mut map: BTreeMap<_, _> = pairs.clone().collect();
-            map.drain_filter(|i, _| *i != sacred);
+            map.extract_if(|i, _| *i != sacred);
             assert!(map.keys().copied().eq(sacred..=sacred));
             map.check();
         }
@@ -1106,7 +1110,7 @@ fn height_2_removing_one() {
         let pairs = (0..MIN_INSERTS_HEIGHT_2).map(|i| (i, i));
         for doomed in (0..MIN_INSERTS_HEIGHT_2).step_by(12) {
             let mut map: BTreeMap<_, _> = pairs.clone().collect();
-            map.drain_filter(|i, _| *i == doomed);
+            map.extract_if(|i, _| *i == doomed);
             assert_eq!(map.len(), MIN_INSERTS_HEIGHT_2 - 1);
             map.check();
         }
@@ -1117,7 +1121,7 @@ fn height_2_keeping_one() {
         let pairs = (0..MIN_INSERTS_HEIGHT_2).map(|i| (i, i));
         for sacred in (0..MIN_INSERTS_HEIGHT_2).step_by(12) {
             let mut map: BTreeMap<_, _> = pairs.clone().collect();
-            map.drain_filter(|i, _| *i != sacred);
+            map.extract_if(|i, _| *i != sacred);
             assert!(map.keys().copied().eq(sacred..=sacred));
             map.check();
         }
@@ -1127,7 +1131,7 @@ fn height_2_keeping_one() {
     fn height_2_removing_all() {
         let pairs = (0..MIN_INSERTS_HEIGHT_2).map(|i| (i, i));
         let mut map: BTreeMap<_, _> = pairs.collect();
-        map.drain_filter(|_, _| true);
+        map.extract_if(|_, _| true);
         assert!(map.is_empty());
         map.check();
     }
@@ -1142,7 +1146,7 @@ fn drop_panic_leak() {
         map.insert(b.spawn(Panic::InDrop), ());
         map.insert(c.spawn(Panic::Never), ());
 
-        catch_unwind(move || drop(map.drain_filter(|dummy, _| dummy.query(true)))).unwrap_err();
+        catch_unwind(move || drop(map.extract_if(|dummy, _| dummy.query(true)))).unwrap_err();
 
         assert_eq!(a.queried(), 1);
         assert_eq!(b.queried(), 1);
@@ -1162,7 +1166,7 @@ fn pred_panic_leak() {
         map.insert(b.spawn(Panic::InQuery), ());
         map.insert(c.spawn(Panic::InQuery), ());
 
-        catch_unwind(AssertUnwindSafe(|| drop(map.drain_filter(|dummy, _| dummy.query(true)))))
+        catch_unwind(AssertUnwindSafe(|| drop(map.extract_if(|dummy, _| dummy.query(true)))))
             .unwrap_err();
 
         assert_eq!(a.queried(), 1);
@@ -1189,7 +1193,7 @@ fn pred_panic_reuse() {
         map.insert(c.spawn(Panic::InQuery), ());
 
         {
-            let mut it = map.drain_filter(|dummy, _| dummy.query(true));
+            let mut it = map.extract_if(|dummy, _| dummy.query(true));
             catch_unwind(AssertUnwindSafe(|| while it.next().is_some() {})).unwrap_err();
             // Iterator behaviour after a panic is explicitly unspecified,
             // so this is just the current implementation:
@@ -1632,8 +1636,8 @@ fn into_values<T: Sync + Ord>(v: BTreeMap<T, T>) -> impl Sync {
         v.into_values()
     }
 
-    fn drain_filter<T: Sync + Ord>(v: &mut BTreeMap<T, T>) -> impl Sync + '_ {
-        v.drain_filter(|_, _| false)
+    fn extract_if<T: Sync + Ord>(v: &mut BTreeMap<T, T>) -> impl Sync + '_ {
+        v.extract_if(|_, _| false)
     }
 
     fn iter<T: Sync>(v: &BTreeMap<T, T>) -> impl Sync + '_ {
@@ -1701,8 +1705,8 @@ fn into_values<T: Send + Ord>(v: BTreeMap<T, T>) -> impl Send {
         v.into_values()
     }
 
-    fn drain_filter<T: Send + Ord>(v: &mut BTreeMap<T, T>) -> impl Send + '_ {
-        v.drain_filter(|_, _| false)
+    fn extract_if<T: Send + Ord>(v: &mut BTreeMap<T, T>) -> impl Send + '_ {
+        v.extract_if(|_, _| false)
     }
 
     fn iter<T: Send + Sync>(v: &BTreeMap<T, T>) -> impl Send + '_ {
@@ -2186,3 +2190,294 @@ fn from_array() {
     let unordered_duplicates = BTreeMap::from([(3, 4), (1, 2), (1, 2)]);
     assert_eq!(map, unordered_duplicates);
 }
+
+#[test]
+fn test_cursor() {
+    let map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+
+    let mut cur = map.lower_bound(Bound::Unbounded);
+    assert_eq!(cur.key(), Some(&1));
+    cur.move_next();
+    assert_eq!(cur.key(), Some(&2));
+    assert_eq!(cur.peek_next(), Some((&3, &'c')));
+    cur.move_prev();
+    assert_eq!(cur.key(), Some(&1));
+    assert_eq!(cur.peek_prev(), None);
+
+    let mut cur = map.upper_bound(Bound::Excluded(&1));
+    assert_eq!(cur.key(), None);
+    cur.move_next();
+    assert_eq!(cur.key(), Some(&1));
+    cur.move_prev();
+    assert_eq!(cur.key(), None);
+    assert_eq!(cur.peek_prev(), Some((&3, &'c')));
+    assert_eq!(cur.peek_next(), Some((&1, &'a')));
+}
+
+#[test]
+fn test_cursor_bounds() {
+    let map = BTreeMap::from([(1, 'a'), (3, 'c'), (5, 'e')]);
+
+    assert_eq!(map.lower_bound(Included(&3)).key(), Some(&3));
+    assert_eq!(map.lower_bound(Excluded(&3)).key(), Some(&5));
+    assert_eq!(map.lower_bound(Included(&4)).key(), Some(&5));
+    assert_eq!(map.lower_bound(Excluded(&5)).key(), None);
+    assert_eq!(map.lower_bound(Unbounded).key(), Some(&1));
+
+    assert_eq!(map.upper_bound(Included(&3)).key(), Some(&3));
+    assert_eq!(map.upper_bound(Excluded(&3)).key(), Some(&1));
+    assert_eq!(map.upper_bound(Included(&2)).key(), Some(&1));
+    assert_eq!(map.upper_bound(Excluded(&1)).key(), None);
+    assert_eq!(map.upper_bound(Unbounded).key(), Some(&5));
+}
+
+#[test]
+fn test_cursor_empty() {
+    let mut map = BTreeMap::<i32, i32>::new();
+    let mut cur = map.lower_bound(Unbounded);
+    assert_eq!(cur.key_value(), None);
+    cur.move_next();
+    assert_eq!(cur.key_value(), None);
+    assert_eq!(cur.peek_next(), None);
+    assert_eq!(cur.peek_prev(), None);
+
+    let mut cur = map.upper_bound_mut(Unbounded);
+    assert_eq!(cur.remove_current(), None);
+    cur.insert_before(1, 10);
+    assert_eq!(cur.key(), None);
+    assert_eq!(cur.peek_prev(), Some((&1, &mut 10)));
+    map.check();
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn test_cursor_mut() {
+    let mut map = BTreeMap::from([(1, 'a'), (3, 'c'), (5, 'e')]);
+    let mut cur = map.lower_bound_mut(Excluded(&3));
+    assert_eq!(cur.key(), Some(&5));
+    cur.insert_before(4, 'd');
+    assert_eq!(cur.key(), Some(&5));
+    assert_eq!(cur.peek_prev(), Some((&4, &mut 'd')));
+
+    cur.move_next();
+    assert_eq!(cur.key(), None);
+    cur.insert_before(6, 'f');
+    assert_eq!(cur.key(), None);
+    assert_eq!(cur.remove_current(), None);
+    assert_eq!(cur.key(), None);
+
+    cur.insert_after(0, '?');
+    assert_eq!(cur.key(), None);
+    assert_eq!(map, BTreeMap::from([(0, '?'), (1, 'a'), (3, 'c'), (4, 'd'), (5, 'e'), (6, 'f')]));
+
+    let mut cur = map.upper_bound_mut(Included(&5));
+    assert_eq!(cur.key(), Some(&5));
+    assert_eq!(cur.remove_current(), Some((5, 'e')));
+    assert_eq!(cur.key(), Some(&6));
+    assert_eq!(cur.remove_current_and_move_back(), Some((6, 'f')));
+    assert_eq!(cur.key(), Some(&4));
+    *cur.value_mut().unwrap() = 'D';
+    map.check();
+    assert_eq!(map, BTreeMap::from([(0, '?'), (1, 'a'), (3, 'c'), (4, 'D')]));
+}
+
+#[test]
+fn test_cursor_mut_insert_many() {
+    let size = if cfg!(miri) { MIN_INSERTS_HEIGHT_1 } else { MIN_INSERTS_HEIGHT_2 } as i32;
+
+    // Insert ascending keys before the ghost, i.e. always at the end.
+    let mut map = BTreeMap::new();
+    let mut cur = map.lower_bound_mut(Unbounded);
+    for i in 0..size {
+        cur.insert_before(i, i);
+        assert_eq!(cur.key(), None);
+    }
+    map.check();
+    assert!(map.keys().copied().eq(0..size));
+
+    // Insert descending keys after the ghost, i.e. always at the front.
+    let mut map = BTreeMap::new();
+    let mut cur = map.lower_bound_mut(Unbounded);
+    for i in (0..size).rev() {
+        cur.insert_after(i, i);
+        assert_eq!(cur.key(), None);
+    }
+    map.check();
+    assert!(map.keys().copied().eq(0..size));
+
+    // Grow from a single element, with the cursor following the insertions,
+    // so that splits happen around the current position.
+    let mut map = BTreeMap::from([(0, 0)]);
+    let mut cur = map.lower_bound_mut(Unbounded);
+    for i in 1..size {
+        cur.insert_after(i, i);
+        assert_eq!(cur.key(), Some(&(i - 1)));
+        cur.move_next();
+        assert_eq!(cur.key(), Some(&i));
+    }
+    map.check();
+    assert!(map.keys().copied().eq(0..size));
+}
+
+#[test]
+fn test_cursor_mut_remove_all() {
+    let size = if cfg!(miri) { MIN_INSERTS_HEIGHT_1 } else { MIN_INSERTS_HEIGHT_2 };
+    let mut map = BTreeMap::from_iter((0..size).map(|i| (i, i)));
+    let mut cur = map.lower_bound_mut(Included(&(size / 2)));
+    for i in size / 2..size {
+        assert_eq!(cur.remove_current(), Some((i, i)));
+    }
+    assert_eq!(cur.key(), None);
+    cur.move_prev();
+    for i in (0..size / 2).rev() {
+        assert_eq!(cur.remove_current_and_move_back(), Some((i, i)));
+    }
+    assert_eq!(cur.key(), None);
+    assert_eq!(cur.remove_current(), None);
+    map.check();
+    assert!(map.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "key must be ordered above the current element")]
+fn test_cursor_mut_insert_after_1() {
+    let mut map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+    let mut cur = map.lower_bound_mut(Included(&2));
+    cur.insert_after(1, 'x');
+}
+
+#[test]
+#[should_panic(expected = "key must be ordered below the next element")]
+fn test_cursor_mut_insert_after_2() {
+    let mut map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+    let mut cur = map.lower_bound_mut(Included(&2));
+    cur.insert_after(3, 'x');
+}
+
+#[test]
+#[should_panic(expected = "key must be ordered below the current element")]
+fn test_cursor_mut_insert_before_1() {
+    let mut map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+    let mut cur = map.lower_bound_mut(Included(&2));
+    cur.insert_before(3, 'x');
+}
+
+#[test]
+#[should_panic(expected = "key must be ordered above the previous element")]
+fn test_cursor_mut_insert_before_2() {
+    let mut map = BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+    let mut cur = map.lower_bound_mut(Included(&2));
+    cur.insert_before(1, 'x');
+}
+
+/// Allocator keeping track of the number of live allocations.
+#[derive(Clone)]
+struct CountingAlloc<'a>(&'a Cell<isize>);
+
+unsafe impl Allocator for CountingAlloc<'_> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.0.set(self.0.get() + 1);
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.0.set(self.0.get() - 1);
+        unsafe { Global.deallocate(ptr, layout) }
+    }
+}
+
+#[test]
+fn test_in_allocator() {
+    let live = Cell::new(0);
+    let alloc = CountingAlloc(&live);
+    {
+        let mut map = BTreeMap::new_in(alloc.clone());
+        for i in 0..MIN_INSERTS_HEIGHT_2 {
+            map.insert(i, i);
+        }
+        map.check();
+        assert!(live.get() > 1);
+
+        let copy = map.clone_in(alloc.clone());
+        assert!(copy.iter().eq(map.iter()));
+
+        let mut right = map.split_off(&(MIN_INSERTS_HEIGHT_2 / 2));
+        map.append(&mut right);
+        assert_eq!(map.len(), MIN_INSERTS_HEIGHT_2);
+
+        let evens: Vec<_> = map.extract_if(|k, _| k % 2 == 0).collect();
+        assert_eq!(evens.len() + map.len(), MIN_INSERTS_HEIGHT_2);
+        map.check();
+
+        *map.entry(0).or_insert(0) += 1;
+        while map.pop_last().is_some() {}
+        assert!(map.is_empty());
+
+        let mut iter = copy.into_iter();
+        iter.next();
+        iter.next_back();
+        drop(iter);
+
+        let map = BTreeMap::from_iter_in((0..10).rev().map(|i| (i, i)), alloc.clone());
+        assert!(map.keys().copied().eq(0..10));
+    }
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn test_try_insert() {
+    let mut map = BTreeMap::new();
+    for i in (0..MIN_INSERTS_HEIGHT_2).rev() {
+        assert_eq!(map.try_insert(i, i), Ok(None));
+    }
+    map.check();
+    assert!(map.keys().copied().eq(0..MIN_INSERTS_HEIGHT_2));
+    assert_eq!(map.try_insert(0, 1), Ok(Some(0)));
+    assert_eq!(map[&0], 1);
+}
+
+/// Allocator that fails once it has handed out a given number of allocations.
+#[derive(Clone)]
+struct LimitedAlloc<'a>(&'a Cell<usize>);
+
+unsafe impl Allocator for LimitedAlloc<'_> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let left = self.0.get().checked_sub(1).ok_or(AllocError)?;
+        self.0.set(left);
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { Global.deallocate(ptr, layout) }
+    }
+}
+
+#[test]
+fn test_try_insert_alloc_failure() {
+    let left = Cell::new(0);
+    let mut map = BTreeMap::new_in(LimitedAlloc(&left));
+    assert!(map.try_insert(0, 0).is_err());
+    assert!(map.root.is_none());
+
+    // Retry each insertion with a larger budget until it succeeds, checking
+    // that the failed attempts left the map untouched.
+    for i in 0..MIN_INSERTS_HEIGHT_2 {
+        let mut budget = 0;
+        left.set(budget);
+        while let Err(((key, value), _)) = map.try_insert(i, i) {
+            assert_eq!((key, value), (i, i));
+            assert_eq!(map.len(), i);
+            map.check();
+            budget += 1;
+            left.set(budget);
+        }
+        // Exactly the nodes needed were allocated.
+        assert_eq!(left.get(), 0);
+    }
+    map.check();
+    assert_eq!(map.height(), Some(2));
+
+    // Replacing a value never allocates.
+    left.set(0);
+    assert_eq!(map.try_insert(0, 1), Ok(Some(0)));
+}
@@ -1,5 +1,7 @@
+#[cfg(not(no_global_oom_handling))]
 mod append;
 mod borrow;
+#[cfg(not(no_global_oom_handling))]
 mod dedup_sorted_iter;
 mod fix;
 pub mod map;
@@ -10,6 +12,7 @@
 mod remove;
 mod search;
 pub mod set;
+#[cfg(not(no_global_oom_handling))]
 mod split;
 
 #[doc(hidden)]
@@ -18,6 +21,7 @@ trait Recover<Q: ?Sized> {
 
     fn get(&self, key: &Q) -> Option<&Self::Key>;
     fn take(&mut self, key: &Q) -> Option<Self::Key>;
+    #[cfg(not(no_global_oom_handling))]
     fn replace(&mut self, key: Self::Key) -> Option<Self::Key>;
 }
 
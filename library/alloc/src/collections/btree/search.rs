@@ -64,6 +64,54 @@ pub fn search_tree<Q: ?Sized>(
         }
     }
 
+    /// Descends to the leaf edge matching the given lower bound, i.e., the edge
+    /// right before the first key that is above the bound.
+    ///
+    /// The result is meaningful only if the tree is ordered by key.
+    pub fn lower_bound<Q: ?Sized>(
+        mut self,
+        mut bound: SearchBound<&Q>,
+    ) -> Handle<NodeRef<BorrowType, K, V, marker::Leaf>, marker::Edge>
+    where
+        Q: Ord,
+        K: Borrow<Q>,
+    {
+        loop {
+            let (edge, new_bound) = self.find_lower_bound_edge(bound);
+            match edge.force() {
+                Leaf(edge) => return edge,
+                Internal(edge) => {
+                    self = edge.descend();
+                    bound = new_bound;
+                }
+            }
+        }
+    }
+
+    /// Mirror image of `lower_bound`: descends to the leaf edge right after
+    /// the last key that is below the given upper bound.
+    ///
+    /// The result is meaningful only if the tree is ordered by key.
+    pub fn upper_bound<Q: ?Sized>(
+        mut self,
+        mut bound: SearchBound<&Q>,
+    ) -> Handle<NodeRef<BorrowType, K, V, marker::Leaf>, marker::Edge>
+    where
+        Q: Ord,
+        K: Borrow<Q>,
+    {
+        loop {
+            let (edge, new_bound) = self.find_upper_bound_edge(bound);
+            match edge.force() {
+                Leaf(edge) => return edge,
+                Internal(edge) => {
+                    self = edge.descend();
+                    bound = new_bound;
+                }
+            }
+        }
+    }
+
     /// Descends to the nearest node where the edge matching the lower bound
     /// of the range is different from the edge matching the upper bound, i.e.,
     /// the nearest node that has at least one key contained in the range.
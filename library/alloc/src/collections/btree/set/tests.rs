@@ -330,18 +330,18 @@ fn test_retain() {
 }
 
 #[test]
-fn test_drain_filter() {
+fn test_extract_if() {
     let mut x: BTreeSet<_> = [1].iter().copied().collect();
     let mut y: BTreeSet<_> = [1].iter().copied().collect();
 
-    x.drain_filter(|_| true);
-    y.drain_filter(|_| false);
+    x.extract_if(|_| true);
+    y.extract_if(|_| false);
     assert_eq!(x.len(), 0);
     assert_eq!(y.len(), 1);
 }
 
 #[test]
-fn test_drain_filter_drop_panic_leak() {
+fn test_extract_if_drop_panic_leak() {
     let a = CrashTestDummy::new(0);
     let b = CrashTestDummy::new(1);
     let c = CrashTestDummy::new(2);
@@ -350,7 +350,7 @@ fn test_drain_filter_drop_panic_leak() {
     set.insert(b.spawn(Panic::InDrop));
     set.insert(c.spawn(Panic::Never));
 
-    catch_unwind(move || drop(set.drain_filter(|dummy| dummy.query(true)))).ok();
+    catch_unwind(move || drop(set.extract_if(|dummy| dummy.query(true)))).ok();
 
     assert_eq!(a.queried(), 1);
     assert_eq!(b.queried(), 1);
@@ -361,7 +361,7 @@ fn test_drain_filter_drop_panic_leak() {
 }
 
 #[test]
-fn test_drain_filter_pred_panic_leak() {
+fn test_extract_if_pred_panic_leak() {
     let a = CrashTestDummy::new(0);
     let b = CrashTestDummy::new(1);
     let c = CrashTestDummy::new(2);
@@ -370,7 +370,7 @@ fn test_drain_filter_pred_panic_leak() {
     set.insert(b.spawn(Panic::InQuery));
     set.insert(c.spawn(Panic::InQuery));
 
-    catch_unwind(AssertUnwindSafe(|| drop(set.drain_filter(|dummy| dummy.query(true))))).ok();
+    catch_unwind(AssertUnwindSafe(|| drop(set.extract_if(|dummy| dummy.query(true))))).ok();
 
     assert_eq!(a.queried(), 1);
     assert_eq!(b.queried(), 1);
@@ -423,6 +423,21 @@ fn test_from_iter() {
     }
 }
 
+#[test]
+fn test_try_from_iter() {
+    let set = BTreeSet::try_from_iter([3, 1, 2, 1]).unwrap();
+    assert!(set.iter().copied().eq(1..=3));
+}
+
+#[test]
+fn test_try_insert() {
+    let mut set = BTreeSet::new();
+    assert_eq!(set.try_insert(2), Ok(true));
+    assert_eq!(set.try_insert(1), Ok(true));
+    assert_eq!(set.try_insert(2), Ok(false));
+    assert!(set.iter().copied().eq(1..=2));
+}
+
 #[test]
 fn test_show() {
     let mut set = BTreeSet::new();
@@ -547,8 +562,8 @@ fn range<T: Sync + Ord>(v: &BTreeSet<T>) -> impl Sync + '_ {
         v.range(..)
     }
 
-    fn drain_filter<T: Sync + Ord>(v: &mut BTreeSet<T>) -> impl Sync + '_ {
-        v.drain_filter(|_| false)
+    fn extract_if<T: Sync + Ord>(v: &mut BTreeSet<T>) -> impl Sync + '_ {
+        v.extract_if(|_| false)
     }
 
     fn difference<T: Sync + Ord>(v: &BTreeSet<T>) -> impl Sync + '_ {
@@ -586,8 +601,8 @@ fn range<T: Send + Sync + Ord>(v: &BTreeSet<T>) -> impl Send + '_ {
         v.range(..)
     }
 
-    fn drain_filter<T: Send + Ord>(v: &mut BTreeSet<T>) -> impl Send + '_ {
-        v.drain_filter(|_| false)
+    fn extract_if<T: Send + Ord>(v: &mut BTreeSet<T>) -> impl Send + '_ {
+        v.extract_if(|_| false)
     }
 
     fn difference<T: Send + Sync + Ord>(v: &BTreeSet<T>) -> impl Send + '_ {
@@ -744,3 +759,56 @@ fn from_array() {
     let unordered_duplicates = BTreeSet::from([4, 1, 4, 3, 2]);
     assert_eq!(set, unordered_duplicates);
 }
+
+#[test]
+fn test_cursor() {
+    let set = BTreeSet::from([1, 3, 5]);
+    let mut cur = set.lower_bound(Bound::Excluded(&1));
+    assert_eq!(cur.get(), Some(&3));
+    assert_eq!(cur.peek_prev(), Some(&1));
+    assert_eq!(cur.peek_next(), Some(&5));
+    cur.move_next();
+    cur.move_next();
+    assert_eq!(cur.get(), None);
+    assert_eq!(cur.peek_next(), Some(&1));
+    assert_eq!(set.upper_bound(Bound::Included(&4)).get(), Some(&3));
+}
+
+#[test]
+fn test_cursor_mut() {
+    let mut set = BTreeSet::from([1, 3, 5]);
+    let mut cur = set.upper_bound_mut(Bound::Included(&3));
+    assert_eq!(cur.get(), Some(&3));
+    cur.insert_after(4);
+    cur.insert_before(2);
+    assert_eq!(cur.get(), Some(&3));
+    assert_eq!(cur.as_cursor().peek_next(), Some(&4));
+    assert_eq!(cur.remove_current(), Some(3));
+    assert_eq!(cur.get(), Some(&4));
+    assert_eq!(cur.remove_current_and_move_back(), Some(4));
+    assert_eq!(cur.get(), Some(&2));
+    assert!(set.iter().eq(&[1, 2, 5]));
+}
+
+#[test]
+fn test_in_allocator() {
+    use std::alloc::System;
+
+    let mut a = BTreeSet::from_iter_in([5, 1, 3, 2], System);
+    let b = BTreeSet::from_iter_in(2..=4, System);
+    assert!((&a & &b).iter().eq(&[2, 3]));
+    assert!((&a - &b).iter().eq(&[1, 5]));
+    assert!((&a | &b).iter().eq(&[1, 2, 3, 4, 5]));
+    assert!((&a ^ &b).iter().eq(&[1, 4, 5]));
+
+    let copy = a.clone_in(System);
+    assert_eq!(copy, a);
+
+    let odds: Vec<_> = a.extract_if(|v| v % 2 != 0).collect();
+    assert_eq!(odds, [1, 3, 5]);
+    assert!(a.into_iter().eq([2]));
+
+    let mut c = BTreeSet::new_in(System);
+    assert!(c.insert(1));
+    assert!(!c.insert(1));
+}
@@ -2,16 +2,11 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
-#[cfg(not(no_global_oom_handling))]
 pub mod binary_heap;
-#[cfg(not(no_global_oom_handling))]
 mod btree;
-#[cfg(not(no_global_oom_handling))]
 pub mod linked_list;
-#[cfg(not(no_global_oom_handling))]
 pub mod vec_deque;
 
-#[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
 pub mod btree_map {
     //! A map based on a B-Tree.
@@ -19,7 +14,6 @@ pub mod btree_map {
     pub use super::btree::map::*;
 }
 
-#[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
 pub mod btree_set {
     //! A set based on a B-Tree.
@@ -27,27 +21,22 @@ pub mod btree_set {
     pub use super::btree::set::*;
 }
 
-#[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
 #[doc(no_inline)]
 pub use binary_heap::BinaryHeap;
 
-#[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
 #[doc(no_inline)]
 pub use btree_map::BTreeMap;
 
-#[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
 #[doc(no_inline)]
 pub use btree_set::BTreeSet;
 
-#[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
 #[doc(no_inline)]
 pub use linked_list::LinkedList;
 
-#[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
 #[doc(no_inline)]
 pub use vec_deque::VecDeque;
@@ -146,7 +135,71 @@ fn fmt(
     }
 }
 
+#[cfg(not(bootstrap))]
+#[stable(feature = "try_reserve", since = "1.57.0")]
+impl core::error::Error for TryReserveError {}
+
+/// Conversion from an [`Iterator`] that returns an error instead of aborting
+/// when allocating memory fails.
+///
+/// This is the fallible counterpart of [`FromIterator`]. It is usually used
+/// through [`TryCollect::try_collect`].
+///
+/// [`FromIterator`]: core::iter::FromIterator
+#[unstable(feature = "try_alloc", reason = "new API", issue = "none")]
+pub trait TryFromIterator<A>: Sized {
+    /// Creates a value from an iterator, returning an error if allocating
+    /// memory for it fails.
+    ///
+    /// If an error is returned, the items taken from the iterator so far are
+    /// dropped.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an
+    /// error is returned.
+    fn try_from_iter<I: IntoIterator<Item = A>>(iter: I) -> Result<Self, TryReserveError>;
+}
+
+/// Adds [`try_collect`](TryCollect::try_collect) to every [`Iterator`].
+#[unstable(feature = "try_alloc", reason = "new API", issue = "none")]
+pub trait TryCollect: Iterator + Sized {
+    /// Transforms an iterator into a collection, returning an error instead of
+    /// aborting if allocating memory for it fails.
+    ///
+    /// This is the fallible counterpart of [`Iterator::collect`], for the
+    /// collections that implement [`TryFromIterator`].
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an
+    /// error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(try_alloc)]
+    /// use std::collections::{TryCollect, VecDeque};
+    ///
+    /// let doubled: Vec<i32> = (1..4).map(|x| x * 2).try_collect().unwrap();
+    /// assert_eq!(doubled, [2, 4, 6]);
+    ///
+    /// let deque: VecDeque<char> = "abc".chars().try_collect().unwrap();
+    /// assert_eq!(deque, ['a', 'b', 'c']);
+    ///
+    /// let s: String = "abc".chars().rev().try_collect().unwrap();
+    /// assert_eq!(s, "cba");
+    /// ```
+    fn try_collect<B: TryFromIterator<Self::Item>>(self) -> Result<B, TryReserveError> {
+        B::try_from_iter(self)
+    }
+}
+
+#[unstable(feature = "try_alloc", reason = "new API", issue = "none")]
+impl<I: Iterator> TryCollect for I {}
+
 /// An intermediate trait for specialization of `Extend`.
+#[cfg(not(no_global_oom_handling))]
 #[doc(hidden)]
 trait SpecExtend<I: IntoIterator> {
     /// Extends `self` with the contents of the given iterator.
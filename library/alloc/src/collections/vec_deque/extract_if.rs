@@ -0,0 +1,162 @@
+use core::ops::{Range, RangeBounds};
+use core::ptr;
+use core::slice;
+
+use crate::alloc::{Allocator, Global};
+
+use super::VecDeque;
+
+/// An iterator which uses a closure to determine if an element should be removed.
+///
+/// This struct is created by [`VecDeque::extract_if`].
+/// See its documentation for more.
+///
+/// # Example
+///
+/// ```
+/// #![feature(vec_deque_extract_if)]
+///
+/// use std::collections::vec_deque::ExtractIf;
+/// use std::collections::VecDeque;
+///
+/// let mut v = VecDeque::from(vec![0, 1, 2]);
+/// let iter: ExtractIf<'_, _, _> = v.extract_if(.., |x| *x % 2 == 0);
+/// ```
+#[unstable(feature = "vec_deque_extract_if", issue = "none")]
+#[derive(Debug)]
+pub struct ExtractIf<
+    'a,
+    T,
+    F,
+    #[unstable(feature = "allocator_api", issue = "32838")] A: Allocator = Global,
+> where
+    F: FnMut(&mut T) -> bool,
+{
+    deque: &'a mut VecDeque<T, A>,
+    /// The logical index of the item that will be inspected by the next call to `next`.
+    idx: usize,
+    /// Elements at and beyond this point will be retained. Must be equal or smaller than `old_len`.
+    end: usize,
+    /// The number of items that have been drained (removed) thus far.
+    del: usize,
+    /// The original length of `deque` prior to draining.
+    old_len: usize,
+    /// The filter test predicate.
+    pred: F,
+    /// A flag that indicates a panic has occurred in the filter test predicate.
+    /// This stops both `next` and the drop implementation from consuming the
+    /// remainder of the `ExtractIf`.
+    panic_flag: bool,
+}
+
+impl<'a, T, F, A: Allocator> ExtractIf<'a, T, F, A>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    pub(super) fn new<R: RangeBounds<usize>>(
+        deque: &'a mut VecDeque<T, A>,
+        pred: F,
+        range: R,
+    ) -> Self {
+        let old_len = deque.len();
+        let Range { start, end } = slice::range(range, ..old_len);
+
+        // Guard against the deque getting leaked (leak amplification). The
+        // elements stay in place behind `tail`, and `head` is restored on drop.
+        deque.head = deque.tail;
+
+        ExtractIf { deque, idx: start, end, del: 0, old_len, pred, panic_flag: false }
+    }
+
+    /// Returns a reference to the underlying allocator.
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        self.deque.allocator()
+    }
+}
+
+#[unstable(feature = "vec_deque_extract_if", issue = "none")]
+impl<T, F, A: Allocator> Iterator for ExtractIf<'_, T, F, A>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        // Once the predicate panicked, it isn't called again.
+        if self.panic_flag {
+            return None;
+        }
+        unsafe {
+            while self.idx < self.end {
+                let off = self.deque.wrap_add(self.deque.tail, self.idx);
+                let cur = self.deque.ptr().add(off);
+                self.panic_flag = true;
+                let drained = (self.pred)(&mut *cur);
+                self.panic_flag = false;
+                // Update the index *after* the predicate is called. If the index
+                // is updated prior and the predicate panics, the element at this
+                // index would be leaked.
+                self.idx += 1;
+                if drained {
+                    self.del += 1;
+                    return Some(ptr::read(cur));
+                } else if self.del > 0 {
+                    let dst = self.deque.wrap_sub(off, self.del);
+                    self.deque.copy_nonoverlapping(dst, off, 1);
+                }
+            }
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end - self.idx))
+    }
+}
+
+#[unstable(feature = "vec_deque_extract_if", issue = "none")]
+impl<T, F, A: Allocator> Drop for ExtractIf<'_, T, F, A>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        struct BackshiftOnDrop<'a, 'b, T, F, A: Allocator>
+        where
+            F: FnMut(&mut T) -> bool,
+        {
+            extract: &'b mut ExtractIf<'a, T, F, A>,
+        }
+
+        impl<'a, 'b, T, F, A: Allocator> Drop for BackshiftOnDrop<'a, 'b, T, F, A>
+        where
+            F: FnMut(&mut T) -> bool,
+        {
+            fn drop(&mut self) {
+                let extract = &mut *self.extract;
+                let deque = &mut *extract.deque;
+                unsafe {
+                    if extract.idx < extract.old_len && extract.del > 0 {
+                        // Close the hole left by the drained elements, moving up
+                        // everything that was not visited, including the elements
+                        // past the end of the range.
+                        let src = deque.wrap_add(deque.tail, extract.idx);
+                        let dst = deque.wrap_sub(src, extract.del);
+                        deque.wrap_copy(dst, src, extract.old_len - extract.idx);
+                    }
+                }
+                deque.head = deque.wrap_add(deque.tail, extract.old_len - extract.del);
+            }
+        }
+
+        let backshift = BackshiftOnDrop { extract: self };
+
+        // Attempt to consume any remaining elements in the range if the filter
+        // predicate has not yet panicked. We'll backshift any remaining elements
+        // whether we've already panicked or if the consumption here panics.
+        if !backshift.extract.panic_flag {
+            backshift.extract.for_each(drop);
+        }
+    }
+}
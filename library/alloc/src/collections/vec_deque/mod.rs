@@ -10,6 +10,7 @@
 use core::cmp::{self, Ordering};
 use core::fmt;
 use core::hash::{Hash, Hasher};
+#[cfg(not(no_global_oom_handling))]
 use core::iter::{repeat_with, FromIterator};
 use core::marker::PhantomData;
 use core::mem::{self, ManuallyDrop};
@@ -18,6 +19,7 @@
 use core::slice;
 
 use crate::alloc::{Allocator, Global};
+use crate::collections::TryFromIterator;
 use crate::collections::TryReserveError;
 use crate::collections::TryReserveErrorKind;
 use crate::raw_vec::RawVec;
@@ -31,6 +33,11 @@
 
 mod drain;
 
+#[unstable(feature = "vec_deque_extract_if", issue = "none")]
+pub use self::extract_if::ExtractIf;
+
+mod extract_if;
+
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::iter_mut::IterMut;
 
@@ -46,8 +53,10 @@
 
 mod iter;
 
+#[cfg(not(no_global_oom_handling))]
 use self::pair_slices::PairSlices;
 
+#[cfg(not(no_global_oom_handling))]
 mod pair_slices;
 
 use self::ring_slices::RingSlices;
@@ -57,6 +66,7 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(not(no_global_oom_handling))]
 const INITIAL_CAPACITY: usize = 7; // 2^3 - 1
 const MINIMUM_CAPACITY: usize = 1; // 2 - 1
 
@@ -105,6 +115,7 @@ pub struct VecDeque<
     buf: RawVec<T, A>,
 }
 
+#[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: Clone, A: Allocator + Clone> Clone for VecDeque<T, A> {
     fn clone(&self) -> Self {
@@ -154,6 +165,7 @@ fn drop(&mut self) {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T> Default for VecDeque<T> {
     /// Creates an empty `VecDeque<T>`.
@@ -215,6 +227,7 @@ fn is_full(&self) -> bool {
 
     /// Returns the index in the underlying buffer for a given logical element
     /// index.
+    #[cfg(not(no_global_oom_handling))]
     #[inline]
     fn wrap_index(&self, idx: usize) -> usize {
         wrap_index(idx, self.cap())
@@ -420,6 +433,7 @@ fn diff(a: usize, b: usize) -> usize {
 
     /// Copies all values from `src` to `dst`, wrapping around if needed.
     /// Assumes capacity is sufficient.
+    #[cfg(not(no_global_oom_handling))]
     #[inline]
     unsafe fn copy_slice(&mut self, dst: usize, src: &[T]) {
         debug_assert!(src.len() <= self.cap());
@@ -492,6 +506,7 @@ impl<T> VecDeque<T> {
     ///
     /// let vector: VecDeque<u32> = VecDeque::new();
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
     #[must_use]
@@ -508,12 +523,68 @@ pub fn new() -> VecDeque<T> {
     ///
     /// let vector: VecDeque<u32> = VecDeque::with_capacity(10);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
     #[must_use]
     pub fn with_capacity(capacity: usize) -> VecDeque<T> {
         Self::with_capacity_in(capacity, Global)
     }
+
+    /// Creates an empty `VecDeque` with space for at least `capacity` elements,
+    /// returning an error if the allocation fails.
+    ///
+    /// This is the fallible counterpart of [`VecDeque::with_capacity`].
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(try_alloc)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let vector: VecDeque<u32> = VecDeque::try_with_capacity(10).unwrap();
+    /// assert!(vector.capacity() >= 10);
+    /// ```
+    #[inline]
+    #[unstable(feature = "try_alloc", reason = "new API", issue = "none")]
+    pub fn try_with_capacity(capacity: usize) -> Result<VecDeque<T>, TryReserveError> {
+        Self::try_with_capacity_in(capacity, Global)
+    }
+
+    /// Creates a `VecDeque` from an iterator, returning an error if allocating
+    /// memory for the elements fails.
+    ///
+    /// This is the fallible counterpart of collecting into a `VecDeque`. If an
+    /// error is returned, the elements taken from the iterator so far are dropped.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(try_alloc)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let deque = VecDeque::try_from_iter(1..4).unwrap();
+    /// assert_eq!(deque, [1, 2, 3]);
+    /// ```
+    #[unstable(feature = "try_alloc", reason = "new API", issue = "none")]
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, TryReserveError> {
+        let iter = iter.into_iter();
+        let mut deq = VecDeque::try_with_capacity(iter.size_hint().0)?;
+        for value in iter {
+            deq.try_push_back(value).map_err(|(_, err)| err)?;
+        }
+        Ok(deq)
+    }
 }
 
 impl<T, A: Allocator> VecDeque<T, A> {
@@ -526,6 +597,7 @@ impl<T, A: Allocator> VecDeque<T, A> {
     ///
     /// let vector: VecDeque<u32> = VecDeque::new();
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[inline]
     #[unstable(feature = "allocator_api", issue = "32838")]
     pub fn new_in(alloc: A) -> VecDeque<T, A> {
@@ -541,6 +613,7 @@ pub fn new_in(alloc: A) -> VecDeque<T, A> {
     ///
     /// let vector: VecDeque<u32> = VecDeque::with_capacity(10);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[unstable(feature = "allocator_api", issue = "32838")]
     pub fn with_capacity_in(capacity: usize, alloc: A) -> VecDeque<T, A> {
         assert!(capacity < 1_usize << usize::BITS - 1, "capacity overflow");
@@ -550,6 +623,88 @@ pub fn with_capacity_in(capacity: usize, alloc: A) -> VecDeque<T, A> {
         VecDeque { tail: 0, head: 0, buf: RawVec::with_capacity_in(cap, alloc) }
     }
 
+    /// Creates an empty `VecDeque` with space for at least `capacity` elements,
+    /// returning an error if the allocation fails.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocator_api, try_alloc)]
+    ///
+    /// use std::alloc::System;
+    /// use std::collections::VecDeque;
+    ///
+    /// let vector: VecDeque<u32, _> = VecDeque::try_with_capacity_in(10, System).unwrap();
+    /// ```
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    pub fn try_with_capacity_in(
+        capacity: usize,
+        alloc: A,
+    ) -> Result<VecDeque<T, A>, TryReserveError> {
+        if capacity >= 1_usize << usize::BITS - 1 {
+            return Err(TryReserveErrorKind::CapacityOverflow.into());
+        }
+        // +1 since the ringbuffer always leaves one space empty
+        let cap = cmp::max(capacity + 1, MINIMUM_CAPACITY + 1).next_power_of_two();
+
+        Ok(VecDeque { tail: 0, head: 0, buf: RawVec::try_with_capacity_in(cap, alloc)? })
+    }
+
+    /// Creates a `VecDeque` from the elements of `iter`, allocating its buffer in `alloc`.
+    ///
+    /// This is the allocator-aware counterpart of [`FromIterator::from_iter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocator_api)]
+    ///
+    /// use std::alloc::System;
+    /// use std::collections::VecDeque;
+    ///
+    /// let deque = VecDeque::from_iter_in(1..=3, System);
+    /// assert_eq!(deque, [1, 2, 3]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    pub fn from_iter_in<I: IntoIterator<Item = T>>(iter: I, alloc: A) -> VecDeque<T, A> {
+        let iterator = iter.into_iter();
+        let (lower, _) = iterator.size_hint();
+        let mut deq = VecDeque::with_capacity_in(lower, alloc);
+        deq.extend(iterator);
+        deq
+    }
+
+    /// Clones the `VecDeque`, allocating the buffer of the clone in `alloc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocator_api)]
+    ///
+    /// use std::alloc::System;
+    /// use std::collections::VecDeque;
+    ///
+    /// let deque = VecDeque::from([1, 2, 3]);
+    /// let copy = deque.clone_in(System);
+    /// assert_eq!(copy, [1, 2, 3]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    pub fn clone_in<B: Allocator>(&self, alloc: B) -> VecDeque<T, B>
+    where
+        T: Clone,
+    {
+        let mut deq = VecDeque::with_capacity_in(self.len(), alloc);
+        deq.extend(self.iter().cloned());
+        deq
+    }
+
     /// Provides a reference to the element at the given index.
     ///
     /// Element at index 0 is the front of the queue.
@@ -675,6 +830,7 @@ pub fn capacity(&self) -> usize {
     /// ```
     ///
     /// [`reserve`]: VecDeque::reserve
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn reserve_exact(&mut self, additional: usize) {
         self.reserve(additional);
@@ -696,6 +852,7 @@ pub fn reserve_exact(&mut self, additional: usize) {
     /// buf.reserve(10);
     /// assert!(buf.capacity() >= 11);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn reserve(&mut self, additional: usize) {
         let old_cap = self.cap();
@@ -821,6 +978,7 @@ pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError>
     /// buf.shrink_to_fit();
     /// assert!(buf.capacity() >= 4);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "deque_extras_15", since = "1.5.0")]
     pub fn shrink_to_fit(&mut self) {
         self.shrink_to(0);
@@ -846,6 +1004,7 @@ pub fn shrink_to_fit(&mut self) {
     /// buf.shrink_to(0);
     /// assert!(buf.capacity() >= 4);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "shrink_to", since = "1.56.0")]
     pub fn shrink_to(&mut self, min_capacity: usize) {
         let min_capacity = cmp::min(min_capacity, self.capacity());
@@ -1492,12 +1651,20 @@ pub fn pop_back(&mut self) -> Option<T> {
     /// d.push_front(2);
     /// assert_eq!(d.front(), Some(&2));
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn push_front(&mut self, value: T) {
         if self.is_full() {
             self.grow();
         }
 
+        self.push_front_within_capacity(value);
+    }
+
+    /// Prepends an element to a `VecDeque` that has room for it.
+    fn push_front_within_capacity(&mut self, value: T) {
+        debug_assert!(!self.is_full());
+
         self.tail = self.wrap_sub(self.tail, 1);
         let tail = self.tail;
         unsafe {
@@ -1505,6 +1672,38 @@ pub fn push_front(&mut self, value: T) {
         }
     }
 
+    /// Prepends an element to the `VecDeque`, or returns an error if growing
+    /// the deque fails.
+    ///
+    /// This is the fallible counterpart of [`VecDeque::push_front`]. If an
+    /// error is returned, the deque is left unchanged and `value` is handed back
+    /// along with it.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(try_alloc)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut d = VecDeque::new();
+    /// d.try_push_front(1).unwrap();
+    /// d.try_push_front(2).unwrap();
+    /// assert_eq!(d.front(), Some(&2));
+    /// ```
+    #[unstable(feature = "try_alloc", reason = "new API", issue = "none")]
+    pub fn try_push_front(&mut self, value: T) -> Result<(), (T, TryReserveError)> {
+        if let Err(err) = self.try_grow() {
+            return Err((value, err));
+        }
+        self.push_front_within_capacity(value);
+        Ok(())
+    }
+
     /// Appends an element to the back of the `VecDeque`.
     ///
     /// # Examples
@@ -1517,17 +1716,57 @@ pub fn push_front(&mut self, value: T) {
     /// buf.push_back(3);
     /// assert_eq!(3, *buf.back().unwrap());
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn push_back(&mut self, value: T) {
         if self.is_full() {
             self.grow();
         }
 
+        self.push_back_within_capacity(value);
+    }
+
+    /// Appends an element to a `VecDeque` that has room for it.
+    fn push_back_within_capacity(&mut self, value: T) {
+        debug_assert!(!self.is_full());
+
         let head = self.head;
         self.head = self.wrap_add(self.head, 1);
         unsafe { self.buffer_write(head, value) }
     }
 
+    /// Appends an element to the back of the `VecDeque`, or returns an error if
+    /// growing the deque fails.
+    ///
+    /// This is the fallible counterpart of [`VecDeque::push_back`]. If an
+    /// error is returned, the deque is left unchanged and `value` is handed back
+    /// along with it.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(try_alloc)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf = VecDeque::new();
+    /// buf.try_push_back(1).unwrap();
+    /// buf.try_push_back(3).unwrap();
+    /// assert_eq!(3, *buf.back().unwrap());
+    /// ```
+    #[unstable(feature = "try_alloc", reason = "new API", issue = "none")]
+    pub fn try_push_back(&mut self, value: T) -> Result<(), (T, TryReserveError)> {
+        if let Err(err) = self.try_grow() {
+            return Err((value, err));
+        }
+        self.push_back_within_capacity(value);
+        Ok(())
+    }
+
     #[inline]
     fn is_contiguous(&self) -> bool {
         // FIXME: Should we consider `head == 0` to mean
@@ -1628,6 +1867,7 @@ pub fn swap_remove_back(&mut self, index: usize) -> Option<T> {
     /// vec_deque.insert(1, 'd');
     /// assert_eq!(vec_deque, &['a', 'd', 'b', 'c']);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "deque_extras_15", since = "1.5.0")]
     pub fn insert(&mut self, index: usize, value: T) {
         assert!(index <= self.len(), "index out of bounds");
@@ -1635,6 +1875,13 @@ pub fn insert(&mut self, index: usize, value: T) {
             self.grow();
         }
 
+        self.insert_within_capacity(index, value);
+    }
+
+    /// Inserts an element into a `VecDeque` that has room for it.
+    fn insert_within_capacity(&mut self, index: usize, value: T) {
+        debug_assert!(!self.is_full());
+
         // Move the least number of elements in the ring buffer and insert
         // the given object
         //
@@ -1829,6 +2076,43 @@ pub fn insert(&mut self, index: usize, value: T) {
         }
     }
 
+    /// Inserts an element at `index` within the `VecDeque`, shifting all elements
+    /// with indices greater than or equal to `index` towards the back, or returns
+    /// an error if growing the deque fails.
+    ///
+    /// This is the fallible counterpart of [`VecDeque::insert`]. If an error is
+    /// returned, the deque is left unchanged and `value` is handed back along
+    /// with it.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than `VecDeque`'s length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(try_alloc)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut vec_deque = VecDeque::from(vec!['a', 'b', 'c']);
+    /// vec_deque.try_insert(1, 'd').unwrap();
+    /// assert_eq!(vec_deque, &['a', 'd', 'b', 'c']);
+    /// ```
+    #[unstable(feature = "try_alloc", reason = "new API", issue = "none")]
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), (T, TryReserveError)> {
+        assert!(index <= self.len(), "index out of bounds");
+        if let Err(err) = self.try_grow() {
+            return Err((value, err));
+        }
+        self.insert_within_capacity(index, value);
+        Ok(())
+    }
+
     /// Removes and returns the element at `index` from the `VecDeque`.
     /// Whichever end is closer to the removal point will be moved to make
     /// room, and all the affected elements will be moved to new positions.
@@ -2030,6 +2314,7 @@ pub fn remove(&mut self, index: usize) -> Option<T> {
     /// assert_eq!(buf, [1]);
     /// assert_eq!(buf2, [2, 3]);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[inline]
     #[must_use = "use `.truncate()` if you don't need the other half"]
     #[stable(feature = "split_off", since = "1.4.0")]
@@ -2097,6 +2382,7 @@ pub fn split_off(&mut self, at: usize) -> Self
     /// assert_eq!(buf, [1, 2, 3, 4]);
     /// assert_eq!(buf2, []);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[inline]
     #[stable(feature = "append", since = "1.4.0")]
     pub fn append(&mut self, other: &mut Self) {
@@ -2179,7 +2465,71 @@ pub fn retain<F>(&mut self, mut f: F)
         }
     }
 
+    /// Creates an iterator which uses a closure to determine if an element in the range should be
+    /// removed.
+    ///
+    /// If the closure returns true, then the element is removed and yielded.
+    /// If the closure returns false, the element will remain in the deque and will not be yielded
+    /// by the iterator.
+    ///
+    /// Only elements that fall in the provided range are considered for extraction, but any
+    /// elements after the range will still have to be moved if any element has been extracted.
+    ///
+    /// If the returned `ExtractIf` is only partially consumed or not consumed at all, each of the
+    /// remaining elements in the range is still subjected to the closure when it is dropped, and
+    /// removed and dropped if the closure returns true.
+    ///
+    /// If the closure panics, the element it was called on and all elements that have not been
+    /// visited yet are retained, and the closure is not called again, not even if the panic is
+    /// caught and the iterator is used afterwards: it then yields no more elements. Elements that
+    /// were already removed stay removed.
+    ///
+    /// Note that `extract_if` also lets you mutate the elements passed to the filter closure,
+    /// regardless of whether you choose to keep or remove them.
+    ///
+    /// # Panics
+    ///
+    /// If `range` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// Splitting a deque into evens and odds, reusing the original allocation:
+    ///
+    /// ```
+    /// #![feature(vec_deque_extract_if)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut numbers = VecDeque::from(vec![1, 2, 3, 4, 5, 6, 8, 9, 11, 13, 14, 15]);
+    ///
+    /// let evens = numbers.extract_if(.., |x| *x % 2 == 0).collect::<VecDeque<_>>();
+    /// let odds = numbers;
+    ///
+    /// assert_eq!(evens, [2, 4, 6, 8, 14]);
+    /// assert_eq!(odds, [1, 3, 5, 9, 11, 13, 15]);
+    /// ```
+    ///
+    /// Using the range argument to only process a part of the deque:
+    ///
+    /// ```
+    /// #![feature(vec_deque_extract_if)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut items = VecDeque::from(vec![0, 0, 0, 0, 0, 0, 0, 1, 2, 1, 2, 1, 2]);
+    /// let ones = items.extract_if(7.., |x| *x == 1).collect::<Vec<_>>();
+    /// assert_eq!(items, [0, 0, 0, 0, 0, 0, 0, 2, 2, 2]);
+    /// assert_eq!(ones.len(), 3);
+    /// ```
+    #[unstable(feature = "vec_deque_extract_if", issue = "none")]
+    pub fn extract_if<F, R>(&mut self, range: R, filter: F) -> ExtractIf<'_, T, F, A>
+    where
+        F: FnMut(&mut T) -> bool,
+        R: RangeBounds<usize>,
+    {
+        ExtractIf::new(self, filter, range)
+    }
+
     // This may panic or abort
+    #[cfg(not(no_global_oom_handling))]
     #[inline(never)]
     fn grow(&mut self) {
         if self.is_full() {
@@ -2194,6 +2544,21 @@ fn grow(&mut self) {
         }
     }
 
+    // Fallible counterpart of `grow`; leaves `self` untouched on error.
+    fn try_grow(&mut self) -> Result<(), TryReserveError> {
+        if self.is_full() {
+            let old_cap = self.cap();
+            // Double the buffer size.
+            self.buf.try_reserve_exact(old_cap, old_cap)?;
+            assert!(self.cap() == old_cap * 2);
+            unsafe {
+                self.handle_capacity_increase(old_cap);
+            }
+            debug_assert!(!self.is_full());
+        }
+        Ok(())
+    }
+
     /// Modifies the `VecDeque` in-place so that `len()` is equal to `new_len`,
     /// either by removing excess elements from the back or by appending
     /// elements generated by calling `generator` to the back.
@@ -2219,6 +2584,7 @@ fn grow(&mut self) {
     /// buf.resize_with(5, || { state += 1; state });
     /// assert_eq!(buf, [5, 10, 101, 102, 103]);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "vec_resize_with", since = "1.33.0")]
     pub fn resize_with(&mut self, new_len: usize, generator: impl FnMut() -> T) {
         let len = self.len();
@@ -2712,6 +3078,7 @@ impl<T: Clone, A: Allocator> VecDeque<T, A> {
     /// buf.resize(5, 20);
     /// assert_eq!(buf, [5, 10, 20, 20, 20]);
     /// ```
+    #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "deque_extras", since = "1.16.0")]
     pub fn resize(&mut self, new_len: usize, value: T) {
         self.resize_with(new_len, || value.clone());
@@ -2829,6 +3196,7 @@ fn index_mut(&mut self, index: usize) -> &mut T {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T> FromIterator<T> for VecDeque<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> VecDeque<T> {
@@ -2840,6 +3208,14 @@ fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> VecDeque<T> {
     }
 }
 
+#[unstable(feature = "try_alloc", reason = "new API", issue = "none")]
+impl<T> TryFromIterator<T> for VecDeque<T> {
+    #[inline]
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, TryReserveError> {
+        VecDeque::try_from_iter(iter)
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T, A: Allocator> IntoIterator for VecDeque<T, A> {
     type Item = T;
@@ -2872,6 +3248,7 @@ fn into_iter(self) -> IterMut<'a, T> {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T, A: Allocator> Extend<T> for VecDeque<T, A> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
@@ -2906,6 +3283,7 @@ fn extend_reserve(&mut self, additional: usize) {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
 #[stable(feature = "extend_ref", since = "1.2.0")]
 impl<'a, T: 'a + Copy, A: Allocator> Extend<&'a T> for VecDeque<T, A> {
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
@@ -2930,6 +3308,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
 #[stable(feature = "vecdeque_vec_conversions", since = "1.10.0")]
 impl<T, A: Allocator> From<Vec<T, A>> for VecDeque<T, A> {
     /// Turn a [`Vec<T>`] into a [`VecDeque<T>`].
@@ -3014,6 +3393,7 @@ fn from(mut other: VecDeque<T, A>) -> Self {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
 #[stable(feature = "std_collections_from_array", since = "1.56.0")]
 impl<T, const N: usize> From<[T; N]> for VecDeque<T> {
     /// ```
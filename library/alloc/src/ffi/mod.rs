@@ -0,0 +1,37 @@
+//! Utilities related to FFI bindings.
+//!
+//! This module provides the owned C string type, [`CString`], and re-exports
+//! the borrowed [`CStr`] from [`core::ffi`]. They are re-exported from
+//! `std::ffi`, whose documentation describes how they relate to the other FFI
+//! string types.
+//!
+//! The C integer type aliases, such as [`c_char`](core::ffi::c_char), also
+//! live in [`core::ffi`].
+
+#![unstable(feature = "alloc_ffi", issue = "none")]
+
+#[cfg(bootstrap)]
+#[stable(feature = "rust1", since = "1.0.0")]
+pub use self::core_c_str::CStr;
+#[cfg(bootstrap)]
+#[stable(feature = "cstr_from_bytes", since = "1.10.0")]
+pub use self::core_c_str::FromBytesWithNulError;
+#[cfg(not(bootstrap))]
+#[stable(feature = "rust1", since = "1.0.0")]
+pub use core::ffi::CStr;
+#[cfg(not(bootstrap))]
+#[stable(feature = "cstr_from_bytes", since = "1.10.0")]
+pub use core::ffi::FromBytesWithNulError;
+
+#[stable(feature = "cstring_from_vec_with_nul", since = "1.58.0")]
+pub use self::c_str::FromVecWithNulError;
+#[stable(feature = "rust1", since = "1.0.0")]
+pub use self::c_str::{CString, IntoStringError, NulError};
+
+mod c_str;
+
+// The bootstrap compiler cannot register `alloc`'s inherent `CStr` methods on
+// a type from `core`, so until then `CStr` is still defined in this crate.
+#[cfg(bootstrap)]
+#[path = "../../../core/src/ffi/c_str.rs"]
+mod core_c_str;
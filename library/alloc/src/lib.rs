@@ -94,12 +94,19 @@
 #![feature(array_chunks)]
 #![feature(array_methods)]
 #![feature(array_windows)]
-#![feature(async_stream)]
+#![feature(async_io)]
+#![feature(async_iterator)]
 #![feature(coerce_unsized)]
-#![cfg_attr(not(no_global_oom_handling), feature(const_btree_new))]
+#![feature(const_btree_new)]
 #![feature(const_cow_is_borrowed)]
+#![feature(const_cstr_unchecked)]
+#![feature(const_slice_from_raw_parts)]
+#![cfg_attr(not(bootstrap), feature(core_c_str))]
+#![feature(core_ffi_c)]
 #![feature(core_intrinsics)]
+#![feature(core_io)]
 #![feature(dispatch_from_dyn)]
+#![cfg_attr(not(bootstrap), feature(error_in_core))]
 #![feature(exact_size_is_empty)]
 #![feature(extend_one)]
 #![feature(fmt_internals)]
@@ -114,9 +121,11 @@
 #![cfg_attr(test, feature(new_uninit))]
 #![feature(nonnull_slice_from_raw_parts)]
 #![feature(pattern)]
+#![feature(provide_any)]
 #![feature(ptr_internals)]
 #![feature(receiver_trait)]
 #![feature(set_ptr_value)]
+#![feature(slice_internals)]
 #![feature(slice_group_by)]
 #![feature(slice_ptr_get)]
 #![feature(slice_ptr_len)]
@@ -136,6 +145,7 @@
 #![feature(cfg_sanitize)]
 #![feature(cfg_target_has_atomic)]
 #![feature(const_fn_trait_bound)]
+#![feature(const_raw_ptr_deref)]
 #![feature(const_trait_impl)]
 #![feature(destructuring_assignment)]
 #![feature(dropck_eyepatch)]
@@ -191,6 +201,8 @@ mod boxed {
 }
 pub mod borrow;
 pub mod collections;
+#[cfg(not(no_global_oom_handling))]
+pub mod ffi;
 pub mod fmt;
 pub mod raw_vec;
 pub mod rc;
@@ -242,6 +242,34 @@ fn test_cowarc_clone_weak() {
     assert!(cow1_weak.upgrade().is_none());
 }
 
+#[test]
+fn test_try_make_mut() {
+    let mut cow0 = Arc::new(75);
+    let cow1 = cow0.clone();
+    let cow2_weak = Arc::downgrade(&cow0);
+
+    *Arc::try_make_mut(&mut cow0).unwrap() += 1;
+    assert!(76 == *cow0);
+    assert!(75 == *cow1);
+
+    drop(cow1);
+    *Arc::try_make_mut(&mut cow0).unwrap() += 1;
+    assert!(77 == *cow0);
+    assert!(cow2_weak.upgrade().is_none());
+}
+
+#[test]
+fn test_try_new_slices() {
+    let zeroed = Arc::<[u32]>::try_new_zeroed_slice(3).unwrap();
+    assert_eq!(*unsafe { zeroed.assume_init() }, [0, 0, 0]);
+
+    let uninit = Arc::<[u32]>::try_new_uninit_slice(3).unwrap();
+    assert_eq!(uninit.len(), 3);
+
+    assert!(Arc::<[u64]>::try_new_uninit_slice(usize::MAX / 4).is_err());
+    assert!(Arc::<[u64]>::try_new_zeroed_slice(usize::MAX / 4).is_err());
+}
+
 #[test]
 fn test_live() {
     let x = Arc::new(5);
@@ -1,4 +1,5 @@
 use crate::alloc::{Allocator, Global};
+use core::ops::{Range, RangeBounds};
 use core::ptr::{self};
 use core::slice::{self};
 
@@ -6,20 +7,20 @@
 
 /// An iterator which uses a closure to determine if an element should be removed.
 ///
-/// This struct is created by [`Vec::drain_filter`].
+/// This struct is created by [`Vec::extract_if`].
 /// See its documentation for more.
 ///
 /// # Example
 ///
 /// ```
-/// #![feature(drain_filter)]
+/// #![feature(extract_if)]
 ///
 /// let mut v = vec![0, 1, 2];
-/// let iter: std::vec::DrainFilter<_, _> = v.drain_filter(|x| *x % 2 == 0);
+/// let iter: std::vec::ExtractIf<'_, _, _> = v.extract_if(.., |x| *x % 2 == 0);
 /// ```
-#[unstable(feature = "drain_filter", reason = "recently added", issue = "43244")]
+#[unstable(feature = "extract_if", reason = "recently added", issue = "43244")]
 #[derive(Debug)]
-pub struct DrainFilter<
+pub struct ExtractIf<
     'a,
     T,
     F,
@@ -27,27 +28,40 @@ pub struct DrainFilter<
 > where
     F: FnMut(&mut T) -> bool,
 {
-    pub(super) vec: &'a mut Vec<T, A>,
+    vec: &'a mut Vec<T, A>,
     /// The index of the item that will be inspected by the next call to `next`.
-    pub(super) idx: usize,
+    idx: usize,
+    /// Elements at and beyond this point will be retained. Must be equal or smaller than `old_len`.
+    end: usize,
     /// The number of items that have been drained (removed) thus far.
-    pub(super) del: usize,
+    del: usize,
     /// The original length of `vec` prior to draining.
-    pub(super) old_len: usize,
+    old_len: usize,
     /// The filter test predicate.
-    pub(super) pred: F,
+    pred: F,
     /// A flag that indicates a panic has occurred in the filter test predicate.
-    /// This is used as a hint in the drop implementation to prevent consumption
-    /// of the remainder of the `DrainFilter`. Any unprocessed items will be
+    /// This stops both `next` and the drop implementation from consuming the
+    /// remainder of the `ExtractIf`. Any unprocessed items will be
     /// backshifted in the `vec`, but no further items will be dropped or
     /// tested by the filter predicate.
-    pub(super) panic_flag: bool,
+    panic_flag: bool,
 }
 
-impl<T, F, A: Allocator> DrainFilter<'_, T, F, A>
+impl<'a, T, F, A: Allocator> ExtractIf<'a, T, F, A>
 where
     F: FnMut(&mut T) -> bool,
 {
+    pub(super) fn new<R: RangeBounds<usize>>(vec: &'a mut Vec<T, A>, pred: F, range: R) -> Self {
+        let old_len = vec.len();
+        let Range { start, end } = slice::range(range, ..old_len);
+
+        // Guard against the vec getting leaked (leak amplification)
+        unsafe {
+            vec.set_len(0);
+        }
+        ExtractIf { vec, idx: start, del: 0, end, old_len, pred, panic_flag: false }
+    }
+
     /// Returns a reference to the underlying allocator.
     #[unstable(feature = "allocator_api", issue = "32838")]
     #[inline]
@@ -56,16 +70,20 @@ pub fn allocator(&self) -> &A {
     }
 }
 
-#[unstable(feature = "drain_filter", reason = "recently added", issue = "43244")]
-impl<T, F, A: Allocator> Iterator for DrainFilter<'_, T, F, A>
+#[unstable(feature = "extract_if", reason = "recently added", issue = "43244")]
+impl<T, F, A: Allocator> Iterator for ExtractIf<'_, T, F, A>
 where
     F: FnMut(&mut T) -> bool,
 {
     type Item = T;
 
     fn next(&mut self) -> Option<T> {
+        // Once the predicate panicked, it isn't called again.
+        if self.panic_flag {
+            return None;
+        }
         unsafe {
-            while self.idx < self.old_len {
+            while self.idx < self.end {
                 let i = self.idx;
                 let v = slice::from_raw_parts_mut(self.vec.as_mut_ptr(), self.old_len);
                 self.panic_flag = true;
@@ -90,12 +108,12 @@ fn next(&mut self) -> Option<T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(self.old_len - self.idx))
+        (0, Some(self.end - self.idx))
     }
 }
 
-#[unstable(feature = "drain_filter", reason = "recently added", issue = "43244")]
-impl<T, F, A: Allocator> Drop for DrainFilter<'_, T, F, A>
+#[unstable(feature = "extract_if", reason = "recently added", issue = "43244")]
+impl<T, F, A: Allocator> Drop for ExtractIf<'_, T, F, A>
 where
     F: FnMut(&mut T) -> bool,
 {
@@ -104,7 +122,7 @@ struct BackshiftOnDrop<'a, 'b, T, F, A: Allocator>
         where
             F: FnMut(&mut T) -> bool,
         {
-            drain: &'b mut DrainFilter<'a, T, F, A>,
+            extract: &'b mut ExtractIf<'a, T, F, A>,
         }
 
         impl<'a, 'b, T, F, A: Allocator> Drop for BackshiftOnDrop<'a, 'b, T, F, A>
@@ -113,31 +131,34 @@ impl<'a, 'b, T, F, A: Allocator> Drop for BackshiftOnDrop<'a, 'b, T, F, A>
         {
             fn drop(&mut self) {
                 unsafe {
-                    if self.drain.idx < self.drain.old_len && self.drain.del > 0 {
-                        // This is a pretty messed up state, and there isn't really an
-                        // obviously right thing to do. We don't want to keep trying
-                        // to execute `pred`, so we just backshift all the unprocessed
+                    if self.extract.idx < self.extract.old_len && self.extract.del > 0 {
+                        // Backshift the elements past the end of the range over the
+                        // hole left by the drained ones.
+                        //
+                        // After a panic this is a pretty messed up state, and there isn't
+                        // really an obviously right thing to do. We don't want to keep
+                        // trying to execute `pred`, so we just backshift all the unprocessed
                         // elements and tell the vec that they still exist. The backshift
                         // is required to prevent a double-drop of the last successfully
                         // drained item prior to a panic in the predicate.
-                        let ptr = self.drain.vec.as_mut_ptr();
-                        let src = ptr.add(self.drain.idx);
-                        let dst = src.sub(self.drain.del);
-                        let tail_len = self.drain.old_len - self.drain.idx;
+                        let ptr = self.extract.vec.as_mut_ptr();
+                        let src = ptr.add(self.extract.idx);
+                        let dst = src.sub(self.extract.del);
+                        let tail_len = self.extract.old_len - self.extract.idx;
                         src.copy_to(dst, tail_len);
                     }
-                    self.drain.vec.set_len(self.drain.old_len - self.drain.del);
+                    self.extract.vec.set_len(self.extract.old_len - self.extract.del);
                 }
             }
         }
 
-        let backshift = BackshiftOnDrop { drain: self };
+        let backshift = BackshiftOnDrop { extract: self };
 
-        // Attempt to consume any remaining elements if the filter predicate
-        // has not yet panicked. We'll backshift any remaining elements
+        // Attempt to consume any remaining elements in the range if the filter
+        // predicate has not yet panicked. We'll backshift any remaining elements
         // whether we've already panicked or if the consumption here panics.
-        if !backshift.drain.panic_flag {
-            backshift.drain.for_each(drop);
+        if !backshift.extract.panic_flag {
+            backshift.extract.for_each(drop);
         }
     }
 }
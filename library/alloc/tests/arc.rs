@@ -1,3 +1,4 @@
+use std::alloc::System;
 use std::any::Any;
 use std::cell::RefCell;
 use std::cmp::PartialEq;
@@ -210,3 +211,22 @@ fn hmm<'a>(val: &'a mut Weak<&'a str>) -> Weak<&'a str> {
     // `val` dropped here while still borrowed
     // borrow might be used here, when `val` is dropped and runs the `Drop` code for type `std::sync::Weak`
 }
+
+#[test]
+fn in_allocator() {
+    let x = Arc::new_in(RefCell::new(5), System);
+    let y = Arc::clone(&x);
+    let weak = Arc::downgrade(&x);
+    assert_eq!(Arc::strong_count(&x), 2);
+
+    *y.borrow_mut() = 6;
+    drop(y);
+    assert_eq!(*weak.upgrade().unwrap().borrow(), 6);
+
+    drop(x);
+    assert!(weak.upgrade().is_none());
+
+    let mut z = Arc::new_in(1, System);
+    *Arc::make_mut(&mut z) += 1;
+    assert_eq!(Arc::try_unwrap(z), Ok(2));
+}
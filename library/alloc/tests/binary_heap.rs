@@ -1,3 +1,4 @@
+use std::alloc::System;
 use std::collections::binary_heap::{Drain, PeekMut};
 use std::collections::BinaryHeap;
 use std::iter::TrustedLen;
@@ -487,3 +488,42 @@ fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
         }
     }
 }
+
+#[test]
+fn test_in_allocator() {
+    let mut heap = BinaryHeap::new_in(System);
+    heap.extend([2, 9, 4]);
+    heap.push(7);
+    assert_eq!(heap.peek(), Some(&9));
+
+    let copy = heap.clone_in(System);
+    assert_eq!(copy.into_sorted_vec(), [2, 4, 7, 9]);
+
+    let heap = BinaryHeap::from_iter_in([5, 1, 3], System);
+    assert_eq!(heap.into_iter_sorted().collect::<Vec<_>>(), [5, 3, 1]);
+
+    let mut heap = BinaryHeap::with_capacity_in(4, System);
+    assert!(heap.capacity() >= 4);
+    heap.push(1);
+    assert_eq!(heap.drain().collect::<Vec<_>>(), [1]);
+}
+
+#[test]
+fn test_try_push() {
+    use std::collections::TryCollect;
+
+    let mut heap = BinaryHeap::try_with_capacity(2).unwrap();
+    heap.try_push(3).unwrap();
+    heap.try_push(9).unwrap();
+    heap.try_reserve(2).unwrap();
+    assert!(heap.capacity() >= 4);
+    heap.try_reserve_exact(3).unwrap();
+    heap.try_push(5).unwrap();
+    assert_eq!(heap.peek(), Some(&9));
+
+    assert!(heap.try_reserve(usize::MAX).is_err());
+    assert_eq!(heap.len(), 3);
+
+    let heap: BinaryHeap<_> = [4, 8, 1].into_iter().try_collect().unwrap();
+    assert_eq!(heap.into_sorted_vec(), [1, 4, 8]);
+}
@@ -1,10 +1,10 @@
-use super::*;
-use crate::borrow::Cow::{Borrowed, Owned};
-use crate::collections::hash_map::DefaultHasher;
-use crate::hash::{Hash, Hasher};
-use crate::os::raw::c_char;
-use crate::rc::Rc;
-use crate::sync::Arc;
+use std::borrow::Cow::{Borrowed, Owned};
+use std::collections::hash_map::DefaultHasher;
+use std::ffi::{CStr, CString, FromBytesWithNulError};
+use std::hash::{Hash, Hasher};
+use std::os::raw::c_char;
+use std::rc::Rc;
+use std::sync::Arc;
 
 #[test]
 fn c_to_rust() {
@@ -177,6 +177,35 @@ fn cstr_const_constructor() {
     assert_eq!(CSTR.to_str().unwrap(), "Hello, world!");
 }
 
+#[test]
+fn cstr_const_from_bytes_with_nul() {
+    const CSTR: &CStr = match CStr::from_bytes_with_nul(b"Hello, world!\0") {
+        Ok(cstr) => cstr,
+        Err(_) => panic!(),
+    };
+    const BYTES: &[u8] = CSTR.to_bytes();
+    const BYTES_WITH_NUL: &[u8] = CSTR.to_bytes_with_nul();
+    const INTERIOR_NUL: bool = CStr::from_bytes_with_nul(b"Hello\0world!\0").is_err();
+    const NOT_NUL_TERMINATED: bool = CStr::from_bytes_with_nul(b"Hello, world!").is_err();
+
+    assert_eq!(CSTR.to_str().unwrap(), "Hello, world!");
+    assert_eq!(BYTES, b"Hello, world!");
+    assert_eq!(BYTES_WITH_NUL, b"Hello, world!\0");
+    assert!(INTERIOR_NUL);
+    assert!(NOT_NUL_TERMINATED);
+}
+
+#[test]
+fn cstr_from_bytes_with_nul_const_matches_runtime() {
+    const INTERIOR_NUL: Result<&CStr, FromBytesWithNulError> =
+        CStr::from_bytes_with_nul(b"Hello\0world!\0");
+    const NOT_NUL_TERMINATED: Result<&CStr, FromBytesWithNulError> =
+        CStr::from_bytes_with_nul(b"Hello, world!");
+
+    assert_eq!(INTERIOR_NUL, CStr::from_bytes_with_nul(b"Hello\0world!\0"));
+    assert_eq!(NOT_NUL_TERMINATED, CStr::from_bytes_with_nul(b"Hello, world!"));
+}
+
 #[test]
 fn cstr_index_from() {
     let original = b"Hello, world!\0";
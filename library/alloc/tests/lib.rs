@@ -3,11 +3,13 @@
 #![feature(box_syntax)]
 #![feature(cow_is_borrowed)]
 #![feature(const_cow_is_borrowed)]
-#![feature(drain_filter)]
+#![feature(const_cstr_methods)]
 #![feature(exact_size_is_empty)]
+#![feature(extract_if)]
 #![feature(new_uninit)]
 #![feature(pattern)]
 #![feature(trusted_len)]
+#![feature(try_alloc)]
 #![feature(try_reserve_kind)]
 #![feature(unboxed_closures)]
 #![feature(associated_type_bounds)]
@@ -25,6 +27,7 @@
 #![feature(const_btree_new)]
 #![feature(const_default_impls)]
 #![feature(const_trait_impl)]
+#![feature(vec_deque_extract_if)]
 
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
@@ -34,6 +37,7 @@
 mod borrow;
 mod boxed;
 mod btree_set_hash;
+mod c_str;
 mod const_fns;
 mod cow_str;
 mod fmt;
@@ -1,5 +1,7 @@
+use std::alloc::{AllocError, Allocator, Layout, System};
 use std::collections::LinkedList;
 use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr::NonNull;
 
 #[test]
 fn test_basic() {
@@ -357,11 +359,11 @@ fn test_contains() {
 }
 
 #[test]
-fn drain_filter_empty() {
+fn extract_if_empty() {
     let mut list: LinkedList<i32> = LinkedList::new();
 
     {
-        let mut iter = list.drain_filter(|_| true);
+        let mut iter = list.extract_if(|_| true);
         assert_eq!(iter.size_hint(), (0, Some(0)));
         assert_eq!(iter.next(), None);
         assert_eq!(iter.size_hint(), (0, Some(0)));
@@ -374,13 +376,13 @@ fn drain_filter_empty() {
 }
 
 #[test]
-fn drain_filter_zst() {
+fn extract_if_zst() {
     let mut list: LinkedList<_> = vec![(), (), (), (), ()].into_iter().collect();
     let initial_len = list.len();
     let mut count = 0;
 
     {
-        let mut iter = list.drain_filter(|_| true);
+        let mut iter = list.extract_if(|_| true);
         assert_eq!(iter.size_hint(), (0, Some(initial_len)));
         while let Some(_) = iter.next() {
             count += 1;
@@ -397,14 +399,14 @@ fn drain_filter_zst() {
 }
 
 #[test]
-fn drain_filter_false() {
+fn extract_if_false() {
     let mut list: LinkedList<_> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10].into_iter().collect();
 
     let initial_len = list.len();
     let mut count = 0;
 
     {
-        let mut iter = list.drain_filter(|_| false);
+        let mut iter = list.extract_if(|_| false);
         assert_eq!(iter.size_hint(), (0, Some(initial_len)));
         for _ in iter.by_ref() {
             count += 1;
@@ -420,14 +422,14 @@ fn drain_filter_false() {
 }
 
 #[test]
-fn drain_filter_true() {
+fn extract_if_true() {
     let mut list: LinkedList<_> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10].into_iter().collect();
 
     let initial_len = list.len();
     let mut count = 0;
 
     {
-        let mut iter = list.drain_filter(|_| true);
+        let mut iter = list.extract_if(|_| true);
         assert_eq!(iter.size_hint(), (0, Some(initial_len)));
         while let Some(_) = iter.next() {
             count += 1;
@@ -444,7 +446,7 @@ fn drain_filter_true() {
 }
 
 #[test]
-fn drain_filter_complex() {
+fn extract_if_complex() {
     {
         //                [+xxx++++++xxxxx++++x+x++]
         let mut list = vec![
@@ -454,7 +456,7 @@ fn drain_filter_complex() {
         .into_iter()
         .collect::<LinkedList<_>>();
 
-        let removed = list.drain_filter(|x| *x % 2 == 0).collect::<Vec<_>>();
+        let removed = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
         assert_eq!(removed.len(), 10);
         assert_eq!(removed, vec![2, 4, 6, 18, 20, 22, 24, 26, 34, 36]);
 
@@ -473,7 +475,7 @@ fn drain_filter_complex() {
         .into_iter()
         .collect::<LinkedList<_>>();
 
-        let removed = list.drain_filter(|x| *x % 2 == 0).collect::<Vec<_>>();
+        let removed = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
         assert_eq!(removed.len(), 10);
         assert_eq!(removed, vec![2, 4, 6, 18, 20, 22, 24, 26, 34, 36]);
 
@@ -491,7 +493,7 @@ fn drain_filter_complex() {
                 .into_iter()
                 .collect::<LinkedList<_>>();
 
-        let removed = list.drain_filter(|x| *x % 2 == 0).collect::<Vec<_>>();
+        let removed = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
         assert_eq!(removed.len(), 10);
         assert_eq!(removed, vec![2, 4, 6, 18, 20, 22, 24, 26, 34, 36]);
 
@@ -508,7 +510,7 @@ fn drain_filter_complex() {
             .into_iter()
             .collect::<LinkedList<_>>();
 
-        let removed = list.drain_filter(|x| *x % 2 == 0).collect::<Vec<_>>();
+        let removed = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
         assert_eq!(removed.len(), 10);
         assert_eq!(removed, vec![2, 4, 6, 8, 10, 12, 14, 16, 18, 20]);
 
@@ -522,7 +524,7 @@ fn drain_filter_complex() {
             .into_iter()
             .collect::<LinkedList<_>>();
 
-        let removed = list.drain_filter(|x| *x % 2 == 0).collect::<Vec<_>>();
+        let removed = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
         assert_eq!(removed.len(), 10);
         assert_eq!(removed, vec![2, 4, 6, 8, 10, 12, 14, 16, 18, 20]);
 
@@ -532,7 +534,7 @@ fn drain_filter_complex() {
 }
 
 #[test]
-fn drain_filter_drop_panic_leak() {
+fn extract_if_drop_panic_leak() {
     static mut DROPS: i32 = 0;
 
     struct D(bool);
@@ -559,14 +561,14 @@ fn drop(&mut self) {
     q.push_front(D(true));
     q.push_front(D(false));
 
-    catch_unwind(AssertUnwindSafe(|| drop(q.drain_filter(|_| true)))).ok();
+    catch_unwind(AssertUnwindSafe(|| drop(q.extract_if(|_| true)))).ok();
 
     assert_eq!(unsafe { DROPS }, 8);
     assert!(q.is_empty());
 }
 
 #[test]
-fn drain_filter_pred_panic_leak() {
+fn extract_if_pred_panic_leak() {
     static mut DROPS: i32 = 0;
 
     #[derive(Debug)]
@@ -591,7 +593,7 @@ fn drop(&mut self) {
     q.push_front(D(0));
 
     catch_unwind(AssertUnwindSafe(|| {
-        drop(q.drain_filter(|item| if item.0 >= 2 { panic!() } else { true }))
+        drop(q.extract_if(|item| if item.0 >= 2 { panic!() } else { true }))
     }))
     .ok();
 
@@ -599,6 +601,45 @@ fn drop(&mut self) {
     assert_eq!(q.len(), 6);
 }
 
+#[test]
+fn extract_if_pred_panic_consumed() {
+    let mut q: LinkedList<u32> = (0..8).collect();
+
+    let mut preds = 0;
+    catch_unwind(AssertUnwindSafe(|| {
+        // The iterator is dropped while unwinding, which must not call the
+        // predicate again, as that would panic a second time.
+        q.extract_if(|item| {
+            preds += 1;
+            if *item >= 2 { panic!() } else { true }
+        })
+        .for_each(drop)
+    }))
+    .unwrap_err();
+
+    assert_eq!(preds, 3);
+    assert_eq!(q.into_iter().collect::<Vec<_>>(), vec![2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn extract_if_pred_panic_reuse() {
+    let mut q: LinkedList<u32> = (0..8).collect();
+
+    let mut preds = 0;
+    {
+        let mut extract = q.extract_if(|item| {
+            preds += 1;
+            if *item >= 2 { panic!() } else { true }
+        });
+        catch_unwind(AssertUnwindSafe(|| while extract.next().is_some() {})).unwrap_err();
+        // The predicate isn't called again after it panicked.
+        assert!(extract.next().is_none());
+    }
+
+    assert_eq!(preds, 3);
+    assert_eq!(q.into_iter().collect::<Vec<_>>(), vec![2, 3, 4, 5, 6, 7]);
+}
+
 #[test]
 fn test_drop() {
     static mut DROPS: i32 = 0;
@@ -703,3 +744,54 @@ fn drop(&mut self) {
 
     assert_eq!(unsafe { DROPS }, 8);
 }
+
+#[test]
+fn test_in_allocator() {
+    let mut list = LinkedList::new_in(System);
+    list.push_back(2);
+    list.push_front(1);
+    list.push_back(3);
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+    let tail = list.split_off(1);
+    assert_eq!(tail.clone_in(System).into_iter().collect::<Vec<_>>(), [2, 3]);
+
+    let mut other = LinkedList::from_iter_in([4, 5], System);
+    list.append(&mut other);
+    assert!(other.is_empty());
+    assert_eq!(list.into_iter().collect::<Vec<_>>(), [1, 4, 5]);
+}
+
+#[test]
+fn test_try_push() {
+    use std::collections::TryCollect;
+
+    let mut list = LinkedList::new();
+    list.try_push_back(2).unwrap();
+    list.try_push_front(1).unwrap();
+    list.try_push_back(3).unwrap();
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+    let copy: LinkedList<_> = list.iter().copied().try_collect().unwrap();
+    assert_eq!(copy, list);
+}
+
+#[test]
+fn test_try_push_hands_back_value() {
+    struct NoAlloc;
+
+    unsafe impl Allocator for NoAlloc {
+        fn allocate(&self, _: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            Err(AllocError)
+        }
+
+        unsafe fn deallocate(&self, _: NonNull<u8>, _: Layout) {
+            unreachable!()
+        }
+    }
+
+    let mut list = LinkedList::new_in(NoAlloc);
+    assert_eq!(list.try_push_back(1).map_err(|(v, _)| v), Err(1));
+    assert_eq!(list.try_push_front(2).map_err(|(v, _)| v), Err(2));
+    assert!(list.is_empty());
+}
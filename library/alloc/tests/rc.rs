@@ -1,3 +1,4 @@
+use std::alloc::System;
 use std::any::Any;
 use std::cell::RefCell;
 use std::cmp::PartialEq;
@@ -206,3 +207,22 @@ fn hmm<'a>(val: &'a mut Weak<&'a str>) -> Weak<&'a str> {
     // `val` dropped here while still borrowed
     // borrow might be used here, when `val` is dropped and runs the `Drop` code for type `std::rc::Weak`
 }
+
+#[test]
+fn in_allocator() {
+    let x = Rc::new_in(RefCell::new(5), System);
+    let y = Rc::clone(&x);
+    let weak = Rc::downgrade(&x);
+    assert_eq!(Rc::strong_count(&x), 2);
+
+    *y.borrow_mut() = 6;
+    drop(y);
+    assert_eq!(*weak.upgrade().unwrap().borrow(), 6);
+
+    drop(x);
+    assert!(weak.upgrade().is_none());
+
+    let mut z = Rc::new_in(1, System);
+    *Rc::make_mut(&mut z) += 1;
+    assert_eq!(Rc::try_unwrap(z), Ok(2));
+}
@@ -1,3 +1,4 @@
+use std::alloc::System;
 use std::assert_matches::assert_matches;
 use std::borrow::Cow;
 use std::cell::Cell;
@@ -860,6 +861,33 @@ fn test_try_reserve_exact() {
     }
 }
 
+#[test]
+fn test_try_push() {
+    let mut s = String::try_with_capacity(2).unwrap();
+    assert!(s.capacity() >= 2);
+    s.try_push('a').unwrap();
+    s.try_push('é').unwrap();
+    s.try_push('💖').unwrap();
+    s.try_push_str("bcd").unwrap();
+    s.try_push_str("").unwrap();
+    assert_eq!(s, "aé💖bcd");
+
+    assert_matches!(
+        String::try_with_capacity(usize::MAX).map_err(|e| e.kind()),
+        Err(CapacityOverflow)
+    );
+}
+
+#[test]
+fn test_try_from_iter() {
+    let s = String::try_from_iter("aé💖".chars().rev()).unwrap();
+    assert_eq!(s, "💖éa");
+
+    let s = String::try_from_iter(['a', 'b'].iter().copied().filter(|&c| c != 'a')).unwrap();
+    assert_eq!(s, "b");
+    assert_eq!(String::try_from_iter(None).unwrap(), "");
+}
+
 #[test]
 fn test_from_char() {
     assert_eq!(String::from('a'), 'a'.to_string());
@@ -874,3 +902,29 @@ fn test_str_concat() {
     let s: String = format!("{}{}", a, b);
     assert_eq!(s.as_bytes()[9], 'd' as u8);
 }
+
+#[test]
+fn test_in_allocator() {
+    let mut s = String::new_in(System);
+    s.push_str("hello");
+    s.push(' ');
+    s += "world";
+    assert_eq!(s, "hello world");
+    assert_eq!(&s[..5], "hello");
+
+    let t = s.split_off(5);
+    assert_eq!(t, " world");
+    assert_eq!(s.clone_in(System), "hello");
+
+    let s = String::from_iter_in(['a', 'b', 'c'], System);
+    assert_eq!(s.as_str(), "abc");
+    let bytes = s.into_bytes();
+    assert_eq!(bytes, b"abc");
+    let s = unsafe { String::from_utf8_unchecked_in(bytes) };
+    assert_eq!(&*s.into_boxed_str(), "abc");
+
+    let mut s = String::with_capacity_in(10, System);
+    assert!(s.capacity() >= 10);
+    s.extend(["x", "y"]);
+    assert_eq!(s.drain(..).collect::<String>(), "xy");
+}
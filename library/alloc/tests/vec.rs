@@ -1185,11 +1185,11 @@ fn overaligned_allocations() {
 }
 
 #[test]
-fn drain_filter_empty() {
+fn extract_if_empty() {
     let mut vec: Vec<i32> = vec![];
 
     {
-        let mut iter = vec.drain_filter(|_| true);
+        let mut iter = vec.extract_if(.., |_| true);
         assert_eq!(iter.size_hint(), (0, Some(0)));
         assert_eq!(iter.next(), None);
         assert_eq!(iter.size_hint(), (0, Some(0)));
@@ -1201,12 +1201,12 @@ fn drain_filter_empty() {
 }
 
 #[test]
-fn drain_filter_zst() {
+fn extract_if_zst() {
     let mut vec = vec![(), (), (), (), ()];
     let initial_len = vec.len();
     let mut count = 0;
     {
-        let mut iter = vec.drain_filter(|_| true);
+        let mut iter = vec.extract_if(.., |_| true);
         assert_eq!(iter.size_hint(), (0, Some(initial_len)));
         while let Some(_) = iter.next() {
             count += 1;
@@ -1223,13 +1223,13 @@ fn drain_filter_zst() {
 }
 
 #[test]
-fn drain_filter_false() {
+fn extract_if_false() {
     let mut vec = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
 
     let initial_len = vec.len();
     let mut count = 0;
     {
-        let mut iter = vec.drain_filter(|_| false);
+        let mut iter = vec.extract_if(.., |_| false);
         assert_eq!(iter.size_hint(), (0, Some(initial_len)));
         for _ in iter.by_ref() {
             count += 1;
@@ -1245,13 +1245,13 @@ fn drain_filter_false() {
 }
 
 #[test]
-fn drain_filter_true() {
+fn extract_if_true() {
     let mut vec = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
 
     let initial_len = vec.len();
     let mut count = 0;
     {
-        let mut iter = vec.drain_filter(|_| true);
+        let mut iter = vec.extract_if(.., |_| true);
         assert_eq!(iter.size_hint(), (0, Some(initial_len)));
         while let Some(_) = iter.next() {
             count += 1;
@@ -1268,7 +1268,7 @@ fn drain_filter_true() {
 }
 
 #[test]
-fn drain_filter_complex() {
+fn extract_if_complex() {
     {
         //                [+xxx++++++xxxxx++++x+x++]
         let mut vec = vec![
@@ -1276,7 +1276,7 @@ fn drain_filter_complex() {
             39,
         ];
 
-        let removed = vec.drain_filter(|x| *x % 2 == 0).collect::<Vec<_>>();
+        let removed = vec.extract_if(.., |x| *x % 2 == 0).collect::<Vec<_>>();
         assert_eq!(removed.len(), 10);
         assert_eq!(removed, vec![2, 4, 6, 18, 20, 22, 24, 26, 34, 36]);
 
@@ -1290,7 +1290,7 @@ fn drain_filter_complex() {
             2, 4, 6, 7, 9, 11, 13, 15, 17, 18, 20, 22, 24, 26, 27, 29, 31, 33, 34, 35, 36, 37, 39,
         ];
 
-        let removed = vec.drain_filter(|x| *x % 2 == 0).collect::<Vec<_>>();
+        let removed = vec.extract_if(.., |x| *x % 2 == 0).collect::<Vec<_>>();
         assert_eq!(removed.len(), 10);
         assert_eq!(removed, vec![2, 4, 6, 18, 20, 22, 24, 26, 34, 36]);
 
@@ -1303,7 +1303,7 @@ fn drain_filter_complex() {
         let mut vec =
             vec![2, 4, 6, 7, 9, 11, 13, 15, 17, 18, 20, 22, 24, 26, 27, 29, 31, 33, 34, 35, 36];
 
-        let removed = vec.drain_filter(|x| *x % 2 == 0).collect::<Vec<_>>();
+        let removed = vec.extract_if(.., |x| *x % 2 == 0).collect::<Vec<_>>();
         assert_eq!(removed.len(), 10);
         assert_eq!(removed, vec![2, 4, 6, 18, 20, 22, 24, 26, 34, 36]);
 
@@ -1315,7 +1315,7 @@ fn drain_filter_complex() {
         //                [xxxxxxxxxx+++++++++++]
         let mut vec = vec![2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
 
-        let removed = vec.drain_filter(|x| *x % 2 == 0).collect::<Vec<_>>();
+        let removed = vec.extract_if(.., |x| *x % 2 == 0).collect::<Vec<_>>();
         assert_eq!(removed.len(), 10);
         assert_eq!(removed, vec![2, 4, 6, 8, 10, 12, 14, 16, 18, 20]);
 
@@ -1327,7 +1327,7 @@ fn drain_filter_complex() {
         //                [+++++++++++xxxxxxxxxx]
         let mut vec = vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20];
 
-        let removed = vec.drain_filter(|x| *x % 2 == 0).collect::<Vec<_>>();
+        let removed = vec.extract_if(.., |x| *x % 2 == 0).collect::<Vec<_>>();
         assert_eq!(removed.len(), 10);
         assert_eq!(removed, vec![2, 4, 6, 8, 10, 12, 14, 16, 18, 20]);
 
@@ -1339,7 +1339,7 @@ fn drain_filter_complex() {
 // FIXME: re-enable emscripten once it can unwind again
 #[test]
 #[cfg(not(target_os = "emscripten"))]
-fn drain_filter_consumed_panic() {
+fn extract_if_consumed_panic() {
     use std::rc::Rc;
     use std::sync::Mutex;
 
@@ -1374,10 +1374,10 @@ fn drop(&mut self) {
             }
             c.index < 6
         };
-        let drain = data.drain_filter(filter);
+        let extract = data.extract_if(.., filter);
 
-        // NOTE: The DrainFilter is explicitly consumed
-        drain.for_each(drop);
+        // NOTE: The ExtractIf is explicitly consumed
+        extract.for_each(drop);
     });
 
     let drop_counts = drop_counts.lock().unwrap();
@@ -1391,7 +1391,7 @@ fn drop(&mut self) {
 // FIXME: Re-enable emscripten once it can catch panics
 #[test]
 #[cfg(not(target_os = "emscripten"))]
-fn drain_filter_unconsumed_panic() {
+fn extract_if_unconsumed_panic() {
     use std::rc::Rc;
     use std::sync::Mutex;
 
@@ -1426,9 +1426,9 @@ fn drop(&mut self) {
             }
             c.index < 6
         };
-        let _drain = data.drain_filter(filter);
+        let _extract = data.extract_if(.., filter);
 
-        // NOTE: The DrainFilter is dropped without being consumed
+        // NOTE: The ExtractIf is dropped without being consumed
     });
 
     let drop_counts = drop_counts.lock().unwrap();
@@ -1440,13 +1440,86 @@ fn drop(&mut self) {
 }
 
 #[test]
-fn drain_filter_unconsumed() {
+fn extract_if_unconsumed() {
     let mut vec = vec![1, 2, 3, 4];
-    let drain = vec.drain_filter(|&mut x| x % 2 != 0);
-    drop(drain);
+    let extract = vec.extract_if(.., |&mut x| x % 2 != 0);
+    drop(extract);
     assert_eq!(vec, [2, 4]);
 }
 
+#[test]
+fn extract_if_range() {
+    let mut vec = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    let removed = vec.extract_if(2..6, |x| *x % 2 == 0).collect::<Vec<_>>();
+    assert_eq!(removed, [4, 6]);
+    assert_eq!(vec, [1, 2, 3, 5, 7, 8]);
+
+    let removed = vec.extract_if(4.., |_| true).collect::<Vec<_>>();
+    assert_eq!(removed, [7, 8]);
+    assert_eq!(vec, [1, 2, 3, 5]);
+
+    let removed = vec.extract_if(..=1, |_| true).collect::<Vec<_>>();
+    assert_eq!(removed, [1, 2]);
+    assert_eq!(vec, [3, 5]);
+
+    let mut iter = vec.extract_if(1..1, |_| true);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+    drop(iter);
+    assert_eq!(vec, [3, 5]);
+}
+
+#[test]
+fn extract_if_range_unconsumed() {
+    let mut vec = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    let mut extract = vec.extract_if(1..5, |&mut x| x % 2 == 0);
+    assert_eq!(extract.next(), Some(2));
+    drop(extract);
+    assert_eq!(vec, [1, 3, 5, 6, 7, 8]);
+}
+
+#[test]
+#[should_panic]
+fn extract_if_range_out_of_bounds() {
+    let mut vec = vec![1, 2, 3];
+    let _ = vec.extract_if(1..4, |_| true);
+}
+
+// FIXME: Re-enable emscripten once it can catch panics
+#[test]
+#[cfg(not(target_os = "emscripten"))]
+fn extract_if_pred_panic_retains() {
+    let mut vec = vec![1, 2, 3, 4, 5];
+    let mut preds = 0;
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let extract = vec.extract_if(.., |x| {
+            preds += 1;
+            if *x >= 3 { panic!() } else { *x % 2 != 0 }
+        });
+        extract.for_each(drop);
+    }));
+    assert!(res.is_err());
+    assert_eq!(preds, 3);
+    assert_eq!(vec, [2, 3, 4, 5]);
+}
+
+#[test]
+fn extract_if_pred_panic_reuse() {
+    let mut vec = vec![1, 2, 3, 4, 5];
+    let mut preds = 0;
+    {
+        let mut extract = vec.extract_if(.., |x| {
+            preds += 1;
+            if *x >= 3 { panic!() } else { *x % 2 != 0 }
+        });
+        catch_unwind(AssertUnwindSafe(|| while extract.next().is_some() {})).unwrap_err();
+        // The predicate isn't called again after it panicked.
+        assert!(extract.next().is_none());
+    }
+    assert_eq!(preds, 3);
+    assert_eq!(vec, [2, 3, 4, 5]);
+}
+
 #[test]
 fn test_reserve_exact() {
     // This is all the same as test_reserve
@@ -1719,6 +1792,135 @@ fn test_try_reserve_exact() {
     }
 }
 
+/// An allocator that fails every allocation request.
+struct FailingAlloc;
+
+unsafe impl std::alloc::Allocator for FailingAlloc {
+    fn allocate(
+        &self,
+        _layout: std::alloc::Layout,
+    ) -> Result<std::ptr::NonNull<[u8]>, std::alloc::AllocError> {
+        Err(std::alloc::AllocError)
+    }
+
+    unsafe fn deallocate(&self, _ptr: std::ptr::NonNull<u8>, _layout: std::alloc::Layout) {
+        unreachable!("nothing was allocated");
+    }
+}
+
+#[test]
+fn test_try_with_capacity() {
+    let vec: Vec<u32> = Vec::try_with_capacity(10).unwrap();
+    assert_eq!(vec.len(), 0);
+    assert!(vec.capacity() >= 10);
+
+    assert_matches!(
+        Vec::<u8>::try_with_capacity(usize::MAX).map_err(|e| e.kind()),
+        Err(CapacityOverflow)
+    );
+    assert_matches!(
+        Vec::<u64>::try_with_capacity(usize::MAX / 4).map_err(|e| e.kind()),
+        Err(CapacityOverflow)
+    );
+    assert_matches!(
+        Vec::<u8, _>::try_with_capacity_in(1, FailingAlloc).map_err(|e| e.kind()),
+        Err(AllocError { .. })
+    );
+
+    // Zero-sized types never allocate.
+    let vec: Vec<(), _> = Vec::try_with_capacity_in(usize::MAX, FailingAlloc).unwrap();
+    assert_eq!(vec.capacity(), usize::MAX);
+}
+
+#[test]
+fn test_try_push() {
+    let mut vec = Vec::new();
+    for i in 0..100 {
+        vec.try_push(i).unwrap();
+    }
+    assert_eq!(vec, (0..100).collect::<Vec<_>>());
+
+    let mut count = 0;
+    {
+        let mut vec = Vec::new_in(FailingAlloc);
+        let (value, err) = vec.try_push(DropCounter { count: &mut count }).unwrap_err();
+        assert_matches!(err.kind(), AllocError { .. });
+        assert!(vec.is_empty());
+        // The element that could not be pushed is handed back, not dropped.
+        assert_eq!(*value.count, 0);
+    }
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn test_try_insert() {
+    let mut vec = vec![1, 2, 3];
+    vec.try_insert(1, 4).unwrap();
+    assert_eq!(vec, [1, 4, 2, 3]);
+    vec.try_insert(4, 5).unwrap();
+    assert_eq!(vec, [1, 4, 2, 3, 5]);
+
+    let mut vec = Vec::new_in(FailingAlloc);
+    assert_matches!(vec.try_insert(0, 1).map_err(|(v, e)| (v, e.kind())), Err((1, AllocError { .. })));
+    assert!(vec.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "insertion index (is 2) should be <= len (is 1)")]
+fn test_try_insert_out_of_bounds() {
+    let mut vec = vec![1];
+    let _ = vec.try_insert(2, 2);
+}
+
+#[test]
+fn test_try_extend() {
+    let mut vec = vec![1, 2];
+    vec.try_extend(3..6).unwrap();
+    assert_eq!(vec, [1, 2, 3, 4, 5]);
+
+    // Iterators with an unhelpful size hint still work.
+    vec.try_extend((6..10).filter(|_| true)).unwrap();
+    assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+    vec.try_extend_from_slice(&[10, 11]).unwrap();
+    assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+
+    let mut vec = Vec::new_in(FailingAlloc);
+    assert_matches!(vec.try_extend(0..3).map_err(|e| e.kind()), Err(AllocError { .. }));
+    assert_matches!(
+        vec.try_extend_from_slice(&[1, 2]).map_err(|e| e.kind()),
+        Err(AllocError { .. })
+    );
+    assert!(vec.is_empty());
+
+    // Extending with nothing never needs to allocate.
+    vec.try_extend(0..0).unwrap();
+    vec.try_extend_from_slice(&[]).unwrap();
+}
+
+#[test]
+fn test_try_from_iter() {
+    let vec = Vec::try_from_iter((0..5).map(|x| x * 2)).unwrap();
+    assert_eq!(vec, [0, 2, 4, 6, 8]);
+
+    let vec = Vec::try_from_iter((0..5).filter(|x| x % 2 == 1)).unwrap();
+    assert_eq!(vec, [1, 3]);
+}
+
+#[test]
+fn test_try_collect() {
+    use std::collections::TryCollect;
+
+    let vec: Vec<_> = (0..5).map(|x| x * 2).try_collect().unwrap();
+    assert_eq!(vec, [0, 2, 4, 6, 8]);
+
+    let strings: Vec<String> = vec.iter().map(|x| x.to_string()).try_collect().unwrap();
+    let s: String = strings.iter().map(String::as_str).try_collect().unwrap();
+    assert_eq!(s, "02468");
+    let s: String = strings.into_iter().rev().try_collect().unwrap();
+    assert_eq!(s, "86420");
+}
+
 #[test]
 fn test_stable_pointers() {
     /// Pull an element from the iterator, then drop it.
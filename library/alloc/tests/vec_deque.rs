@@ -1,10 +1,13 @@
+use std::alloc::{AllocError, Allocator, Layout, System};
 use std::assert_matches::assert_matches;
+use std::cell::Cell;
 use std::collections::TryReserveErrorKind::*;
-use std::collections::{vec_deque::Drain, VecDeque};
+use std::collections::{vec_deque::Drain, TryReserveError, VecDeque};
 use std::fmt::Debug;
 use std::mem::size_of;
 use std::ops::Bound::*;
 use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr::NonNull;
 
 use crate::hash;
 
@@ -1388,6 +1391,65 @@ fn test_try_reserve_exact() {
     }
 }
 
+#[test]
+fn test_try_push() {
+    let mut d = VecDeque::try_with_capacity(2).unwrap();
+    assert!(d.capacity() >= 2);
+    // Push from both ends so that the buffer wraps around when it grows.
+    for i in 0..20 {
+        d.try_push_back(i).unwrap();
+        d.try_push_front(-i).unwrap();
+    }
+    d.try_insert(20, 100).unwrap();
+    let expected: Vec<_> = (0..20).rev().map(|i| -i).chain(Some(100)).chain(0..20).collect();
+    assert_eq!(d, expected);
+
+    assert_matches!(
+        VecDeque::<u8>::try_with_capacity(usize::MAX).map_err(|e| e.kind()),
+        Err(CapacityOverflow)
+    );
+}
+
+/// Hands out a single allocation from `System`, then fails.
+struct SingleAlloc(Cell<bool>);
+
+unsafe impl Allocator for SingleAlloc {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if self.0.replace(true) {
+            Err(AllocError)
+        } else {
+            System.allocate(layout)
+        }
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { System.deallocate(ptr, layout) }
+    }
+}
+
+#[test]
+fn test_try_push_hands_back_value() {
+    let alloc = SingleAlloc(Cell::new(false));
+    let mut d = VecDeque::try_with_capacity_in(1, &alloc).unwrap();
+    d.try_push_back(1).unwrap();
+    assert_eq!(d.len(), d.capacity());
+
+    let kind = |(v, e): (i32, TryReserveError)| (v, e.kind());
+    assert_matches!(d.try_push_back(2).map_err(kind), Err((2, AllocError { .. })));
+    assert_matches!(d.try_push_front(3).map_err(kind), Err((3, AllocError { .. })));
+    assert_matches!(d.try_insert(0, 4).map_err(kind), Err((4, AllocError { .. })));
+    assert_eq!(d, [1]);
+}
+
+#[test]
+fn test_try_from_iter() {
+    let d = VecDeque::try_from_iter(0..5).unwrap();
+    assert_eq!(d, [0, 1, 2, 3, 4]);
+
+    let d = VecDeque::try_from_iter((0..20).filter(|x| x % 3 == 0)).unwrap();
+    assert_eq!(d, [0, 3, 6, 9, 12, 15, 18]);
+}
+
 #[test]
 fn test_rotate_nop() {
     let mut v: VecDeque<_> = (0..10).collect();
@@ -1784,3 +1846,121 @@ fn test_from_zero_sized_vec() {
     let queue = VecDeque::from(v);
     assert_eq!(queue.len(), 100);
 }
+
+#[test]
+fn test_extract_if() {
+    // Exercise every rotation of the ring buffer, so that both the extracted
+    // range and the backshifted tail wrap around the end of the buffer.
+    for rotation in 0..8 {
+        let mut deque = VecDeque::with_capacity(8);
+        for _ in 0..rotation {
+            deque.push_back(0);
+            deque.pop_front();
+        }
+        deque.extend(1..=7);
+
+        let removed = deque.extract_if(.., |x| *x % 2 == 0).collect::<Vec<_>>();
+        assert_eq!(removed, [2, 4, 6]);
+        assert_eq!(deque, [1, 3, 5, 7]);
+    }
+}
+
+#[test]
+fn test_extract_if_range() {
+    let mut deque: VecDeque<_> = (1..=8).collect();
+    deque.push_front(0);
+
+    let removed = deque.extract_if(2..6, |x| *x % 2 == 0).collect::<Vec<_>>();
+    assert_eq!(removed, [2, 4]);
+    assert_eq!(deque, [0, 1, 3, 5, 6, 7, 8]);
+
+    let removed = deque.extract_if(5.., |_| true).collect::<Vec<_>>();
+    assert_eq!(removed, [7, 8]);
+    assert_eq!(deque, [0, 1, 3, 5, 6]);
+
+    let mut iter = deque.extract_if(2..2, |_| true);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+    drop(iter);
+    assert_eq!(deque, [0, 1, 3, 5, 6]);
+}
+
+#[test]
+#[should_panic]
+fn test_extract_if_range_out_of_bounds() {
+    let mut deque: VecDeque<_> = (1..=3).collect();
+    let _ = deque.extract_if(..5, |_| true);
+}
+
+#[test]
+fn test_extract_if_unconsumed() {
+    let mut deque: VecDeque<_> = (1..=6).collect();
+    deque.push_front(0);
+
+    drop(deque.extract_if(1..4, |_| true));
+    assert_eq!(deque, [0, 4, 5, 6]);
+
+    let mut iter = deque.extract_if(.., |x| *x % 2 == 0);
+    assert_eq!(iter.next(), Some(0));
+    drop(iter);
+    assert_eq!(deque, [5]);
+}
+
+#[test]
+fn test_extract_if_zst() {
+    let mut deque: VecDeque<_> = std::iter::repeat(()).take(5).collect();
+    let mut count = 0;
+    deque
+        .extract_if(1..4, |_| {
+            count += 1;
+            count % 2 == 0
+        })
+        .for_each(drop);
+    assert_eq!(count, 3);
+    assert_eq!(deque.len(), 4);
+}
+
+#[test]
+fn test_extract_if_pred_panic() {
+    let mut deque: VecDeque<_> = (1..=5).collect();
+    deque.push_front(0);
+
+    let mut preds = 0;
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        let extract = deque.extract_if(.., |x| {
+            preds += 1;
+            if *x >= 3 { panic!() } else { *x % 2 != 0 }
+        });
+        extract.for_each(drop);
+    }));
+    assert!(res.is_err());
+    assert_eq!(preds, 4);
+    assert_eq!(deque, [0, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_extract_if_pred_panic_reuse() {
+    let mut deque: VecDeque<_> = (1..=5).collect();
+    let mut preds = 0;
+    {
+        let mut extract = deque.extract_if(.., |x| {
+            preds += 1;
+            if *x >= 3 { panic!() } else { *x % 2 != 0 }
+        });
+        catch_unwind(AssertUnwindSafe(|| while extract.next().is_some() {})).unwrap_err();
+        // The predicate isn't called again after it panicked.
+        assert!(extract.next().is_none());
+    }
+    assert_eq!(preds, 3);
+    assert_eq!(deque, [2, 3, 4, 5]);
+}
+
+#[test]
+fn test_in_allocator() {
+    let mut deque = VecDeque::from_iter_in(1..=3, System);
+    deque.push_front(0);
+    assert_eq!(deque, [0, 1, 2, 3]);
+
+    let copy = deque.clone_in(System);
+    assert_eq!(copy, deque);
+}
@@ -0,0 +1,147 @@
+use crate::async_iter::AsyncIterator;
+use crate::fmt;
+use crate::future::Future;
+use crate::pin::Pin;
+use crate::task::{ready, Context, Poll};
+
+/// Future for the [`next`] method on [`AsyncIterator`].
+///
+/// This `struct` is created by the [`next`] method on [`AsyncIterator`]. See
+/// its documentation for more.
+///
+/// [`next`]: AsyncIterator::next
+#[unstable(feature = "async_iterator", issue = "79024")]
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Next<'a, I: ?Sized> {
+    iter: &'a mut I,
+}
+
+impl<'a, I: ?Sized> Next<'a, I> {
+    pub(super) fn new(iter: &'a mut I) -> Next<'a, I> {
+        Next { iter }
+    }
+}
+
+#[unstable(feature = "async_iterator", issue = "79024")]
+impl<I: ?Sized> Unpin for Next<'_, I> {}
+
+#[unstable(feature = "async_iterator", issue = "79024")]
+impl<I: ?Sized + AsyncIterator + Unpin> Future for Next<'_, I> {
+    type Output = Option<I::Item>;
+
+    #[inline]
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.iter).poll_next(cx)
+    }
+}
+
+/// An async iterator that maps the values of `iter` with `f`.
+///
+/// This `struct` is created by the [`map`] method on [`AsyncIterator`]. See
+/// its documentation for more.
+///
+/// [`map`]: AsyncIterator::map
+#[unstable(feature = "async_iterator", issue = "79024")]
+#[derive(Clone)]
+#[must_use = "async iterators do nothing unless polled"]
+pub struct Map<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> Map<I, F> {
+    pub(super) fn new(iter: I, f: F) -> Map<I, F> {
+        Map { iter, f }
+    }
+}
+
+#[unstable(feature = "async_iterator", issue = "79024")]
+impl<I: fmt::Debug, F> fmt::Debug for Map<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Map").field("iter", &self.iter).finish()
+    }
+}
+
+// The closure is never pinned, so only the inner async iterator matters.
+#[unstable(feature = "async_iterator", issue = "79024")]
+impl<I: Unpin, F> Unpin for Map<I, F> {}
+
+#[unstable(feature = "async_iterator", issue = "79024")]
+impl<B, I: AsyncIterator, F> AsyncIterator for Map<I, F>
+where
+    F: FnMut(I::Item) -> B,
+{
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<B>> {
+        // SAFETY: pin projection. `iter` is structurally pinned, `f` is not, and `Map`
+        // neither implements `Drop` nor moves out of `iter`.
+        let this = unsafe { self.get_unchecked_mut() };
+        let iter = unsafe { Pin::new_unchecked(&mut this.iter) };
+        iter.poll_next(cx).map(|item| item.map(&mut this.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An async iterator that filters the elements of `iter` with `predicate`.
+///
+/// This `struct` is created by the [`filter`] method on [`AsyncIterator`]. See
+/// its documentation for more.
+///
+/// [`filter`]: AsyncIterator::filter
+#[unstable(feature = "async_iterator", issue = "79024")]
+#[derive(Clone)]
+#[must_use = "async iterators do nothing unless polled"]
+pub struct Filter<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<I, P> Filter<I, P> {
+    pub(super) fn new(iter: I, predicate: P) -> Filter<I, P> {
+        Filter { iter, predicate }
+    }
+}
+
+#[unstable(feature = "async_iterator", issue = "79024")]
+impl<I: fmt::Debug, P> fmt::Debug for Filter<I, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Filter").field("iter", &self.iter).finish()
+    }
+}
+
+// The predicate is never pinned, so only the inner async iterator matters.
+#[unstable(feature = "async_iterator", issue = "79024")]
+impl<I: Unpin, P> Unpin for Filter<I, P> {}
+
+#[unstable(feature = "async_iterator", issue = "79024")]
+impl<I: AsyncIterator, P> AsyncIterator for Filter<I, P>
+where
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<I::Item>> {
+        // SAFETY: pin projection. `iter` is structurally pinned, `predicate` is not, and
+        // `Filter` neither implements `Drop` nor moves out of `iter`.
+        let this = unsafe { self.get_unchecked_mut() };
+        let mut iter = unsafe { Pin::new_unchecked(&mut this.iter) };
+        loop {
+            match ready!(iter.as_mut().poll_next(cx)) {
+                Some(item) if !(this.predicate)(&item) => continue,
+                item => return Poll::Ready(item),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper) // can't know a lower bound, due to the predicate
+    }
+}
@@ -0,0 +1,203 @@
+use super::{Filter, Map, Next};
+use crate::ops::DerefMut;
+use crate::pin::Pin;
+use crate::task::{Context, Poll};
+
+/// An interface for dealing with asynchronous iterators.
+///
+/// This is the main async iterator trait. For more about the concept of async iterators
+/// generally, please see the [module-level documentation]. In particular, you
+/// may want to know how to [implement `AsyncIterator`][impl].
+///
+/// [module-level documentation]: index.html
+/// [impl]: index.html#implementing-async-iterator
+#[unstable(feature = "async_iterator", issue = "79024")]
+#[must_use = "async iterators do nothing unless polled"]
+pub trait AsyncIterator {
+    /// The type of items yielded by the async iterator.
+    type Item;
+
+    /// Attempt to pull out the next value of this async iterator, registering the
+    /// current task for wakeup if the value is not yet available, and returning
+    /// `None` if the async iterator is exhausted.
+    ///
+    /// # Return value
+    ///
+    /// There are several possible return values, each indicating a distinct
+    /// async iterator state:
+    ///
+    /// - `Poll::Pending` means that this async iterator's next value is not ready
+    /// yet. Implementations will ensure that the current task will be notified
+    /// when the next value may be ready.
+    ///
+    /// - `Poll::Ready(Some(val))` means that the async iterator has successfully
+    /// produced a value, `val`, and may produce further values on subsequent
+    /// `poll_next` calls.
+    ///
+    /// - `Poll::Ready(None)` means that the async iterator has terminated, and
+    /// `poll_next` should not be invoked again.
+    ///
+    /// # Panics
+    ///
+    /// Once an async iterator has finished (returned `Ready(None)` from `poll_next`), calling its
+    /// `poll_next` method again may panic, block forever, or cause other kinds of
+    /// problems; the `AsyncIterator` trait places no requirements on the effects of
+    /// such a call. However, as the `poll_next` method is not marked `unsafe`,
+    /// Rust's usual rules apply: calls must never cause undefined behavior
+    /// (memory corruption, incorrect use of `unsafe` functions, or the like),
+    /// regardless of the async iterator's state.
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>;
+
+    /// Returns the bounds on the remaining length of the async iterator.
+    ///
+    /// Specifically, `size_hint()` returns a tuple where the first element
+    /// is the lower bound, and the second element is the upper bound.
+    ///
+    /// The second half of the tuple that is returned is an <code>[Option]<[usize]></code>.
+    /// A [`None`] here means that either there is no known upper bound, or the
+    /// upper bound is larger than [`usize`].
+    ///
+    /// # Implementation notes
+    ///
+    /// It is not enforced that an async iterator implementation yields the declared
+    /// number of elements. A buggy async iterator may yield less than the lower bound
+    /// or more than the upper bound of elements.
+    ///
+    /// `size_hint()` is primarily intended to be used for optimizations such as
+    /// reserving space for the elements of the async iterator, but must not be
+    /// trusted to e.g., omit bounds checks in unsafe code. An incorrect
+    /// implementation of `size_hint()` should not lead to memory safety
+    /// violations.
+    ///
+    /// That said, the implementation should provide a correct estimation,
+    /// because otherwise it would be a violation of the trait's protocol.
+    ///
+    /// The default implementation returns <code>(0, [None])</code> which is correct for any
+    /// async iterator.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+
+    /// Returns a future which resolves to the next value of this async iterator.
+    ///
+    /// The future resolves to [`None`] when the async iterator is exhausted, and
+    /// to `Some(item)` otherwise, just like [`Iterator::next`]. Since the future
+    /// borrows the async iterator mutably, it can be polled to completion once
+    /// per item, typically in a `while let` loop.
+    ///
+    /// This method requires the async iterator to be [`Unpin`]. Async iterators
+    /// that are not can be pinned first, for example with [`Box::pin`]. Trait
+    /// objects can be used through a `&mut` reference or a `Box`.
+    ///
+    /// [`Box::pin`]: ../../std/boxed/struct.Box.html#method.pin
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator)]
+    /// #![feature(async_iter_from_iter)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    ///
+    /// async fn sum() -> i32 {
+    ///     let mut iter = async_iter::from_iter([1, 2, 3]);
+    ///     let mut sum = 0;
+    ///     while let Some(n) = iter.next().await {
+    ///         sum += n;
+    ///     }
+    ///     sum
+    /// }
+    /// ```
+    fn next(&mut self) -> Next<'_, Self>
+    where
+        Self: Sized + Unpin,
+    {
+        Next::new(self)
+    }
+
+    /// Takes a closure and creates an async iterator which calls that closure
+    /// on each element.
+    ///
+    /// This is the asynchronous counterpart of [`Iterator::map`]: the closure is
+    /// called as soon as the underlying async iterator produces an item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator)]
+    /// #![feature(async_iter_from_iter)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    ///
+    /// async fn doubled() -> Option<i32> {
+    ///     let mut iter = async_iter::from_iter([1, 2, 3]).map(|x| x * 2);
+    ///     iter.next().await
+    /// }
+    /// ```
+    fn map<B, F>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> B,
+    {
+        Map::new(self, f)
+    }
+
+    /// Creates an async iterator which uses a closure to determine if an
+    /// element should be yielded.
+    ///
+    /// This is the asynchronous counterpart of [`Iterator::filter`]. Elements
+    /// for which the predicate returns `false` are skipped without returning
+    /// [`Poll::Pending`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator)]
+    /// #![feature(async_iter_from_iter)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    ///
+    /// async fn first_even() -> Option<i32> {
+    ///     let mut iter = async_iter::from_iter([1, 3, 4, 5]).filter(|x| x % 2 == 0);
+    ///     iter.next().await
+    /// }
+    /// ```
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        Filter::new(self, predicate)
+    }
+}
+
+#[unstable(feature = "async_iterator", issue = "79024")]
+impl<S: ?Sized + AsyncIterator + Unpin> AsyncIterator for &mut S {
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        S::poll_next(Pin::new(&mut **self), cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
+
+#[unstable(feature = "async_iterator", issue = "79024")]
+impl<P> AsyncIterator for Pin<P>
+where
+    P: DerefMut + Unpin,
+    P::Target: AsyncIterator,
+{
+    type Item = <P::Target as AsyncIterator>::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().as_mut().poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
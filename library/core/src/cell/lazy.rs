@@ -0,0 +1,290 @@
+use crate::cell::{Cell, OnceCell};
+use crate::fmt;
+use crate::ops::Deref;
+
+/// A value which is initialized on the first access.
+///
+/// For a thread-safe version of this struct, see [`std::sync::LazyLock`].
+///
+/// If the initialization function panics, the `LazyCell` is poisoned: the
+/// function has been consumed, so every later access panics as well. Use
+/// [`LazyCell::try_force`] with an [`FnMut`] initializing function to retry
+/// instead.
+///
+/// [`std::sync::LazyLock`]: ../../std/sync/struct.LazyLock.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(once_cell)]
+///
+/// use std::cell::LazyCell;
+///
+/// let lazy: LazyCell<i32> = LazyCell::new(|| {
+///     println!("initializing");
+///     92
+/// });
+/// println!("ready");
+/// println!("{}", *lazy);
+/// println!("{}", *lazy);
+///
+/// // Prints:
+/// //   ready
+/// //   initializing
+/// //   92
+/// //   92
+/// ```
+#[unstable(feature = "once_cell", issue = "74465")]
+pub struct LazyCell<T, F = fn() -> T> {
+    cell: OnceCell<T>,
+    init: Cell<Option<F>>,
+}
+
+#[unstable(feature = "once_cell", issue = "74465")]
+impl<T: fmt::Debug, F> fmt::Debug for LazyCell<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyCell").field("cell", &self.cell).field("init", &"..").finish()
+    }
+}
+
+impl<T, F> LazyCell<T, F> {
+    /// Creates a new lazy value with the given initializing function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    ///
+    /// # fn main() {
+    /// use std::cell::LazyCell;
+    ///
+    /// let hello = "Hello, World!".to_string();
+    ///
+    /// let lazy = LazyCell::new(|| hello.to_uppercase());
+    ///
+    /// assert_eq!(&*lazy, "HELLO, WORLD!");
+    /// # }
+    /// ```
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub const fn new(init: F) -> LazyCell<T, F> {
+        LazyCell { cell: OnceCell::new(), init: Cell::new(Some(init)) }
+    }
+
+    /// Consumes this `LazyCell` returning the stored value.
+    ///
+    /// Returns `Ok(value)` if the value was already initialized, and
+    /// `Err(f)` with the initializing function otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `LazyCell` is poisoned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    ///
+    /// use std::cell::LazyCell;
+    ///
+    /// let hello = "Hello, World!".to_string();
+    ///
+    /// let lazy = LazyCell::new(|| hello.to_uppercase());
+    ///
+    /// assert_eq!(&*lazy, "HELLO, WORLD!");
+    /// assert_eq!(LazyCell::into_value(lazy).ok(), Some("HELLO, WORLD!".to_string()));
+    /// ```
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub fn into_value(this: LazyCell<T, F>) -> Result<T, F> {
+        let LazyCell { cell, init } = this;
+        match cell.into_inner() {
+            Some(value) => Ok(value),
+            None => match init.into_inner() {
+                Some(f) => Err(f),
+                None => panic!("`LazyCell` instance has previously been poisoned"),
+            },
+        }
+    }
+
+    /// Returns a reference to the value if it is initialized, or `None` if not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    ///
+    /// use std::cell::LazyCell;
+    ///
+    /// let lazy = LazyCell::new(|| 92);
+    ///
+    /// assert_eq!(LazyCell::get(&lazy), None);
+    /// let _ = LazyCell::force(&lazy);
+    /// assert_eq!(LazyCell::get(&lazy), Some(&92));
+    /// ```
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub fn get(this: &LazyCell<T, F>) -> Option<&T> {
+        this.cell.get()
+    }
+
+    /// Returns a mutable reference to the value if it is initialized, or
+    /// `None` if not.
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub fn get_mut(this: &mut LazyCell<T, F>) -> Option<&mut T> {
+        this.cell.get_mut()
+    }
+}
+
+impl<T, F: FnOnce() -> T> LazyCell<T, F> {
+    /// Forces the evaluation of this lazy value and returns a reference to
+    /// the result.
+    ///
+    /// This is equivalent to the `Deref` impl, but is explicit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    ///
+    /// use std::cell::LazyCell;
+    ///
+    /// let lazy = LazyCell::new(|| 92);
+    ///
+    /// assert_eq!(LazyCell::force(&lazy), &92);
+    /// assert_eq!(&*lazy, &92);
+    /// ```
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub fn force(this: &LazyCell<T, F>) -> &T {
+        this.cell.get_or_init(|| match this.init.take() {
+            Some(f) => f(),
+            None => panic!("`LazyCell` instance has previously been poisoned"),
+        })
+    }
+
+    /// Forces the evaluation of this lazy value and returns a mutable
+    /// reference to the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    ///
+    /// use std::cell::LazyCell;
+    ///
+    /// let mut lazy = LazyCell::new(|| 92);
+    ///
+    /// let p = LazyCell::force_mut(&mut lazy);
+    /// assert_eq!(*p, 92);
+    /// *p = 44;
+    /// assert_eq!(*lazy, 44);
+    /// ```
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub fn force_mut(this: &mut LazyCell<T, F>) -> &mut T {
+        if this.cell.get_mut().is_none() {
+            let value = match this.init.get_mut().take() {
+                Some(f) => f(),
+                None => panic!("`LazyCell` instance has previously been poisoned"),
+            };
+            // The cell is empty and we hold a unique reference, so this
+            // cannot fail.
+            let _ = this.cell.set(value);
+        }
+        this.cell.get_mut().unwrap()
+    }
+}
+
+impl<T, E, F: FnMut() -> Result<T, E>> LazyCell<T, F> {
+    /// Forces the evaluation of this lazy value with a fallible initializing
+    /// function and returns a reference to the result.
+    ///
+    /// If the function returns an error, the error is passed on and the
+    /// `LazyCell` stays uninitialized, so the next access calls the function
+    /// again. Unlike [`force`], a panicking function is not consumed either:
+    /// the panic is propagated and the `LazyCell` can be forced again later.
+    ///
+    /// [`force`]: LazyCell::force
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    ///
+    /// use std::cell::{Cell, LazyCell};
+    ///
+    /// let attempts = Cell::new(0);
+    /// let lazy = LazyCell::new(|| {
+    ///     attempts.set(attempts.get() + 1);
+    ///     if attempts.get() < 2 { Err("not yet") } else { Ok(92) }
+    /// });
+    ///
+    /// assert_eq!(LazyCell::try_force(&lazy), Err("not yet"));
+    /// assert_eq!(LazyCell::get(&lazy), None);
+    /// assert_eq!(LazyCell::try_force(&lazy), Ok(&92));
+    /// assert_eq!(attempts.get(), 2);
+    /// ```
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub fn try_force(this: &LazyCell<T, F>) -> Result<&T, E> {
+        this.cell.get_or_try_init(|| {
+            // `force` needs `F: FnOnce() -> T`, so it never consumes a function
+            // returning `Result<T, E>`. The function is only missing while an
+            // outer call is running it.
+            let mut init = match Restore::take(&this.init) {
+                Some(init) => init,
+                None => panic!("reentrant init"),
+            };
+            let value = init.call()?;
+            init.finish();
+            Ok(value)
+        })
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "74465")]
+impl<T, F: FnOnce() -> T> Deref for LazyCell<T, F> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        LazyCell::force(self)
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "74465")]
+impl<T: Default> Default for LazyCell<T> {
+    /// Creates a new lazy value using `Default` as the initializing function.
+    fn default() -> LazyCell<T> {
+        LazyCell::new(T::default)
+    }
+}
+
+/// Holds the initializing function while [`LazyCell::try_force`] runs it, and
+/// puts it back into the `LazyCell` unless it produced the value, so that a
+/// panicking or failing function is called again on the next access.
+struct Restore<'a, F> {
+    init: &'a Cell<Option<F>>,
+    f: Option<F>,
+}
+
+impl<'a, F> Restore<'a, F> {
+    /// Takes the function out of `init`, or returns `None` if it is missing.
+    fn take(init: &'a Cell<Option<F>>) -> Option<Restore<'a, F>> {
+        let f = init.take()?;
+        Some(Restore { init, f: Some(f) })
+    }
+
+    fn call<R>(&mut self) -> R
+    where
+        F: FnMut() -> R,
+    {
+        (self.f.as_mut().unwrap())()
+    }
+
+    /// Drops the function once it produced the value.
+    fn finish(mut self) {
+        self.f = None;
+    }
+}
+
+impl<F> Drop for Restore<'_, F> {
+    fn drop(&mut self) {
+        if let Some(f) = self.f.take() {
+            self.init.set(Some(f));
+        }
+    }
+}
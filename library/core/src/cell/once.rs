@@ -1,21 +1,24 @@
-//! Lazy values and one-time initialization of static data.
-
-use crate::cell::{Cell, UnsafeCell};
+use crate::cell::UnsafeCell;
 use crate::fmt;
 use crate::mem;
-use crate::ops::Deref;
 
 /// A cell which can be written to only once.
 ///
 /// Unlike `RefCell`, a `OnceCell` only provides shared `&T` references to its value.
 /// Unlike `Cell`, a `OnceCell` doesn't require copying or replacing the value to access it.
 ///
+/// A failed or panicking initialization leaves the cell empty, so a later
+/// call can try again. For a thread-safe version of this struct, see
+/// [`std::sync::OnceLock`].
+///
+/// [`std::sync::OnceLock`]: ../../std/sync/struct.OnceLock.html
+///
 /// # Examples
 ///
 /// ```
 /// #![feature(once_cell)]
 ///
-/// use std::lazy::OnceCell;
+/// use std::cell::OnceCell;
 ///
 /// let cell = OnceCell::new();
 /// assert!(cell.get().is_none());
@@ -118,7 +121,7 @@ pub fn get_mut(&mut self) -> Option<&mut T> {
     /// ```
     /// #![feature(once_cell)]
     ///
-    /// use std::lazy::OnceCell;
+    /// use std::cell::OnceCell;
     ///
     /// let cell = OnceCell::new();
     /// assert!(cell.get().is_none());
@@ -161,7 +164,7 @@ pub fn set(&self, value: T) -> Result<(), T> {
     /// ```
     /// #![feature(once_cell)]
     ///
-    /// use std::lazy::OnceCell;
+    /// use std::cell::OnceCell;
     ///
     /// let cell = OnceCell::new();
     /// let value = cell.get_or_init(|| 92);
@@ -196,7 +199,7 @@ pub fn get_or_init<F>(&self, f: F) -> &T
     /// ```
     /// #![feature(once_cell)]
     ///
-    /// use std::lazy::OnceCell;
+    /// use std::cell::OnceCell;
     ///
     /// let cell = OnceCell::new();
     /// assert_eq!(cell.get_or_try_init(|| Err(())), Err(()));
@@ -242,7 +245,7 @@ fn outlined_call<F, T, E>(f: F) -> Result<T, E>
     /// ```
     /// #![feature(once_cell)]
     ///
-    /// use std::lazy::OnceCell;
+    /// use std::cell::OnceCell;
     ///
     /// let cell: OnceCell<String> = OnceCell::new();
     /// assert_eq!(cell.into_inner(), None);
@@ -269,7 +272,7 @@ pub fn into_inner(self) -> Option<T> {
     /// ```
     /// #![feature(once_cell)]
     ///
-    /// use std::lazy::OnceCell;
+    /// use std::cell::OnceCell;
     ///
     /// let mut cell: OnceCell<String> = OnceCell::new();
     /// assert_eq!(cell.take(), None);
@@ -284,106 +287,3 @@ pub fn take(&mut self) -> Option<T> {
         mem::take(self).into_inner()
     }
 }
-
-/// A value which is initialized on the first access.
-///
-/// # Examples
-///
-/// ```
-/// #![feature(once_cell)]
-///
-/// use std::lazy::Lazy;
-///
-/// let lazy: Lazy<i32> = Lazy::new(|| {
-///     println!("initializing");
-///     92
-/// });
-/// println!("ready");
-/// println!("{}", *lazy);
-/// println!("{}", *lazy);
-///
-/// // Prints:
-/// //   ready
-/// //   initializing
-/// //   92
-/// //   92
-/// ```
-#[unstable(feature = "once_cell", issue = "74465")]
-pub struct Lazy<T, F = fn() -> T> {
-    cell: OnceCell<T>,
-    init: Cell<Option<F>>,
-}
-
-#[unstable(feature = "once_cell", issue = "74465")]
-impl<T: fmt::Debug, F> fmt::Debug for Lazy<T, F> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Lazy").field("cell", &self.cell).field("init", &"..").finish()
-    }
-}
-
-impl<T, F> Lazy<T, F> {
-    /// Creates a new lazy value with the given initializing function.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// #![feature(once_cell)]
-    ///
-    /// # fn main() {
-    /// use std::lazy::Lazy;
-    ///
-    /// let hello = "Hello, World!".to_string();
-    ///
-    /// let lazy = Lazy::new(|| hello.to_uppercase());
-    ///
-    /// assert_eq!(&*lazy, "HELLO, WORLD!");
-    /// # }
-    /// ```
-    #[unstable(feature = "once_cell", issue = "74465")]
-    pub const fn new(init: F) -> Lazy<T, F> {
-        Lazy { cell: OnceCell::new(), init: Cell::new(Some(init)) }
-    }
-}
-
-impl<T, F: FnOnce() -> T> Lazy<T, F> {
-    /// Forces the evaluation of this lazy value and returns a reference to
-    /// the result.
-    ///
-    /// This is equivalent to the `Deref` impl, but is explicit.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// #![feature(once_cell)]
-    ///
-    /// use std::lazy::Lazy;
-    ///
-    /// let lazy = Lazy::new(|| 92);
-    ///
-    /// assert_eq!(Lazy::force(&lazy), &92);
-    /// assert_eq!(&*lazy, &92);
-    /// ```
-    #[unstable(feature = "once_cell", issue = "74465")]
-    pub fn force(this: &Lazy<T, F>) -> &T {
-        this.cell.get_or_init(|| match this.init.take() {
-            Some(f) => f(),
-            None => panic!("`Lazy` instance has previously been poisoned"),
-        })
-    }
-}
-
-#[unstable(feature = "once_cell", issue = "74465")]
-impl<T, F: FnOnce() -> T> Deref for Lazy<T, F> {
-    type Target = T;
-    fn deref(&self) -> &T {
-        Lazy::force(self)
-    }
-}
-
-#[unstable(feature = "once_cell", issue = "74465")]
-impl<T: Default> Default for Lazy<T> {
-    /// Creates a new lazy value using `Default` as the initializing function.
-    fn default() -> Lazy<T> {
-        Lazy::new(T::default)
-    }
-}
@@ -11,6 +11,7 @@
 
 mod future;
 mod into_future;
+mod join;
 mod pending;
 mod poll_fn;
 mod ready;
@@ -21,6 +22,9 @@
 #[unstable(feature = "into_future", issue = "67644")]
 pub use into_future::IntoFuture;
 
+#[unstable(feature = "future_join", issue = "none")]
+pub use self::join::join;
+
 #[stable(feature = "future_readiness_fns", since = "1.48.0")]
 pub use pending::{pending, Pending};
 #[stable(feature = "future_readiness_fns", since = "1.48.0")]
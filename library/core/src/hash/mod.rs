@@ -591,6 +591,37 @@ fn hash_one<T: Hash>(&self, x: T) -> u64
 #[stable(since = "1.7.0", feature = "build_hasher")]
 pub struct BuildHasherDefault<H>(marker::PhantomData<H>);
 
+impl<H> BuildHasherDefault<H> {
+    /// Creates a new `BuildHasherDefault` for Hasher `H`.
+    ///
+    /// Unlike [`Default::default`], this can be called in `const` contexts,
+    /// which allows hash maps using a `BuildHasherDefault` to be created in
+    /// `const` and `static` items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(build_hasher_default_const_new)]
+    /// #![feature(const_collections_with_hasher)]
+    ///
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::collections::HashMap;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// const EMPTY: HashMap<u32, u32, BuildHasherDefault<DefaultHasher>> =
+    ///     HashMap::with_hasher(BuildHasherDefault::new());
+    ///
+    /// let mut map = EMPTY;
+    /// map.insert(1, 2);
+    /// assert_eq!(map[&1], 2);
+    /// ```
+    #[unstable(feature = "build_hasher_default_const_new", issue = "none")]
+    #[rustc_const_unstable(feature = "build_hasher_default_const_new", issue = "none")]
+    pub const fn new() -> Self {
+        BuildHasherDefault(marker::PhantomData)
+    }
+}
+
 #[stable(since = "1.9.0", feature = "core_impl_debug")]
 impl<H> fmt::Debug for BuildHasherDefault<H> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -0,0 +1,384 @@
+use super::{ErrorKind, IoError};
+use crate::cmp;
+use crate::future::Future;
+use crate::mem;
+use crate::ops::DerefMut;
+use crate::pin::Pin;
+use crate::task::{Context, Poll};
+
+/// Read bytes asynchronously.
+///
+/// This trait is the asynchronous analogue of `std::io::Read`. Instead of
+/// blocking when no data is available, [`poll_read`] returns
+/// [`Poll::Pending`] and arranges for the current task to be woken up once the
+/// source becomes readable again.
+///
+/// Failures are reported as [`Self::Error`], which is `std::io::Error` for the
+/// sources of the standard library.
+///
+/// [`poll_read`]: AsyncRead::poll_read
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_io)]
+///
+/// use std::io::AsyncRead;
+///
+/// async fn read_header<R>(mut reader: R) -> Result<[u8; 4], R::Error>
+/// where
+///     R: AsyncRead + Unpin,
+/// {
+///     let mut header = [0; 4];
+///     reader.read_exact(&mut header).await?;
+///     Ok(header)
+/// }
+/// ```
+#[unstable(feature = "async_io", issue = "none")]
+pub trait AsyncRead {
+    /// The type of errors reported by this source.
+    type Error: IoError;
+
+    /// Attempts to read from the source into `buf`.
+    ///
+    /// On success, returns `Poll::Ready(Ok(num_bytes_read))`, with the same
+    /// meaning as the return value of `std::io::Read::read`. In particular, a
+    /// return value of `0` signals the end of the stream or an empty `buf`.
+    ///
+    /// If no data is available yet, returns `Poll::Pending` and arranges for
+    /// the current task (via `cx.waker()`) to be woken up when the source
+    /// becomes readable or is closed.
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<Result<usize, Self::Error>>;
+
+    /// Returns a future which reads the exact number of bytes required to
+    /// fill `buf`.
+    ///
+    /// The future behaves like `std::io::Read::read_exact`: errors of kind
+    /// [`ErrorKind::Interrupted`] are retried, and reaching end of file before
+    /// `buf` is full results in an error of kind [`ErrorKind::UnexpectedEof`].
+    /// The contents of `buf` are unspecified if the future resolves to an
+    /// error.
+    fn read_exact<'a>(&'a mut self, buf: &'a mut [u8]) -> ReadExact<'a, Self>
+    where
+        Self: Sized + Unpin,
+    {
+        ReadExact { reader: self, buf }
+    }
+}
+
+/// Write bytes asynchronously.
+///
+/// This trait is the asynchronous analogue of `std::io::Write`. Instead of
+/// blocking when the sink cannot accept more data, [`poll_write`] returns
+/// [`Poll::Pending`] and arranges for the current task to be woken up once
+/// the sink becomes writable again.
+///
+/// Failures are reported as [`Self::Error`], which is `std::io::Error` for the
+/// sinks of the standard library.
+///
+/// [`poll_write`]: AsyncWrite::poll_write
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_io)]
+///
+/// use std::io::AsyncWrite;
+///
+/// async fn greet<W: AsyncWrite + Unpin>(mut writer: W) -> Result<(), W::Error> {
+///     writer.write_all(b"hello world\n").await
+/// }
+/// ```
+#[unstable(feature = "async_io", issue = "none")]
+pub trait AsyncWrite {
+    /// The type of errors reported by this sink.
+    type Error: IoError;
+
+    /// Attempts to write `buf` into the sink.
+    ///
+    /// On success, returns `Poll::Ready(Ok(num_bytes_written))`, with the same
+    /// meaning as the return value of `std::io::Write::write`.
+    ///
+    /// If the sink cannot accept data yet, returns `Poll::Pending` and
+    /// arranges for the current task (via `cx.waker()`) to be woken up when it
+    /// becomes writable or is closed.
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, Self::Error>>;
+
+    /// Attempts to flush the sink, ensuring that any buffered data reaches its
+    /// destination.
+    ///
+    /// Returns `Poll::Pending` and arranges for the current task to be woken
+    /// up if the flush cannot complete yet.
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>>;
+
+    /// Attempts to close the sink.
+    ///
+    /// Implementations should flush any buffered data before closing. Once
+    /// this returns `Poll::Ready(Ok(()))`, the sink should not be written to
+    /// again.
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>>;
+
+    /// Returns a future which writes the entire contents of `buf`.
+    ///
+    /// The future behaves like `std::io::Write::write_all`: errors of kind
+    /// [`ErrorKind::Interrupted`] are retried, and a write returning `0`
+    /// results in an error of kind [`ErrorKind::WriteZero`].
+    fn write_all<'a>(&'a mut self, buf: &'a [u8]) -> WriteAll<'a, Self>
+    where
+        Self: Sized + Unpin,
+    {
+        WriteAll { writer: self, buf }
+    }
+}
+
+/// Read bytes asynchronously from a source with an internal buffer.
+///
+/// This trait is the asynchronous analogue of `std::io::BufRead`.
+#[unstable(feature = "async_io", issue = "none")]
+pub trait AsyncBufRead: AsyncRead {
+    /// Attempts to return the contents of the internal buffer, filling it
+    /// with more data from the inner reader if it is empty.
+    ///
+    /// An empty buffer returned indicates that the end of the stream has been
+    /// reached. If no data is available yet, returns `Poll::Pending` and
+    /// arranges for the current task to be woken up when there is.
+    ///
+    /// This function is a lower-level call. It needs to be paired with the
+    /// [`consume`] method to function properly.
+    ///
+    /// [`consume`]: AsyncBufRead::consume
+    fn poll_fill_buf(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<&[u8], Self::Error>>;
+
+    /// Tells this buffer that `amt` bytes have been consumed from the buffer,
+    /// so they should no longer be returned by [`poll_fill_buf`].
+    ///
+    /// The `amt` must be `<=` the number of bytes in the buffer returned by
+    /// [`poll_fill_buf`].
+    ///
+    /// [`poll_fill_buf`]: AsyncBufRead::poll_fill_buf
+    fn consume(self: Pin<&mut Self>, amt: usize);
+}
+
+/// Future for the [`read_exact`] method on [`AsyncRead`].
+///
+/// [`read_exact`]: AsyncRead::read_exact
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[unstable(feature = "async_io", issue = "none")]
+pub struct ReadExact<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut [u8],
+}
+
+#[unstable(feature = "async_io", issue = "none")]
+impl<R: AsyncRead + ?Sized + Unpin> Future for ReadExact<'_, R> {
+    type Output = Result<(), R::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        while !this.buf.is_empty() {
+            match Pin::new(&mut *this.reader).poll_read(cx, this.buf) {
+                Poll::Ready(Ok(0)) => {
+                    return Poll::Ready(Err(ErrorKind::UnexpectedEof.into()));
+                }
+                Poll::Ready(Ok(n)) => {
+                    let buf = mem::take(&mut this.buf);
+                    this.buf = &mut buf[n..];
+                }
+                Poll::Ready(Err(ref e)) if e.kind() == ErrorKind::Interrupted => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Future for the [`write_all`] method on [`AsyncWrite`].
+///
+/// [`write_all`]: AsyncWrite::write_all
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[unstable(feature = "async_io", issue = "none")]
+pub struct WriteAll<'a, W: ?Sized> {
+    writer: &'a mut W,
+    buf: &'a [u8],
+}
+
+#[unstable(feature = "async_io", issue = "none")]
+impl<W: AsyncWrite + ?Sized + Unpin> Future for WriteAll<'_, W> {
+    type Output = Result<(), W::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        while !this.buf.is_empty() {
+            match Pin::new(&mut *this.writer).poll_write(cx, this.buf) {
+                Poll::Ready(Ok(0)) => return Poll::Ready(Err(ErrorKind::WriteZero.into())),
+                Poll::Ready(Ok(n)) => this.buf = &this.buf[n..],
+                Poll::Ready(Err(ref e)) if e.kind() == ErrorKind::Interrupted => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+// =============================================================================
+// Forwarding implementations
+
+#[unstable(feature = "async_io", issue = "none")]
+impl<R: AsyncRead + ?Sized + Unpin> AsyncRead for &mut R {
+    type Error = R::Error;
+
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<Result<usize, R::Error>> {
+        Pin::new(&mut **self).poll_read(cx, buf)
+    }
+}
+
+#[unstable(feature = "async_io", issue = "none")]
+impl<P> AsyncRead for Pin<P>
+where
+    P: DerefMut + Unpin,
+    P::Target: AsyncRead,
+{
+    type Error = <P::Target as AsyncRead>::Error;
+
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<Result<usize, Self::Error>> {
+        self.get_mut().as_mut().poll_read(cx, buf)
+    }
+}
+
+#[unstable(feature = "async_io", issue = "none")]
+impl<W: AsyncWrite + ?Sized + Unpin> AsyncWrite for &mut W {
+    type Error = W::Error;
+
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, W::Error>> {
+        Pin::new(&mut **self).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), W::Error>> {
+        Pin::new(&mut **self).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), W::Error>> {
+        Pin::new(&mut **self).poll_close(cx)
+    }
+}
+
+#[unstable(feature = "async_io", issue = "none")]
+impl<P> AsyncWrite for Pin<P>
+where
+    P: DerefMut + Unpin,
+    P::Target: AsyncWrite,
+{
+    type Error = <P::Target as AsyncWrite>::Error;
+
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, Self::Error>> {
+        self.get_mut().as_mut().poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().as_mut().poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().as_mut().poll_close(cx)
+    }
+}
+
+#[unstable(feature = "async_io", issue = "none")]
+impl<B: AsyncBufRead + ?Sized + Unpin> AsyncBufRead for &mut B {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<&[u8], B::Error>> {
+        Pin::new(&mut **self.get_mut()).poll_fill_buf(cx)
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        Pin::new(&mut **self).consume(amt)
+    }
+}
+
+#[unstable(feature = "async_io", issue = "none")]
+impl<P> AsyncBufRead for Pin<P>
+where
+    P: DerefMut + Unpin,
+    P::Target: AsyncBufRead,
+{
+    fn poll_fill_buf(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<&[u8], Self::Error>> {
+        self.get_mut().as_mut().poll_fill_buf(cx)
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.get_mut().as_mut().consume(amt)
+    }
+}
+
+// =============================================================================
+// In-memory implementations
+//
+// These never block or fail, so they only report the kind of error that
+// `read_exact` and `write_all` may produce.
+
+#[unstable(feature = "async_io", issue = "none")]
+impl AsyncRead for &[u8] {
+    type Error = ErrorKind;
+
+    #[inline]
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<Result<usize, ErrorKind>> {
+        let amt = cmp::min(buf.len(), self.len());
+        let (a, b) = self.split_at(amt);
+        buf[..amt].copy_from_slice(a);
+        *self = b;
+        Poll::Ready(Ok(amt))
+    }
+}
+
+#[unstable(feature = "async_io", issue = "none")]
+impl AsyncBufRead for &[u8] {
+    #[inline]
+    fn poll_fill_buf(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Result<&[u8], ErrorKind>> {
+        Poll::Ready(Ok(*self.get_mut()))
+    }
+
+    #[inline]
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        *self = &self[amt..];
+    }
+}
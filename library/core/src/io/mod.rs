@@ -0,0 +1,17 @@
+//! Traits and types for I/O that do not depend on the operating system.
+//!
+//! This module provides [`ErrorKind`], the categories of I/O errors, and the
+//! asynchronous I/O traits [`AsyncRead`], [`AsyncWrite`] and [`AsyncBufRead`].
+//! The traits report failures through an associated error type implementing
+//! [`IoError`], so they can be implemented by sources and sinks that do not
+//! use `std::io::Error`. `std::io` re-exports everything defined here.
+
+mod async_io;
+mod error;
+
+#[unstable(feature = "async_io", issue = "none")]
+pub use self::async_io::{AsyncBufRead, AsyncRead, AsyncWrite, ReadExact, WriteAll};
+#[stable(feature = "rust1", since = "1.0.0")]
+pub use self::error::ErrorKind;
+#[unstable(feature = "async_io", issue = "none")]
+pub use self::error::IoError;
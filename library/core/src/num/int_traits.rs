@@ -0,0 +1,403 @@
+//! Traits grouping the operations shared by the primitive integer types.
+
+use crate::fmt;
+use crate::hash::Hash;
+use crate::iter::{Product, Sum};
+use crate::num::ParseIntError;
+use crate::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign};
+use crate::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+use crate::ops::{Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign};
+use crate::str::FromStr;
+
+mod private {
+    /// This trait being unreachable from outside the crate prevents other
+    /// implementations of the integer traits, which allows adding more trait
+    /// methods later on.
+    #[unstable(feature = "int_traits", issue = "none")]
+    pub trait Sealed {}
+}
+
+/// Operations shared by all primitive integer types.
+///
+/// This allows writing code that is generic over the integer type. Every
+/// constant and method behaves like the inherent item of the same name on the
+/// implementing type; see for example [`u32::checked_add`].
+///
+/// This trait is sealed and cannot be implemented outside of `core`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(int_traits)]
+///
+/// use std::num::Integer;
+///
+/// fn checked_sum<T: Integer>(values: &[T]) -> Option<T> {
+///     values.iter().try_fold(T::ZERO, |acc, &x| acc.checked_add(x))
+/// }
+///
+/// assert_eq!(checked_sum(&[1u8, 2, 3]), Some(6));
+/// assert_eq!(checked_sum(&[100u8, 100, 100]), None);
+/// assert_eq!(checked_sum(&[-100i64, 100, 100]), Some(100));
+/// ```
+#[unstable(feature = "int_traits", issue = "none")]
+pub trait Integer:
+    private::Sealed
+    + Copy
+    + Default
+    + Eq
+    + Ord
+    + Hash
+    + Send
+    + Sync
+    + fmt::Debug
+    + fmt::Display
+    + fmt::Binary
+    + fmt::Octal
+    + fmt::LowerHex
+    + fmt::UpperHex
+    + FromStr<Err = ParseIntError>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Not<Output = Self>
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+    + BitAndAssign
+    + BitOrAssign
+    + BitXorAssign
+    + ShlAssign<u32>
+    + ShrAssign<u32>
+    + Sum
+    + Product
+    + 'static
+{
+    /// The integer `0`.
+    const ZERO: Self;
+    /// The integer `1`.
+    const ONE: Self;
+    /// The smallest value that can be represented by this integer type.
+    const MIN: Self;
+    /// The largest value that can be represented by this integer type.
+    const MAX: Self;
+    /// The size of this integer type in bits.
+    const BITS: u32;
+
+    /// Converts a string slice in a given base to an integer.
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError>;
+
+    /// Returns the number of ones in the binary representation of `self`.
+    fn count_ones(self) -> u32;
+    /// Returns the number of zeros in the binary representation of `self`.
+    fn count_zeros(self) -> u32;
+    /// Returns the number of leading zeros in the binary representation of `self`.
+    fn leading_zeros(self) -> u32;
+    /// Returns the number of trailing zeros in the binary representation of `self`.
+    fn trailing_zeros(self) -> u32;
+    /// Shifts the bits to the left by `n`, wrapping the truncated bits to the end.
+    fn rotate_left(self, n: u32) -> Self;
+    /// Shifts the bits to the right by `n`, wrapping the truncated bits to the beginning.
+    fn rotate_right(self, n: u32) -> Self;
+    /// Reverses the byte order of the integer.
+    fn swap_bytes(self) -> Self;
+    /// Reverses the order of bits in the integer.
+    fn reverse_bits(self) -> Self;
+    /// Raises `self` to the power of `exp`, using exponentiation by squaring.
+    fn pow(self, exp: u32) -> Self;
+
+    /// Checked integer addition.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// Checked integer subtraction.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Checked integer multiplication.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// Checked integer division.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    /// Checked integer remainder.
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    /// Checked exponentiation.
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+    /// Saturating integer addition.
+    fn saturating_add(self, rhs: Self) -> Self;
+    /// Saturating integer subtraction.
+    fn saturating_sub(self, rhs: Self) -> Self;
+    /// Saturating integer multiplication.
+    fn saturating_mul(self, rhs: Self) -> Self;
+    /// Wrapping (modular) addition.
+    fn wrapping_add(self, rhs: Self) -> Self;
+    /// Wrapping (modular) subtraction.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    /// Wrapping (modular) multiplication.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    /// Calculates `self + rhs`, along with a boolean indicating whether an
+    /// arithmetic overflow would occur.
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+    /// Calculates `self - rhs`, along with a boolean indicating whether an
+    /// arithmetic overflow would occur.
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+    /// Calculates `self * rhs`, along with a boolean indicating whether an
+    /// arithmetic overflow would occur.
+    fn overflowing_mul(self, rhs: Self) -> (Self, bool);
+    /// Calculates the quotient of Euclidean division of `self` by `rhs`.
+    fn div_euclid(self, rhs: Self) -> Self;
+    /// Calculates the least nonnegative remainder of `self (mod rhs)`.
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    /// Calculates the quotient of `self` and `rhs`, rounding the result
+    /// towards negative infinity.
+    #[unstable(feature = "int_roundings", issue = "88581")]
+    fn div_floor(self, rhs: Self) -> Self;
+    /// Calculates the quotient of `self` and `rhs`, rounding the result
+    /// towards positive infinity.
+    #[unstable(feature = "int_roundings", issue = "88581")]
+    fn div_ceil(self, rhs: Self) -> Self;
+    /// Rounds `self` to a multiple of `rhs`, towards positive infinity if
+    /// `rhs` is positive and towards negative infinity if it is negative.
+    #[unstable(feature = "int_roundings", issue = "88581")]
+    fn next_multiple_of(self, rhs: Self) -> Self;
+    /// Like [`next_multiple_of`](Integer::next_multiple_of), but returns
+    /// `None` if `rhs` is zero or the operation would result in overflow.
+    #[unstable(feature = "int_roundings", issue = "88581")]
+    fn checked_next_multiple_of(self, rhs: Self) -> Option<Self>;
+
+    /// Returns the logarithm of the number with respect to an arbitrary base,
+    /// rounded down.
+    #[unstable(feature = "int_log", issue = "70887")]
+    fn ilog(self, base: Self) -> u32;
+    /// Returns the base 2 logarithm of the number, rounded down.
+    #[unstable(feature = "int_log", issue = "70887")]
+    fn ilog2(self) -> u32;
+    /// Returns the base 10 logarithm of the number, rounded down.
+    #[unstable(feature = "int_log", issue = "70887")]
+    fn ilog10(self) -> u32;
+    /// Returns the logarithm of the number with respect to an arbitrary base,
+    /// rounded down, or `None` if it is not defined.
+    #[unstable(feature = "int_log", issue = "70887")]
+    fn checked_ilog(self, base: Self) -> Option<u32>;
+    /// Returns the base 2 logarithm of the number, rounded down, or `None` if
+    /// it is not defined.
+    #[unstable(feature = "int_log", issue = "70887")]
+    fn checked_ilog2(self) -> Option<u32>;
+    /// Returns the base 10 logarithm of the number, rounded down, or `None`
+    /// if it is not defined.
+    #[unstable(feature = "int_log", issue = "70887")]
+    fn checked_ilog10(self) -> Option<u32>;
+
+    /// Returns the square root of the number, rounded down.
+    #[unstable(feature = "isqrt", issue = "none")]
+    fn isqrt(self) -> Self;
+
+    /// Calculates `self + rhs + carry`, returning the sum and the output
+    /// carry.
+    #[unstable(feature = "bigint_helper_methods", issue = "85532")]
+    fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool);
+    /// Calculates `self - rhs - borrow`, returning the difference and the
+    /// output borrow.
+    #[unstable(feature = "bigint_helper_methods", issue = "85532")]
+    fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool);
+    /// Calculates the complete product `self * rhs` as its low-order and
+    /// high-order halves.
+    #[unstable(feature = "bigint_helper_methods", issue = "85532")]
+    fn widening_mul(self, rhs: Self) -> (Self, Self);
+    /// Calculates the complete value of `self * rhs + carry` as its low-order
+    /// and high-order halves.
+    #[unstable(feature = "bigint_helper_methods", issue = "85532")]
+    fn carrying_mul(self, rhs: Self, carry: Self) -> (Self, Self);
+}
+
+/// Operations shared by the primitive signed integer types.
+///
+/// This trait is sealed and cannot be implemented outside of `core`.
+#[unstable(feature = "int_traits", issue = "none")]
+pub trait SignedInteger: Integer + Neg<Output = Self> {
+    /// The unsigned integer type of the same size.
+    type Unsigned: UnsignedInteger<Signed = Self>;
+
+    /// Computes the absolute value of `self`.
+    fn abs(self) -> Self;
+    /// Computes the absolute value of `self` without any wrapping or
+    /// panicking, as the unsigned type of the same size.
+    fn unsigned_abs(self) -> Self::Unsigned;
+    /// Returns a number representing the sign of `self`.
+    fn signum(self) -> Self;
+    /// Returns `true` if `self` is positive.
+    fn is_positive(self) -> bool;
+    /// Returns `true` if `self` is negative.
+    fn is_negative(self) -> bool;
+    /// Checked absolute value.
+    fn checked_abs(self) -> Option<Self>;
+    /// Checked negation.
+    fn checked_neg(self) -> Option<Self>;
+    /// Wrapping (modular) negation.
+    fn wrapping_neg(self) -> Self;
+
+    /// Returns the square root of the number, rounded down, or `None` if it
+    /// is negative.
+    #[unstable(feature = "isqrt", issue = "none")]
+    fn checked_isqrt(self) -> Option<Self>;
+}
+
+/// Operations shared by the primitive unsigned integer types.
+///
+/// This trait is sealed and cannot be implemented outside of `core`.
+#[unstable(feature = "int_traits", issue = "none")]
+pub trait UnsignedInteger: Integer {
+    /// The signed integer type of the same size.
+    type Signed: SignedInteger<Unsigned = Self>;
+
+    /// Returns `true` if and only if `self == 2^k` for some `k`.
+    fn is_power_of_two(self) -> bool;
+    /// Returns the smallest power of two greater than or equal to `self`.
+    fn next_power_of_two(self) -> Self;
+    /// Returns the smallest power of two greater than or equal to `self`, or
+    /// `None` if it does not fit.
+    fn checked_next_power_of_two(self) -> Option<Self>;
+}
+
+/// Implements trait methods by forwarding to the inherent methods of the same
+/// name, which take precedence in path resolution.
+macro_rules! forward {
+    ($($name:ident($($arg:ident: $ty:ty),*) -> $ret:ty;)*) => {
+        $(
+            #[inline]
+            fn $name(self, $($arg: $ty),*) -> $ret {
+                Self::$name(self, $($arg),*)
+            }
+        )*
+    };
+}
+
+macro_rules! impl_integer {
+    ($($t:ty)*) => {
+        $(
+            #[unstable(feature = "int_traits", issue = "none")]
+            impl private::Sealed for $t {}
+
+            #[unstable(feature = "int_traits", issue = "none")]
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MIN: Self = Self::MIN;
+                const MAX: Self = Self::MAX;
+                const BITS: u32 = Self::BITS;
+
+                #[inline]
+                fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+                    Self::from_str_radix(src, radix)
+                }
+
+                // The inherent methods keep an `unstable_` prefix so they do not
+                // shadow the `num_integer::Integer` methods of the same name.
+                #[inline]
+                fn div_floor(self, rhs: Self) -> Self {
+                    Self::unstable_div_floor(self, rhs)
+                }
+
+                #[inline]
+                fn div_ceil(self, rhs: Self) -> Self {
+                    Self::unstable_div_ceil(self, rhs)
+                }
+
+                #[inline]
+                fn next_multiple_of(self, rhs: Self) -> Self {
+                    Self::unstable_next_multiple_of(self, rhs)
+                }
+
+                forward! {
+                    count_ones() -> u32;
+                    count_zeros() -> u32;
+                    leading_zeros() -> u32;
+                    trailing_zeros() -> u32;
+                    rotate_left(n: u32) -> Self;
+                    rotate_right(n: u32) -> Self;
+                    swap_bytes() -> Self;
+                    reverse_bits() -> Self;
+                    pow(exp: u32) -> Self;
+                    checked_add(rhs: Self) -> Option<Self>;
+                    checked_sub(rhs: Self) -> Option<Self>;
+                    checked_mul(rhs: Self) -> Option<Self>;
+                    checked_div(rhs: Self) -> Option<Self>;
+                    checked_rem(rhs: Self) -> Option<Self>;
+                    checked_pow(exp: u32) -> Option<Self>;
+                    saturating_add(rhs: Self) -> Self;
+                    saturating_sub(rhs: Self) -> Self;
+                    saturating_mul(rhs: Self) -> Self;
+                    wrapping_add(rhs: Self) -> Self;
+                    wrapping_sub(rhs: Self) -> Self;
+                    wrapping_mul(rhs: Self) -> Self;
+                    overflowing_add(rhs: Self) -> (Self, bool);
+                    overflowing_sub(rhs: Self) -> (Self, bool);
+                    overflowing_mul(rhs: Self) -> (Self, bool);
+                    div_euclid(rhs: Self) -> Self;
+                    rem_euclid(rhs: Self) -> Self;
+                    checked_next_multiple_of(rhs: Self) -> Option<Self>;
+                    ilog(base: Self) -> u32;
+                    ilog2() -> u32;
+                    ilog10() -> u32;
+                    checked_ilog(base: Self) -> Option<u32>;
+                    checked_ilog2() -> Option<u32>;
+                    checked_ilog10() -> Option<u32>;
+                    isqrt() -> Self;
+                    carrying_add(rhs: Self, carry: bool) -> (Self, bool);
+                    borrowing_sub(rhs: Self, borrow: bool) -> (Self, bool);
+                    widening_mul(rhs: Self) -> (Self, Self);
+                    carrying_mul(rhs: Self, carry: Self) -> (Self, Self);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed_unsigned {
+    ($($s:ty, $u:ty;)*) => {
+        $(
+            #[unstable(feature = "int_traits", issue = "none")]
+            impl SignedInteger for $s {
+                type Unsigned = $u;
+
+                forward! {
+                    abs() -> Self;
+                    unsigned_abs() -> $u;
+                    signum() -> Self;
+                    is_positive() -> bool;
+                    is_negative() -> bool;
+                    checked_abs() -> Option<Self>;
+                    checked_neg() -> Option<Self>;
+                    wrapping_neg() -> Self;
+                    checked_isqrt() -> Option<Self>;
+                }
+            }
+
+            #[unstable(feature = "int_traits", issue = "none")]
+            impl UnsignedInteger for $u {
+                type Signed = $s;
+
+                forward! {
+                    is_power_of_two() -> bool;
+                    next_power_of_two() -> Self;
+                    checked_next_power_of_two() -> Option<Self>;
+                }
+            }
+        )*
+    };
+}
+
+impl_integer! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+impl_signed_unsigned! {
+    i8, u8;
+    i16, u16;
+    i32, u32;
+    i64, u64;
+    i128, u128;
+    isize, usize;
+}
@@ -42,6 +42,7 @@ macro_rules! unlikely {
 
 mod error;
 mod int_log10;
+mod int_traits;
 mod nonzero;
 #[unstable(feature = "saturating_int_impl", issue = "87920")]
 mod saturating;
@@ -52,6 +53,9 @@ macro_rules! unlikely {
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use wrapping::Wrapping;
 
+#[unstable(feature = "int_traits", issue = "none")]
+pub use int_traits::{Integer, SignedInteger, UnsignedInteger};
+
 #[stable(feature = "rust1", since = "1.0.0")]
 #[cfg(not(no_fp_fmt_parse))]
 pub use dec2flt::ParseFloatError;
@@ -94,13 +98,9 @@ macro_rules! usize_isize_from_xe_bytes_doc {
 }
 
 macro_rules! widening_impl {
-    ($SelfT:ty, $WideT:ty, $BITS:literal, unsigned) => {
-        widening_impl!($SelfT, $WideT, $BITS, "");
-    };
-    ($SelfT:ty, $WideT:ty, $BITS:literal, signed) => {
-        widening_impl!($SelfT, $WideT, $BITS, "# //");
-    };
-    ($SelfT:ty, $WideT:ty, $BITS:literal, $AdaptiveTestPrefix:literal) => {
+    // `$full_mul` computes the low and high halves of `$lhs * $rhs + $carry`.
+    (@impl $SelfT:ty, $AdaptiveTestPrefix:literal,
+     |$lhs:ident, $rhs:ident, $carry:ident| $full_mul:expr) => {
         /// Calculates the complete product `self * rhs` without the possibility to overflow.
         ///
         /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
@@ -124,11 +124,8 @@ macro_rules! widening_impl {
                       without modifying the original"]
         #[inline]
         pub const fn widening_mul(self, rhs: Self) -> (Self, Self) {
-            // note: longer-term this should be done via an intrinsic,
-            //   but for now we can deal without an impl for u128/i128
-            // SAFETY: overflow will be contained within the wider types
-            let wide = unsafe { (self as $WideT).unchecked_mul(rhs as $WideT) };
-            (wide as $SelfT, (wide >> $BITS) as $SelfT)
+            let ($lhs, $rhs, $carry) = (self, rhs, 0);
+            $full_mul
         }
 
         /// Calculates the "full multiplication" `self * rhs + carry`
@@ -188,17 +185,84 @@ pub const fn widening_mul(self, rhs: Self) -> (Self, Self) {
                       without modifying the original"]
         #[inline]
         pub const fn carrying_mul(self, rhs: Self, carry: Self) -> (Self, Self) {
-            // note: longer-term this should be done via an intrinsic,
-            //   but for now we can deal without an impl for u128/i128
+            let ($lhs, $rhs, $carry) = (self, rhs, carry);
+            $full_mul
+        }
+    };
+    // There is no type wider than 128 bits to compute the product in.
+    (u128) => {
+        widening_impl!(@impl u128, "", |lhs, rhs, carry| carrying_mul_u128(lhs, rhs, carry));
+    };
+    (i128) => {
+        widening_impl!(@impl i128, "# //", |lhs, rhs, carry| carrying_mul_i128(lhs, rhs, carry));
+    };
+    ($SelfT:ty, $WideT:ty, $BITS:literal, unsigned) => {
+        widening_impl!($SelfT, $WideT, $BITS, "");
+    };
+    ($SelfT:ty, $WideT:ty, $BITS:literal, signed) => {
+        widening_impl!($SelfT, $WideT, $BITS, "# //");
+    };
+    ($SelfT:ty, $WideT:ty, $BITS:literal, $AdaptiveTestPrefix:literal) => {
+        widening_impl!(@impl $SelfT, $AdaptiveTestPrefix, |lhs, rhs, carry| {
+            // note: longer-term this should be done via an intrinsic
             // SAFETY: overflow will be contained within the wider types
             let wide = unsafe {
-                (self as $WideT).unchecked_mul(rhs as $WideT).unchecked_add(carry as $WideT)
+                (lhs as $WideT).unchecked_mul(rhs as $WideT).unchecked_add(carry as $WideT)
             };
             (wide as $SelfT, (wide >> $BITS) as $SelfT)
-        }
+        });
     };
 }
 
+/// Computes `lhs * rhs + carry` as its low and high halves, like `u128::carrying_mul`.
+///
+/// The operands are split into 64-bit halves whose partial products are summed as in long
+/// multiplication, as there is no wider type to compute the product in.
+#[inline]
+const fn carrying_mul_u128(lhs: u128, rhs: u128, carry: u128) -> (u128, u128) {
+    const LOW: u128 = u64::MAX as u128;
+
+    let (lhs_lo, lhs_hi) = (lhs & LOW, lhs >> 64);
+    let (rhs_lo, rhs_hi) = (rhs & LOW, rhs >> 64);
+    let lo_lo = lhs_lo * rhs_lo;
+    let lo_hi = lhs_lo * rhs_hi;
+    let hi_lo = lhs_hi * rhs_lo;
+    let hi_hi = lhs_hi * rhs_hi;
+
+    // Sum of three values below 2^64, so this cannot overflow.
+    let mid = (lo_lo >> 64) + (lo_hi & LOW) + (hi_lo & LOW);
+    let lo = (lo_lo & LOW) | (mid << 64);
+    let hi = hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (mid >> 64);
+
+    // The full result is at most `(2^128 - 1)^2 + 2^128 - 1 < 2^256`, so `hi` cannot overflow.
+    let (lo, overflow) = lo.overflowing_add(carry);
+    (lo, hi + overflow as u128)
+}
+
+/// Computes `lhs * rhs + carry` as its low and high halves, like `i128::carrying_mul`.
+#[inline]
+const fn carrying_mul_i128(lhs: i128, rhs: i128, carry: i128) -> (i128, i128) {
+    let (lo, mut hi) = carrying_mul_u128(lhs as u128, rhs as u128, 0);
+
+    // Reinterpreting a negative operand as unsigned adds 2^128 to it, which adds the other
+    // operand to the high half of the product.
+    if lhs < 0 {
+        hi = hi.wrapping_sub(rhs as u128);
+    }
+    if rhs < 0 {
+        hi = hi.wrapping_sub(lhs as u128);
+    }
+
+    // Add the sign-extended carry.
+    let (lo, overflow) = lo.overflowing_add(carry as u128);
+    hi = hi.wrapping_add(overflow as u128);
+    if carry < 0 {
+        hi = hi.wrapping_sub(1);
+    }
+
+    (lo as i128, hi as i128)
+}
+
 #[lang = "i8"]
 impl i8 {
     int_impl! { i8, i8, u8, 8, 7, -128, 127, 2, "-0x7e", "0xa", "0x12", "0x12", "0x48",
@@ -240,6 +304,7 @@ impl i128 {
       0x56, 0x34, 0x12, 0x90, 0x78, 0x56, 0x34, 0x12]",
     "[0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56, \
       0x78, 0x90, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12]", "", "" }
+    widening_impl! { i128 }
 }
 
 #[cfg(target_pointer_width = "16")]
@@ -858,6 +923,7 @@ impl u128 {
     "[0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56, \
       0x78, 0x90, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12]",
      "", ""}
+    widening_impl! { u128 }
 }
 
 #[cfg(target_pointer_width = "16")]
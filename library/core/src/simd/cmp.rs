@@ -0,0 +1,64 @@
+use super::intrinsics;
+use super::{LaneCount, Mask, Simd, SimdElement, SupportedLaneCount};
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SimdElement + PartialEq,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Tests if each lane is equal to the corresponding lane in `other`.
+    #[inline]
+    #[must_use]
+    pub fn lanes_eq(self, other: Self) -> Mask<T::Mask, LANES> {
+        // SAFETY: comparisons produce valid masks.
+        unsafe { Mask::from_int_unchecked(intrinsics::simd_eq(self, other)) }
+    }
+
+    /// Tests if each lane is not equal to the corresponding lane in `other`.
+    #[inline]
+    #[must_use]
+    pub fn lanes_ne(self, other: Self) -> Mask<T::Mask, LANES> {
+        // SAFETY: comparisons produce valid masks.
+        unsafe { Mask::from_int_unchecked(intrinsics::simd_ne(self, other)) }
+    }
+}
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SimdElement + PartialOrd,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Tests if each lane is less than the corresponding lane in `other`.
+    #[inline]
+    #[must_use]
+    pub fn lanes_lt(self, other: Self) -> Mask<T::Mask, LANES> {
+        // SAFETY: comparisons produce valid masks.
+        unsafe { Mask::from_int_unchecked(intrinsics::simd_lt(self, other)) }
+    }
+
+    /// Tests if each lane is less than or equal to the corresponding lane in
+    /// `other`.
+    #[inline]
+    #[must_use]
+    pub fn lanes_le(self, other: Self) -> Mask<T::Mask, LANES> {
+        // SAFETY: comparisons produce valid masks.
+        unsafe { Mask::from_int_unchecked(intrinsics::simd_le(self, other)) }
+    }
+
+    /// Tests if each lane is greater than the corresponding lane in `other`.
+    #[inline]
+    #[must_use]
+    pub fn lanes_gt(self, other: Self) -> Mask<T::Mask, LANES> {
+        // SAFETY: comparisons produce valid masks.
+        unsafe { Mask::from_int_unchecked(intrinsics::simd_gt(self, other)) }
+    }
+
+    /// Tests if each lane is greater than or equal to the corresponding lane
+    /// in `other`.
+    #[inline]
+    #[must_use]
+    pub fn lanes_ge(self, other: Self) -> Mask<T::Mask, LANES> {
+        // SAFETY: comparisons produce valid masks.
+        unsafe { Mask::from_int_unchecked(intrinsics::simd_ge(self, other)) }
+    }
+}
@@ -0,0 +1,104 @@
+/// Implemented only by the types in this module.
+pub trait Sealed {}
+
+/// Marker trait for types that may be used as SIMD vector elements.
+///
+/// This trait is sealed and implemented for the primitive integer and float
+/// types.
+///
+/// # Safety
+///
+/// Implementors must be primitive types that the codegen backends accept as
+/// vector elements, which is why no types outside of `core` may implement it.
+#[unstable(feature = "portable_simd", issue = "none")]
+pub unsafe trait SimdElement: Sealed + Copy {
+    /// The mask element type corresponding to this element type, i.e. the
+    /// signed integer of the same width.
+    type Mask: MaskElement;
+
+    /// Whether this is a floating point type.
+    #[doc(hidden)]
+    const IS_FLOAT: bool;
+
+    /// Converts a float to `f64`. Only called when `IS_FLOAT` is `true`.
+    #[doc(hidden)]
+    fn to_f64(self) -> f64;
+
+    /// Converts from `f64` with the semantics of an `as` cast.
+    #[doc(hidden)]
+    fn from_f64(value: f64) -> Self;
+}
+
+/// Marker trait for types that may be used as SIMD mask elements.
+///
+/// This trait is sealed and implemented for the primitive signed integer
+/// types. Each lane of a mask vector is either `0` (false) or `-1` (true).
+///
+/// # Safety
+///
+/// Implementors must be signed integers for which `TRUE` and `FALSE` are `-1`
+/// and `0`, which is why no types outside of `core` may implement it.
+#[unstable(feature = "portable_simd", issue = "none")]
+pub unsafe trait MaskElement: SimdElement<Mask = Self> + PartialEq {
+    /// The value of a true lane, with all bits set.
+    #[doc(hidden)]
+    const TRUE: Self;
+
+    /// The value of a false lane, with no bits set.
+    #[doc(hidden)]
+    const FALSE: Self;
+}
+
+macro_rules! impl_element {
+    ($($ty:ty => $mask:ty, $is_float:literal;)*) => {
+        $(
+            impl Sealed for $ty {}
+
+            #[unstable(feature = "portable_simd", issue = "none")]
+            unsafe impl SimdElement for $ty {
+                type Mask = $mask;
+
+                const IS_FLOAT: bool = $is_float;
+
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                #[inline]
+                fn from_f64(value: f64) -> Self {
+                    value as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_element! {
+    u8 => i8, false;
+    u16 => i16, false;
+    u32 => i32, false;
+    u64 => i64, false;
+    usize => isize, false;
+    i8 => i8, false;
+    i16 => i16, false;
+    i32 => i32, false;
+    i64 => i64, false;
+    isize => isize, false;
+    f32 => i32, true;
+    f64 => i64, true;
+}
+
+macro_rules! impl_mask_element {
+    ($($ty:ty)*) => {
+        $(
+            #[unstable(feature = "portable_simd", issue = "none")]
+            unsafe impl MaskElement for $ty {
+                const TRUE: Self = -1;
+                const FALSE: Self = 0;
+            }
+        )*
+    };
+}
+
+impl_mask_element! { i8 i16 i32 i64 isize }
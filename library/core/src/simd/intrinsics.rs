@@ -0,0 +1,101 @@
+//! Declarations of the `simd_*` platform intrinsics used by this module.
+//!
+//! These are lowered by the codegen backends to the target's vector
+//! instructions where available. Targets without suitable vector units get
+//! scalarized code, so every intrinsic here is usable on every target.
+//!
+//! Unless documented otherwise, the intrinsics operate lane-wise and require
+//! all vector arguments to have the same number of lanes.
+
+extern "platform-intrinsic" {
+    /// Lane-wise addition. Integers wrap on overflow.
+    pub(crate) fn simd_add<T>(x: T, y: T) -> T;
+
+    /// Lane-wise subtraction. Integers wrap on overflow.
+    pub(crate) fn simd_sub<T>(x: T, y: T) -> T;
+
+    /// Lane-wise multiplication. Integers wrap on overflow.
+    pub(crate) fn simd_mul<T>(x: T, y: T) -> T;
+
+    /// Lane-wise division. For integers, division by zero and `MIN / -1` are
+    /// undefined behavior.
+    pub(crate) fn simd_div<T>(x: T, y: T) -> T;
+
+    /// Lane-wise remainder. For integers, division by zero and `MIN % -1` are
+    /// undefined behavior.
+    pub(crate) fn simd_rem<T>(x: T, y: T) -> T;
+
+    /// Lane-wise shift left. Shifting by the lane width or more is undefined
+    /// behavior.
+    pub(crate) fn simd_shl<T>(x: T, y: T) -> T;
+
+    /// Lane-wise shift right, arithmetic for signed and logical for unsigned
+    /// integers. Shifting by the lane width or more is undefined behavior.
+    pub(crate) fn simd_shr<T>(x: T, y: T) -> T;
+
+    pub(crate) fn simd_and<T>(x: T, y: T) -> T;
+    pub(crate) fn simd_or<T>(x: T, y: T) -> T;
+    pub(crate) fn simd_xor<T>(x: T, y: T) -> T;
+
+    /// Lane-wise negation. Integers wrap on overflow.
+    pub(crate) fn simd_neg<T>(x: T) -> T;
+
+    /// Lane-wise `as` conversion. Converting a float to an integer it cannot
+    /// represent is undefined behavior.
+    pub(crate) fn simd_cast<T, U>(x: T) -> U;
+
+    /// Lane-wise comparisons. `U` must be an integer vector with as many lanes
+    /// as `T`; lanes where the comparison holds are set to `-1` (all bits
+    /// set), the others to `0`.
+    pub(crate) fn simd_eq<T, U>(x: T, y: T) -> U;
+    pub(crate) fn simd_ne<T, U>(x: T, y: T) -> U;
+    pub(crate) fn simd_lt<T, U>(x: T, y: T) -> U;
+    pub(crate) fn simd_le<T, U>(x: T, y: T) -> U;
+    pub(crate) fn simd_gt<T, U>(x: T, y: T) -> U;
+    pub(crate) fn simd_ge<T, U>(x: T, y: T) -> U;
+
+    /// Builds a vector out of the lanes of `x` and `y`, which are numbered
+    /// consecutively. `idx` must be a constant `[u32; N]` naming the source
+    /// lane of each of the `N` output lanes.
+    pub(crate) fn simd_shuffle<T, U, V>(x: T, y: T, idx: U) -> V;
+
+    /// For each lane, reads from `ptr` where `mask` is `-1` and takes the lane
+    /// of `val` where it is `0`.
+    pub(crate) fn simd_gather<T, U, V>(val: T, ptr: U, mask: V) -> T;
+
+    /// For each lane, writes the lane of `val` to `ptr` where `mask` is `-1`.
+    pub(crate) fn simd_scatter<T, U, V>(val: T, ptr: U, mask: V);
+
+    /// Saturating lane-wise integer addition and subtraction.
+    pub(crate) fn simd_saturating_add<T>(x: T, y: T) -> T;
+    pub(crate) fn simd_saturating_sub<T>(x: T, y: T) -> T;
+
+    /// Ordered reductions, folding the lanes into `acc` from first to last.
+    /// Integers wrap on overflow.
+    pub(crate) fn simd_reduce_add_ordered<T, U>(x: T, acc: U) -> U;
+    pub(crate) fn simd_reduce_mul_ordered<T, U>(x: T, acc: U) -> U;
+
+    /// Returns `true` if all, respectively any, lanes of an integer mask
+    /// vector are `-1`. Every lane must be `0` or `-1`.
+    pub(crate) fn simd_reduce_all<T>(x: T) -> bool;
+    pub(crate) fn simd_reduce_any<T>(x: T) -> bool;
+
+    /// Reduces the lanes to their maximum or minimum. For floats, `NaN` lanes
+    /// are ignored unless all lanes are `NaN`.
+    pub(crate) fn simd_reduce_max<T, U>(x: T) -> U;
+    pub(crate) fn simd_reduce_min<T, U>(x: T) -> U;
+
+    /// Bitwise reductions of integer vectors.
+    pub(crate) fn simd_reduce_and<T, U>(x: T) -> U;
+    pub(crate) fn simd_reduce_or<T, U>(x: T) -> U;
+    pub(crate) fn simd_reduce_xor<T, U>(x: T) -> U;
+
+    /// Picks the lane of `x` where `mask` is `-1` and the lane of `y` where it
+    /// is `0`. Every lane of `mask` must be `0` or `-1`.
+    pub(crate) fn simd_select<M, T>(mask: M, x: T, y: T) -> T;
+
+    /// Lane-wise float operations.
+    pub(crate) fn simd_fabs<T>(x: T) -> T;
+    pub(crate) fn simd_fmin<T>(x: T, y: T) -> T;
+    pub(crate) fn simd_fmax<T>(x: T, y: T) -> T;
+}
@@ -0,0 +1,29 @@
+use super::elements::Sealed;
+
+/// Specifies the number of lanes in a SIMD vector as a type.
+///
+/// Vectors and masks are only defined for the lane counts listed in the
+/// implementations of [`SupportedLaneCount`].
+#[unstable(feature = "portable_simd", issue = "none")]
+#[derive(Debug)]
+pub struct LaneCount<const LANES: usize>;
+
+/// Statically guarantees that a lane count is supported.
+///
+/// This trait is sealed: it is implemented for powers of two from 1 to 64,
+/// and cannot be implemented outside of `core`.
+#[unstable(feature = "portable_simd", issue = "none")]
+pub trait SupportedLaneCount: Sealed {}
+
+impl<const LANES: usize> Sealed for LaneCount<LANES> {}
+
+macro_rules! supported_lane_count {
+    ($($lanes:literal)*) => {
+        $(
+            #[unstable(feature = "portable_simd", issue = "none")]
+            impl SupportedLaneCount for LaneCount<$lanes> {}
+        )*
+    };
+}
+
+supported_lane_count! { 1 2 4 8 16 32 64 }
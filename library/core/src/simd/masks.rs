@@ -0,0 +1,476 @@
+use super::intrinsics;
+use super::{LaneCount, MaskElement, Simd, SimdElement, SupportedLaneCount};
+use crate::cmp::Ordering;
+use crate::fmt;
+use crate::hash::{Hash, Hasher};
+use crate::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+/// A SIMD vector mask for `LANES` elements of width specified by `T`.
+///
+/// A mask holds one boolean per lane. It is stored as a vector of the signed
+/// integer `T`, with `-1` for true lanes and `0` for false ones, which is the
+/// representation produced by the comparison methods of [`Simd`] and expected
+/// by [`select`](Mask::select). The element type `T` is usually the mask type
+/// of the vectors being compared, e.g. `i32` for vectors of `f32`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+///
+/// use core::simd::{f32x4, mask32x4};
+///
+/// let a = f32x4::from_array([1.0, 2.0, 3.0, 4.0]);
+/// let big = a.lanes_gt(f32x4::splat(2.5));
+/// assert_eq!(big, mask32x4::from_array([false, false, true, true]));
+/// assert_eq!(big.select(a, f32x4::splat(0.0)).to_array(), [0.0, 0.0, 3.0, 4.0]);
+/// ```
+#[unstable(feature = "portable_simd", issue = "none")]
+#[repr(transparent)]
+pub struct Mask<T, const LANES: usize>(Simd<T, LANES>)
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount;
+
+impl<T, const LANES: usize> Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Constructs a mask with all lanes set to `value`.
+    #[inline]
+    #[must_use]
+    pub fn splat(value: bool) -> Self {
+        Self(Simd::splat(if value { T::TRUE } else { T::FALSE }))
+    }
+
+    /// Constructs a mask from an array of booleans.
+    #[inline]
+    #[must_use]
+    pub fn from_array(array: [bool; LANES]) -> Self {
+        let mut mask = Self::splat(false);
+        for (lane, &value) in array.iter().enumerate() {
+            // SAFETY: `lane` is in bounds, as it comes from an array of `LANES` elements.
+            unsafe { mask.set_unchecked(lane, value) };
+        }
+        mask
+    }
+
+    /// Converts the mask to an array of booleans.
+    #[inline]
+    #[must_use]
+    pub fn to_array(self) -> [bool; LANES] {
+        let mut array = [false; LANES];
+        for (lane, value) in array.iter_mut().enumerate() {
+            // SAFETY: `lane` is in bounds, as it comes from an array of `LANES` elements.
+            *value = unsafe { self.test_unchecked(lane) };
+        }
+        array
+    }
+
+    /// Converts a vector of integers to a mask, where 0 represents `false` and
+    /// -1 represents `true`.
+    ///
+    /// # Safety
+    ///
+    /// All lanes must be either 0 or -1.
+    #[inline]
+    #[must_use]
+    pub unsafe fn from_int_unchecked(value: Simd<T, LANES>) -> Self {
+        Self(value)
+    }
+
+    /// Converts a vector of integers to a mask, where 0 represents `false` and
+    /// -1 represents `true`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any lane is not 0 or -1.
+    #[inline]
+    #[must_use]
+    pub fn from_int(value: Simd<T, LANES>) -> Self {
+        assert!(
+            value.as_array().iter().all(|&x| x == T::TRUE || x == T::FALSE),
+            "all values must be either 0 or -1",
+        );
+        // SAFETY: the validity has been checked
+        unsafe { Self::from_int_unchecked(value) }
+    }
+
+    /// Converts the mask to a vector of integers, where 0 represents `false`
+    /// and -1 represents `true`.
+    #[inline]
+    #[must_use]
+    pub fn to_int(self) -> Simd<T, LANES> {
+        self.0
+    }
+
+    /// Converts the mask to a mask of a different element width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    ///
+    /// use core::simd::{mask32x4, mask8x4};
+    ///
+    /// let mask = mask32x4::from_array([true, false, false, true]);
+    /// assert_eq!(mask.cast::<i8>(), mask8x4::from_array([true, false, false, true]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cast<U: MaskElement>(self) -> Mask<U, LANES> {
+        // SAFETY: sign-extending or truncating 0 and -1 yields 0 and -1.
+        unsafe { Mask(intrinsics::simd_cast(self.0)) }
+    }
+
+    /// Tests the value of the specified lane.
+    ///
+    /// # Safety
+    ///
+    /// `lane` must be less than `LANES`.
+    #[inline]
+    #[must_use]
+    pub unsafe fn test_unchecked(&self, lane: usize) -> bool {
+        // SAFETY: the caller guarantees that `lane` is in bounds.
+        unsafe { *self.0.as_array().get_unchecked(lane) == T::TRUE }
+    }
+
+    /// Tests the value of the specified lane.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lane` is greater than or equal to the number of lanes in
+    /// the vector.
+    #[inline]
+    #[must_use]
+    pub fn test(&self, lane: usize) -> bool {
+        assert!(lane < LANES, "lane index out of range");
+        // SAFETY: the lane index has been checked
+        unsafe { self.test_unchecked(lane) }
+    }
+
+    /// Sets the value of the specified lane.
+    ///
+    /// # Safety
+    ///
+    /// `lane` must be less than `LANES`.
+    #[inline]
+    pub unsafe fn set_unchecked(&mut self, lane: usize, value: bool) {
+        // SAFETY: the caller guarantees that `lane` is in bounds.
+        unsafe {
+            *self.0.as_mut_array().get_unchecked_mut(lane) = if value { T::TRUE } else { T::FALSE };
+        }
+    }
+
+    /// Sets the value of the specified lane.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lane` is greater than or equal to the number of lanes in
+    /// the vector.
+    #[inline]
+    pub fn set(&mut self, lane: usize, value: bool) {
+        assert!(lane < LANES, "lane index out of range");
+        // SAFETY: the lane index has been checked
+        unsafe { self.set_unchecked(lane, value) }
+    }
+
+    /// Returns `true` if any lane is set, or `false` otherwise.
+    #[inline]
+    #[must_use]
+    pub fn any(self) -> bool {
+        // SAFETY: every lane of a mask is 0 or -1.
+        unsafe { intrinsics::simd_reduce_any(self.0) }
+    }
+
+    /// Returns `true` if all lanes are set, or `false` otherwise.
+    #[inline]
+    #[must_use]
+    pub fn all(self) -> bool {
+        // SAFETY: every lane of a mask is 0 or -1.
+        unsafe { intrinsics::simd_reduce_all(self.0) }
+    }
+
+    /// Chooses lanes from two vectors.
+    ///
+    /// For each lane in the mask, choose the corresponding lane from
+    /// `true_values` if that lane mask is true, and `false_values` if that
+    /// lane mask is false.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    ///
+    /// use core::simd::{i32x4, mask32x4};
+    ///
+    /// let a = i32x4::from_array([0, 1, 2, 3]);
+    /// let b = i32x4::from_array([4, 5, 6, 7]);
+    /// let mask = mask32x4::from_array([true, false, false, true]);
+    /// let c = mask.select(a, b);
+    /// assert_eq!(c.to_array(), [0, 5, 6, 3]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn select<U>(
+        self,
+        true_values: Simd<U, LANES>,
+        false_values: Simd<U, LANES>,
+    ) -> Simd<U, LANES>
+    where
+        U: SimdElement<Mask = T>,
+    {
+        // SAFETY: every lane of a mask is 0 or -1.
+        unsafe { intrinsics::simd_select(self.0, true_values, false_values) }
+    }
+
+    /// Chooses lanes from two masks.
+    ///
+    /// For each lane in the mask, choose the corresponding lane from
+    /// `true_values` if that lane mask is true, and `false_values` if that
+    /// lane mask is false.
+    #[inline]
+    #[must_use]
+    pub fn select_mask(self, true_values: Self, false_values: Self) -> Self {
+        self & true_values | !self & false_values
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Copy for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Clone for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Default for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Creates a mask with all lanes set to `false`.
+    #[inline]
+    fn default() -> Self {
+        Self::splat(false)
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> PartialEq for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        !(*self ^ *other).any()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Eq for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+/// Lexicographic order of the lanes, with `false < true`.
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> PartialOrd for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Lexicographic order of the lanes, with `false < true`.
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Ord for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_array().cmp(&other.to_array())
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Hash for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_array().hash(state)
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> fmt::Debug for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.to_array().iter()).finish()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> From<[bool; LANES]> for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(array: [bool; LANES]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> From<Mask<T, LANES>> for [bool; LANES]
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(mask: Mask<T, LANES>) -> Self {
+        mask.to_array()
+    }
+}
+
+macro_rules! impl_bitwise_op {
+    ($($trait:ident::$fn:ident, $assign_trait:ident::$assign_fn:ident, $intrinsic:ident;)*) => {
+        $(
+            #[unstable(feature = "portable_simd", issue = "none")]
+            impl<T, const LANES: usize> $trait for Mask<T, LANES>
+            where
+                T: MaskElement,
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn $fn(self, rhs: Self) -> Self {
+                    // SAFETY: the bitwise operation of two masks is a mask.
+                    unsafe { Self(intrinsics::$intrinsic(self.0, rhs.0)) }
+                }
+            }
+
+            #[unstable(feature = "portable_simd", issue = "none")]
+            impl<T, const LANES: usize> $trait<bool> for Mask<T, LANES>
+            where
+                T: MaskElement,
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn $fn(self, rhs: bool) -> Self {
+                    self.$fn(Self::splat(rhs))
+                }
+            }
+
+            #[unstable(feature = "portable_simd", issue = "none")]
+            impl<T, const LANES: usize> $trait<Mask<T, LANES>> for bool
+            where
+                T: MaskElement,
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                type Output = Mask<T, LANES>;
+
+                #[inline]
+                fn $fn(self, rhs: Mask<T, LANES>) -> Mask<T, LANES> {
+                    Mask::splat(self).$fn(rhs)
+                }
+            }
+
+            #[unstable(feature = "portable_simd", issue = "none")]
+            impl<T, const LANES: usize> $assign_trait for Mask<T, LANES>
+            where
+                T: MaskElement,
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                #[inline]
+                fn $assign_fn(&mut self, rhs: Self) {
+                    *self = self.$fn(rhs);
+                }
+            }
+
+            #[unstable(feature = "portable_simd", issue = "none")]
+            impl<T, const LANES: usize> $assign_trait<bool> for Mask<T, LANES>
+            where
+                T: MaskElement,
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                #[inline]
+                fn $assign_fn(&mut self, rhs: bool) {
+                    *self = self.$fn(rhs);
+                }
+            }
+        )*
+    };
+}
+
+impl_bitwise_op! {
+    BitAnd::bitand, BitAndAssign::bitand_assign, simd_and;
+    BitOr::bitor, BitOrAssign::bitor_assign, simd_or;
+    BitXor::bitxor, BitXorAssign::bitxor_assign, simd_xor;
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Not for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        self ^ true
+    }
+}
+
+macro_rules! mask_aliases {
+    ($($elem:ty { $($alias:ident = $lanes:literal,)* })*) => {
+        $($(
+            #[doc = concat!("A mask of ", $lanes, " lanes, each as wide as `", stringify!($elem), "`.")]
+            #[unstable(feature = "portable_simd", issue = "none")]
+            #[allow(non_camel_case_types)]
+            pub type $alias = Mask<$elem, $lanes>;
+        )*)*
+    };
+}
+
+mask_aliases! {
+    i8 { mask8x4 = 4, mask8x8 = 8, mask8x16 = 16, mask8x32 = 32, mask8x64 = 64, }
+    i16 { mask16x4 = 4, mask16x8 = 8, mask16x16 = 16, mask16x32 = 32, }
+    i32 { mask32x2 = 2, mask32x4 = 4, mask32x8 = 8, mask32x16 = 16, }
+    i64 { mask64x2 = 2, mask64x4 = 4, mask64x8 = 8, }
+    isize { masksizex2 = 2, masksizex4 = 4, masksizex8 = 8, }
+}
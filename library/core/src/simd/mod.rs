@@ -0,0 +1,47 @@
+//! Portable SIMD module.
+//!
+//! This module offers a portable abstraction for SIMD operations that is not
+//! bound to any particular hardware architecture. A [`Simd<T, LANES>`] holds
+//! `LANES` values of a primitive number type `T` and its operators act on
+//! all of them at once, while a [`Mask<T, LANES>`] holds the boolean result
+//! of a lane-wise comparison.
+//!
+//! The operations are built on the `simd_*` platform intrinsics, which the
+//! codegen backends lower to the vector instructions of the target. Targets
+//! without a suitable vector unit get the equivalent scalar code, so
+//! programs using this module are portable to every target, though not
+//! necessarily faster on all of them. Use [`core::arch`] for operations
+//! specific to one architecture.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(portable_simd)]
+//!
+//! use core::simd::f32x4;
+//!
+//! let a = f32x4::from_array([1.0, 2.0, 3.0, 4.0]);
+//! let b = f32x4::splat(0.5);
+//! let c = a * b + f32x4::splat(1.0);
+//! assert_eq!(c.to_array(), [1.5, 2.0, 2.5, 3.0]);
+//! assert_eq!(c.horizontal_sum(), 9.0);
+//! ```
+
+mod intrinsics;
+
+mod cmp;
+mod elements;
+mod lane_count;
+mod masks;
+mod num;
+mod ops;
+mod ptr;
+mod reduction;
+mod swizzle;
+mod vector;
+
+pub use elements::{MaskElement, SimdElement};
+pub use lane_count::{LaneCount, SupportedLaneCount};
+pub use masks::*;
+pub use swizzle::{simd_swizzle, Swizzle, Swizzle2, Which};
+pub use vector::*;
@@ -0,0 +1,300 @@
+//! Lane-wise counterparts of the inherent methods of the numeric types.
+
+use super::intrinsics;
+use super::{LaneCount, Mask, Simd, SupportedLaneCount};
+use crate::mem;
+
+macro_rules! impl_uint_methods {
+    ($($ty:ident)*) => {
+        $(
+            impl<const LANES: usize> Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                /// Lane-wise saturating addition.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// #![feature(portable_simd)]
+                ///
+                /// use core::simd::Simd;
+                ///
+                #[doc = concat!("let x = Simd::from_array([2, 1, 0, ", stringify!($ty), "::MAX]);")]
+                #[doc = concat!("let max = Simd::splat(", stringify!($ty), "::MAX);")]
+                /// assert_eq!(x + max, Simd::from_array([1, 0, max[0], max[0] - 1]));
+                /// assert_eq!(x.saturating_add(max), max);
+                /// ```
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_add(self, rhs: Self) -> Self {
+                    // SAFETY: saturating arithmetic is defined for all integers.
+                    unsafe { intrinsics::simd_saturating_add(self, rhs) }
+                }
+
+                /// Lane-wise saturating subtraction.
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_sub(self, rhs: Self) -> Self {
+                    // SAFETY: saturating arithmetic is defined for all integers.
+                    unsafe { intrinsics::simd_saturating_sub(self, rhs) }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_int_methods {
+    ($($ty:ident)*) => {
+        $(
+            impl<const LANES: usize> Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                /// Lane-wise saturating addition.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// #![feature(portable_simd)]
+                ///
+                /// use core::simd::Simd;
+                ///
+                #[doc = concat!("let x = Simd::from_array([", stringify!($ty), "::MIN, 0, 1, ", stringify!($ty), "::MAX]);")]
+                #[doc = concat!("let max = Simd::splat(", stringify!($ty), "::MAX);")]
+                /// assert_eq!(x.saturating_add(max), Simd::from_array([-1, max[0], max[0], max[0]]));
+                /// ```
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_add(self, rhs: Self) -> Self {
+                    // SAFETY: saturating arithmetic is defined for all integers.
+                    unsafe { intrinsics::simd_saturating_add(self, rhs) }
+                }
+
+                /// Lane-wise saturating subtraction.
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_sub(self, rhs: Self) -> Self {
+                    // SAFETY: saturating arithmetic is defined for all integers.
+                    unsafe { intrinsics::simd_saturating_sub(self, rhs) }
+                }
+
+                /// Lane-wise absolute value, wrapping `MIN` to itself like
+                #[doc = concat!("[`", stringify!($ty), "::wrapping_abs`].")]
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// #![feature(portable_simd)]
+                ///
+                /// use core::simd::Simd;
+                ///
+                #[doc = concat!("let x = Simd::from_array([", stringify!($ty), "::MIN, -2, 0, 3]);")]
+                #[doc = concat!("assert_eq!(x.abs(), Simd::from_array([", stringify!($ty), "::MIN, 2, 0, 3]));")]
+                /// ```
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn abs(self) -> Self {
+                    // All ones for negative lanes, zero otherwise.
+                    let m = self >> Simd::splat(<$ty>::BITS as $ty - 1);
+                    (self ^ m) - m
+                }
+
+                /// Lane-wise saturating absolute value, mapping `MIN` to
+                /// `MAX`.
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_abs(self) -> Self {
+                    // All ones for negative lanes, zero otherwise.
+                    let m = self >> Simd::splat(<$ty>::BITS as $ty - 1);
+                    (self ^ m).saturating_sub(m)
+                }
+
+                /// Lane-wise saturating negation, mapping `MIN` to `MAX`.
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn saturating_neg(self) -> Self {
+                    Self::splat(0).saturating_sub(self)
+                }
+
+                /// Returns a mask of the lanes that are positive.
+                #[inline]
+                #[must_use]
+                pub fn is_positive(self) -> Mask<$ty, LANES> {
+                    self.lanes_gt(Self::splat(0))
+                }
+
+                /// Returns a mask of the lanes that are negative.
+                #[inline]
+                #[must_use]
+                pub fn is_negative(self) -> Mask<$ty, LANES> {
+                    self.lanes_lt(Self::splat(0))
+                }
+
+                /// Returns the sign of each lane: `0` for zero, `1` for
+                /// positive and `-1` for negative numbers.
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn signum(self) -> Self {
+                    self.is_positive().select(
+                        Self::splat(1),
+                        self.is_negative().select(Self::splat(-1), Self::splat(0)),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float_methods {
+    ($($ty:ident => $bits:ident, $mask:ident;)*) => {
+        $(
+            impl<const LANES: usize> Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                /// Raw transmutation to an unsigned integer vector type with
+                /// the same size and number of lanes.
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn to_bits(self) -> Simd<$bits, LANES> {
+                    // SAFETY: the vectors have lanes of the same size, and every
+                    // bit pattern is a valid integer.
+                    unsafe { mem::transmute_copy(&self) }
+                }
+
+                /// Raw transmutation from an unsigned integer vector type with
+                /// the same size and number of lanes.
+                #[inline]
+                #[must_use = "this returns a new vector, without modifying the original"]
+                pub fn from_bits(bits: Simd<$bits, LANES>) -> Self {
+                    // SAFETY: the vectors have lanes of the same size, and every
+                    // bit pattern is a valid float.
+                    unsafe { mem::transmute_copy(&bits) }
+                }
+
+                /// Lane-wise absolute value.
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn abs(self) -> Self {
+                    // SAFETY: the operation is defined for all floats.
+                    unsafe { intrinsics::simd_fabs(self) }
+                }
+
+                /// Takes the reciprocal (inverse) of each lane, `1/x`.
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn recip(self) -> Self {
+                    Self::splat(1.0) / self
+                }
+
+                /// Returns a mask of the lanes that are `NaN`.
+                #[inline]
+                #[must_use]
+                pub fn is_nan(self) -> Mask<$mask, LANES> {
+                    self.lanes_ne(self)
+                }
+
+                /// Returns a mask of the lanes that are positive or negative
+                /// infinity.
+                #[inline]
+                #[must_use]
+                pub fn is_infinite(self) -> Mask<$mask, LANES> {
+                    self.abs().lanes_eq(Self::splat(<$ty>::INFINITY))
+                }
+
+                /// Returns a mask of the lanes that are neither infinite nor
+                /// `NaN`.
+                #[inline]
+                #[must_use]
+                pub fn is_finite(self) -> Mask<$mask, LANES> {
+                    self.abs().lanes_lt(Self::splat(<$ty>::INFINITY))
+                }
+
+                /// Returns a mask of the lanes with a positive sign, including
+                /// `+0.0`, `NaN`s with positive sign bit and positive
+                /// infinity.
+                #[inline]
+                #[must_use]
+                pub fn is_sign_positive(self) -> Mask<$mask, LANES> {
+                    !self.is_sign_negative()
+                }
+
+                /// Returns a mask of the lanes with a negative sign, including
+                /// `-0.0`, `NaN`s with negative sign bit and negative
+                /// infinity.
+                #[inline]
+                #[must_use]
+                pub fn is_sign_negative(self) -> Mask<$mask, LANES> {
+                    let sign_bits = self.to_bits() & Simd::splat(1 << (<$bits>::BITS - 1));
+                    sign_bits.lanes_ne(Simd::splat(0))
+                }
+
+                /// Returns the lane-wise minimum with `other`.
+                ///
+                /// If one of the values is `NaN`, then the other value is
+                #[doc = concat!("returned, like [`", stringify!($ty), "::min`].")]
+                #[inline]
+                #[must_use = "this returns the result of the comparison, without modifying either input"]
+                pub fn min(self, other: Self) -> Self {
+                    // SAFETY: the operation is defined for all floats.
+                    unsafe { intrinsics::simd_fmin(self, other) }
+                }
+
+                /// Returns the lane-wise maximum with `other`.
+                ///
+                /// If one of the values is `NaN`, then the other value is
+                #[doc = concat!("returned, like [`", stringify!($ty), "::max`].")]
+                #[inline]
+                #[must_use = "this returns the result of the comparison, without modifying either input"]
+                pub fn max(self, other: Self) -> Self {
+                    // SAFETY: the operation is defined for all floats.
+                    unsafe { intrinsics::simd_fmax(self, other) }
+                }
+
+                /// Restricts each lane to a certain interval, unless it is
+                /// `NaN`.
+                ///
+                /// For each lane in `self`, returns the corresponding lane in
+                /// `max` if the lane is greater than `max`, and the
+                /// corresponding lane in `min` if the lane is less than
+                /// `min`. Otherwise returns the lane in `self`.
+                ///
+                /// # Panics
+                ///
+                /// Panics if any lane of `min` is greater than the
+                /// corresponding lane of `max`, or if either is `NaN`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// #![feature(portable_simd)]
+                ///
+                /// use core::simd::Simd;
+                ///
+                #[doc = concat!("let x = Simd::<", stringify!($ty), ", 4>::from_array([-3.0, 0.5, 3.0, ", stringify!($ty), "::NAN]);")]
+                /// let clamped = x.clamp(Simd::splat(-1.0), Simd::splat(1.0));
+                /// assert_eq!(&clamped[..3], &[-1.0, 0.5, 1.0]);
+                /// assert!(clamped[3].is_nan());
+                /// ```
+                #[inline]
+                #[must_use = "method returns a new vector and does not mutate the original value"]
+                pub fn clamp(self, min: Self, max: Self) -> Self {
+                    assert!(
+                        min.lanes_le(max).all(),
+                        "each lane in `min` must be less than or equal to the corresponding lane in `max`",
+                    );
+                    let x = self.lanes_lt(min).select(min, self);
+                    x.lanes_gt(max).select(max, x)
+                }
+            }
+        )*
+    };
+}
+
+impl_uint_methods! { u8 u16 u32 u64 usize }
+impl_int_methods! { i8 i16 i32 i64 isize }
+impl_float_methods! {
+    f32 => u32, i32;
+    f64 => u64, i64;
+}
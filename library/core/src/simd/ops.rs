@@ -0,0 +1,193 @@
+//! Lane-wise arithmetic and bitwise operators.
+//!
+//! The operators are implemented for each element type separately, rather
+//! than generically, so that each gets the semantics of its scalar
+//! counterpart: integers wrap and mask shift amounts, while division by zero
+//! panics, and floats follow IEEE 754.
+
+use super::intrinsics;
+use super::{LaneCount, Simd, SupportedLaneCount};
+use crate::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign};
+use crate::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+use crate::ops::{Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign};
+
+/// Implements a binary operator and its assigning counterpart for a vector
+/// type, with `$body` computing the result from `$lhs` and `$rhs`.
+macro_rules! impl_binary_op {
+    ($ty:ty: $trait:ident::$fn:ident, $assign_trait:ident::$assign_fn:ident => |$lhs:ident, $rhs:ident| $body:expr) => {
+        #[unstable(feature = "portable_simd", issue = "none")]
+        impl<const LANES: usize> $trait for Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, rhs: Self) -> Self {
+                let ($lhs, $rhs) = (self, rhs);
+                $body
+            }
+        }
+
+        #[unstable(feature = "portable_simd", issue = "none")]
+        impl<const LANES: usize> $assign_trait for Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = $trait::$fn(*self, rhs);
+            }
+        }
+    };
+}
+
+/// Implements a unary operator for a vector type.
+macro_rules! impl_unary_op {
+    ($ty:ty: $trait:ident::$fn:ident => |$x:ident| $body:expr) => {
+        #[unstable(feature = "portable_simd", issue = "none")]
+        impl<const LANES: usize> $trait for Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self) -> Self {
+                let $x = self;
+                $body
+            }
+        }
+    };
+}
+
+macro_rules! impl_int_ops {
+    ($($ty:ident)*) => {
+        $(
+            impl_binary_op! { $ty: Add::add, AddAssign::add_assign => |lhs, rhs| {
+                // SAFETY: integer addition wraps.
+                unsafe { intrinsics::simd_add(lhs, rhs) }
+            }}
+
+            impl_binary_op! { $ty: Sub::sub, SubAssign::sub_assign => |lhs, rhs| {
+                // SAFETY: integer subtraction wraps.
+                unsafe { intrinsics::simd_sub(lhs, rhs) }
+            }}
+
+            impl_binary_op! { $ty: Mul::mul, MulAssign::mul_assign => |lhs, rhs| {
+                // SAFETY: integer multiplication wraps.
+                unsafe { intrinsics::simd_mul(lhs, rhs) }
+            }}
+
+            impl_binary_op! { $ty: Div::div, DivAssign::div_assign => |lhs, rhs| {
+                if rhs.lanes_eq(Simd::splat(0)).any() {
+                    panic!("attempt to divide by zero");
+                }
+                // `MIN / -1` overflows for signed integers. The first check is
+                // constant, which removes the rest for unsigned integers.
+                if <$ty>::MIN != 0
+                    && (lhs.lanes_eq(Simd::splat(<$ty>::MIN))
+                        & rhs.lanes_eq(Simd::splat((0 as $ty).wrapping_sub(1))))
+                    .any()
+                {
+                    panic!("attempt to divide with overflow");
+                }
+                // SAFETY: division by zero and overflow have been ruled out.
+                unsafe { intrinsics::simd_div(lhs, rhs) }
+            }}
+
+            impl_binary_op! { $ty: Rem::rem, RemAssign::rem_assign => |lhs, rhs| {
+                if rhs.lanes_eq(Simd::splat(0)).any() {
+                    panic!("attempt to calculate the remainder with a divisor of zero");
+                }
+                if <$ty>::MIN != 0
+                    && (lhs.lanes_eq(Simd::splat(<$ty>::MIN))
+                        & rhs.lanes_eq(Simd::splat((0 as $ty).wrapping_sub(1))))
+                    .any()
+                {
+                    panic!("attempt to calculate the remainder with overflow");
+                }
+                // SAFETY: division by zero and overflow have been ruled out.
+                unsafe { intrinsics::simd_rem(lhs, rhs) }
+            }}
+
+            impl_binary_op! { $ty: Shl::shl, ShlAssign::shl_assign => |lhs, rhs| {
+                // SAFETY: the shift amounts are masked to less than the lane width.
+                unsafe { intrinsics::simd_shl(lhs, rhs & Simd::splat(<$ty>::BITS as $ty - 1)) }
+            }}
+
+            impl_binary_op! { $ty: Shr::shr, ShrAssign::shr_assign => |lhs, rhs| {
+                // SAFETY: the shift amounts are masked to less than the lane width.
+                unsafe { intrinsics::simd_shr(lhs, rhs & Simd::splat(<$ty>::BITS as $ty - 1)) }
+            }}
+
+            impl_binary_op! { $ty: BitAnd::bitand, BitAndAssign::bitand_assign => |lhs, rhs| {
+                // SAFETY: bitwise operations are defined for all integers.
+                unsafe { intrinsics::simd_and(lhs, rhs) }
+            }}
+
+            impl_binary_op! { $ty: BitOr::bitor, BitOrAssign::bitor_assign => |lhs, rhs| {
+                // SAFETY: bitwise operations are defined for all integers.
+                unsafe { intrinsics::simd_or(lhs, rhs) }
+            }}
+
+            impl_binary_op! { $ty: BitXor::bitxor, BitXorAssign::bitxor_assign => |lhs, rhs| {
+                // SAFETY: bitwise operations are defined for all integers.
+                unsafe { intrinsics::simd_xor(lhs, rhs) }
+            }}
+
+            impl_unary_op! { $ty: Not::not => |x| x ^ Simd::splat(!0) }
+        )*
+    };
+}
+
+macro_rules! impl_signed_ops {
+    ($($ty:ident)*) => {
+        $(
+            impl_unary_op! { $ty: Neg::neg => |x| {
+                // SAFETY: integer negation wraps.
+                unsafe { intrinsics::simd_neg(x) }
+            }}
+        )*
+    };
+}
+
+macro_rules! impl_float_ops {
+    ($($ty:ident)*) => {
+        $(
+            impl_binary_op! { $ty: Add::add, AddAssign::add_assign => |lhs, rhs| {
+                // SAFETY: float arithmetic is defined for all values.
+                unsafe { intrinsics::simd_add(lhs, rhs) }
+            }}
+
+            impl_binary_op! { $ty: Sub::sub, SubAssign::sub_assign => |lhs, rhs| {
+                // SAFETY: float arithmetic is defined for all values.
+                unsafe { intrinsics::simd_sub(lhs, rhs) }
+            }}
+
+            impl_binary_op! { $ty: Mul::mul, MulAssign::mul_assign => |lhs, rhs| {
+                // SAFETY: float arithmetic is defined for all values.
+                unsafe { intrinsics::simd_mul(lhs, rhs) }
+            }}
+
+            impl_binary_op! { $ty: Div::div, DivAssign::div_assign => |lhs, rhs| {
+                // SAFETY: float arithmetic is defined for all values.
+                unsafe { intrinsics::simd_div(lhs, rhs) }
+            }}
+
+            impl_binary_op! { $ty: Rem::rem, RemAssign::rem_assign => |lhs, rhs| {
+                // SAFETY: float arithmetic is defined for all values.
+                unsafe { intrinsics::simd_rem(lhs, rhs) }
+            }}
+
+            impl_unary_op! { $ty: Neg::neg => |x| {
+                // SAFETY: float negation is defined for all values.
+                unsafe { intrinsics::simd_neg(x) }
+            }}
+        )*
+    };
+}
+
+impl_int_ops! { u8 u16 u32 u64 usize i8 i16 i32 i64 isize }
+impl_signed_ops! { i8 i16 i32 i64 isize }
+impl_float_ops! { f32 f64 }
@@ -0,0 +1,76 @@
+//! Private vectors of pointers, as taken by the gather and scatter intrinsics.
+
+use super::{LaneCount, Simd, SupportedLaneCount};
+use crate::mem;
+
+/// A vector of `*const T`.
+#[repr(simd)]
+pub(crate) struct SimdConstPtr<T, const LANES: usize>([*const T; LANES]);
+
+impl<T, const LANES: usize> Copy for SimdConstPtr<T, LANES> {}
+
+impl<T, const LANES: usize> Clone for SimdConstPtr<T, LANES> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const LANES: usize> SimdConstPtr<T, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+    T: Sized,
+{
+    #[inline]
+    pub(crate) fn splat(ptr: *const T) -> Self {
+        Self([ptr; LANES])
+    }
+
+    /// Offsets each lane by the corresponding lane of `addend`, counted in
+    /// units of `T`, like [`pointer::wrapping_add`].
+    #[inline]
+    pub(crate) fn wrapping_add(self, addend: Simd<usize, LANES>) -> Self {
+        // SAFETY: pointers and `usize` have the same size, and integer
+        // vectors of the same shape have the same layout as pointer vectors.
+        unsafe {
+            let x: Simd<usize, LANES> = mem::transmute_copy(&self);
+            mem::transmute_copy(&{ x + (addend * Simd::splat(mem::size_of::<T>())) })
+        }
+    }
+}
+
+/// A vector of `*mut T`. Be very careful around potential aliasing.
+#[repr(simd)]
+pub(crate) struct SimdMutPtr<T, const LANES: usize>([*mut T; LANES]);
+
+impl<T, const LANES: usize> Copy for SimdMutPtr<T, LANES> {}
+
+impl<T, const LANES: usize> Clone for SimdMutPtr<T, LANES> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const LANES: usize> SimdMutPtr<T, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+    T: Sized,
+{
+    #[inline]
+    pub(crate) fn splat(ptr: *mut T) -> Self {
+        Self([ptr; LANES])
+    }
+
+    /// Offsets each lane by the corresponding lane of `addend`, counted in
+    /// units of `T`, like [`pointer::wrapping_add`].
+    #[inline]
+    pub(crate) fn wrapping_add(self, addend: Simd<usize, LANES>) -> Self {
+        // SAFETY: pointers and `usize` have the same size, and integer
+        // vectors of the same shape have the same layout as pointer vectors.
+        unsafe {
+            let x: Simd<usize, LANES> = mem::transmute_copy(&self);
+            mem::transmute_copy(&{ x + (addend * Simd::splat(mem::size_of::<T>())) })
+        }
+    }
+}
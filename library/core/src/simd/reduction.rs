@@ -0,0 +1,133 @@
+//! Horizontal operations, which reduce the lanes of a vector to a scalar.
+
+use super::intrinsics;
+use super::{LaneCount, Simd, SupportedLaneCount};
+
+macro_rules! impl_int_reductions {
+    ($($ty:ident)*) => {
+        $(
+            impl<const LANES: usize> Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                /// Returns the sum of the lanes of the vector, with wrapping
+                /// addition.
+                #[inline]
+                #[must_use]
+                pub fn horizontal_sum(self) -> $ty {
+                    // SAFETY: integer addition wraps.
+                    unsafe { intrinsics::simd_reduce_add_ordered(self, 0) }
+                }
+
+                /// Returns the product of the lanes of the vector, with
+                /// wrapping multiplication.
+                #[inline]
+                #[must_use]
+                pub fn horizontal_product(self) -> $ty {
+                    // SAFETY: integer multiplication wraps.
+                    unsafe { intrinsics::simd_reduce_mul_ordered(self, 1) }
+                }
+
+                /// Returns the maximum lane of the vector.
+                #[inline]
+                #[must_use]
+                pub fn horizontal_max(self) -> $ty {
+                    // SAFETY: the reduction is defined for all integers.
+                    unsafe { intrinsics::simd_reduce_max(self) }
+                }
+
+                /// Returns the minimum lane of the vector.
+                #[inline]
+                #[must_use]
+                pub fn horizontal_min(self) -> $ty {
+                    // SAFETY: the reduction is defined for all integers.
+                    unsafe { intrinsics::simd_reduce_min(self) }
+                }
+
+                /// Returns the bitwise "and" of the lanes of the vector.
+                #[inline]
+                #[must_use]
+                pub fn horizontal_and(self) -> $ty {
+                    // SAFETY: the reduction is defined for all integers.
+                    unsafe { intrinsics::simd_reduce_and(self) }
+                }
+
+                /// Returns the bitwise "or" of the lanes of the vector.
+                #[inline]
+                #[must_use]
+                pub fn horizontal_or(self) -> $ty {
+                    // SAFETY: the reduction is defined for all integers.
+                    unsafe { intrinsics::simd_reduce_or(self) }
+                }
+
+                /// Returns the bitwise "xor" of the lanes of the vector.
+                #[inline]
+                #[must_use]
+                pub fn horizontal_xor(self) -> $ty {
+                    // SAFETY: the reduction is defined for all integers.
+                    unsafe { intrinsics::simd_reduce_xor(self) }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float_reductions {
+    ($($ty:ident)*) => {
+        $(
+            impl<const LANES: usize> Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                /// Returns the sum of the lanes of the vector.
+                ///
+                /// The lanes are added in order, from the first to the last,
+                /// so the result is the same on every target.
+                #[inline]
+                #[must_use]
+                pub fn horizontal_sum(self) -> $ty {
+                    // SAFETY: float arithmetic is defined for all values.
+                    unsafe { intrinsics::simd_reduce_add_ordered(self, 0.0) }
+                }
+
+                /// Returns the product of the lanes of the vector.
+                ///
+                /// The lanes are multiplied in order, from the first to the
+                /// last, so the result is the same on every target.
+                #[inline]
+                #[must_use]
+                pub fn horizontal_product(self) -> $ty {
+                    // SAFETY: float arithmetic is defined for all values.
+                    unsafe { intrinsics::simd_reduce_mul_ordered(self, 1.0) }
+                }
+
+                /// Returns the maximum lane of the vector.
+                ///
+                /// Returns values based on equality, so a vector containing
+                /// both `0.` and `-0.` may return either. `NaN` lanes are
+                /// ignored, unless all lanes are `NaN`.
+                #[inline]
+                #[must_use]
+                pub fn horizontal_max(self) -> $ty {
+                    // SAFETY: the reduction is defined for all values.
+                    unsafe { intrinsics::simd_reduce_max(self) }
+                }
+
+                /// Returns the minimum lane of the vector.
+                ///
+                /// Returns values based on equality, so a vector containing
+                /// both `0.` and `-0.` may return either. `NaN` lanes are
+                /// ignored, unless all lanes are `NaN`.
+                #[inline]
+                #[must_use]
+                pub fn horizontal_min(self) -> $ty {
+                    // SAFETY: the reduction is defined for all values.
+                    unsafe { intrinsics::simd_reduce_min(self) }
+                }
+            }
+        )*
+    };
+}
+
+impl_int_reductions! { u8 u16 u32 u64 usize i8 i16 i32 i64 isize }
+impl_float_reductions! { f32 f64 }
@@ -0,0 +1,525 @@
+use super::intrinsics;
+use super::ptr::{SimdConstPtr, SimdMutPtr};
+use super::{LaneCount, Mask, MaskElement, SimdElement, SupportedLaneCount};
+use crate::array;
+use crate::cmp::Ordering;
+use crate::fmt;
+use crate::hash::{Hash, Hasher};
+use crate::ops::{Index, IndexMut};
+use crate::slice::SliceIndex;
+
+/// A SIMD vector of `LANES` elements of type `T`.
+///
+/// `Simd<T, LANES>` has the same shape as `[T; LANES]`, but with the alignment
+/// required by the target's vector registers, and its operations act on all
+/// lanes at once. The arithmetic operators apply lane-wise, so `a + b` adds
+/// the first lane of `a` to the first lane of `b` and so on; comparisons
+/// produce a [`Mask`] with one boolean per lane.
+///
+/// Operations are lowered to vector instructions where the target has them
+/// and are split into scalar operations elsewhere, so code written against
+/// this type works on every target.
+///
+/// Integer arithmetic wraps on overflow, and shift amounts are masked to the
+/// lane width, like [`wrapping_shl`](u32::wrapping_shl). Integer division by
+/// zero panics, as does signed division of `MIN` by `-1`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+///
+/// use core::simd::Simd;
+///
+/// let a = Simd::from_array([1, 2, 3, 4]);
+/// let b = Simd::<i32, 4>::splat(10);
+/// assert_eq!((a + b).to_array(), [11, 12, 13, 14]);
+/// assert_eq!((a * b).horizontal_sum(), 100);
+/// ```
+#[unstable(feature = "portable_simd", issue = "none")]
+#[repr(simd)]
+pub struct Simd<T, const LANES: usize>([T; LANES])
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount;
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// The number of lanes in this vector.
+    pub const LANES: usize = LANES;
+
+    /// Returns the number of lanes in this vector.
+    #[inline]
+    #[must_use]
+    pub const fn lanes(&self) -> usize {
+        LANES
+    }
+
+    /// Constructs a vector with all lanes set to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    ///
+    /// use core::simd::u32x4;
+    ///
+    /// let v = u32x4::splat(8);
+    /// assert_eq!(v.as_array(), &[8, 8, 8, 8]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn splat(value: T) -> Self {
+        Self([value; LANES])
+    }
+
+    /// Constructs a vector from an array.
+    #[inline]
+    #[must_use]
+    pub const fn from_array(array: [T; LANES]) -> Self {
+        Self(array)
+    }
+
+    /// Converts the vector to an array.
+    #[inline]
+    #[must_use]
+    pub const fn to_array(self) -> [T; LANES] {
+        self.0
+    }
+
+    /// Returns a reference to the lanes of the vector as an array.
+    #[inline]
+    #[must_use]
+    pub const fn as_array(&self) -> &[T; LANES] {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the lanes of the vector as an array.
+    #[inline]
+    #[must_use]
+    pub fn as_mut_array(&mut self) -> &mut [T; LANES] {
+        &mut self.0
+    }
+
+    /// Constructs a vector from the first `LANES` elements of a slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice has fewer than `LANES` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    ///
+    /// use core::simd::u32x4;
+    ///
+    /// let source = [1, 2, 3, 4, 5, 6];
+    /// let v = u32x4::from_slice(&source);
+    /// assert_eq!(v.as_array(), &[1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_slice(slice: &[T]) -> Self {
+        assert!(slice.len() >= LANES, "slice length must be at least the number of lanes");
+        let mut array = [slice[0]; LANES];
+        array.copy_from_slice(&slice[..LANES]);
+        Self(array)
+    }
+
+    /// Converts each lane to the element type `U`, with the same semantics as
+    /// an `as` cast.
+    ///
+    /// In particular, integers are truncated or extended, and conversions
+    /// from floats to integers saturate, with `NaN` mapping to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    ///
+    /// use core::simd::{f32x4, i32x4};
+    ///
+    /// let floats = f32x4::from_array([-1.5, 2.5, f32::NAN, 1e20]);
+    /// assert_eq!(floats.cast::<i32>(), i32x4::from_array([-1, 2, 0, i32::MAX]));
+    /// assert_eq!(i32x4::splat(300).cast::<u8>().to_array(), [44; 4]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cast<U: SimdElement>(self) -> Simd<U, LANES> {
+        if T::IS_FLOAT && !U::IS_FLOAT {
+            // `simd_cast` is undefined for float lanes that do not fit in the
+            // integer type, so go through the saturating scalar conversion.
+            Simd(array::from_fn(|i| U::from_f64(self[i].to_f64())))
+        } else {
+            // SAFETY: every other combination of element types is defined for
+            // all input values.
+            unsafe { intrinsics::simd_cast(self) }
+        }
+    }
+
+    /// Reads from the indices of `slice` given by `idxs` to construct a
+    /// vector. Lanes whose index is out of bounds take the value of the
+    /// corresponding lane of `or`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    ///
+    /// use core::simd::Simd;
+    ///
+    /// let vec: Vec<i32> = vec![10, 11, 12, 13, 14, 15, 16, 17, 18];
+    /// let idxs = Simd::from_array([9, 3, 0, 5]);
+    /// let alt = Simd::from_array([-5, -4, -3, -2]);
+    ///
+    /// let result = Simd::gather_or(&vec, idxs, alt); // The first index is out of bounds.
+    /// assert_eq!(result, Simd::from_array([-5, 13, 10, 15]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn gather_or(slice: &[T], idxs: Simd<usize, LANES>, or: Self) -> Self {
+        Self::gather_select(slice, Mask::splat(true), idxs, or)
+    }
+
+    /// Reads from the indices of `slice` given by `idxs` to construct a
+    /// vector. Lanes whose index is out of bounds are set to the default
+    /// value of the element type.
+    #[inline]
+    #[must_use]
+    pub fn gather_or_default(slice: &[T], idxs: Simd<usize, LANES>) -> Self
+    where
+        T: Default,
+    {
+        Self::gather_or(slice, idxs, Self::splat(T::default()))
+    }
+
+    /// Reads from the indices of `slice` given by `idxs` to construct a
+    /// vector, for the lanes enabled by `enable`. Lanes that are disabled or
+    /// whose index is out of bounds take the value of the corresponding lane
+    /// of `or`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    ///
+    /// use core::simd::{Mask, Simd};
+    ///
+    /// let vec: Vec<i32> = vec![10, 11, 12, 13, 14, 15, 16, 17, 18];
+    /// let idxs = Simd::from_array([9, 3, 0, 5]);
+    /// let alt = Simd::from_array([-5, -4, -3, -2]);
+    /// let enable = Mask::from_array([true, true, true, false]);
+    ///
+    /// let result = Simd::gather_select(&vec, enable, idxs, alt);
+    /// assert_eq!(result, Simd::from_array([-5, 13, 10, -2]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn gather_select(
+        slice: &[T],
+        enable: Mask<isize, LANES>,
+        idxs: Simd<usize, LANES>,
+        or: Self,
+    ) -> Self {
+        let enable: Mask<isize, LANES> = enable & idxs.lanes_lt(Simd::splat(slice.len()));
+        let base_ptr = SimdConstPtr::splat(slice.as_ptr());
+        let ptrs = base_ptr.wrapping_add(idxs);
+        // SAFETY: lanes whose index is out of bounds have been masked off, so only
+        // elements of `slice` are read.
+        unsafe { intrinsics::simd_gather(or, ptrs, enable.to_int()) }
+    }
+
+    /// Writes the lanes of the vector to the indices of `slice` given by
+    /// `idxs`. Lanes whose index is out of bounds are not written.
+    ///
+    /// If two lanes have the same index, the value of the later lane is
+    /// written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    ///
+    /// use core::simd::Simd;
+    ///
+    /// let mut vec: Vec<i32> = vec![10, 11, 12, 13, 14, 15, 16, 17, 18];
+    /// let idxs = Simd::from_array([9, 3, 0, 0]);
+    /// let vals = Simd::from_array([-27, 82, -41, 124]);
+    ///
+    /// vals.scatter(&mut vec, idxs); // index 0 receives two writes.
+    /// assert_eq!(vec, vec![124, 11, 12, 82, 14, 15, 16, 17, 18]);
+    /// ```
+    #[inline]
+    pub fn scatter(self, slice: &mut [T], idxs: Simd<usize, LANES>) {
+        self.scatter_select(slice, Mask::splat(true), idxs)
+    }
+
+    /// Writes the lanes of the vector enabled by `enable` to the indices of
+    /// `slice` given by `idxs`. Lanes that are disabled or whose index is out
+    /// of bounds are not written.
+    ///
+    /// If two enabled lanes have the same index, the value of the later lane
+    /// is written.
+    #[inline]
+    pub fn scatter_select(
+        self,
+        slice: &mut [T],
+        enable: Mask<isize, LANES>,
+        idxs: Simd<usize, LANES>,
+    ) {
+        let enable: Mask<isize, LANES> = enable & idxs.lanes_lt(Simd::splat(slice.len()));
+        // SAFETY: We have masked-off out-of-bounds lanes. The pointers are
+        // derived from the unique borrow of `slice` and only used to write
+        // to it before the borrow ends.
+        unsafe {
+            let base_ptr = SimdMutPtr::splat(slice.as_mut_ptr());
+            let ptrs = base_ptr.wrapping_add(idxs);
+            intrinsics::simd_scatter(self, ptrs, enable.to_int())
+        }
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Copy for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Clone for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Default for Simd<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::splat(T::default())
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> PartialEq for Simd<T, LANES>
+where
+    T: SimdElement + PartialEq,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        // Compare as a whole, which gives the same result as comparing the
+        // arrays but lets the comparison itself be vectorized.
+        self.lanes_eq(*other).all()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Eq for Simd<T, LANES>
+where
+    T: SimdElement + Eq,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+}
+
+/// Lexicographic order, as for arrays.
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> PartialOrd for Simd<T, LANES>
+where
+    T: SimdElement + PartialOrd,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_array().partial_cmp(other.as_array())
+    }
+}
+
+/// Lexicographic order, as for arrays.
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Ord for Simd<T, LANES>
+where
+    T: SimdElement + Ord,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_array().cmp(other.as_array())
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> Hash for Simd<T, LANES>
+where
+    T: SimdElement + Hash,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_array().hash(state)
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> fmt::Debug for Simd<T, LANES>
+where
+    T: SimdElement + fmt::Debug,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_array()).finish()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<I, T, const LANES: usize> Index<I> for Simd<T, LANES>
+where
+    I: SliceIndex<[T]>,
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_array()[index]
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<I, T, const LANES: usize> IndexMut<I> for Simd<T, LANES>
+where
+    I: SliceIndex<[T]>,
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_mut_array()[index]
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> AsRef<[T; LANES]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn as_ref(&self) -> &[T; LANES] {
+        self.as_array()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> AsMut<[T; LANES]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T; LANES] {
+        self.as_mut_array()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> AsRef<[T]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.as_array()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> AsMut<[T]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_array()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> From<[T; LANES]> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(array: [T; LANES]) -> Self {
+        Self(array)
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> From<Simd<T, LANES>> for [T; LANES]
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(vector: Simd<T, LANES>) -> Self {
+        vector.to_array()
+    }
+}
+
+#[unstable(feature = "portable_simd", issue = "none")]
+impl<T, const LANES: usize> From<Mask<T, LANES>> for Simd<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(mask: Mask<T, LANES>) -> Self {
+        mask.to_int()
+    }
+}
+
+macro_rules! vector_aliases {
+    ($($elem:ty { $($alias:ident = $lanes:literal,)* })*) => {
+        $($(
+            #[doc = concat!("A SIMD vector of ", $lanes, " `", stringify!($elem), "`s.")]
+            #[unstable(feature = "portable_simd", issue = "none")]
+            #[allow(non_camel_case_types)]
+            pub type $alias = Simd<$elem, $lanes>;
+        )*)*
+    };
+}
+
+vector_aliases! {
+    u8 { u8x2 = 2, u8x4 = 4, u8x8 = 8, u8x16 = 16, u8x32 = 32, u8x64 = 64, }
+    u16 { u16x2 = 2, u16x4 = 4, u16x8 = 8, u16x16 = 16, u16x32 = 32, }
+    u32 { u32x2 = 2, u32x4 = 4, u32x8 = 8, u32x16 = 16, }
+    u64 { u64x2 = 2, u64x4 = 4, u64x8 = 8, }
+    usize { usizex2 = 2, usizex4 = 4, usizex8 = 8, }
+    i8 { i8x2 = 2, i8x4 = 4, i8x8 = 8, i8x16 = 16, i8x32 = 32, i8x64 = 64, }
+    i16 { i16x2 = 2, i16x4 = 4, i16x8 = 8, i16x16 = 16, i16x32 = 32, }
+    i32 { i32x2 = 2, i32x4 = 4, i32x8 = 8, i32x16 = 16, }
+    i64 { i64x2 = 2, i64x4 = 4, i64x8 = 8, }
+    isize { isizex2 = 2, isizex4 = 4, isizex8 = 8, }
+    f32 { f32x2 = 2, f32x4 = 4, f32x8 = 8, f32x16 = 16, }
+    f64 { f64x2 = 2, f64x4 = 4, f64x8 = 8, }
+}
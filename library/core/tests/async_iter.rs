@@ -0,0 +1,83 @@
+use core::async_iter::{self, AsyncIterator};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+fn collect<I: AsyncIterator + Unpin>(mut iter: I) -> Vec<I::Item> {
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut items = Vec::new();
+    loop {
+        match Pin::new(&mut iter.next()).poll(&mut cx) {
+            Poll::Ready(Some(item)) => items.push(item),
+            Poll::Ready(None) => return items,
+            Poll::Pending => {}
+        }
+    }
+}
+
+/// Yields `Pending` before every item, to exercise adapters on not-ready inputs.
+struct Yielding<I> {
+    iter: I,
+    ready: bool,
+}
+
+impl<I: Iterator + Unpin> AsyncIterator for Yielding<I> {
+    type Item = I::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<I::Item>> {
+        if self.ready {
+            self.ready = false;
+            Poll::Ready(self.iter.next())
+        } else {
+            self.ready = true;
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn from_iter_next() {
+    let iter = async_iter::from_iter([1, 2, 3]);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(collect(iter), [1, 2, 3]);
+}
+
+#[test]
+fn map() {
+    let iter = async_iter::from_iter(0..4).map(|x| x * 2);
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(collect(iter), [0, 2, 4, 6]);
+}
+
+#[test]
+fn filter() {
+    let iter = async_iter::from_iter(0..10).filter(|x| x % 3 == 0);
+    assert_eq!(iter.size_hint(), (0, Some(10)));
+    assert_eq!(collect(iter), [0, 3, 6, 9]);
+}
+
+#[test]
+fn adapters_propagate_pending() {
+    let mut cx = Context::from_waker(Waker::noop());
+
+    let mut iter = Yielding { iter: 1..5, ready: false }.filter(|x| x % 2 == 0).map(|x| x * 10);
+    assert_eq!(Pin::new(&mut iter).poll_next(&mut cx), Poll::Pending);
+    // `1` is filtered out, after which the source is pending again.
+    assert_eq!(Pin::new(&mut iter).poll_next(&mut cx), Poll::Pending);
+    assert_eq!(Pin::new(&mut iter).poll_next(&mut cx), Poll::Ready(Some(20)));
+
+    assert_eq!(collect(iter), [40]);
+}
+
+#[test]
+fn next_on_trait_object() {
+    let mut iter = async_iter::from_iter(1..4);
+    let dyn_iter: &mut (dyn AsyncIterator<Item = i32> + Unpin) = &mut iter;
+    assert_eq!(collect(dyn_iter), [1, 2, 3]);
+}
+
+#[test]
+fn next_on_pinned_box() {
+    let iter = Box::pin(async_iter::from_iter("abc".chars()).map(|c| c.to_ascii_uppercase()));
+    assert_eq!(collect(iter), ['A', 'B', 'C']);
+}
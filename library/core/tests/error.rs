@@ -0,0 +1,62 @@
+use core::error::Error;
+use core::fmt;
+
+#[derive(Debug, PartialEq)]
+struct Leaf;
+
+impl fmt::Display for Leaf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("leaf")
+    }
+}
+
+impl Error for Leaf {}
+
+#[derive(Debug)]
+struct Wrapper(Leaf);
+
+impl fmt::Display for Wrapper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("wrapper")
+    }
+}
+
+impl Error for Wrapper {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[test]
+fn test_same_trait_as_std() {
+    fn takes_std_error(_: &dyn std::error::Error) {}
+    takes_std_error(&Leaf as &dyn Error);
+
+    let boxed: Box<dyn std::error::Error> = Box::new(Leaf);
+    assert_eq!(boxed.downcast::<Leaf>().ok().as_deref(), Some(&Leaf));
+}
+
+#[test]
+fn test_downcast_ref() {
+    let wrapper = Wrapper(Leaf);
+    let err: &(dyn Error + 'static) = &wrapper;
+    assert!(err.is::<Wrapper>());
+    assert!(err.downcast_ref::<Leaf>().is_none());
+    assert_eq!(err.source().unwrap().downcast_ref::<Leaf>(), Some(&Leaf));
+}
+
+#[test]
+fn test_chain() {
+    let wrapper = Wrapper(Leaf);
+    let err: &(dyn Error + 'static) = &wrapper;
+    let messages: Vec<String> = err.chain().map(|e| e.to_string()).collect();
+    assert_eq!(messages, ["wrapper", "leaf"]);
+}
+
+#[test]
+fn test_core_impls() {
+    let err = "x".parse::<u8>().unwrap_err();
+    let err: &dyn Error = &err;
+    assert_eq!(err.to_string(), "invalid digit found in string");
+    assert!(err.source().is_none());
+}
@@ -0,0 +1,77 @@
+use core::future::{join, Future};
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+fn poll_to_completion<F: Future>(fut: F) -> F::Output {
+    let mut fut = Box::pin(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+/// Returns `Pending` the given number of times before completing with `value`.
+struct Delay<T> {
+    polls: usize,
+    value: Option<T>,
+}
+
+impl<T: Unpin> Future for Delay<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        if self.polls == 0 {
+            Poll::Ready(self.value.take().expect("polled after completion"))
+        } else {
+            self.polls -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+fn delay<T>(polls: usize, value: T) -> Delay<T> {
+    Delay { polls, value: Some(value) }
+}
+
+#[test]
+fn join_single() {
+    assert_eq!(poll_to_completion(async { join!(async { 1 }).await }), 1);
+}
+
+#[test]
+fn join_heterogeneous() {
+    let output = poll_to_completion(async {
+        join!(delay(3, 1u8), async { "two" }, delay(1, 3.0f32), delay(0, ()),).await
+    });
+    assert_eq!(output, (1, "two", 3.0, ()));
+}
+
+#[test]
+fn join_polls_concurrently() {
+    // Every future is polled on every round, so joining futures which each
+    // need `n` polls takes `n + 1` rounds in total rather than their sum.
+    let mut rounds = 0;
+    let mut fut = Box::pin(async { join!(delay(2, 'a'), delay(2, 'b'), delay(2, 'c')).await });
+    let mut cx = Context::from_waker(Waker::noop());
+    let output = loop {
+        rounds += 1;
+        if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+            break output;
+        }
+    };
+    assert_eq!(output, ('a', 'b', 'c'));
+    assert_eq!(rounds, 3);
+}
+
+#[test]
+fn join_borrows() {
+    let mut a = 0;
+    let mut b = String::new();
+    poll_to_completion(async {
+        join!(async { a += 1 }, async { b.push('x') }).await;
+    });
+    assert_eq!((a, b.as_str()), (1, "x"));
+}
@@ -1,6 +1,5 @@
 use core::{
-    cell::Cell,
-    lazy::{Lazy, OnceCell},
+    cell::{Cell, LazyCell, OnceCell},
     sync::atomic::{AtomicUsize, Ordering::SeqCst},
 };
 
@@ -91,7 +90,7 @@ fn into_inner() {
 #[test]
 fn lazy_new() {
     let called = Cell::new(0);
-    let x = Lazy::new(|| {
+    let x = LazyCell::new(|| {
         called.set(called.get() + 1);
         92
     });
@@ -107,6 +106,59 @@ fn lazy_new() {
     assert_eq!(called.get(), 1);
 }
 
+#[test]
+fn lazy_force_mut() {
+    let called = Cell::new(0);
+    let mut x = LazyCell::new(|| {
+        called.set(called.get() + 1);
+        92
+    });
+    assert_eq!(LazyCell::get(&x), None);
+
+    *LazyCell::force_mut(&mut x) += 1;
+    assert_eq!(called.get(), 1);
+    assert_eq!(*x, 93);
+    assert_eq!(LazyCell::get_mut(&mut x), Some(&mut 93));
+    assert_eq!(called.get(), 1);
+}
+
+#[test]
+fn lazy_consumes_captured_state() {
+    let s = String::from("hello");
+    let x = LazyCell::new(move || s);
+    assert_eq!(&*x, "hello");
+}
+
+#[test]
+fn lazy_into_value() {
+    let x: LazyCell<i32> = LazyCell::new(|| 92);
+    let f = LazyCell::into_value(x).unwrap_err();
+    assert_eq!(f(), 92);
+
+    let x = LazyCell::new(|| 92);
+    LazyCell::force(&x);
+    assert_eq!(LazyCell::into_value(x).ok(), Some(92));
+}
+
+#[test]
+fn lazy_try_force() {
+    let called = Cell::new(0);
+    let x = LazyCell::new(|| {
+        called.set(called.get() + 1);
+        if called.get() == 1 {
+            Err("kaboom")
+        } else {
+            Ok(92)
+        }
+    });
+
+    assert_eq!(LazyCell::try_force(&x), Err("kaboom"));
+    assert_eq!(LazyCell::get(&x), None);
+    assert_eq!(LazyCell::try_force(&x), Ok(&92));
+    assert_eq!(LazyCell::try_force(&x), Ok(&92));
+    assert_eq!(called.get(), 2);
+}
+
 #[test]
 fn aliasing_in_get() {
     let x = OnceCell::new();
@@ -2,6 +2,8 @@
 #![feature(array_chunks)]
 #![feature(array_methods)]
 #![feature(array_windows)]
+#![feature(async_iter_from_iter)]
+#![feature(async_iterator)]
 #![feature(bool_to_option)]
 #![feature(box_syntax)]
 #![feature(cell_update)]
@@ -23,9 +25,12 @@
 #![feature(div_duration)]
 #![feature(duration_consts_2)]
 #![feature(duration_constants)]
+#![cfg_attr(not(bootstrap), feature(error_in_core))]
+#![feature(error_iter)]
 #![feature(exact_size_is_empty)]
 #![feature(extern_types)]
 #![feature(flt2dec)]
+#![feature(future_join)]
 #![feature(fmt_internals)]
 #![feature(array_from_fn)]
 #![feature(hashmap_internals)]
@@ -48,6 +53,9 @@
 #![feature(slice_internals)]
 #![feature(slice_partition_dedup)]
 #![feature(int_log)]
+#![feature(int_traits)]
+#![feature(isqrt)]
+#![feature(bigint_helper_methods)]
 #![feature(iter_advance_by)]
 #![feature(iter_partition_in_place)]
 #![feature(iter_intersperse)]
@@ -58,9 +66,11 @@
 #![feature(const_slice_from_raw_parts)]
 #![feature(const_raw_ptr_deref)]
 #![feature(never_type)]
+#![feature(noop_waker)]
 #![feature(unwrap_infallible)]
 #![feature(result_into_ok_or_err)]
 #![feature(ptr_metadata)]
+#![feature(provide_any)]
 #![feature(once_cell)]
 #![feature(unsized_tuple_coercion)]
 #![feature(const_option)]
@@ -74,6 +84,12 @@
 #![feature(unzip_option)]
 #![feature(const_array_from_ref)]
 #![feature(const_slice_from_ref)]
+#![feature(portable_simd)]
+#![feature(ip)]
+#![feature(ip_in_core)]
+#![feature(const_ip)]
+#![feature(const_ipv4)]
+#![feature(const_ipv6)]
 #![deny(unsafe_op_in_unsafe_fn)]
 
 extern crate test;
@@ -82,6 +98,7 @@
 mod any;
 mod array;
 mod ascii;
+mod async_iter;
 mod atomic;
 mod bool;
 mod cell;
@@ -89,7 +106,10 @@
 mod clone;
 mod cmp;
 mod const_ptr;
+#[cfg(not(bootstrap))]
+mod error;
 mod fmt;
+mod future;
 mod hash;
 mod intrinsics;
 mod iter;
@@ -97,6 +117,7 @@
 mod macros;
 mod manually_drop;
 mod mem;
+mod net;
 mod nonzero;
 mod num;
 mod ops;
@@ -105,6 +126,7 @@
 mod pin;
 mod ptr;
 mod result;
+mod simd;
 mod slice;
 mod str;
 mod str_lossy;
@@ -338,6 +338,36 @@ fn test_checked_next_multiple_of() {
                 assert_eq!(MIN.checked_next_multiple_of(-3), None);
                 assert_eq!(MIN.checked_next_multiple_of(-1), Some(MIN));
             }
+
+            #[test]
+            fn test_isqrt() {
+                assert_eq!((0 as $T).isqrt(), 0);
+                assert_eq!((1 as $T).isqrt(), 1);
+                assert_eq!((99 as $T).isqrt(), 9);
+                assert_eq!((100 as $T).isqrt(), 10);
+                let r = MAX.isqrt();
+                assert!(r * r <= MAX);
+                assert!((r + 1).checked_mul(r + 1).is_none());
+                assert_eq!((-1 as $T).checked_isqrt(), None);
+                assert_eq!(MIN.checked_isqrt(), None);
+                assert_eq!((64 as $T).checked_isqrt(), Some(8));
+            }
+
+            #[test]
+            #[should_panic(expected = "argument of integer square root cannot be negative")]
+            fn test_isqrt_negative() {
+                let _ = (-4 as $T).isqrt();
+            }
+
+            #[test]
+            fn test_widening_mul() {
+                assert_eq!((5 as $T).widening_mul(-2), (-10, -1));
+                assert_eq!((-1 as $T).widening_mul(1), (-1, -1));
+                assert_eq!(MAX.widening_mul(2), (-2, 0));
+                assert_eq!(MIN.widening_mul(MIN), (0, 1 << ($T::BITS - 2)));
+                assert_eq!(MIN.carrying_mul(-1, -1), (MAX, 0));
+                assert_eq!((3 as $T).carrying_mul(4, -13), (-1, -1));
+            }
         }
     };
 }
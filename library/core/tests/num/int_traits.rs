@@ -0,0 +1,41 @@
+use core::num::{Integer, SignedInteger, UnsignedInteger};
+
+fn sum_of_squares<T: Integer>(values: &[T]) -> Option<T> {
+    values.iter().try_fold(T::ZERO, |acc, &x| acc.checked_add(x.checked_mul(x)?))
+}
+
+fn split_abs<T: SignedInteger>(x: T) -> (bool, T::Unsigned) {
+    (x.is_negative(), x.unsigned_abs())
+}
+
+fn log2_ceil<T: UnsignedInteger>(x: T) -> u32 {
+    x.next_power_of_two().trailing_zeros()
+}
+
+#[test]
+fn generic_integer() {
+    assert_eq!(sum_of_squares(&[1u8, 2, 3]), Some(14));
+    assert_eq!(sum_of_squares(&[10u8, 10, 10]), None);
+    assert_eq!(sum_of_squares(&[-3i128, 4]), Some(25));
+    assert_eq!(<u16 as Integer>::BITS, 16);
+    assert_eq!(<i8 as Integer>::MIN, i8::MIN);
+    assert_eq!(<usize as Integer>::from_str_radix("ff", 16), Ok(255));
+}
+
+#[test]
+fn generic_signed_unsigned() {
+    assert_eq!(split_abs(i8::MIN), (true, 128u8));
+    assert_eq!(split_abs(7i64), (false, 7u64));
+    assert_eq!(log2_ceil(5u32), 3);
+    assert_eq!(log2_ceil(64u64), 6);
+}
+
+#[test]
+fn generic_rounding_and_bignum_helpers() {
+    fn check<T: Integer>(x: T, y: T) -> (T, T, u32, (T, T)) {
+        (x.div_ceil(y), x.isqrt(), x.ilog2(), x.carrying_mul(y, y))
+    }
+    assert_eq!(check(100u8, 7), (15, 10, 6, (195, 2)));
+    assert_eq!(check(1000i32, -7), (-142, 31, 9, (-7007, -1)));
+    assert_eq!(check(u128::MAX, u128::MAX), (1, u64::MAX as u128, 127, (0, u128::MAX)));
+}
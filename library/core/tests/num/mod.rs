@@ -32,6 +32,7 @@
 mod dec2flt;
 mod flt2dec;
 mod int_log;
+mod int_traits;
 mod ops;
 mod wrapping;
 
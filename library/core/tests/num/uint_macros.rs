@@ -230,6 +230,28 @@ fn test_checked_next_multiple_of() {
                 assert_eq!((1 as $T).checked_next_multiple_of(0), None);
                 assert_eq!(MAX.checked_next_multiple_of(2), None);
             }
+
+            #[test]
+            fn test_isqrt() {
+                for n in 0..=1000 as $T {
+                    let r = n.isqrt();
+                    assert!(r * r <= n);
+                    assert!((r + 1).checked_mul(r + 1).map_or(true, |s| s > n));
+                }
+                let r = MAX.isqrt();
+                assert_eq!(r, $T::MAX >> ($T::BITS / 2));
+                assert!(r * r <= MAX);
+                assert!((r + 1).checked_mul(r + 1).is_none());
+            }
+
+            #[test]
+            fn test_widening_mul() {
+                assert_eq!((5 as $T).widening_mul(2), (10, 0));
+                assert_eq!(MAX.widening_mul(2), (MAX - 1, 1));
+                assert_eq!(MAX.widening_mul(MAX), (1, MAX - 1));
+                assert_eq!(MAX.carrying_mul(MAX, MAX), (0, MAX));
+                assert_eq!((7 as $T).carrying_mul(6, 100), (142, 0));
+            }
         }
     };
 }
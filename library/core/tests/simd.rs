@@ -0,0 +1,201 @@
+use core::simd::*;
+
+#[test]
+fn int_arithmetic() {
+    let a = i32x4::from_array([1, -2, i32::MAX, i32::MIN]);
+    let b = i32x4::from_array([3, 4, 1, 1]);
+    assert_eq!((a + b).to_array(), [4, 2, i32::MIN, i32::MIN + 1]);
+    assert_eq!((a - b).to_array(), [-2, -6, i32::MAX - 1, i32::MAX]);
+    assert_eq!((a * b).to_array(), [3, -8, i32::MAX, i32::MIN]);
+    assert_eq!((a / b).to_array(), [0, 0, i32::MAX, i32::MIN]);
+    assert_eq!((a % b).to_array(), [1, -2, 0, 0]);
+    assert_eq!((-a).to_array(), [-1, 2, -i32::MAX, i32::MIN]);
+    assert_eq!((!a).to_array(), [!1, !-2, i32::MIN, i32::MAX]);
+
+    let mut c = a;
+    c += b;
+    c -= b;
+    assert_eq!(c, a);
+}
+
+#[test]
+fn int_bitwise() {
+    let a = u8x4::from_array([0b1100, 0b1010, 0xff, 0]);
+    let b = u8x4::from_array([0b1010, 0b1010, 0x0f, 0xff]);
+    assert_eq!((a & b).to_array(), [0b1000, 0b1010, 0x0f, 0]);
+    assert_eq!((a | b).to_array(), [0b1110, 0b1010, 0xff, 0xff]);
+    assert_eq!((a ^ b).to_array(), [0b0110, 0, 0xf0, 0xff]);
+}
+
+#[test]
+fn int_shifts_are_masked() {
+    let a = u8x4::splat(1);
+    let b = u8x4::from_array([1, 7, 8, 9]);
+    assert_eq!((a << b).to_array(), [2, 128, 1, 2]);
+    let c = i16x4::splat(-32768);
+    let d = i16x4::from_array([1, 15, 16, -1]);
+    assert_eq!((c >> d).to_array(), [-16384, -1, -32768, -1]);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn int_div_by_zero() {
+    let _ = u32x4::splat(1) / u32x4::from_array([1, 2, 0, 4]);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn int_div_overflow() {
+    let _ = i8x4::splat(i8::MIN) / i8x4::from_array([1, 2, -1, 4]);
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+fn int_rem_by_zero() {
+    let _ = i64x2::splat(1) % i64x2::splat(0);
+}
+
+#[test]
+fn float_arithmetic() {
+    let a = f32x4::from_array([1.0, -2.5, 0.0, f32::INFINITY]);
+    let b = f32x4::splat(2.0);
+    assert_eq!((a + b).to_array(), [3.0, -0.5, 2.0, f32::INFINITY]);
+    assert_eq!((a * b).to_array(), [2.0, -5.0, 0.0, f32::INFINITY]);
+    assert_eq!((a / b).to_array(), [0.5, -1.25, 0.0, f32::INFINITY]);
+    assert_eq!((-a).to_array(), [-1.0, 2.5, -0.0, f32::NEG_INFINITY]);
+    assert!((a % f32x4::splat(0.0)).is_nan().all());
+}
+
+#[test]
+fn float_methods() {
+    let a = f64x4::from_array([-1.5, 0.0, f64::NAN, -0.0]);
+    assert_eq!(a.abs().to_array()[..2], [1.5, 0.0]);
+    assert_eq!(a.is_nan().to_array(), [false, false, true, false]);
+    assert_eq!(a.is_sign_negative().to_array(), [true, false, false, true]);
+    assert_eq!(a.is_finite().to_array(), [true, true, false, true]);
+    assert_eq!(f64x4::splat(f64::NEG_INFINITY).is_infinite(), mask64x4::splat(true));
+
+    let b = f64x4::splat(1.0);
+    assert_eq!(a.min(b).to_array(), [-1.5, 0.0, 1.0, -0.0]);
+    assert_eq!(a.max(b).to_array(), [1.0, 1.0, 1.0, 1.0]);
+    assert_eq!(f64x4::from_bits(a.to_bits())[..2], a[..2]);
+    assert_eq!(f64x2::from_array([2.0, 0.5]).recip().to_array(), [0.5, 2.0]);
+}
+
+#[test]
+fn int_methods() {
+    let a = i8x4::from_array([i8::MIN, -3, 0, 5]);
+    assert_eq!(a.abs().to_array(), [i8::MIN, 3, 0, 5]);
+    assert_eq!(a.saturating_abs().to_array(), [i8::MAX, 3, 0, 5]);
+    assert_eq!(a.saturating_neg().to_array(), [i8::MAX, 3, 0, -5]);
+    assert_eq!(a.signum().to_array(), [-1, -1, 0, 1]);
+    assert_eq!(a.is_negative().to_array(), [true, true, false, false]);
+    assert_eq!(a.is_positive().to_array(), [false, false, false, true]);
+    assert_eq!(a.saturating_sub(i8x4::splat(100)).to_array(), [i8::MIN, -103, -100, -95]);
+
+    let b = u16x4::from_array([0, 1, u16::MAX - 1, u16::MAX]);
+    assert_eq!(b.saturating_add(u16x4::splat(1)).to_array(), [1, 2, u16::MAX, u16::MAX]);
+    assert_eq!(b.saturating_sub(u16x4::splat(1)).to_array(), [0, 0, u16::MAX - 2, u16::MAX - 1]);
+}
+
+#[test]
+fn reductions() {
+    let a = u32x8::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(a.horizontal_sum(), 36);
+    assert_eq!(a.horizontal_product(), 40320);
+    assert_eq!(a.horizontal_max(), 8);
+    assert_eq!(a.horizontal_min(), 1);
+    assert_eq!(a.horizontal_and(), 0);
+    assert_eq!(a.horizontal_or(), 15);
+    assert_eq!(a.horizontal_xor(), 8);
+    assert_eq!(u8x4::splat(200).horizontal_sum(), 32);
+
+    let b = f32x4::from_array([1.0, f32::NAN, -3.0, 2.0]);
+    assert_eq!(b.horizontal_max(), 2.0);
+    assert_eq!(b.horizontal_min(), -3.0);
+    assert_eq!(f32x4::from_array([1.0, 2.0, 3.0, 4.0]).horizontal_sum(), 10.0);
+    assert_eq!(f32x4::from_array([1.0, 2.0, 3.0, 4.0]).horizontal_product(), 24.0);
+}
+
+#[test]
+fn comparisons_and_masks() {
+    let a = i32x4::from_array([1, 2, 3, 4]);
+    let b = i32x4::from_array([4, 2, 2, 1]);
+    assert_eq!(a.lanes_eq(b).to_array(), [false, true, false, false]);
+    assert_eq!(a.lanes_ne(b).to_array(), [true, false, true, true]);
+    assert_eq!(a.lanes_lt(b).to_array(), [true, false, false, false]);
+    assert_eq!(a.lanes_le(b).to_array(), [true, true, false, false]);
+    assert_eq!(a.lanes_gt(b).to_array(), [false, false, true, true]);
+    assert_eq!(a.lanes_ge(b).to_array(), [false, true, true, true]);
+
+    let m = a.lanes_gt(b);
+    assert!(m.any());
+    assert!(!m.all());
+    assert_eq!((!m).to_array(), [true, true, false, false]);
+    assert_eq!((m & true).to_array(), m.to_array());
+    assert_eq!((m | true), mask32x4::splat(true));
+    assert_eq!(m.to_int().to_array(), [0, 0, -1, -1]);
+    assert_eq!(m.select(a, b).to_array(), [4, 2, 3, 4]);
+    assert_eq!(m.cast::<i8>().to_array(), m.to_array());
+
+    let mut m = mask8x4::default();
+    m.set(2, true);
+    assert!(m.test(2));
+    assert_eq!(m.to_array(), [false, false, true, false]);
+}
+
+#[test]
+#[should_panic(expected = "all values must be either 0 or -1")]
+fn mask_from_invalid_int() {
+    let _ = mask32x4::from_int(i32x4::from_array([0, -1, 1, 0]));
+}
+
+#[test]
+fn casts() {
+    let a = f32x4::from_array([-1.5, 300.7, f32::NAN, -1e10]);
+    assert_eq!(a.cast::<u8>().to_array(), [0, 255, 0, 0]);
+    assert_eq!(a.cast::<i32>().to_array(), [-1, 300, 0, i32::MIN]);
+    assert_eq!(i32x4::from_array([-1, 256, 2, 3]).cast::<u8>().to_array(), [255, 0, 2, 3]);
+    assert_eq!(u8x4::splat(255).cast::<f64>().to_array(), [255.0; 4]);
+}
+
+#[test]
+fn gather_scatter() {
+    let data = [10, 11, 12, 13, 14];
+    let idxs = usizex4::from_array([4, 0, 9, 2]);
+    let v = Simd::gather_or_default(&data, idxs);
+    assert_eq!(v.to_array(), [14, 10, 0, 12]);
+
+    let mut out = [0; 5];
+    v.scatter_select(&mut out, masksizex4::from_array([true, true, true, false]), idxs);
+    assert_eq!(out, [10, 0, 0, 0, 14]);
+}
+
+#[test]
+fn swizzles() {
+    let a = u32x4::from_array([0, 1, 2, 3]);
+    let b = u32x4::from_array([4, 5, 6, 7]);
+    assert_eq!(simd_swizzle!(a, [3, 3, 0, 1, 2, 2, 1, 0]).to_array(), [3, 3, 0, 1, 2, 2, 1, 0]);
+    assert_eq!(simd_swizzle!(a, b, [Which::Second(3), Which::First(0)]).to_array(), [7, 0]);
+    assert_eq!(a.reverse().to_array(), [3, 2, 1, 0]);
+    assert_eq!(a.rotate_lanes_left::<5>().to_array(), [1, 2, 3, 0]);
+    assert_eq!(a.rotate_lanes_right::<2>().to_array(), [2, 3, 0, 1]);
+
+    let (lo, hi) = a.interleave(b);
+    assert_eq!(lo.to_array(), [0, 4, 1, 5]);
+    assert_eq!(hi.to_array(), [2, 6, 3, 7]);
+    assert_eq!(lo.deinterleave(hi), (a, b));
+}
+
+#[test]
+fn array_conversions() {
+    let mut v = i16x8::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(v[..3], [1, 2, 3]);
+    v[0] = 10;
+    v.as_mut_array()[1] = 20;
+    let array: [i16; 8] = v.into();
+    assert_eq!(array, [10, 20, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(i16x8::from(array), v);
+    assert_eq!(format!("{:?}", u8x2::from_array([1, 2])), "[1, 2]");
+    assert_eq!(i16x8::LANES, 8);
+}
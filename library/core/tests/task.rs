@@ -1,4 +1,4 @@
-use core::task::Poll;
+use core::task::{Poll, Waker};
 
 #[test]
 fn poll_const() {
@@ -12,3 +12,14 @@ fn poll_const() {
     const IS_PENDING: bool = POLL.is_pending();
     assert!(IS_PENDING);
 }
+
+#[test]
+fn noop_waker() {
+    let waker = Waker::noop();
+    waker.wake_by_ref();
+    assert!(waker.will_wake(Waker::noop()));
+
+    let cloned = waker.clone();
+    assert!(cloned.will_wake(waker));
+    cloned.wake();
+}
@@ -63,6 +63,27 @@
 //! variables is cached once the first backtrace is created, so altering
 //! `RUST_LIB_BACKTRACE` or `RUST_BACKTRACE` at runtime might not actually change
 //! how backtraces are captured.
+//!
+//! ## Deferred symbolization
+//!
+//! Symbols are only resolved when they are first needed, and
+//! `Backtrace::frames_unresolved` gives access to the raw frames without
+//! resolving them at all.
+//!
+//! `Backtrace::to_unresolved` goes one step further and records each frame as
+//! an address relative to the module (executable or shared library) that
+//! contains it, together with the module's path and build id. The resulting
+//! `UnresolvedBacktrace` can be converted to bytes, stored or sent to another
+//! process, and symbolized there with `UnresolvedBacktrace::resolve`, for
+//! example by a crash reporter running the same executable. Resolution looks
+//! the modules up among the ones loaded into the resolving process, so frames
+//! in modules it hasn't loaded stay unresolved. External symbolizers can use
+//! the module-relative addresses directly.
+//!
+//! Module paths and build ids are currently only recorded on Linux, FreeBSD,
+//! NetBSD and OpenBSD. On other platforms an `UnresolvedBacktrace` can only be
+//! resolved by the process that captured it; resolved anywhere else, its
+//! frames have no symbols.
 
 #![unstable(feature = "backtrace", issue = "53487")]
 
@@ -99,8 +120,11 @@
 use crate::env;
 use crate::ffi::c_void;
 use crate::fmt;
-use crate::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+use crate::path::{Path, PathBuf};
+use crate::sync::atomic::{AtomicUsize, Ordering::Relaxed, Ordering::SeqCst};
 use crate::sync::Once;
+use crate::str;
+use crate::sys::loaded_modules;
 use crate::sys_common::backtrace::{lock, output_filename};
 use crate::vec::Vec;
 
@@ -158,11 +182,17 @@ pub struct BacktraceFrame {
 #[derive(Debug)]
 enum RawFrame {
     Actual(backtrace_rs::Frame),
+    Recorded(UnresolvedFrame),
     #[cfg(test)]
     Fake,
 }
 
-struct BacktraceSymbol {
+/// A single symbol of a backtrace frame.
+///
+/// A frame may resolve to more than one symbol when functions have been
+/// inlined into it, in which case the innermost function comes first.
+#[unstable(feature = "backtrace_frames", issue = "79676")]
+pub struct BacktraceSymbol {
     name: Option<Vec<u8>>,
     filename: Option<BytesOrWide>,
     lineno: Option<u32>,
@@ -174,6 +204,47 @@ enum BytesOrWide {
     Wide(Vec<u16>),
 }
 
+/// A backtrace whose symbols haven't been resolved, recorded in a form that
+/// can be resolved later, possibly by another process.
+///
+/// Each frame is recorded as an instruction pointer together with the base
+/// address, path and build id of the module containing it. See the
+/// [module-level documentation](crate::backtrace#deferred-symbolization) for
+/// how this is used.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(backtrace, backtrace_frames)]
+///
+/// use std::backtrace::{Backtrace, UnresolvedBacktrace};
+///
+/// let mut backtrace = Backtrace::force_capture();
+/// let bytes = backtrace.to_unresolved().to_bytes();
+///
+/// // ... later, possibly in another process:
+/// let unresolved = UnresolvedBacktrace::from_bytes(&bytes).unwrap();
+/// println!("{}", unresolved.resolve());
+/// ```
+#[unstable(feature = "backtrace_frames", issue = "79676")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnresolvedBacktrace {
+    actual_start: usize,
+    // The `process_token` of the process that captured the backtrace.
+    process: usize,
+    frames: Vec<UnresolvedFrame>,
+}
+
+/// A single frame of an [`UnresolvedBacktrace`].
+#[unstable(feature = "backtrace_frames", issue = "79676")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnresolvedFrame {
+    ip: usize,
+    module_base: Option<usize>,
+    module_path: Option<PathBuf>,
+    build_id: Option<Vec<u8>>,
+}
+
 impl fmt::Debug for Backtrace {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         let capture = match &self.inner {
@@ -373,6 +444,398 @@ impl<'a> Backtrace {
     pub fn frames(&'a self) -> &'a [BacktraceFrame] {
         if let Inner::Captured(c) = &self.inner { &c.force().frames } else { &[] }
     }
+
+    /// Returns the backtrace frames without resolving their symbols.
+    ///
+    /// Resolving symbols is by far the most expensive part of working with a
+    /// backtrace, and it isn't always needed: the instruction pointer and
+    /// module base address of each frame are enough for an external tool to
+    /// symbolize the backtrace later. This method gives access to that raw
+    /// data without paying for symbolication. To store a backtrace and
+    /// resolve it later, use [`Backtrace::to_unresolved`] instead.
+    ///
+    /// If the symbols of this backtrace have already been resolved, for
+    /// example by calling [`Backtrace::frames`] or by formatting it, the
+    /// returned frames carry their symbols. Otherwise
+    /// [`BacktraceFrame::symbols`] is empty for every frame, and a later call
+    /// to [`Backtrace::frames`] resolves them.
+    ///
+    /// This takes `&mut self` so that no resolution can run concurrently
+    /// with the returned borrow.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn frames_unresolved(&'a mut self) -> &'a [BacktraceFrame] {
+        if let Inner::Captured(c) = &mut self.inner { &c.capture.get_mut().frames } else { &[] }
+    }
+}
+
+impl Backtrace {
+    /// Records this backtrace in a form that can be stored and resolved
+    /// later, without resolving its symbols.
+    ///
+    /// A backtrace that is disabled or unsupported has no frames.
+    ///
+    /// This takes `&mut self` for the same reason as
+    /// [`Backtrace::frames_unresolved`].
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn to_unresolved(&mut self) -> UnresolvedBacktrace {
+        let capture = match &mut self.inner {
+            Inner::Captured(c) => c.capture.get_mut(),
+            _ => {
+                return UnresolvedBacktrace {
+                    actual_start: 0,
+                    process: process_token(),
+                    frames: Vec::new(),
+                };
+            }
+        };
+        let frames = capture.frames.iter().map(|frame| frame.frame.to_unresolved()).collect();
+        UnresolvedBacktrace { actual_start: capture.actual_start, process: process_token(), frames }
+    }
+}
+
+impl BacktraceFrame {
+    /// Returns the instruction pointer of this frame.
+    ///
+    /// This is normally the next instruction to execute in the frame, not the
+    /// one that was executing, so symbolizers typically look up `ip - 1`.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn ip(&self) -> *mut c_void {
+        self.frame.ip()
+    }
+
+    /// Returns the starting address of the function containing this frame,
+    /// as reported by the unwinder.
+    ///
+    /// On some platforms this is simply the instruction pointer.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn symbol_address(&self) -> *mut c_void {
+        self.frame.symbol_address()
+    }
+
+    /// Returns the base address at which the module (executable or shared
+    /// library) containing this frame is loaded, if known.
+    ///
+    /// Together with [`BacktraceFrame::ip`] this gives the module-relative
+    /// address of the frame, which an external symbolizer can look up in the
+    /// debug information of that module. [`Backtrace::to_unresolved`] records
+    /// it along with the module's path and build id.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn module_base_address(&self) -> Option<*mut c_void> {
+        self.frame.module_base_address()
+    }
+
+    /// Returns the symbols this frame resolved to.
+    ///
+    /// This is empty if the frame could not be resolved, or if the frame was
+    /// obtained through [`Backtrace::frames_unresolved`] before the
+    /// backtrace's symbols were resolved.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn symbols(&self) -> &[BacktraceSymbol] {
+        &self.symbols
+    }
+}
+
+impl BacktraceSymbol {
+    /// Returns the raw, possibly mangled, name of this symbol.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn mangled_name(&self) -> Option<&[u8]> {
+        self.name.as_deref()
+    }
+
+    /// Returns the demangled name of this symbol, without the trailing hash
+    /// that Rust symbols carry.
+    ///
+    /// Names that aren't recognized as mangled Rust or C++ symbols are
+    /// returned as they are, lossily converted to UTF-8.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn demangled_name(&self) -> Option<String> {
+        self.name.as_ref().map(|b| format!("{:#}", backtrace_rs::SymbolName::new(b)))
+    }
+
+    /// Returns the path of the source file this symbol was defined in, if
+    /// debug information is available.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn filename(&self) -> Option<PathBuf> {
+        self.filename.as_ref().and_then(BytesOrWide::to_path_buf)
+    }
+
+    /// Returns the line number within [`BacktraceSymbol::filename`], if
+    /// debug information is available.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn lineno(&self) -> Option<u32> {
+        self.lineno
+    }
+
+    /// Returns the column number within [`BacktraceSymbol::filename`], if
+    /// debug information is available.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn colno(&self) -> Option<u32> {
+        self.colno
+    }
+}
+
+impl UnresolvedBacktrace {
+    /// Returns the recorded frames.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn frames(&self) -> &[UnresolvedFrame] {
+        &self.frames
+    }
+
+    /// Resolves the symbols of the recorded frames in the current process.
+    ///
+    /// Each frame is looked up in the module with the recorded build id, or
+    /// if there is none, the recorded path, among the modules loaded into
+    /// the current process. The module may be loaded at a different address
+    /// than in the process that captured the backtrace. Frames whose module
+    /// isn't loaded have no symbols, and neither do frames recorded without
+    /// a module path or build id, unless this is the process that captured
+    /// them.
+    ///
+    /// The frames of the returned backtrace report the recorded instruction
+    /// pointers and module base addresses, not the ones they were resolved
+    /// at.
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn resolve(&self) -> Backtrace {
+        if self.frames.is_empty() {
+            return Backtrace { inner: Inner::Unsupported };
+        }
+
+        let same_process = self.process == process_token();
+        // SAFETY: We don't attempt to lock this reentrantly.
+        let _lock = unsafe { lock() };
+        let frames = self
+            .frames
+            .iter()
+            .map(|frame| {
+                let mut symbols = Vec::new();
+                if let Some(addr) = frame.local_address(same_process) {
+                    unsafe {
+                        backtrace_rs::resolve_unsynchronized(addr as *mut c_void, |symbol| {
+                            symbols.push(BacktraceSymbol::new(symbol));
+                        });
+                    }
+                }
+                BacktraceFrame { frame: RawFrame::Recorded(frame.clone()), symbols }
+            })
+            .collect();
+        let capture = Capture { actual_start: self.actual_start, resolved: true, frames };
+        Backtrace { inner: Inner::Captured(LazilyResolvedCapture::new(capture)) }
+    }
+
+    /// Encodes this backtrace as bytes that [`UnresolvedBacktrace::from_bytes`]
+    /// turns back into an equal `UnresolvedBacktrace`.
+    ///
+    /// The encoding is plain ASCII text, one frame per line, and is only
+    /// meant to be read by `from_bytes`.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        fn hex(out: &mut String, bytes: Option<&[u8]>) {
+            match bytes {
+                Some(bytes) => bytes.iter().for_each(|b| out.push_str(&format!("{:02x}", b))),
+                None => out.push('-'),
+            }
+        }
+
+        let mut out = format!("backtrace v1 {:x} {:x}\n", self.actual_start, self.process);
+        for frame in &self.frames {
+            out.push_str(&format!("{:x} ", frame.ip));
+            match frame.module_base {
+                Some(base) => out.push_str(&format!("{:x} ", base)),
+                None => out.push_str("- "),
+            }
+            hex(&mut out, frame.build_id.as_deref());
+            out.push(' ');
+            hex(&mut out, frame.module_path.as_deref().and_then(path_to_bytes).as_deref());
+            out.push('\n');
+        }
+        out.into_bytes()
+    }
+
+    /// Decodes a backtrace encoded by [`UnresolvedBacktrace::to_bytes`].
+    ///
+    /// Returns `None` if `bytes` isn't a valid encoding.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn from_bytes(bytes: &[u8]) -> Option<UnresolvedBacktrace> {
+        fn number(s: &str) -> Option<Option<usize>> {
+            if s == "-" { Some(None) } else { usize::from_str_radix(s, 16).ok().map(Some) }
+        }
+        fn hex(s: &str) -> Option<Option<Vec<u8>>> {
+            if s == "-" {
+                return Some(None);
+            }
+            if s.len() % 2 != 0 {
+                return None;
+            }
+            (0..s.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+                .collect::<Option<Vec<u8>>>()
+                .map(Some)
+        }
+
+        let mut lines = str::from_utf8(bytes).ok()?.strip_suffix('\n')?.split('\n');
+        let (actual_start, process) =
+            lines.next()?.strip_prefix("backtrace v1 ")?.split_once(' ')?;
+        let actual_start = usize::from_str_radix(actual_start, 16).ok()?;
+        let process = usize::from_str_radix(process, 16).ok()?;
+        let frames = lines
+            .map(|line| {
+                let mut fields = line.split(' ');
+                let frame = UnresolvedFrame {
+                    ip: number(fields.next()?)??,
+                    module_base: number(fields.next()?)?,
+                    build_id: hex(fields.next()?)?,
+                    module_path: match hex(fields.next()?)? {
+                        Some(bytes) => Some(path_from_bytes(bytes)?),
+                        None => None,
+                    },
+                };
+                if fields.next().is_some() { None } else { Some(frame) }
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(UnresolvedBacktrace { actual_start, process, frames })
+    }
+}
+
+impl UnresolvedFrame {
+    /// Returns the instruction pointer of this frame in the process that
+    /// captured it.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    /// Returns the base address of the module containing this frame in the
+    /// process that captured it, if known.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn module_base_address(&self) -> Option<usize> {
+        self.module_base
+    }
+
+    /// Returns the address of this frame relative to the base address of its
+    /// module, if known.
+    ///
+    /// This is the address to look up in the module's debug information.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn module_offset(&self) -> Option<usize> {
+        self.module_base.map(|base| self.ip.wrapping_sub(base))
+    }
+
+    /// Returns the path of the module containing this frame, if known.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn module_path(&self) -> Option<&Path> {
+        self.module_path.as_deref()
+    }
+
+    /// Returns the build id of the module containing this frame, if it has
+    /// one.
+    #[must_use]
+    #[unstable(feature = "backtrace_frames", issue = "79676")]
+    pub fn module_build_id(&self) -> Option<&[u8]> {
+        self.build_id.as_deref()
+    }
+
+    /// Returns the address this frame would have in the current process, if
+    /// its module is loaded here.
+    ///
+    /// `same_process` tells whether the frame was captured by the current
+    /// process.
+    fn local_address(&self, same_process: bool) -> Option<usize> {
+        match (self.module_base, self.module_path.is_some() || self.build_id.is_some()) {
+            (Some(base), true) => {
+                let path = self.module_path.as_deref();
+                let local_base = loaded_modules::module_base(path, self.build_id.as_deref())?;
+                Some(self.ip.wrapping_sub(base).wrapping_add(local_base))
+            }
+            // Without any module information the frame can only be resolved
+            // by the process that captured it. Anywhere else its address is
+            // meaningless.
+            _ if same_process => Some(self.ip),
+            _ => None,
+        }
+    }
+}
+
+/// Returns a random token identifying the current process.
+///
+/// An `UnresolvedBacktrace` records the token of the process that captured
+/// it, so that `resolve` can tell whether it's running in that process. A
+/// child created by `fork` keeps the token, as it also keeps the addresses.
+fn process_token() -> usize {
+    static TOKEN: AtomicUsize = AtomicUsize::new(0);
+
+    match TOKEN.load(Relaxed) {
+        0 => {
+            // Zero means "not generated yet", so never use it as a token.
+            let token = crate::sys::hashmap_random_keys().0 as usize | 1;
+            match TOKEN.compare_exchange(0, token, Relaxed, Relaxed) {
+                Ok(_) => token,
+                Err(token) => token,
+            }
+        }
+        token => token,
+    }
+}
+
+#[cfg(unix)]
+fn path_to_bytes(path: &Path) -> Option<Vec<u8>> {
+    use crate::os::unix::prelude::*;
+    Some(path.as_os_str().as_bytes().to_vec())
+}
+
+#[cfg(not(unix))]
+fn path_to_bytes(path: &Path) -> Option<Vec<u8>> {
+    path.to_str().map(|s| s.as_bytes().to_vec())
+}
+
+#[cfg(unix)]
+fn path_from_bytes(bytes: Vec<u8>) -> Option<PathBuf> {
+    use crate::os::unix::prelude::*;
+    Some(crate::ffi::OsString::from_vec(bytes).into())
+}
+
+#[cfg(not(unix))]
+fn path_from_bytes(bytes: Vec<u8>) -> Option<PathBuf> {
+    String::from_utf8(bytes).ok().map(PathBuf::from)
+}
+
+impl BytesOrWide {
+    fn to_path_buf(&self) -> Option<PathBuf> {
+        match self {
+            #[cfg(unix)]
+            BytesOrWide::Bytes(bytes) => {
+                use crate::os::unix::prelude::*;
+                Some(crate::ffi::OsStr::from_bytes(bytes).into())
+            }
+            #[cfg(not(unix))]
+            BytesOrWide::Bytes(bytes) => crate::str::from_utf8(bytes).ok().map(PathBuf::from),
+            #[cfg(windows)]
+            BytesOrWide::Wide(wide) => {
+                use crate::os::windows::prelude::*;
+                Some(crate::ffi::OsString::from_wide(wide).into())
+            }
+            #[cfg(not(windows))]
+            BytesOrWide::Wide(_wide) => None,
+        }
+    }
 }
 
 impl fmt::Display for Backtrace {
@@ -468,32 +931,82 @@ fn resolve(&mut self) {
             let symbols = &mut frame.symbols;
             let frame = match &frame.frame {
                 RawFrame::Actual(frame) => frame,
+                // Recorded frames are resolved when they are created.
+                RawFrame::Recorded(_) => continue,
                 #[cfg(test)]
                 RawFrame::Fake => unimplemented!(),
             };
             unsafe {
                 backtrace_rs::resolve_frame_unsynchronized(frame, |symbol| {
-                    symbols.push(BacktraceSymbol {
-                        name: symbol.name().map(|m| m.as_bytes().to_vec()),
-                        filename: symbol.filename_raw().map(|b| match b {
-                            BytesOrWideString::Bytes(b) => BytesOrWide::Bytes(b.to_owned()),
-                            BytesOrWideString::Wide(b) => BytesOrWide::Wide(b.to_owned()),
-                        }),
-                        lineno: symbol.lineno(),
-                        colno: symbol.colno(),
-                    });
+                    symbols.push(BacktraceSymbol::new(symbol));
                 });
             }
         }
     }
 }
 
+impl BacktraceSymbol {
+    fn new(symbol: &backtrace_rs::Symbol) -> BacktraceSymbol {
+        BacktraceSymbol {
+            name: symbol.name().map(|m| m.as_bytes().to_vec()),
+            filename: symbol.filename_raw().map(|b| match b {
+                BytesOrWideString::Bytes(b) => BytesOrWide::Bytes(b.to_owned()),
+                BytesOrWideString::Wide(b) => BytesOrWide::Wide(b.to_owned()),
+            }),
+            lineno: symbol.lineno(),
+            colno: symbol.colno(),
+        }
+    }
+}
+
 impl RawFrame {
     fn ip(&self) -> *mut c_void {
         match self {
             RawFrame::Actual(frame) => frame.ip(),
+            RawFrame::Recorded(frame) => frame.ip as *mut c_void,
+            #[cfg(test)]
+            RawFrame::Fake => 1 as *mut c_void,
+        }
+    }
+
+    fn symbol_address(&self) -> *mut c_void {
+        match self {
+            RawFrame::Actual(frame) => frame.symbol_address(),
+            // The function start isn't recorded, so report the instruction
+            // pointer like platforms that don't know it either.
+            RawFrame::Recorded(frame) => frame.ip as *mut c_void,
             #[cfg(test)]
             RawFrame::Fake => 1 as *mut c_void,
         }
     }
+
+    fn module_base_address(&self) -> Option<*mut c_void> {
+        match self {
+            RawFrame::Actual(frame) => frame.module_base_address(),
+            RawFrame::Recorded(frame) => frame.module_base.map(|base| base as *mut c_void),
+            #[cfg(test)]
+            RawFrame::Fake => None,
+        }
+    }
+
+    fn to_unresolved(&self) -> UnresolvedFrame {
+        let ip = self.ip() as usize;
+        match self {
+            RawFrame::Recorded(frame) => frame.clone(),
+            _ => match loaded_modules::module_containing(ip) {
+                Some(module) => UnresolvedFrame {
+                    ip,
+                    module_base: Some(module.base),
+                    module_path: module.path,
+                    build_id: module.build_id,
+                },
+                None => UnresolvedFrame {
+                    ip,
+                    module_base: self.module_base_address().map(|base| base as usize),
+                    module_path: None,
+                    build_id: None,
+                },
+            },
+        }
+    }
 }
@@ -93,3 +93,144 @@ fn test_frames() {
 
     assert!(iter.all(|(f, e)| format!("{:#?}", f) == *e));
 }
+
+#[test]
+fn test_symbol_accessors() {
+    let backtrace = Backtrace {
+        inner: Inner::Captured(LazilyResolvedCapture::new(Capture {
+            actual_start: 1,
+            resolved: true,
+            frames: generate_fake_frames(),
+        })),
+    };
+
+    let frames = backtrace.frames();
+    assert_eq!(frames.len(), 3);
+    assert_eq!(frames[0].ip(), 1 as *mut c_void);
+    assert_eq!(frames[0].module_base_address(), None);
+
+    let symbol = &frames[0].symbols()[0];
+    assert_eq!(symbol.mangled_name(), Some(&b"std::backtrace::Backtrace::create"[..]));
+    assert_eq!(symbol.demangled_name().as_deref(), Some("std::backtrace::Backtrace::create"));
+    assert_eq!(symbol.filename(), Some(PathBuf::from("rust/backtrace.rs")));
+    assert_eq!(symbol.lineno(), Some(100));
+    assert_eq!(symbol.colno(), None);
+
+    let symbol = &frames[1].symbols()[0];
+    assert_eq!(symbol.filename(), None);
+    assert_eq!(symbol.lineno(), None);
+
+    let symbols = frames[2].symbols();
+    assert_eq!(symbols.len(), 2);
+    assert_eq!(symbols[0].demangled_name().as_deref(), Some("std::rt::lang_start_internal"));
+    assert_eq!(symbols[0].colno(), Some(5));
+    assert_eq!(symbols[1].demangled_name().as_deref(), Some("std::rt::lang_start"));
+}
+
+#[test]
+fn test_frames_unresolved() {
+    let mut backtrace = Backtrace {
+        inner: Inner::Captured(LazilyResolvedCapture::new(Capture {
+            actual_start: 0,
+            resolved: false,
+            frames: vec![
+                BacktraceFrame { frame: RawFrame::Fake, symbols: Vec::new() },
+                BacktraceFrame { frame: RawFrame::Fake, symbols: Vec::new() },
+            ],
+        })),
+    };
+
+    // Resolving a fake frame would panic, so this also checks that no
+    // resolution happens here.
+    let frames = backtrace.frames_unresolved();
+    assert_eq!(frames.len(), 2);
+    assert!(frames.iter().all(|f| f.ip() == 1 as *mut c_void && f.symbols().is_empty()));
+
+    assert!(Backtrace::disabled().frames_unresolved().is_empty());
+}
+
+#[test]
+fn test_frames_unresolved_then_resolve() {
+    let mut backtrace = Backtrace::force_capture();
+    let ips: Vec<_> = backtrace.frames_unresolved().iter().map(|f| f.ip()).collect();
+    let frames = backtrace.frames();
+    assert_eq!(frames.len(), ips.len());
+    assert!(frames.iter().zip(ips).all(|(f, ip)| f.ip() == ip));
+}
+
+#[test]
+fn test_unresolved_bytes_roundtrip() {
+    let unresolved = UnresolvedBacktrace {
+        actual_start: 1,
+        process: 0xabc,
+        frames: vec![
+            UnresolvedFrame {
+                ip: 0x5555_1234,
+                module_base: Some(0x5555_0000),
+                module_path: Some(PathBuf::from("/usr/bin/my app")),
+                build_id: Some(vec![0xde, 0xad, 0xbe, 0xef]),
+            },
+            UnresolvedFrame { ip: 0x42, module_base: None, module_path: None, build_id: None },
+        ],
+    };
+
+    let bytes = unresolved.to_bytes();
+    assert_eq!(UnresolvedBacktrace::from_bytes(&bytes), Some(unresolved.clone()));
+
+    let frame = &unresolved.frames()[0];
+    assert_eq!(frame.module_offset(), Some(0x1234));
+    assert_eq!(frame.module_build_id(), Some(&[0xde, 0xad, 0xbe, 0xef][..]));
+    assert_eq!(unresolved.frames()[1].module_offset(), None);
+
+    assert_eq!(UnresolvedBacktrace::from_bytes(b""), None);
+    assert_eq!(UnresolvedBacktrace::from_bytes(b"backtrace v1 0\n42 - - -\n"), None);
+    assert_eq!(UnresolvedBacktrace::from_bytes(b"backtrace v1 0 1\n42 - - -"), None);
+    assert_eq!(UnresolvedBacktrace::from_bytes(b"backtrace v1 0 1\n42 - abc -\n"), None);
+    assert_eq!(UnresolvedBacktrace::from_bytes(b"backtrace v1 0 1\n42 - - - -\n"), None);
+}
+
+#[test]
+fn test_unresolved_resolve() {
+    let mut backtrace = Backtrace::force_capture();
+    let unresolved = backtrace.to_unresolved();
+    let unresolved = UnresolvedBacktrace::from_bytes(&unresolved.to_bytes()).unwrap();
+    let resolved = unresolved.resolve();
+
+    let expected = backtrace.frames();
+    let frames = resolved.frames();
+    assert_eq!(frames.len(), expected.len());
+    for (frame, expected) in frames.iter().zip(expected) {
+        assert_eq!(frame.ip(), expected.ip());
+        let names = |f: &BacktraceFrame| -> Vec<_> {
+            f.symbols().iter().map(|s| s.mangled_name().map(<[u8]>::to_vec)).collect()
+        };
+        assert_eq!(names(frame), names(expected));
+    }
+
+    assert!(Backtrace::disabled().to_unresolved().frames().is_empty());
+}
+
+#[test]
+fn test_unresolved_resolve_in_other_process() {
+    let mut backtrace = Backtrace::force_capture();
+    let mut unresolved = backtrace.to_unresolved();
+    for frame in &mut unresolved.frames {
+        frame.module_path = None;
+        frame.build_id = None;
+    }
+
+    // Without module information, the capturing process still resolves the
+    // frames at their recorded addresses.
+    let resolved = unresolved.resolve();
+    let names = |f: &BacktraceFrame| -> Vec<_> {
+        f.symbols().iter().map(|s| s.mangled_name().map(<[u8]>::to_vec)).collect()
+    };
+    let expected = backtrace.frames();
+    assert!(resolved.frames().iter().zip(expected).all(|(f, e)| names(f) == names(e)));
+
+    // Any other process can't, and must not resolve them to its own symbols.
+    unresolved.process ^= 2;
+    let resolved = UnresolvedBacktrace::from_bytes(&unresolved.to_bytes()).unwrap().resolve();
+    assert_eq!(resolved.frames().len(), expected.len());
+    assert!(resolved.frames().iter().all(|f| f.symbols().is_empty()));
+}
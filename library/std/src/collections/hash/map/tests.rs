@@ -1,6 +1,7 @@
 use super::Entry::{Occupied, Vacant};
 use super::HashMap;
 use super::RandomState;
+use crate::alloc::System;
 use crate::assert_matches::assert_matches;
 use crate::cell::RefCell;
 use rand::{thread_rng, Rng};
@@ -835,6 +836,51 @@ fn test_try_reserve() {
     );
 }
 
+#[test]
+fn test_try_with_capacity() {
+    let mut map: HashMap<u8, u8> = HashMap::try_with_capacity(10).unwrap();
+    assert!(map.capacity() >= 10);
+    assert_eq!(map.try_insert_with_capacity(1, 10), Ok(None));
+    assert_eq!(map.try_insert_with_capacity(1, 20), Ok(Some(10)));
+    assert_eq!(map[&1], 20);
+
+    assert_matches!(
+        HashMap::<u8, u8>::try_with_capacity(usize::MAX).map_err(|e| e.kind()),
+        Err(CapacityOverflow)
+    );
+}
+
+#[test]
+fn test_try_insert_with_capacity_existing_key() {
+    let mut map: HashMap<u32, u32> = HashMap::new();
+    map.try_insert_with_capacity(0, 0).unwrap();
+    let mut key = 1;
+    while map.len() < map.capacity() {
+        map.try_insert_with_capacity(key, key).unwrap();
+        key += 1;
+    }
+
+    // The map is full, but replacing a value doesn't need more room.
+    let capacity = map.capacity();
+    assert_eq!(map.try_insert_with_capacity(0, 100), Ok(Some(0)));
+    assert_eq!(map.capacity(), capacity);
+    assert_eq!(map[&0], 100);
+}
+
+#[test]
+fn test_try_from_iter() {
+    use crate::collections::TryCollect;
+
+    let map: HashMap<_, _> = HashMap::try_from_iter([(1, 10), (2, 20), (1, 30)]).unwrap();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&1], 30);
+    assert_eq!(map[&2], 20);
+
+    let map: HashMap<_, _> = (0..100).map(|i| (i, i * 2)).try_collect().unwrap();
+    assert_eq!(map.len(), 100);
+    assert!(map.iter().all(|(k, v)| *v == k * 2));
+}
+
 #[test]
 fn test_raw_entry() {
     use super::RawEntryMut::{Occupied, Vacant};
@@ -928,7 +974,7 @@ fn test_raw_entry() {
     }
 }
 
-mod test_drain_filter {
+mod test_extract_if {
     use super::*;
 
     use crate::panic::{catch_unwind, AssertUnwindSafe};
@@ -952,7 +998,7 @@ fn eq_sorted<I: IntoIterator<Item = Self::Item>>(self, other: I) -> bool {
     #[test]
     fn empty() {
         let mut map: HashMap<i32, i32> = HashMap::new();
-        map.drain_filter(|_, _| unreachable!("there's nothing to decide on"));
+        map.extract_if(|_, _| unreachable!("there's nothing to decide on"));
         assert!(map.is_empty());
     }
 
@@ -960,7 +1006,7 @@ fn empty() {
     fn consuming_nothing() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map: HashMap<_, _> = pairs.collect();
-        assert!(map.drain_filter(|_, _| false).eq_sorted(crate::iter::empty()));
+        assert!(map.extract_if(|_, _| false).eq_sorted(crate::iter::empty()));
         assert_eq!(map.len(), 3);
     }
 
@@ -968,7 +1014,7 @@ fn consuming_nothing() {
     fn consuming_all() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map: HashMap<_, _> = pairs.clone().collect();
-        assert!(map.drain_filter(|_, _| true).eq_sorted(pairs));
+        assert!(map.extract_if(|_, _| true).eq_sorted(pairs));
         assert!(map.is_empty());
     }
 
@@ -977,7 +1023,7 @@ fn mutating_and_keeping() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map: HashMap<_, _> = pairs.collect();
         assert!(
-            map.drain_filter(|_, v| {
+            map.extract_if(|_, v| {
                 *v += 6;
                 false
             })
@@ -992,7 +1038,7 @@ fn mutating_and_removing() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map: HashMap<_, _> = pairs.collect();
         assert!(
-            map.drain_filter(|_, v| {
+            map.extract_if(|_, v| {
                 *v += 6;
                 true
             })
@@ -1018,7 +1064,7 @@ fn drop(&mut self) {
         let mut map = (0..3).map(|i| (i, D)).collect::<HashMap<_, _>>();
 
         catch_unwind(move || {
-            drop(map.drain_filter(|_, _| {
+            drop(map.extract_if(|_, _| {
                 PREDS.fetch_add(1, Ordering::SeqCst);
                 true
             }))
@@ -1044,7 +1090,7 @@ fn drop(&mut self) {
         let mut map = (0..3).map(|i| (i, D)).collect::<HashMap<_, _>>();
 
         catch_unwind(AssertUnwindSafe(|| {
-            drop(map.drain_filter(|_, _| match PREDS.fetch_add(1, Ordering::SeqCst) {
+            drop(map.extract_if(|_, _| match PREDS.fetch_add(1, Ordering::SeqCst) {
                 0 => true,
                 _ => panic!(),
             }))
@@ -1072,21 +1118,76 @@ fn drop(&mut self) {
         let mut map = (0..3).map(|i| (i, D)).collect::<HashMap<_, _>>();
 
         {
-            let mut it = map.drain_filter(|_, _| match PREDS.fetch_add(1, Ordering::SeqCst) {
+            let mut it = map.extract_if(|_, _| match PREDS.fetch_add(1, Ordering::SeqCst) {
                 0 => true,
                 _ => panic!(),
             });
             catch_unwind(AssertUnwindSafe(|| while it.next().is_some() {})).unwrap_err();
-            // Iterator behaviour after a panic is explicitly unspecified,
-            // so this is just the current implementation:
-            let result = catch_unwind(AssertUnwindSafe(|| it.next()));
-            assert!(result.is_err());
+            // Like every `extract_if`, the iterator doesn't call the predicate
+            // again once it panicked, and yields no more elements.
+            assert!(it.next().is_none());
         }
 
-        assert_eq!(PREDS.load(Ordering::SeqCst), 3);
+        assert_eq!(PREDS.load(Ordering::SeqCst), 2);
+        assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+        assert_eq!(map.len(), 2);
+    }
+
+    // Same as above, but drop the iterator right after the panic in the predicate
+    #[test]
+    fn pred_panic_drop_after_next() {
+        static PREDS: AtomicUsize = AtomicUsize::new(0);
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        struct D;
+        impl Drop for D {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let mut map = (0..3).map(|i| (i, D)).collect::<HashMap<_, _>>();
+
+        {
+            let mut it = map.extract_if(|_, _| match PREDS.fetch_add(1, Ordering::SeqCst) {
+                0 => true,
+                _ => panic!(),
+            });
+            catch_unwind(AssertUnwindSafe(|| while it.next().is_some() {})).unwrap_err();
+            // The predicate is not called again when the iterator is dropped.
+        }
+
+        assert_eq!(PREDS.load(Ordering::SeqCst), 2);
         assert_eq!(DROPS.load(Ordering::SeqCst), 1);
         assert_eq!(map.len(), 2);
     }
+
+    // The predicate itself is dropped, not leaked, after it panicked
+    #[test]
+    fn pred_panic_drops_pred() {
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        struct D;
+        impl Drop for D {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let mut map = (0..3).map(|i| (i, i)).collect::<HashMap<_, _>>();
+        let captured = D;
+
+        catch_unwind(AssertUnwindSafe(|| {
+            drop(map.extract_if(move |_, _| {
+                let _captured = &captured;
+                panic!()
+            }))
+        }))
+        .unwrap_err();
+
+        assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+        assert_eq!(map.len(), 3);
+    }
 }
 
 #[test]
@@ -1100,3 +1201,97 @@ fn from_array() {
     // that's a problem!
     let _must_not_require_type_annotation = HashMap::from([(1, 2)]);
 }
+
+#[test]
+fn test_in_allocator() {
+    let mut map = HashMap::new_in(System);
+    for i in 0..100 {
+        map.insert(i, i * 2);
+    }
+    assert_eq!(map[&50], 100);
+
+    let copy = map.clone_in(System);
+    assert_eq!(copy, map);
+    let copy = map.clone();
+    assert_eq!(copy, map);
+
+    *map.entry(1000).or_insert(0) += 1;
+    assert_eq!(map.remove(&1000), Some(1));
+
+    let odds: Vec<_> = map.extract_if(|k, _| k % 2 != 0).collect();
+    assert_eq!(odds.len(), 50);
+    assert_eq!(map.len(), 50);
+    assert_eq!(map.drain().count(), 50);
+
+    let map: HashMap<_, _, RandomState, _> = HashMap::from_iter_in((0..10).map(|i| (i, i)), System);
+    let mut keys: Vec<_> = map.into_keys().collect();
+    keys.sort_unstable();
+    assert_eq!(keys, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_get_many_mut() {
+    let mut map = HashMap::new();
+    map.insert("foo".to_owned(), 0);
+    map.insert("bar".to_owned(), 10);
+    map.insert("baz".to_owned(), 20);
+    map.insert("qux".to_owned(), 30);
+
+    let xs = map.get_many_mut(["foo", "qux"]);
+    assert_eq!(xs, Some([&mut 0, &mut 30]));
+
+    let xs = map.get_many_mut(["foo", "dud"]);
+    assert_eq!(xs, None);
+
+    let xs = map.get_many_mut(["foo", "foo"]);
+    assert_eq!(xs, None);
+
+    if let Some([foo, baz]) = map.get_many_mut(["foo", "baz"]) {
+        crate::mem::swap(foo, baz);
+    }
+    assert_eq!(map["foo"], 20);
+    assert_eq!(map["baz"], 0);
+}
+
+#[test]
+fn test_entry_ref() {
+    use super::EntryRef;
+
+    let mut map: HashMap<String, usize> = HashMap::new();
+
+    // Vacant entries only convert the key when a value is inserted.
+    match map.entry_ref("foo") {
+        EntryRef::Occupied(_) => unreachable!(),
+        EntryRef::Vacant(view) => {
+            assert_eq!(view.key(), "foo");
+        }
+    }
+    assert!(map.is_empty());
+
+    assert_eq!(*map.entry_ref("foo").or_insert(1), 1);
+    assert_eq!(*map.entry_ref("foo").or_insert(2), 1);
+    assert_eq!(*map.entry_ref("bar").or_insert_with_key(|k| k.len()), 3);
+    assert_eq!(*map.entry_ref("baz").and_modify(|v| *v += 1).or_default(), 0);
+    assert_eq!(*map.entry_ref("baz").and_modify(|v| *v += 1).or_default(), 1);
+    assert_eq!(map.len(), 3);
+
+    match map.entry_ref("foo") {
+        EntryRef::Vacant(_) => unreachable!(),
+        EntryRef::Occupied(mut view) => {
+            assert_eq!(view.key(), "foo");
+            assert_eq!(view.insert(100), 1);
+            *view.get_mut() += 1;
+            assert_eq!(view.remove_entry(), ("foo".to_owned(), 101));
+        }
+    }
+    assert_eq!(map.get("foo"), None);
+
+    // Inserting through the entry reuses its hash, so the map must still find
+    // every key afterwards, including across resizes.
+    for i in 0..1000 {
+        map.entry_ref(&*i.to_string()).or_insert(i);
+    }
+    for i in 0..1000 {
+        assert_eq!(map[&*i.to_string()], i);
+    }
+}
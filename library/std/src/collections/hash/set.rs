@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests;
 
-use hashbrown::hash_set as base;
+use hashbrown::hash_map as base;
 
 use crate::borrow::Borrow;
 use crate::collections::TryReserveError;
@@ -110,7 +110,7 @@
 #[cfg_attr(not(test), rustc_diagnostic_item = "HashSet")]
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct HashSet<T, S = RandomState> {
-    base: base::HashSet<T, S>,
+    base: base::HashMap<T, (), S>,
 }
 
 impl<T> HashSet<T, RandomState> {
@@ -148,7 +148,7 @@ pub fn new() -> HashSet<T, RandomState> {
     #[must_use]
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn with_capacity(capacity: usize) -> HashSet<T, RandomState> {
-        HashSet { base: base::HashSet::with_capacity_and_hasher(capacity, Default::default()) }
+        HashSet { base: base::HashMap::with_capacity_and_hasher(capacity, Default::default()) }
     }
 }
 
@@ -187,7 +187,7 @@ pub fn capacity(&self) -> usize {
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn iter(&self) -> Iter<'_, T> {
-        Iter { base: self.base.iter() }
+        Iter { base: self.base.keys() }
     }
 
     /// Returns the number of elements in the set.
@@ -258,22 +258,24 @@ pub fn drain(&mut self) -> Drain<'_, T> {
     /// If the iterator is only partially consumed or not consumed at all, each of the remaining
     /// values will still be subjected to the closure and removed and dropped if it returns true.
     ///
-    /// It is unspecified how many more values will be subjected to the closure
-    /// if a panic occurs in the closure, or if a panic occurs while dropping a value, or if the
-    /// `DrainFilter` itself is leaked.
+    /// If the closure panics, the value it was called on and all values that have not been visited
+    /// yet remain in the set, and the closure is not called again, not even if the panic is caught
+    /// and the iterator is used afterwards: it then yields no more values. It is unspecified how
+    /// many more values will be subjected to the closure if a panic occurs while dropping a value,
+    /// or if the `ExtractIf` itself is leaked.
     ///
     /// # Examples
     ///
     /// Splitting a set into even and odd values, reusing the original set:
     ///
     /// ```
-    /// #![feature(hash_drain_filter)]
+    /// #![feature(hash_extract_if)]
     /// use std::collections::HashSet;
     ///
     /// let mut set: HashSet<i32> = (0..8).collect();
-    /// let drained: HashSet<i32> = set.drain_filter(|v| v % 2 == 0).collect();
+    /// let extracted: HashSet<i32> = set.extract_if(|v| v % 2 == 0).collect();
     ///
-    /// let mut evens = drained.into_iter().collect::<Vec<_>>();
+    /// let mut evens = extracted.into_iter().collect::<Vec<_>>();
     /// let mut odds = set.into_iter().collect::<Vec<_>>();
     /// evens.sort();
     /// odds.sort();
@@ -282,12 +284,12 @@ pub fn drain(&mut self) -> Drain<'_, T> {
     /// assert_eq!(odds, vec![1, 3, 5, 7]);
     /// ```
     #[inline]
-    #[unstable(feature = "hash_drain_filter", issue = "59618")]
-    pub fn drain_filter<F>(&mut self, pred: F) -> DrainFilter<'_, T, F>
+    #[unstable(feature = "hash_extract_if", issue = "59618")]
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, T, F>
     where
         F: FnMut(&T) -> bool,
     {
-        DrainFilter { base: self.base.drain_filter(pred) }
+        ExtractIf { base: self.base.drain_filter(ExtractIfPred { pred, panicked: false }) }
     }
 
     /// Clears the set, removing all values.
@@ -333,8 +335,9 @@ pub fn clear(&mut self) {
     /// ```
     #[inline]
     #[stable(feature = "hashmap_build_hasher", since = "1.7.0")]
-    pub fn with_hasher(hasher: S) -> HashSet<T, S> {
-        HashSet { base: base::HashSet::with_hasher(hasher) }
+    #[rustc_const_unstable(feature = "const_collections_with_hasher", issue = "none")]
+    pub const fn with_hasher(hasher: S) -> HashSet<T, S> {
+        HashSet { base: base::HashMap::with_hasher(hasher) }
     }
 
     /// Creates an empty `HashSet` with the specified capacity, using
@@ -364,7 +367,7 @@ pub fn with_hasher(hasher: S) -> HashSet<T, S> {
     #[inline]
     #[stable(feature = "hashmap_build_hasher", since = "1.7.0")]
     pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> HashSet<T, S> {
-        HashSet { base: base::HashSet::with_capacity_and_hasher(capacity, hasher) }
+        HashSet { base: base::HashMap::with_capacity_and_hasher(capacity, hasher) }
     }
 
     /// Returns a reference to the set's [`BuildHasher`].
@@ -619,7 +622,7 @@ pub fn contains<Q: ?Sized>(&self, value: &Q) -> bool
         T: Borrow<Q>,
         Q: Hash + Eq,
     {
-        self.base.contains(value)
+        self.base.contains_key(value)
     }
 
     /// Returns a reference to the value in the set, if any, that is equal to the given value.
@@ -644,7 +647,7 @@ pub fn get<Q: ?Sized>(&self, value: &Q) -> Option<&T>
         T: Borrow<Q>,
         Q: Hash + Eq,
     {
-        self.base.get(value)
+        self.base.get_key_value(value).map(|(k, _)| k)
     }
 
     /// Inserts the given `value` into the set if it is not present, then
@@ -668,7 +671,10 @@ pub fn get<Q: ?Sized>(&self, value: &Q) -> Option<&T>
     pub fn get_or_insert(&mut self, value: T) -> &T {
         // Although the raw entry gives us `&mut T`, we only return `&T` to be consistent with
         // `get`. Key mutation is "raw" because you're not supposed to affect `Eq` or `Hash`.
-        self.base.get_or_insert(value)
+        match self.base.raw_entry_mut().from_key(&value) {
+            base::RawEntryMut::Occupied(entry) => entry.into_key(),
+            base::RawEntryMut::Vacant(entry) => entry.insert(value, ()).0,
+        }
     }
 
     /// Inserts an owned copy of the given `value` into the set if it is not
@@ -700,7 +706,10 @@ pub fn get_or_insert_owned<Q: ?Sized>(&mut self, value: &Q) -> &T
     {
         // Although the raw entry gives us `&mut T`, we only return `&T` to be consistent with
         // `get`. Key mutation is "raw" because you're not supposed to affect `Eq` or `Hash`.
-        self.base.get_or_insert_owned(value)
+        match self.base.raw_entry_mut().from_key(value) {
+            base::RawEntryMut::Occupied(entry) => entry.into_key(),
+            base::RawEntryMut::Vacant(entry) => entry.insert(value.to_owned(), ()).0,
+        }
     }
 
     /// Inserts a value computed from `f` into the set if the given `value` is
@@ -733,7 +742,147 @@ pub fn get_or_insert_with<Q: ?Sized, F>(&mut self, value: &Q, f: F) -> &T
     {
         // Although the raw entry gives us `&mut T`, we only return `&T` to be consistent with
         // `get`. Key mutation is "raw" because you're not supposed to affect `Eq` or `Hash`.
-        self.base.get_or_insert_with(value, f)
+        match self.base.raw_entry_mut().from_key(value) {
+            base::RawEntryMut::Occupied(entry) => entry.into_key(),
+            base::RawEntryMut::Vacant(entry) => entry.insert(f(value), ()).0,
+        }
+    }
+
+    /// Returns `true` if the set contains a value, using a hash of `value`
+    /// that was computed beforehand.
+    ///
+    /// This lets a hash be computed once, with [`BuildHasher::hash_one`] on
+    /// the set's [`hasher`], and reused across several lookups. `hash` must
+    /// be the hash of `value` under that hasher; otherwise the result is
+    /// unspecified, but memory safe.
+    ///
+    /// [`hasher`]: HashSet::hasher
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(build_hasher_simple_hash_one)]
+    /// #![feature(hash_raw_entry)]
+    ///
+    /// use std::collections::HashSet;
+    /// use std::hash::BuildHasher;
+    ///
+    /// let set: HashSet<_> = ["a", "b"].into_iter().collect();
+    /// let hash = set.hasher().hash_one("a");
+    /// assert!(set.contains_hashed_nocheck(hash, "a"));
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_raw_entry", issue = "56167")]
+    pub fn contains_hashed_nocheck<Q: ?Sized>(&self, hash: u64, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Eq,
+    {
+        self.get_hashed_nocheck(hash, value).is_some()
+    }
+
+    /// Returns a reference to the value in the set, if any, that is equal to
+    /// the given value, using a hash of `value` that was computed beforehand.
+    ///
+    /// See [`contains_hashed_nocheck`] for the requirements on `hash`.
+    ///
+    /// [`contains_hashed_nocheck`]: HashSet::contains_hashed_nocheck
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(build_hasher_simple_hash_one)]
+    /// #![feature(hash_raw_entry)]
+    ///
+    /// use std::collections::HashSet;
+    /// use std::hash::BuildHasher;
+    ///
+    /// let set: HashSet<String> = ["a".to_owned()].into_iter().collect();
+    /// let hash = set.hasher().hash_one("a");
+    /// assert_eq!(set.get_hashed_nocheck(hash, "a"), Some(&"a".to_owned()));
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_raw_entry", issue = "56167")]
+    pub fn get_hashed_nocheck<Q: ?Sized>(&self, hash: u64, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Eq,
+    {
+        self.base.raw_entry().from_key_hashed_nocheck(hash, value).map(|(k, _)| k)
+    }
+
+    /// Inserts the given `value` into the set if it is not present, then
+    /// returns a reference to the value in the set, using a hash of `value`
+    /// that was computed beforehand.
+    ///
+    /// See [`contains_hashed_nocheck`] for the requirements on `hash`.
+    ///
+    /// [`contains_hashed_nocheck`]: HashSet::contains_hashed_nocheck
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(build_hasher_simple_hash_one)]
+    /// #![feature(hash_raw_entry)]
+    ///
+    /// use std::collections::HashSet;
+    /// use std::hash::BuildHasher;
+    ///
+    /// let mut set = HashSet::new();
+    /// let hash = set.hasher().hash_one(7);
+    /// assert_eq!(set.get_or_insert_hashed_nocheck(hash, 7), &7);
+    /// assert!(set.contains_hashed_nocheck(hash, &7));
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_raw_entry", issue = "56167")]
+    pub fn get_or_insert_hashed_nocheck(&mut self, hash: u64, value: T) -> &T {
+        match self.base.raw_entry_mut().from_key_hashed_nocheck(hash, &value) {
+            base::RawEntryMut::Occupied(entry) => entry.into_key(),
+            base::RawEntryMut::Vacant(entry) => entry.insert_hashed_nocheck(hash, value, ()).0,
+        }
+    }
+
+    /// Inserts a value computed from `f` into the set if the given `value` is
+    /// not present, then returns a reference to the value in the set, using a
+    /// hash of `value` that was computed beforehand.
+    ///
+    /// See [`contains_hashed_nocheck`] for the requirements on `hash`, which
+    /// must also be the hash of the value returned by `f`.
+    ///
+    /// [`contains_hashed_nocheck`]: HashSet::contains_hashed_nocheck
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(build_hasher_simple_hash_one)]
+    /// #![feature(hash_raw_entry)]
+    ///
+    /// use std::collections::HashSet;
+    /// use std::hash::BuildHasher;
+    ///
+    /// let mut interner: HashSet<Box<str>> = HashSet::new();
+    /// let hash = interner.hasher().hash_one("fish");
+    /// let fish = interner.get_or_insert_with_hashed_nocheck(hash, "fish", |s| Box::from(s));
+    /// assert_eq!(&**fish, "fish");
+    /// assert_eq!(interner.len(), 1);
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_raw_entry", issue = "56167")]
+    pub fn get_or_insert_with_hashed_nocheck<Q: ?Sized, F>(
+        &mut self,
+        hash: u64,
+        value: &Q,
+        f: F,
+    ) -> &T
+    where
+        T: Borrow<Q>,
+        Q: Eq,
+        F: FnOnce(&Q) -> T,
+    {
+        match self.base.raw_entry_mut().from_key_hashed_nocheck(hash, value) {
+            base::RawEntryMut::Occupied(entry) => entry.into_key(),
+            base::RawEntryMut::Vacant(entry) => entry.insert_hashed_nocheck(hash, f(value), ()).0,
+        }
     }
 
     /// Returns `true` if `self` has no elements in common with `other`.
@@ -830,7 +979,7 @@ pub fn is_superset(&self, other: &HashSet<T, S>) -> bool {
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn insert(&mut self, value: T) -> bool {
-        self.base.insert(value)
+        self.base.insert(value, ()).is_none()
     }
 
     /// Adds a value to the set, replacing the existing value, if any, that is equal to the given
@@ -851,7 +1000,13 @@ pub fn insert(&mut self, value: T) -> bool {
     #[inline]
     #[stable(feature = "set_recovery", since = "1.9.0")]
     pub fn replace(&mut self, value: T) -> Option<T> {
-        self.base.replace(value)
+        match self.base.entry(value) {
+            base::Entry::Occupied(entry) => Some(entry.replace_key()),
+            base::Entry::Vacant(entry) => {
+                entry.insert(());
+                None
+            }
+        }
     }
 
     /// Removes a value from the set. Returns whether the value was
@@ -879,7 +1034,7 @@ pub fn remove<Q: ?Sized>(&mut self, value: &Q) -> bool
         T: Borrow<Q>,
         Q: Hash + Eq,
     {
-        self.base.remove(value)
+        self.base.remove(value).is_some()
     }
 
     /// Removes and returns the value in the set, if any, that is equal to the given one.
@@ -904,7 +1059,7 @@ pub fn take<Q: ?Sized>(&mut self, value: &Q) -> Option<T>
         T: Borrow<Q>,
         Q: Hash + Eq,
     {
-        self.base.take(value)
+        self.base.remove_entry(value).map(|(k, _)| k)
     }
 
     /// Retains only the elements specified by the predicate.
@@ -923,11 +1078,11 @@ pub fn take<Q: ?Sized>(&mut self, value: &Q) -> Option<T>
     /// assert_eq!(set.len(), 3);
     /// ```
     #[stable(feature = "retain_hash_collection", since = "1.18.0")]
-    pub fn retain<F>(&mut self, f: F)
+    pub fn retain<F>(&mut self, mut f: F)
     where
         F: FnMut(&T) -> bool,
     {
-        self.base.retain(f)
+        self.base.retain(|k, _| f(k))
     }
 }
 
@@ -1034,17 +1189,17 @@ impl<T, S> Extend<T> for HashSet<T, S>
 {
     #[inline]
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        self.base.extend(iter);
+        self.base.extend(iter.into_iter().map(|k| (k, ())));
     }
 
     #[inline]
     fn extend_one(&mut self, item: T) {
-        self.base.insert(item);
+        self.base.insert(item, ());
     }
 
     #[inline]
     fn extend_reserve(&mut self, additional: usize) {
-        self.base.extend_reserve(additional);
+        Extend::<(T, ())>::extend_reserve(&mut self.base, additional);
     }
 }
 
@@ -1061,7 +1216,7 @@ fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
 
     #[inline]
     fn extend_one(&mut self, &item: &'a T) {
-        self.base.insert(item);
+        self.base.insert(item, ());
     }
 
     #[inline]
@@ -1232,7 +1387,7 @@ fn sub(self, rhs: &HashSet<T, S>) -> HashSet<T, S> {
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct Iter<'a, K: 'a> {
-    base: base::Iter<'a, K>,
+    base: base::Keys<'a, K, ()>,
 }
 
 /// An owning iterator over the items of a `HashSet`.
@@ -1254,7 +1409,7 @@ pub struct Iter<'a, K: 'a> {
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct IntoIter<K> {
-    base: base::IntoIter<K>,
+    base: base::IntoKeys<K, ()>,
 }
 
 /// A draining iterator over the items of a `HashSet`.
@@ -1275,32 +1430,69 @@ pub struct IntoIter<K> {
 /// ```
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct Drain<'a, K: 'a> {
-    base: base::Drain<'a, K>,
+    base: base::Drain<'a, K, ()>,
 }
 
 /// A draining, filtering iterator over the items of a `HashSet`.
 ///
-/// This `struct` is created by the [`drain_filter`] method on [`HashSet`].
+/// This `struct` is created by the [`extract_if`] method on [`HashSet`].
 ///
-/// [`drain_filter`]: HashSet::drain_filter
+/// [`extract_if`]: HashSet::extract_if
 ///
 /// # Examples
 ///
 /// ```
-/// #![feature(hash_drain_filter)]
+/// #![feature(hash_extract_if)]
 ///
 /// use std::collections::HashSet;
 ///
 /// let mut a: HashSet<u32> = vec![1, 2, 3].into_iter().collect();
 ///
-/// let mut drain_filtered = a.drain_filter(|v| v % 2 == 0);
+/// let mut extracted = a.extract_if(|v| v % 2 == 0);
 /// ```
-#[unstable(feature = "hash_drain_filter", issue = "59618")]
-pub struct DrainFilter<'a, K, F>
+#[unstable(feature = "hash_extract_if", issue = "59618")]
+pub struct ExtractIf<'a, K, F>
+where
+    F: FnMut(&K) -> bool,
+{
+    base: base::DrainFilter<'a, K, (), ExtractIfPred<F>>,
+}
+
+/// The predicate of an [`ExtractIf`], which is not called again once it
+/// panicked.
+///
+/// The underlying iterator goes on visiting the remaining values when it is
+/// dropped, which also happens while unwinding from a panic in the predicate.
+/// Those values are then kept in the set.
+struct ExtractIfPred<F> {
+    pred: F,
+    panicked: bool,
+}
+
+impl<'k, 'v, K, F> FnOnce<(&'k K, &'v mut ())> for ExtractIfPred<F>
+where
+    F: FnMut(&K) -> bool,
+{
+    type Output = bool;
+
+    extern "rust-call" fn call_once(mut self, args: (&'k K, &'v mut ())) -> bool {
+        self.call_mut(args)
+    }
+}
+
+impl<'k, 'v, K, F> FnMut<(&'k K, &'v mut ())> for ExtractIfPred<F>
 where
     F: FnMut(&K) -> bool,
 {
-    base: base::DrainFilter<'a, K, F>,
+    extern "rust-call" fn call_mut(&mut self, (k, _): (&'k K, &'v mut ())) -> bool {
+        if self.panicked {
+            return false;
+        }
+        self.panicked = true;
+        let extract = (self.pred)(k);
+        self.panicked = false;
+        extract
+    }
 }
 
 /// A lazy iterator producing elements in the intersection of `HashSet`s.
@@ -1443,7 +1635,7 @@ impl<T, S> IntoIterator for HashSet<T, S> {
     /// ```
     #[inline]
     fn into_iter(self) -> IntoIter<T> {
-        IntoIter { base: self.base.into_iter() }
+        IntoIter { base: self.base.into_keys() }
     }
 }
 
@@ -1520,7 +1712,7 @@ impl<'a, K> Iterator for Drain<'a, K> {
 
     #[inline]
     fn next(&mut self) -> Option<K> {
-        self.base.next()
+        self.base.next().map(|(k, _)| k)
     }
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -1540,12 +1732,12 @@ impl<K> FusedIterator for Drain<'_, K> {}
 #[stable(feature = "std_debug", since = "1.16.0")]
 impl<K: fmt::Debug> fmt::Debug for Drain<'_, K> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Debug::fmt(&self.base, f)
+        f.debug_list().entries(self.base.rustc_iter().map(|(k, _)| k)).finish()
     }
 }
 
-#[unstable(feature = "hash_drain_filter", issue = "59618")]
-impl<K, F> Iterator for DrainFilter<'_, K, F>
+#[unstable(feature = "hash_extract_if", issue = "59618")]
+impl<K, F> Iterator for ExtractIf<'_, K, F>
 where
     F: FnMut(&K) -> bool,
 {
@@ -1553,7 +1745,7 @@ impl<K, F> Iterator for DrainFilter<'_, K, F>
 
     #[inline]
     fn next(&mut self) -> Option<K> {
-        self.base.next()
+        self.base.next().map(|(k, _)| k)
     }
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -1561,16 +1753,16 @@ fn size_hint(&self) -> (usize, Option<usize>) {
     }
 }
 
-#[unstable(feature = "hash_drain_filter", issue = "59618")]
-impl<K, F> FusedIterator for DrainFilter<'_, K, F> where F: FnMut(&K) -> bool {}
+#[unstable(feature = "hash_extract_if", issue = "59618")]
+impl<K, F> FusedIterator for ExtractIf<'_, K, F> where F: FnMut(&K) -> bool {}
 
-#[unstable(feature = "hash_drain_filter", issue = "59618")]
-impl<'a, K, F> fmt::Debug for DrainFilter<'a, K, F>
+#[unstable(feature = "hash_extract_if", issue = "59618")]
+impl<'a, K, F> fmt::Debug for ExtractIf<'a, K, F>
 where
     F: FnMut(&K) -> bool,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("DrainFilter").finish_non_exhaustive()
+        f.debug_struct("ExtractIf").finish_non_exhaustive()
     }
 }
 
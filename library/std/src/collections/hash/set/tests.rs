@@ -378,6 +378,38 @@ fn hash<H: hash::Hasher>(&self, h: &mut H) {
     assert_eq!(it.next(), None);
 }
 
+#[test]
+fn test_hashed_nocheck() {
+    let mut s: HashSet<String> = HashSet::new();
+
+    let compute_hash = |s: &HashSet<String>, v: &str| -> u64 {
+        use core::hash::{BuildHasher, Hash, Hasher};
+
+        let mut hasher = s.hasher().build_hasher();
+        v.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    let hash_a = compute_hash(&s, "a");
+    assert!(!s.contains_hashed_nocheck(hash_a, "a"));
+    assert_eq!(s.get_hashed_nocheck(hash_a, "a"), None);
+
+    assert_eq!(s.get_or_insert_hashed_nocheck(hash_a, "a".to_owned()), "a");
+    assert_eq!(s.len(), 1);
+    assert!(s.contains_hashed_nocheck(hash_a, "a"));
+    assert_eq!(s.get_hashed_nocheck(hash_a, "a").map(String::as_str), Some("a"));
+
+    // An existing value is returned without calling `f`.
+    let a = s.get_or_insert_with_hashed_nocheck(hash_a, "a", |_| unreachable!());
+    assert_eq!(a, "a");
+    assert_eq!(s.len(), 1);
+
+    let hash_b = compute_hash(&s, "b");
+    assert_eq!(s.get_or_insert_with_hashed_nocheck(hash_b, "b", str::to_owned), "b");
+    assert_eq!(s.len(), 2);
+    assert!(s.contains("b"));
+}
+
 #[test]
 fn test_extend_ref() {
     let mut a = HashSet::new();
@@ -418,18 +450,18 @@ fn test_retain() {
 }
 
 #[test]
-fn test_drain_filter() {
+fn test_extract_if() {
     let mut x: HashSet<_> = [1].iter().copied().collect();
     let mut y: HashSet<_> = [1].iter().copied().collect();
 
-    x.drain_filter(|_| true);
-    y.drain_filter(|_| false);
+    x.extract_if(|_| true);
+    y.extract_if(|_| false);
     assert_eq!(x.len(), 0);
     assert_eq!(y.len(), 1);
 }
 
 #[test]
-fn test_drain_filter_drop_panic_leak() {
+fn test_extract_if_drop_panic_leak() {
     static PREDS: AtomicU32 = AtomicU32::new(0);
     static DROPS: AtomicU32 = AtomicU32::new(0);
 
@@ -446,7 +478,7 @@ fn drop(&mut self) {
     let mut set = (0..3).map(|i| D(i)).collect::<HashSet<_>>();
 
     catch_unwind(move || {
-        drop(set.drain_filter(|_| {
+        drop(set.extract_if(|_| {
             PREDS.fetch_add(1, Ordering::SeqCst);
             true
         }))
@@ -458,7 +490,7 @@ fn drop(&mut self) {
 }
 
 #[test]
-fn test_drain_filter_pred_panic_leak() {
+fn test_extract_if_pred_panic_leak() {
     static PREDS: AtomicU32 = AtomicU32::new(0);
     static DROPS: AtomicU32 = AtomicU32::new(0);
 
@@ -473,7 +505,7 @@ fn drop(&mut self) {
     let mut set: HashSet<_> = (0..3).map(|_| D).collect();
 
     catch_unwind(AssertUnwindSafe(|| {
-        drop(set.drain_filter(|_| match PREDS.fetch_add(1, Ordering::SeqCst) {
+        drop(set.extract_if(|_| match PREDS.fetch_add(1, Ordering::SeqCst) {
             0 => true,
             _ => panic!(),
         }))
@@ -485,6 +517,32 @@ fn drop(&mut self) {
     assert_eq!(set.len(), 0);
 }
 
+#[test]
+fn test_extract_if_pred_panic_drops_pred() {
+    static DROPS: AtomicU32 = AtomicU32::new(0);
+
+    struct D;
+    impl Drop for D {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let mut set: HashSet<_> = (0..3).collect();
+    let captured = D;
+
+    catch_unwind(AssertUnwindSafe(|| {
+        drop(set.extract_if(move |_| {
+            let _captured = &captured;
+            panic!()
+        }))
+    }))
+    .unwrap_err();
+
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+    assert_eq!(set.len(), 3);
+}
+
 #[test]
 fn from_array() {
     let set = HashSet::from([1, 2, 3, 4]);
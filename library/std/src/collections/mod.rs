@@ -428,6 +428,8 @@
     issue = "48043"
 )]
 pub use alloc_crate::collections::TryReserveErrorKind;
+#[unstable(feature = "try_alloc", reason = "new API", issue = "none")]
+pub use alloc_crate::collections::{TryCollect, TryFromIterator};
 
 mod hash;
 
@@ -1,4 +1,5 @@
 use super::Error;
+use crate::any::Demand;
 use crate::fmt;
 
 #[derive(Debug, PartialEq)]
@@ -35,3 +36,43 @@ fn downcasting() {
         Err(e) => assert_eq!(*e.downcast::<A>().unwrap(), A),
     }
 }
+
+#[derive(Debug)]
+struct C {
+    code: u16,
+    source: A,
+}
+
+impl fmt::Display for C {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "C")
+    }
+}
+
+impl Error for C {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+
+    fn provide<'a>(&'a self, req: &mut Demand<'a>) {
+        req.provide_value::<u16>(|| self.code).provide_ref::<A>(&self.source);
+    }
+}
+
+#[test]
+fn member_access() {
+    let c = C { code: 404, source: A };
+    let e = &c as &dyn Error;
+    assert_eq!(e.request_value::<u16>(), Some(404));
+    assert_eq!(e.request_ref::<A>(), Some(&A));
+    assert_eq!(e.request_ref::<B>(), None);
+    assert_eq!(e.request_value::<u32>(), None);
+
+    // Requests are forwarded through references and boxes.
+    let boxed: Box<dyn Error + Send + Sync> = Box::new(C { code: 404, source: A });
+    assert_eq!(boxed.request_value::<u16>(), Some(404));
+    assert_eq!((&&c as &dyn Error).request_ref::<A>(), Some(&A));
+
+    // Errors that don't provide anything return `None`.
+    assert_eq!((&A as &dyn Error).request_value::<u16>(), None);
+}
@@ -13,7 +13,7 @@
 
 use crate::ffi::OsString;
 use crate::fmt;
-use crate::io::{self, Initializer, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write};
+use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write};
 use crate::path::{Path, PathBuf};
 use crate::sys::fs as fs_imp;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
@@ -180,6 +180,14 @@ pub struct File {
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct Permissions(fs_imp::FilePermissions);
 
+/// Representation of the various timestamps on a file.
+///
+/// Used with [`File::set_times`]; timestamps which are not set are left
+/// unchanged.
+#[derive(Copy, Clone, Debug, Default)]
+#[unstable(feature = "file_set_times", issue = "none")]
+pub struct FileTimes(fs_imp::FileTimes);
+
 /// A structure representing a type of file with accessors for each file type.
 /// It is returned by [`Metadata::file_type`] method.
 #[stable(feature = "file_type", since = "1.1.0")]
@@ -574,6 +582,283 @@ pub fn try_clone(&self) -> io::Result<File> {
     pub fn set_permissions(&self, perm: Permissions) -> io::Result<()> {
         self.inner.set_permissions(perm.0)
     }
+
+    /// Acquires an exclusive advisory lock on the file, blocking until the
+    /// lock can be acquired.
+    ///
+    /// While the exclusive lock is held, no other file handle can acquire a
+    /// shared or exclusive lock on the same file. Locks are advisory: they
+    /// only interact with [`lock`], [`lock_shared`], [`try_lock`],
+    /// [`try_lock_shared`] and [`unlock`], and whether they block reads or
+    /// writes through other handles is platform specific.
+    ///
+    /// The lock belongs to the open file description, not to this `File`
+    /// value. A handle obtained from [`try_clone`], or a descriptor inherited
+    /// by a child process across `fork`, refers to the same lock: unlocking
+    /// through any of them releases it for all, and the lock is only released
+    /// implicitly once every one of them has been closed. Opening the same
+    /// path again with [`File::open`] creates an independent handle that
+    /// contends for the lock like any other process would.
+    ///
+    /// If this handle, or a clone of it, already holds a lock, the behavior
+    /// is platform specific: the lock may be converted, or the call may
+    /// deadlock.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_EX` flag, and the `LockFileEx` function on Windows with
+    /// the `LOCKFILE_EXCLUSIVE_LOCK` flag. On Windows, locks are mandatory
+    /// for reads and writes through other handles, and a file opened only for
+    /// appending cannot be locked.
+    ///
+    /// Solaris and illumos have no `flock`, so a POSIX record lock on the
+    /// whole file is taken with `fcntl` and `F_SETLKW` instead. Such a lock
+    /// belongs to the process rather than to the open file description: it is
+    /// not inherited across `fork`, and closing any handle to the file
+    /// releases it. An exclusive lock also requires the file to be open for
+    /// writing, and a shared lock requires it to be open for reading.
+    ///
+    /// File locking is not supported on Emscripten, ESP-IDF, Haiku,
+    /// HermitCore, L4Re, Redox, SOLID, VxWorks, WASI or platforms without a
+    /// filesystem, where an error of kind [`Unsupported`] is returned. Note
+    /// that, this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`lock`]: File::lock
+    /// [`lock_shared`]: File::lock_shared
+    /// [`try_lock`]: File::try_lock
+    /// [`try_lock_shared`]: File::try_lock_shared
+    /// [`unlock`]: File::unlock
+    /// [`try_clone`]: File::try_clone
+    /// [`Unsupported`]: io::ErrorKind::Unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("foo.txt")?;
+    ///     f.lock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn lock(&self) -> io::Result<()> {
+        self.inner.lock()
+    }
+
+    /// Acquires a shared advisory lock on the file, blocking until the lock
+    /// can be acquired.
+    ///
+    /// Any number of handles may hold a shared lock on the same file at the
+    /// same time, but not while another handle holds an exclusive lock. See
+    /// [`lock`] for how locks are shared between cloned and inherited
+    /// handles.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_SH` flag, and the `LockFileEx` function on Windows. See
+    /// [`lock`] for Solaris, illumos and platforms where locking is not
+    /// supported. Note that, this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`lock`]: File::lock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("foo.txt")?;
+    ///     f.lock_shared()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.inner.lock_shared()
+    }
+
+    /// Tries to acquire an exclusive advisory lock on the file without
+    /// blocking.
+    ///
+    /// Returns `Ok(true)` if the lock was acquired and `Ok(false)` if another
+    /// handle currently holds a lock on the file. See [`lock`] for the
+    /// semantics of the lock itself.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_EX` and `LOCK_NB` flags, and the `LockFileEx` function
+    /// on Windows with the `LOCKFILE_EXCLUSIVE_LOCK` and
+    /// `LOCKFILE_FAIL_IMMEDIATELY` flags. See [`lock`] for Solaris, illumos
+    /// and platforms where locking is not supported. Note that, this
+    /// [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`lock`]: File::lock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::create("foo.txt")?;
+    ///     if !f.try_lock()? {
+    ///         println!("foo.txt is in use by another process");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.inner.try_lock()
+    }
+
+    /// Tries to acquire a shared advisory lock on the file without blocking.
+    ///
+    /// Returns `Ok(true)` if the lock was acquired and `Ok(false)` if another
+    /// handle currently holds an exclusive lock on the file. See [`lock`] for
+    /// the semantics of the lock itself.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_SH` and `LOCK_NB` flags, and the `LockFileEx` function
+    /// on Windows with the `LOCKFILE_FAIL_IMMEDIATELY` flag. See [`lock`] for
+    /// Solaris, illumos and platforms where locking is not supported. Note
+    /// that, this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`lock`]: File::lock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("foo.txt")?;
+    ///     if !f.try_lock_shared()? {
+    ///         println!("foo.txt is being written to by another process");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.inner.try_lock_shared()
+    }
+
+    /// Releases the lock held on the file, if any.
+    ///
+    /// Since the lock belongs to the open file description, this also
+    /// releases a lock acquired through a clone of this handle. Unlocking a
+    /// file that is not locked is not an error.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `flock` function on Unix
+    /// with the `LOCK_UN` flag, and the `UnlockFile` function on Windows. See
+    /// [`lock`] for Solaris, illumos and platforms where locking is not
+    /// supported. Note that, this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    /// [`lock`]: File::lock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let f = File::open("foo.txt")?;
+    ///     f.lock()?;
+    ///     f.unlock()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "none")]
+    pub fn unlock(&self) -> io::Result<()> {
+        self.inner.unlock()
+    }
+
+    /// Changes the timestamps of the underlying file.
+    ///
+    /// Timestamps which are not set in `times` are left unchanged.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This function currently corresponds to the `futimens` function on Unix
+    /// (falling back to `futimes` on macOS before 10.13) and the
+    /// `SetFileTime` function on Windows. Note that, this
+    /// [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the user lacks permission to
+    /// change timestamps on the underlying file, or if a timestamp cannot be
+    /// represented on the platform. It may also return an error in other
+    /// os-specific unspecified cases.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_set_times)]
+    /// use std::fs::{self, FileTimes, OpenOptions};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let src = fs::metadata("src")?;
+    ///     let dest = OpenOptions::new().write(true).open("dest")?;
+    ///     let times = FileTimes::new()
+    ///         .set_accessed(src.accessed()?)
+    ///         .set_modified(src.modified()?);
+    ///     dest.set_times(times)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Note that this method alters the timestamps of the underlying file,
+    /// even though it takes `&self` rather than `&mut self`.
+    #[unstable(feature = "file_set_times", issue = "none")]
+    pub fn set_times(&self, times: FileTimes) -> io::Result<()> {
+        self.inner.set_times(times.0)
+    }
+
+    /// Changes the modification time of the underlying file.
+    ///
+    /// This is an alias for `set_times(FileTimes::new().set_modified(time))`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_set_times)]
+    /// use std::fs::OpenOptions;
+    /// use std::time::SystemTime;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let file = OpenOptions::new().write(true).open("foo.txt")?;
+    ///     file.set_modified(SystemTime::now())?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "file_set_times", issue = "none")]
+    #[inline]
+    pub fn set_modified(&self, time: SystemTime) -> io::Result<()> {
+        self.set_times(FileTimes::new().set_modified(time))
+    }
 }
 
 // In addition to the `impl`s here, `File` also has `impl`s for
@@ -629,10 +914,8 @@ fn is_read_vectored(&self) -> bool {
         self.inner.is_read_vectored()
     }
 
-    #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        // SAFETY: Read is guaranteed to work on uninitialized memory
-        unsafe { Initializer::nop() }
+    fn read_buf(&mut self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
+        self.inner.read_buf(cursor)
     }
 
     // Reserves space in the buffer based on the file size when available.
@@ -687,10 +970,8 @@ fn is_read_vectored(&self) -> bool {
         self.inner.is_read_vectored()
     }
 
-    #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        // SAFETY: Read is guaranteed to work on uninitialized memory
-        unsafe { Initializer::nop() }
+    fn read_buf(&mut self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
+        self.inner.read_buf(cursor)
     }
 
     // Reserves space in the buffer based on the file size when available.
@@ -1234,6 +1515,39 @@ fn from_inner(attr: fs_imp::FileAttr) -> Metadata {
     }
 }
 
+impl FileTimes {
+    /// Creates a new `FileTimes` with no times set.
+    ///
+    /// Using the resulting `FileTimes` in [`File::set_times`] will not modify
+    /// any timestamps.
+    #[unstable(feature = "file_set_times", issue = "none")]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the last access time of a file.
+    #[unstable(feature = "file_set_times", issue = "none")]
+    #[must_use]
+    pub fn set_accessed(mut self, t: SystemTime) -> Self {
+        self.0.set_accessed(t.into_inner());
+        self
+    }
+
+    /// Sets the last modified time of a file.
+    #[unstable(feature = "file_set_times", issue = "none")]
+    #[must_use]
+    pub fn set_modified(mut self, t: SystemTime) -> Self {
+        self.0.set_modified(t.into_inner());
+        self
+    }
+}
+
+impl AsInnerMut<fs_imp::FileTimes> for FileTimes {
+    fn as_inner_mut(&mut self) -> &mut fs_imp::FileTimes {
+        &mut self.0
+    }
+}
+
 impl Permissions {
     /// Returns `true` if these permissions describe a readonly (unwritable) file.
     ///
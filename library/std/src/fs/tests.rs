@@ -1,7 +1,8 @@
 use crate::io::prelude::*;
 
 use crate::fs::{self, File, OpenOptions};
-use crate::io::{ErrorKind, SeekFrom};
+use crate::io::{BorrowedBuf, ErrorKind, SeekFrom};
+use crate::mem::MaybeUninit;
 use crate::path::Path;
 use crate::str;
 use crate::sys_common::io::test::{tmpdir, TempDir};
@@ -267,6 +268,27 @@ fn file_test_io_eof() {
     check!(fs::remove_file(&filename));
 }
 
+#[test]
+fn file_test_io_read_buf() {
+    let tmpdir = tmpdir();
+    let filename = tmpdir.join("file_rt_io_file_test_read_buf.txt");
+    check!(fs::write(&filename, &[1, 2, 3, 4]));
+
+    let mut file = check!(File::open(&filename));
+    let buf: &mut [_] = &mut [MaybeUninit::uninit(); 128];
+    let mut buf: BorrowedBuf<'_> = buf.into();
+    check!(file.read_buf(buf.unfilled()));
+    assert_eq!(buf.filled(), &[1, 2, 3, 4]);
+    // File::read_buf should omit buffer initialization.
+    assert_eq!(buf.init_len(), 4);
+
+    buf.clear();
+    check!(file.read_buf(buf.unfilled()));
+    assert_eq!(buf.filled(), &[]);
+
+    check!(fs::remove_file(&filename));
+}
+
 #[test]
 #[cfg(unix)]
 fn file_test_io_read_write_at() {
@@ -1440,3 +1462,93 @@ fn create_dir_long_paths() {
     path.push("a");
     fs::create_dir(&path).unwrap();
 }
+
+#[test]
+#[cfg(any(
+    windows,
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn file_lock_multiple_handles() {
+    let tmpdir = tmpdir();
+    let path = tmpdir.join("lock");
+    let first = check!(OpenOptions::new().read(true).write(true).create(true).open(&path));
+    let second = check!(OpenOptions::new().read(true).write(true).open(&path));
+
+    check!(first.lock());
+    assert!(!check!(second.try_lock()));
+    assert!(!check!(second.try_lock_shared()));
+    check!(first.unlock());
+
+    check!(first.lock_shared());
+    assert!(check!(second.try_lock_shared()));
+    check!(first.unlock());
+    check!(second.unlock());
+
+    assert!(check!(second.try_lock()));
+    assert!(!check!(first.try_lock()));
+    check!(second.unlock());
+    assert!(check!(first.try_lock()));
+    check!(first.unlock());
+}
+
+#[test]
+#[cfg(any(
+    windows,
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn file_lock_shared_with_clone() {
+    let tmpdir = tmpdir();
+    let path = tmpdir.join("lock");
+    let file = check!(File::create(&path));
+    let clone = check!(file.try_clone());
+    let other = check!(OpenOptions::new().write(true).open(&path));
+
+    check!(file.lock());
+    // The lock belongs to the underlying file description, so it is released
+    // through the clone as well.
+    assert!(!check!(other.try_lock()));
+    check!(clone.unlock());
+    assert!(check!(other.try_lock()));
+    check!(other.unlock());
+
+    // Dropping every handle releases the lock.
+    check!(file.lock());
+    drop(file);
+    drop(clone);
+    assert!(check!(other.try_lock()));
+}
+
+#[test]
+fn file_set_times() {
+    use crate::fs::FileTimes;
+    use crate::time::{Duration, SystemTime};
+
+    let tmpdir = tmpdir();
+    let path = tmpdir.join("foo");
+    let file = check!(File::create(&path));
+    let accessed = SystemTime::UNIX_EPOCH + Duration::from_secs(12345);
+    let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(54321);
+    match file.set_times(FileTimes::new().set_accessed(accessed).set_modified(modified)) {
+        Err(e) if e.kind() == ErrorKind::Unsupported => return,
+        Err(e) => panic!("error setting file times: {:?}", e),
+        Ok(_) => {}
+    }
+    let metadata = check!(file.metadata());
+    assert_eq!(check!(metadata.accessed()), accessed);
+    assert_eq!(check!(metadata.modified()), modified);
+
+    // Times that are not set are left untouched.
+    let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(99999);
+    check!(file.set_modified(modified));
+    let metadata = check!(fs::metadata(&path));
+    assert_eq!(check!(metadata.accessed()), accessed);
+    assert_eq!(check!(metadata.modified()), modified);
+}
@@ -0,0 +1,110 @@
+//! Asynchronous values.
+
+#![stable(feature = "futures_api", since = "1.36.0")]
+
+#[cfg(test)]
+mod tests;
+
+use crate::pin::Pin;
+use crate::sync::atomic::{AtomicBool, Ordering};
+use crate::sync::Arc;
+use crate::task::{Context, Poll, Wake, Waker};
+use crate::thread::{self, Thread};
+
+#[doc(inline)]
+#[stable(feature = "futures_api", since = "1.36.0")]
+pub use core::future::*;
+
+/// Blocks the current thread until the provided future has resolved.
+///
+/// The future is polled on the current thread. Whenever it returns
+/// [`Poll::Pending`], the thread is parked with [`thread::park`] until the
+/// future's [`Waker`] is woken, after which the future is polled again.
+///
+/// This is not a replacement for an async runtime: it does not provide I/O
+/// or timer support, and only the given future makes progress while the
+/// thread is blocked. It is intended for tests and small tools that need to
+/// drive a future to completion from synchronous code. Calls to `block_on`
+/// may be nested; each call only wakes up for its own future.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(future_block_on)]
+///
+/// use std::future;
+///
+/// let value = future::block_on(async { 21 * 2 });
+/// assert_eq!(value, 42);
+/// ```
+///
+/// Futures may be woken from other threads:
+///
+/// ```
+/// #![feature(future_block_on)]
+/// #![feature(future_poll_fn)]
+///
+/// use std::future;
+/// use std::sync::mpsc;
+/// use std::task::Poll;
+/// use std::thread;
+///
+/// let (tx, rx) = mpsc::channel();
+/// let mut waker_sent = false;
+///
+/// let value = future::block_on(future::poll_fn(|cx| match rx.try_recv() {
+///     Ok(value) => Poll::Ready(value),
+///     Err(_) => {
+///         if !waker_sent {
+///             waker_sent = true;
+///             let (tx, waker) = (tx.clone(), cx.waker().clone());
+///             thread::spawn(move || {
+///                 tx.send(7).unwrap();
+///                 waker.wake();
+///             });
+///         }
+///         Poll::Pending
+///     }
+/// }));
+/// assert_eq!(value, 7);
+/// ```
+#[unstable(feature = "future_block_on", issue = "none")]
+pub fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = fut;
+    // SAFETY: `fut` is shadowed below and so can never be moved again.
+    let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+
+    let signal = Arc::new(Signal { woken: AtomicBool::new(false), thread: thread::current() });
+    let waker = Waker::from(signal.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+            return output;
+        }
+        // Tracking wakeups in a flag rather than relying on the park token
+        // alone keeps nested `block_on` calls, or anything else on this
+        // thread that parks, from swallowing our wakeup.
+        while !signal.woken.swap(false, Ordering::Acquire) {
+            thread::park();
+        }
+    }
+}
+
+/// The waker state shared between `block_on` and the [`Waker`]s it hands out.
+struct Signal {
+    woken: AtomicBool,
+    thread: Thread,
+}
+
+impl Wake for Signal {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        if !self.woken.swap(true, Ordering::Release) {
+            self.thread.unpark();
+        }
+    }
+}
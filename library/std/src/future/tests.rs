@@ -0,0 +1,126 @@
+use super::block_on;
+use crate::future::Future;
+use crate::pin::Pin;
+use crate::sync::{Arc, Mutex};
+use crate::task::{Context, Poll, Waker};
+use crate::thread;
+use crate::time::Duration;
+
+/// A future which completes once `complete` has been called, possibly from
+/// another thread.
+#[derive(Clone, Default)]
+struct Flag(Arc<Mutex<(bool, Option<Waker>)>>);
+
+impl Flag {
+    fn complete(&self) {
+        let mut state = self.0.lock().unwrap();
+        state.0 = true;
+        if let Some(waker) = state.1.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Future for Flag {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.0.lock().unwrap();
+        if state.0 {
+            Poll::Ready(())
+        } else {
+            state.1 = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// Registers the current task's waker with a `Flag` and completes immediately.
+struct Register<'a>(&'a Flag);
+
+impl Future for Register<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        (self.0).0.lock().unwrap().1 = Some(cx.waker().clone());
+        Poll::Ready(())
+    }
+}
+
+/// Returns `Pending` once without arranging a wakeup, relying on an earlier one.
+struct PendingOnce(bool);
+
+impl Future for PendingOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            Poll::Pending
+        }
+    }
+}
+
+/// A future which wakes itself and returns `Pending` a number of times.
+struct YieldNow(usize);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 == 0 {
+            Poll::Ready(())
+        } else {
+            self.0 -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn ready() {
+    assert_eq!(block_on(async { 42 }), 42);
+}
+
+#[test]
+fn self_wake() {
+    block_on(YieldNow(10));
+}
+
+#[test]
+fn wake_from_other_thread() {
+    let flag = Flag::default();
+    let other = flag.clone();
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        other.complete();
+    });
+    block_on(flag);
+    handle.join().unwrap();
+}
+
+#[test]
+fn nested() {
+    let outer = Flag::default();
+    let inner = Flag::default();
+
+    let (o, i) = (outer.clone(), inner.clone());
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        // Wake the outer call while the inner one is parked. The inner call
+        // consumes the unpark, but the outer one must still see the wakeup.
+        o.complete();
+        thread::sleep(Duration::from_millis(10));
+        i.complete();
+    });
+
+    block_on(async move {
+        Register(&outer).await;
+        block_on(inner);
+        PendingOnce(false).await;
+    });
+    handle.join().unwrap();
+}
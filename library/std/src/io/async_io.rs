@@ -0,0 +1,54 @@
+//! Implementations of the asynchronous I/O traits for the types of `std::io`.
+//!
+//! The traits themselves are defined in `core::io`, and re-exported from
+//! [`std::io`](crate::io).
+
+#[cfg(test)]
+mod tests;
+
+use crate::io::{self, AsyncBufRead, AsyncRead, BufRead, Error, ErrorKind, IoError, Read, Result};
+use crate::pin::Pin;
+use crate::task::{Context, Poll};
+
+#[unstable(feature = "async_io", issue = "none")]
+impl IoError for Error {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        Error::kind(self)
+    }
+}
+
+// `Cursor` never blocks, so it simply defers to its synchronous implementations.
+
+#[unstable(feature = "async_io", issue = "none")]
+impl<T> AsyncRead for io::Cursor<T>
+where
+    T: AsRef<[u8]> + Unpin,
+{
+    type Error = Error;
+
+    #[inline]
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<Result<usize>> {
+        Poll::Ready(Read::read(&mut *self, buf))
+    }
+}
+
+#[unstable(feature = "async_io", issue = "none")]
+impl<T> AsyncBufRead for io::Cursor<T>
+where
+    T: AsRef<[u8]> + Unpin,
+{
+    #[inline]
+    fn poll_fill_buf(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<&[u8]>> {
+        Poll::Ready(BufRead::fill_buf(self.get_mut()))
+    }
+
+    #[inline]
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        BufRead::consume(&mut *self, amt)
+    }
+}
@@ -0,0 +1,189 @@
+use crate::future::Future;
+use crate::io;
+use crate::io::{AsyncBufRead, AsyncRead, AsyncWrite, Cursor, ErrorKind, IoError};
+use crate::pin::Pin;
+use crate::sync::Arc;
+use crate::task::{Context, Poll, Wake, Waker};
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+/// Polls `fut` until it completes, which must happen without any wakeups.
+fn block_on<F: Future + Unpin>(mut fut: F) -> F::Output {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = Pin::new(&mut fut).poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+/// Hands out at most one byte per poll, alternating with `Pending` and
+/// `Interrupted` results to exercise the retry logic of the combinators.
+struct Trickle<T> {
+    inner: T,
+    step: u8,
+}
+
+impl<T> Trickle<T> {
+    fn new(inner: T) -> Trickle<T> {
+        Trickle { inner, step: 0 }
+    }
+
+    fn step<E: IoError>(&mut self) -> Option<Poll<Result<usize, E>>> {
+        self.step = (self.step + 1) % 3;
+        match self.step {
+            1 => Some(Poll::Pending),
+            2 => Some(Poll::Ready(Err(ErrorKind::Interrupted.into()))),
+            _ => None,
+        }
+    }
+}
+
+impl<T: AsyncRead + Unpin> AsyncRead for Trickle<T> {
+    type Error = T::Error;
+
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<Result<usize, T::Error>> {
+        if let Some(poll) = self.step() {
+            return poll;
+        }
+        let len = buf.len().min(1);
+        Pin::new(&mut self.inner).poll_read(cx, &mut buf[..len])
+    }
+}
+
+impl<T: AsyncWrite + Unpin> AsyncWrite for Trickle<T> {
+    type Error = T::Error;
+
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, T::Error>> {
+        if let Some(poll) = self.step() {
+            return poll;
+        }
+        let len = buf.len().min(1);
+        Pin::new(&mut self.inner).poll_write(cx, &buf[..len])
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), T::Error>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), T::Error>> {
+        Pin::new(&mut self.inner).poll_close(cx)
+    }
+}
+
+/// A writer which accepts nothing.
+struct Full;
+
+impl AsyncWrite for Full {
+    type Error = io::Error;
+
+    fn poll_write(self: Pin<&mut Self>, _: &mut Context<'_>, _: &[u8]) -> Poll<io::Result<usize>> {
+        Poll::Ready(Ok(0))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn read_exact() {
+    let mut reader: &[u8] = b"hello world";
+    let mut buf = [0; 5];
+    block_on(reader.read_exact(&mut buf)).unwrap();
+    assert_eq!(&buf, b"hello");
+    assert_eq!(reader, b" world");
+}
+
+#[test]
+fn read_exact_retries() {
+    let mut reader = Trickle::new(&b"hello world"[..]);
+    let mut buf = [0; 11];
+    block_on(reader.read_exact(&mut buf)).unwrap();
+    assert_eq!(&buf, b"hello world");
+}
+
+#[test]
+fn read_exact_eof() {
+    let mut reader: &[u8] = b"hi";
+    let mut buf = [0; 3];
+    let err = block_on(reader.read_exact(&mut buf)).unwrap_err();
+    assert_eq!(err, ErrorKind::UnexpectedEof);
+
+    let mut reader = Cursor::new(b"hi");
+    let err = block_on(reader.read_exact(&mut buf)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn write_all() {
+    let mut writer = Vec::new();
+    block_on(writer.write_all(b"hello")).unwrap();
+    block_on(writer.write_all(b" world")).unwrap();
+    assert_eq!(writer, b"hello world");
+}
+
+#[test]
+fn write_all_retries() {
+    let mut writer = Trickle::new(Vec::new());
+    block_on(writer.write_all(b"hello world")).unwrap();
+    assert_eq!(writer.inner, b"hello world");
+}
+
+#[test]
+fn write_all_zero() {
+    let err = block_on(Full.write_all(b"hello")).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::WriteZero);
+}
+
+#[test]
+fn boxed_and_pinned() {
+    let mut reader: Box<dyn AsyncRead<Error = ErrorKind> + Unpin> = Box::new(&b"abc"[..]);
+    let mut buf = [0; 3];
+    block_on(reader.read_exact(&mut buf)).unwrap();
+    assert_eq!(&buf, b"abc");
+
+    let mut reader = Box::pin(Cursor::new(b"def".to_vec()));
+    block_on(reader.read_exact(&mut buf)).unwrap();
+    assert_eq!(&buf, b"def");
+
+    let mut writer = Box::pin(Vec::new());
+    block_on(writer.write_all(b"ghi")).unwrap();
+    assert_eq!(*writer, b"ghi");
+}
+
+#[test]
+fn buf_read() {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    let mut reader = Cursor::new(b"hello");
+
+    let mut pinned = Pin::new(&mut reader);
+    match pinned.as_mut().poll_fill_buf(&mut cx) {
+        Poll::Ready(Ok(buf)) => assert_eq!(buf, b"hello"),
+        _ => panic!("expected data"),
+    }
+    pinned.as_mut().consume(3);
+    match pinned.as_mut().poll_fill_buf(&mut cx) {
+        Poll::Ready(Ok(buf)) => assert_eq!(buf, b"lo"),
+        _ => panic!("expected data"),
+    }
+    assert_eq!(reader.position(), 3);
+}
@@ -1,5 +1,6 @@
 use crate::io::prelude::*;
-use crate::io::{Cursor, IoSlice, IoSliceMut, SeekFrom};
+use crate::io::{BorrowedBuf, Cursor, IoSlice, IoSliceMut, SeekFrom};
+use crate::mem::MaybeUninit;
 
 #[test]
 fn test_vec_writer() {
@@ -193,6 +194,26 @@ fn test_mem_reader() {
     assert_eq!(reader.read(&mut buf).unwrap(), 0);
 }
 
+#[test]
+fn test_mem_reader_read_buf() {
+    let mut reader = Cursor::new(vec![0, 1, 2, 3, 4, 5, 6, 7]);
+    let buf: &mut [_] = &mut [MaybeUninit::uninit(); 5];
+    let mut buf: BorrowedBuf<'_> = buf.into();
+
+    reader.read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), [0, 1, 2, 3, 4]);
+    assert_eq!(reader.position(), 5);
+
+    buf.clear();
+    reader.read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), [5, 6, 7]);
+    assert_eq!(reader.position(), 8);
+
+    buf.clear();
+    reader.read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), []);
+}
+
 #[test]
 fn test_mem_reader_vectored() {
     let mut reader = Cursor::new(vec![0, 1, 2, 3, 4, 5, 6, 7]);
@@ -5,7 +5,8 @@
 use crate::cmp;
 use crate::fmt;
 use crate::io::{
-    self, BufRead, Error, ErrorKind, Initializer, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write,
+    self, BorrowedCursor, BufRead, Error, ErrorKind, IoSlice, IoSliceMut, Read, Seek, SeekFrom,
+    Write,
 };
 use crate::mem;
 
@@ -30,8 +31,8 @@ fn is_read_vectored(&self) -> bool {
     }
 
     #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        (**self).initializer()
+    fn read_buf(&mut self, buf: BorrowedCursor<'_>) -> io::Result<()> {
+        (**self).read_buf(buf)
     }
 
     #[inline]
@@ -134,8 +135,8 @@ fn is_read_vectored(&self) -> bool {
     }
 
     #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        (**self).initializer()
+    fn read_buf(&mut self, buf: BorrowedCursor<'_>) -> io::Result<()> {
+        (**self).read_buf(buf)
     }
 
     #[inline]
@@ -266,8 +267,14 @@ fn is_read_vectored(&self) -> bool {
     }
 
     #[inline]
-    unsafe fn initializer(&self) -> Initializer {
-        Initializer::nop()
+    fn read_buf(&mut self, mut cursor: BorrowedCursor<'_>) -> io::Result<()> {
+        let amt = cmp::min(cursor.capacity(), self.len());
+        let (a, b) = self.split_at(amt);
+
+        cursor.append(a);
+
+        *self = b;
+        Ok(())
     }
 
     #[inline]
@@ -0,0 +1,322 @@
+#[cfg(test)]
+mod tests;
+
+use crate::fmt;
+use crate::io::{self, IoSlice, IoSliceMut, Read, Write};
+use crate::process::{ChildStderr, ChildStdin, ChildStdout, Stdio};
+use crate::sys::pipe::{anon_pipe_pair, AnonPipe};
+use crate::sys_common::{AsInner, FromInner, IntoInner};
+
+/// Creates an anonymous pipe.
+///
+/// A pipe is a one-way data channel: bytes written to the [`PipeWriter`] can
+/// be read from the [`PipeReader`] in the same order. Both ends can be
+/// converted into a [`Stdio`], which makes it possible to connect several
+/// child processes to each other, to hand the same end of a pipe to more
+/// than one child, or to collect the output of several streams into a
+/// single reader.
+///
+/// Reading from the [`PipeReader`] blocks until data is available and
+/// returns `Ok(0)` once every [`PipeWriter`] referring to the pipe has been
+/// dropped, including copies owned by child processes. Writing to the
+/// [`PipeWriter`] blocks once the pipe's internal buffer is full; it fails
+/// with [`ErrorKind::BrokenPipe`] once every [`PipeReader`] is gone.
+///
+/// # Platform-specific behavior
+///
+/// This function currently corresponds to the `pipe2` function with
+/// `O_CLOEXEC` on Unix (or `pipe` followed by setting the `FD_CLOEXEC` flag
+/// on platforms without `pipe2`) and to the `CreatePipe` function on Windows.
+/// In either case, neither end is inherited by child processes unless it is
+/// passed to them explicitly through [`Stdio`]. On platforms without pipes,
+/// an error is returned.
+///
+/// Note that this [may change in the future][changes].
+///
+/// [changes]: io#platform-specific-behavior
+/// [`ErrorKind::BrokenPipe`]: io::ErrorKind::BrokenPipe
+///
+/// # Examples
+///
+/// Building the shell pipeline `ls | sort`, while collecting the standard
+/// output and standard error of `sort` in a single stream:
+///
+/// ```no_run
+/// #![feature(anonymous_pipe)]
+/// use std::io::{self, Read};
+/// use std::process::Command;
+///
+/// fn main() -> io::Result<()> {
+///     let (ls_out, sort_in) = io::pipe()?;
+///     let (mut reader, writer) = io::pipe()?;
+///
+///     let mut ls = Command::new("ls").stdout(sort_in).spawn()?;
+///     let mut sort = Command::new("sort")
+///         .stdin(ls_out)
+///         .stdout(writer.try_clone()?)
+///         .stderr(writer)
+///         .spawn()?;
+///
+///     // The parent's copies of the write ends were dropped together with the
+///     // `Command`s, so `reader` reports EOF once `sort` has exited.
+///     let mut output = String::new();
+///     reader.read_to_string(&mut output)?;
+///
+///     ls.wait()?;
+///     sort.wait()?;
+///     print!("{}", output);
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub fn pipe() -> io::Result<(PipeReader, PipeWriter)> {
+    anon_pipe_pair().map(|(reader, writer)| (PipeReader(reader), PipeWriter(writer)))
+}
+
+/// The read end of an anonymous pipe, created by [`pipe`].
+///
+/// `PipeReader` implements [`Read`] and can be converted into a [`Stdio`] to
+/// serve as the standard input of a child process. A [`ChildStdout`] or
+/// [`ChildStderr`] can be converted into a `PipeReader` as well.
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub struct PipeReader(AnonPipe);
+
+/// The write end of an anonymous pipe, created by [`pipe`].
+///
+/// `PipeWriter` implements [`Write`] and can be converted into a [`Stdio`] to
+/// serve as the standard output or standard error of a child process. A
+/// [`ChildStdin`] can be converted into a `PipeWriter` as well.
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub struct PipeWriter(AnonPipe);
+
+impl PipeReader {
+    /// Creates a new `PipeReader` instance that shares the same underlying
+    /// pipe.
+    ///
+    /// Data read from either instance is consumed from the pipe and is not
+    /// seen by the other one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(anonymous_pipe)]
+    /// use std::io::{self, Write};
+    /// use std::process::Command;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let (reader, mut writer) = io::pipe()?;
+    ///
+    ///     // Two workers draining the same queue of jobs.
+    ///     let mut first = Command::new("worker").stdin(reader.try_clone()?).spawn()?;
+    ///     let mut second = Command::new("worker").stdin(reader).spawn()?;
+    ///
+    ///     writer.write_all(b"job 1\njob 2\njob 3\n")?;
+    ///     drop(writer);
+    ///
+    ///     first.wait()?;
+    ///     second.wait()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<Self> {
+        self.0.try_clone().map(Self)
+    }
+}
+
+impl PipeWriter {
+    /// Creates a new `PipeWriter` instance that shares the same underlying
+    /// pipe.
+    ///
+    /// The pipe only reports end of file to readers once all instances have
+    /// been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(anonymous_pipe)]
+    /// use std::io::{self, Read};
+    /// use std::process::Command;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let (mut reader, writer) = io::pipe()?;
+    ///
+    ///     // Merge stdout and stderr of the child into a single stream.
+    ///     let mut child =
+    ///         Command::new("make").stdout(writer.try_clone()?).stderr(writer).spawn()?;
+    ///
+    ///     let mut log = String::new();
+    ///     reader.read_to_string(&mut log)?;
+    ///     child.wait()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<Self> {
+        self.0.try_clone().map(Self)
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Read for PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.0.is_read_vectored()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Read for &PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.0.is_read_vectored()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Write for PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Write for &PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl fmt::Debug for PipeReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PipeReader").finish_non_exhaustive()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl fmt::Debug for PipeWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PipeWriter").finish_non_exhaustive()
+    }
+}
+
+impl AsInner<AnonPipe> for PipeReader {
+    fn as_inner(&self) -> &AnonPipe {
+        &self.0
+    }
+}
+
+impl IntoInner<AnonPipe> for PipeReader {
+    fn into_inner(self) -> AnonPipe {
+        self.0
+    }
+}
+
+impl FromInner<AnonPipe> for PipeReader {
+    fn from_inner(pipe: AnonPipe) -> PipeReader {
+        PipeReader(pipe)
+    }
+}
+
+impl AsInner<AnonPipe> for PipeWriter {
+    fn as_inner(&self) -> &AnonPipe {
+        &self.0
+    }
+}
+
+impl IntoInner<AnonPipe> for PipeWriter {
+    fn into_inner(self) -> AnonPipe {
+        self.0
+    }
+}
+
+impl FromInner<AnonPipe> for PipeWriter {
+    fn from_inner(pipe: AnonPipe) -> PipeWriter {
+        PipeWriter(pipe)
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<PipeReader> for Stdio {
+    /// Converts a [`PipeReader`] into a [`Stdio`], to be used as the standard
+    /// input of a child process.
+    fn from(pipe: PipeReader) -> Stdio {
+        Stdio::from_inner(pipe.0.into())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<PipeWriter> for Stdio {
+    /// Converts a [`PipeWriter`] into a [`Stdio`], to be used as the standard
+    /// output or standard error of a child process.
+    fn from(pipe: PipeWriter) -> Stdio {
+        Stdio::from_inner(pipe.0.into())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<ChildStdout> for PipeReader {
+    /// Converts the standard output of a child process into a [`PipeReader`].
+    fn from(child: ChildStdout) -> PipeReader {
+        PipeReader(child.into_inner())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<ChildStderr> for PipeReader {
+    /// Converts the standard error of a child process into a [`PipeReader`].
+    fn from(child: ChildStderr) -> PipeReader {
+        PipeReader(child.into_inner())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<ChildStdin> for PipeWriter {
+    /// Converts the standard input of a child process into a [`PipeWriter`].
+    fn from(child: ChildStdin) -> PipeWriter {
+        PipeWriter(child.into_inner())
+    }
+}
@@ -0,0 +1,53 @@
+use crate::io::prelude::*;
+use crate::io::{pipe, ErrorKind, IoSlice};
+use crate::thread;
+
+#[test]
+fn read_write() {
+    let (mut reader, mut writer) = pipe().unwrap();
+    writer.write_all(b"hello").unwrap();
+    assert_eq!(writer.write_vectored(&[IoSlice::new(b", "), IoSlice::new(b"world")]).unwrap(), 7);
+    drop(writer);
+
+    let mut buf = String::new();
+    reader.read_to_string(&mut buf).unwrap();
+    assert_eq!(buf, "hello, world");
+}
+
+#[test]
+fn eof_after_all_writers_dropped() {
+    let (reader, writer) = pipe().unwrap();
+    let writer2 = writer.try_clone().unwrap();
+
+    let t = thread::spawn(move || {
+        (&writer).write_all(b"one ").unwrap();
+        drop(writer);
+        (&writer2).write_all(b"two").unwrap();
+    });
+
+    let mut buf = Vec::new();
+    (&reader).read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, b"one two");
+    t.join().unwrap();
+}
+
+#[test]
+fn cloned_readers_share_data() {
+    let (reader, mut writer) = pipe().unwrap();
+    let mut reader2 = reader.try_clone().unwrap();
+    drop(reader);
+
+    writer.write_all(b"shared").unwrap();
+    drop(writer);
+
+    let mut buf = Vec::new();
+    reader2.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, b"shared");
+}
+
+#[test]
+fn broken_pipe() {
+    let (reader, mut writer) = pipe().unwrap();
+    drop(reader);
+    assert_eq!(writer.write(b"x").unwrap_err().kind(), ErrorKind::BrokenPipe);
+}
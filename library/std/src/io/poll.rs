@@ -0,0 +1,481 @@
+#[cfg(all(test, any(unix, windows)))]
+mod tests;
+
+use crate::fmt;
+use crate::io;
+use crate::sys::poller as imp;
+use crate::time::Duration;
+
+/// Waits for readiness events on several I/O sources at once.
+///
+/// A `Poller` watches a set of sources, usually non-blocking sockets, and
+/// reports which of them have become readable or writable. Every registered
+/// source is identified by a `key` chosen by the caller, which is handed back
+/// in the [`Event`]s returned by [`wait`]. This makes it possible to drive many
+/// connections from a single thread without an external event loop.
+///
+/// Registering a source does not take ownership of it. A source must be
+/// removed with [`delete`] before it is closed; otherwise a new source that
+/// happens to reuse the same descriptor may produce spurious events.
+///
+/// [`wait`]: Poller::wait
+/// [`delete`]: Poller::delete
+///
+/// # Platform-specific behavior
+///
+/// This type currently uses `epoll` on Linux and Android, `kqueue` on macOS,
+/// iOS and the BSDs, `poll` on other Unix platforms and `WSAPoll` on Windows.
+/// [`PollMode::Edge`] is only supported by the `epoll` and `kqueue` backends;
+/// elsewhere registering a source in that mode fails with
+/// [`ErrorKind::Unsupported`]. With the `poll` and `WSAPoll` backends,
+/// registrations changed from another thread take effect at the next call to
+/// [`wait`].
+///
+/// Note that this [may change in the future][changes].
+///
+/// [changes]: io#platform-specific-behavior
+/// [`ErrorKind::Unsupported`]: io::ErrorKind::Unsupported
+///
+/// # Examples
+///
+/// An echo server that handles every connection on the same thread:
+///
+/// ```no_run
+/// #![feature(io_poller)]
+/// use std::collections::HashMap;
+/// use std::io::{self, Events, Interest, PollMode, Poller, Read, Write};
+/// use std::net::TcpListener;
+///
+/// fn main() -> io::Result<()> {
+///     let listener = TcpListener::bind("127.0.0.1:8080")?;
+///     listener.set_nonblocking(true)?;
+///
+///     let poller = Poller::new()?;
+///     poller.add(&listener, 0, Interest::READABLE, PollMode::Level)?;
+///
+///     let mut clients = HashMap::new();
+///     let mut events = Events::with_capacity(64);
+///     let mut next_key = 1;
+///     loop {
+///         poller.wait(&mut events, None)?;
+///         for event in &events {
+///             if event.key() == 0 {
+///                 let (stream, _) = listener.accept()?;
+///                 stream.set_nonblocking(true)?;
+///                 poller.add(&stream, next_key, Interest::READABLE, PollMode::Level)?;
+///                 clients.insert(next_key, stream);
+///                 next_key += 1;
+///                 continue;
+///             }
+///
+///             let stream = clients.get_mut(&event.key()).unwrap();
+///             let mut buf = [0; 1024];
+///             match stream.read(&mut buf) {
+///                 Ok(0) => {
+///                     poller.delete(&*stream)?;
+///                     clients.remove(&event.key());
+///                 }
+///                 Ok(n) => stream.write_all(&buf[..n])?,
+///                 Err(e) if e.kind() == io::ErrorKind::WouldBlock => {}
+///                 Err(e) => return Err(e),
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[unstable(feature = "io_poller", issue = "none")]
+pub struct Poller {
+    inner: imp::Poller,
+}
+
+impl Poller {
+    /// Creates a new poller without any registered sources.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the operating system refuses to create the
+    /// underlying queue, or [`ErrorKind::Unsupported`] on platforms that have
+    /// no readiness notification mechanism.
+    ///
+    /// [`ErrorKind::Unsupported`]: io::ErrorKind::Unsupported
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn new() -> io::Result<Poller> {
+        imp::Poller::new().map(|inner| Poller { inner })
+    }
+
+    /// Registers `source` with this poller.
+    ///
+    /// Events for `source` are reported with the given `key` once it becomes
+    /// ready for any operation in `interest`. A source can only be registered
+    /// once per poller; use [`modify`] to change its interest, key or mode.
+    ///
+    /// The source should be in non-blocking mode: with [`PollMode::Edge`] it
+    /// has to be read or written until the operation fails with
+    /// [`ErrorKind::WouldBlock`] before a new event is reported.
+    ///
+    /// [`modify`]: Poller::modify
+    /// [`ErrorKind::WouldBlock`]: io::ErrorKind::WouldBlock
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(io_poller)]
+    /// use std::io::{Interest, PollMode, Poller};
+    /// use std::net::UdpSocket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UdpSocket::bind("127.0.0.1:34254")?;
+    ///     socket.set_nonblocking(true)?;
+    ///
+    ///     let poller = Poller::new()?;
+    ///     poller.add(&socket, 7, Interest::READABLE, PollMode::Edge)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn add<S: Source + ?Sized>(
+        &self,
+        source: &S,
+        key: usize,
+        interest: Interest,
+        mode: PollMode,
+    ) -> io::Result<()> {
+        self.inner.add(source.as_raw_source(), key, interest, mode)
+    }
+
+    /// Changes the key, interest and mode of a source registered with [`add`].
+    ///
+    /// If the source is already ready for the new interest, an event is
+    /// reported by the next call to [`wait`], even in [`PollMode::Edge`].
+    ///
+    /// [`add`]: Poller::add
+    /// [`wait`]: Poller::wait
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn modify<S: Source + ?Sized>(
+        &self,
+        source: &S,
+        key: usize,
+        interest: Interest,
+        mode: PollMode,
+    ) -> io::Result<()> {
+        self.inner.modify(source.as_raw_source(), key, interest, mode)
+    }
+
+    /// Removes a source registered with [`add`] from this poller.
+    ///
+    /// [`add`]: Poller::add
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn delete<S: Source + ?Sized>(&self, source: &S) -> io::Result<()> {
+        self.inner.delete(source.as_raw_source())
+    }
+
+    /// Blocks until at least one registered source is ready, or until
+    /// `timeout` has elapsed.
+    ///
+    /// The previous contents of `events` are cleared and replaced by at most
+    /// [`events.capacity()`] new events. A `timeout` of [`None`] blocks
+    /// indefinitely, while a zero `timeout` only checks for events that are
+    /// already pending. Non-zero timeouts are rounded up to the granularity of
+    /// the underlying system call. When the timeout elapses, `events` is left
+    /// empty.
+    ///
+    /// On Unix, this function fails with [`ErrorKind::Interrupted`] if a
+    /// signal arrives while waiting.
+    ///
+    /// [`events.capacity()`]: Events::capacity
+    /// [`ErrorKind::Interrupted`]: io::ErrorKind::Interrupted
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(io_poller)]
+    /// use std::io::{Events, Interest, PollMode, Poller};
+    /// use std::net::TcpStream;
+    /// use std::time::Duration;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let stream = TcpStream::connect("127.0.0.1:8080")?;
+    ///     stream.set_nonblocking(true)?;
+    ///
+    ///     let poller = Poller::new()?;
+    ///     poller.add(&stream, 0, Interest::READABLE, PollMode::Level)?;
+    ///
+    ///     let mut events = Events::with_capacity(1);
+    ///     poller.wait(&mut events, Some(Duration::from_secs(1)))?;
+    ///     if events.is_empty() {
+    ///         println!("no data arrived within a second");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn wait(&self, events: &mut Events, timeout: Option<Duration>) -> io::Result<()> {
+        events.inner.clear();
+        self.inner.wait(&mut events.inner, timeout)
+    }
+}
+
+#[unstable(feature = "io_poller", issue = "none")]
+impl fmt::Debug for Poller {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// The kinds of readiness a [`Poller`] waits for.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(io_poller)]
+/// use std::io::Interest;
+///
+/// assert!(Interest::BOTH.is_readable());
+/// assert!(Interest::BOTH.is_writable());
+/// assert!(!Interest::READABLE.is_writable());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[unstable(feature = "io_poller", issue = "none")]
+pub struct Interest {
+    readable: bool,
+    writable: bool,
+}
+
+impl Interest {
+    /// Interest in the source becoming readable.
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub const READABLE: Interest = Interest { readable: true, writable: false };
+
+    /// Interest in the source becoming writable.
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub const WRITABLE: Interest = Interest { readable: false, writable: true };
+
+    /// Interest in the source becoming either readable or writable.
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub const BOTH: Interest = Interest { readable: true, writable: true };
+
+    /// No interest at all.
+    ///
+    /// A source registered with this interest stays registered, but no
+    /// events are reported for it until its interest is changed with
+    /// [`Poller::modify`].
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub const NONE: Interest = Interest { readable: false, writable: false };
+
+    /// Returns `true` if this includes interest in readability.
+    #[must_use]
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub const fn is_readable(self) -> bool {
+        self.readable
+    }
+
+    /// Returns `true` if this includes interest in writability.
+    #[must_use]
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub const fn is_writable(self) -> bool {
+        self.writable
+    }
+}
+
+/// How a [`Poller`] reports the readiness of a source.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[unstable(feature = "io_poller", issue = "none")]
+pub enum PollMode {
+    /// An event is reported by every call to [`Poller::wait`] for as long as
+    /// the source is ready.
+    #[unstable(feature = "io_poller", issue = "none")]
+    Level,
+    /// An event is only reported when the source changes from not ready to
+    /// ready.
+    #[unstable(feature = "io_poller", issue = "none")]
+    Edge,
+}
+
+/// A readiness event returned by [`Poller::wait`].
+///
+/// An event may report readiness that was not asked for: errors and hang-ups
+/// are reported as the source being both readable and writable, so that the
+/// next operation on the source returns the error. A source can also appear
+/// more than once in the same [`Events`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[unstable(feature = "io_poller", issue = "none")]
+pub struct Event {
+    key: usize,
+    readable: bool,
+    writable: bool,
+}
+
+impl Event {
+    pub(crate) fn new(key: usize, readable: bool, writable: bool) -> Event {
+        Event { key, readable, writable }
+    }
+
+    /// Returns the key the source was registered with.
+    #[must_use]
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn key(&self) -> usize {
+        self.key
+    }
+
+    /// Returns `true` if the source is readable.
+    #[must_use]
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn is_readable(&self) -> bool {
+        self.readable
+    }
+
+    /// Returns `true` if the source is writable.
+    #[must_use]
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn is_writable(&self) -> bool {
+        self.writable
+    }
+}
+
+/// A buffer of [`Event`]s filled in by [`Poller::wait`].
+#[unstable(feature = "io_poller", issue = "none")]
+pub struct Events {
+    inner: imp::Events,
+}
+
+impl Events {
+    /// Creates a buffer with room for `capacity` events.
+    ///
+    /// A single call to [`Poller::wait`] returns at most `capacity` events;
+    /// the remaining ones are reported by later calls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    #[must_use]
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn with_capacity(capacity: usize) -> Events {
+        assert!(capacity > 0, "an event buffer needs room for at least one event");
+        Events { inner: imp::Events::with_capacity(capacity) }
+    }
+
+    /// Returns the maximum number of events this buffer can hold.
+    #[must_use]
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Returns the number of events in this buffer.
+    #[must_use]
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if this buffer contains no events.
+    #[must_use]
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all events from this buffer.
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+
+    /// Returns an iterator over the events in this buffer.
+    #[unstable(feature = "io_poller", issue = "none")]
+    pub fn iter(&self) -> EventsIter<'_> {
+        EventsIter { events: self, pos: 0 }
+    }
+}
+
+#[unstable(feature = "io_poller", issue = "none")]
+impl fmt::Debug for Events {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "io_poller", issue = "none")]
+impl<'a> IntoIterator for &'a Events {
+    type Item = Event;
+    type IntoIter = EventsIter<'a>;
+
+    fn into_iter(self) -> EventsIter<'a> {
+        self.iter()
+    }
+}
+
+/// An iterator over the [`Event`]s in an [`Events`] buffer.
+///
+/// This `struct` is created by the [`iter`] method on [`Events`].
+///
+/// [`iter`]: Events::iter
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "io_poller", issue = "none")]
+pub struct EventsIter<'a> {
+    events: &'a Events,
+    pos: usize,
+}
+
+#[unstable(feature = "io_poller", issue = "none")]
+impl Iterator for EventsIter<'_> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        let event = self.events.inner.get(self.pos)?;
+        self.pos += 1;
+        Some(event)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.events.len() - self.pos;
+        (remaining, Some(remaining))
+    }
+}
+
+#[unstable(feature = "io_poller", issue = "none")]
+impl ExactSizeIterator for EventsIter<'_> {}
+
+/// An I/O source that can be registered with a [`Poller`].
+///
+/// This trait is implemented for every type that implements
+/// [`AsFd`](crate::os::unix::io::AsFd) on Unix and
+/// [`AsSocket`](crate::os::windows::io::AsSocket) on Windows, such as the
+/// types in [`std::net`](crate::net). It cannot be implemented outside of the
+/// standard library.
+#[unstable(feature = "io_poller", issue = "none")]
+pub trait Source: private::AsRawSource {}
+
+mod private {
+    use crate::sys::poller::RawSource;
+
+    pub trait AsRawSource {
+        fn as_raw_source(&self) -> RawSource;
+    }
+
+    #[cfg(unix)]
+    impl<T: crate::os::unix::io::AsFd + ?Sized> AsRawSource for T {
+        fn as_raw_source(&self) -> RawSource {
+            use crate::os::unix::io::AsRawFd;
+            self.as_fd().as_raw_fd()
+        }
+    }
+
+    #[cfg(windows)]
+    impl<T: crate::os::windows::io::AsSocket + ?Sized> AsRawSource for T {
+        fn as_raw_source(&self) -> RawSource {
+            use crate::os::windows::io::AsRawSocket;
+            self.as_socket().as_raw_socket()
+        }
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "io_poller", issue = "none")]
+impl<T: crate::os::unix::io::AsFd + ?Sized> Source for T {}
+
+#[cfg(windows)]
+#[unstable(feature = "io_poller", issue = "none")]
+impl<T: crate::os::windows::io::AsSocket + ?Sized> Source for T {}
@@ -0,0 +1,197 @@
+use crate::io::{ErrorKind, Events, Interest, PollMode, Poller};
+use crate::net::{TcpListener, TcpStream, UdpSocket};
+use crate::time::{Duration, Instant};
+
+const TIMEOUT: Option<Duration> = Some(Duration::from_secs(10));
+
+fn udp_pair() -> (UdpSocket, UdpSocket) {
+    let a = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let b = UdpSocket::bind("127.0.0.1:0").unwrap();
+    a.connect(b.local_addr().unwrap()).unwrap();
+    b.connect(a.local_addr().unwrap()).unwrap();
+    a.set_nonblocking(true).unwrap();
+    b.set_nonblocking(true).unwrap();
+    (a, b)
+}
+
+#[test]
+fn readable() {
+    let (a, b) = udp_pair();
+    let poller = Poller::new().unwrap();
+    poller.add(&b, 42, Interest::READABLE, PollMode::Level).unwrap();
+
+    let mut events = Events::with_capacity(8);
+    poller.wait(&mut events, Some(Duration::ZERO)).unwrap();
+    assert!(events.is_empty());
+
+    a.send(b"hello").unwrap();
+    poller.wait(&mut events, TIMEOUT).unwrap();
+    let event = events.iter().next().unwrap();
+    assert_eq!(event.key(), 42);
+    assert!(event.is_readable());
+
+    // Level-triggered events are reported until the data is read.
+    poller.wait(&mut events, TIMEOUT).unwrap();
+    assert_eq!(events.len(), 1);
+    let mut buf = [0; 5];
+    assert_eq!(b.recv(&mut buf).unwrap(), 5);
+    poller.wait(&mut events, Some(Duration::ZERO)).unwrap();
+    assert!(events.is_empty());
+}
+
+#[test]
+fn writable() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    stream.set_nonblocking(true).unwrap();
+
+    let poller = Poller::new().unwrap();
+    poller.add(&stream, 1, Interest::BOTH, PollMode::Level).unwrap();
+
+    let mut events = Events::with_capacity(8);
+    poller.wait(&mut events, TIMEOUT).unwrap();
+    assert!(events.iter().any(|event| event.key() == 1 && event.is_writable()));
+    assert!(!events.iter().any(|event| event.is_readable()));
+}
+
+#[test]
+#[should_panic]
+fn zero_capacity() {
+    let _ = Events::with_capacity(0);
+}
+
+#[test]
+fn timeout() {
+    let (_a, b) = udp_pair();
+    let poller = Poller::new().unwrap();
+    poller.add(&b, 0, Interest::READABLE, PollMode::Level).unwrap();
+
+    let mut events = Events::with_capacity(1);
+    let timeout = Duration::from_millis(50);
+    let start = Instant::now();
+    poller.wait(&mut events, Some(timeout)).unwrap();
+    assert!(events.is_empty());
+    assert!(start.elapsed() >= timeout);
+}
+
+#[test]
+fn modify_and_delete() {
+    let (a, b) = udp_pair();
+    let poller = Poller::new().unwrap();
+    poller.add(&b, 1, Interest::NONE, PollMode::Level).unwrap();
+    a.send(b"x").unwrap();
+
+    let mut events = Events::with_capacity(8);
+    poller.wait(&mut events, Some(Duration::from_millis(10))).unwrap();
+    assert!(events.is_empty());
+
+    poller.modify(&b, 2, Interest::READABLE, PollMode::Level).unwrap();
+    poller.wait(&mut events, TIMEOUT).unwrap();
+    assert_eq!(events.iter().map(|event| event.key()).collect::<Vec<_>>(), [2]);
+
+    poller.delete(&b).unwrap();
+    poller.wait(&mut events, Some(Duration::from_millis(10))).unwrap();
+    assert!(events.is_empty());
+    assert!(poller.modify(&b, 3, Interest::READABLE, PollMode::Level).is_err());
+}
+
+#[test]
+fn registration_errors() {
+    let (_a, b) = udp_pair();
+    let poller = Poller::new().unwrap();
+    let err = poller.modify(&b, 1, Interest::READABLE, PollMode::Level).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    assert_eq!(poller.delete(&b).unwrap_err().kind(), ErrorKind::NotFound);
+
+    poller.add(&b, 1, Interest::READABLE, PollMode::Level).unwrap();
+    let err = poller.add(&b, 2, Interest::BOTH, PollMode::Level).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+    // Interest in nothing still counts as a registration.
+    poller.modify(&b, 1, Interest::NONE, PollMode::Level).unwrap();
+    let err = poller.add(&b, 2, Interest::WRITABLE, PollMode::Level).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+    poller.delete(&b).unwrap();
+    assert_eq!(poller.delete(&b).unwrap_err().kind(), ErrorKind::NotFound);
+    poller.add(&b, 3, Interest::WRITABLE, PollMode::Level).unwrap();
+}
+
+#[test]
+fn multiple_sources() {
+    let (a1, b1) = udp_pair();
+    let (a2, b2) = udp_pair();
+    let poller = Poller::new().unwrap();
+    poller.add(&b1, 1, Interest::READABLE, PollMode::Level).unwrap();
+    poller.add(&b2, 2, Interest::READABLE, PollMode::Level).unwrap();
+    a1.send(b"1").unwrap();
+    a2.send(b"2").unwrap();
+
+    let mut keys = Vec::new();
+    let mut events = Events::with_capacity(1);
+    let mut buf = [0; 1];
+    while keys.len() < 2 {
+        poller.wait(&mut events, TIMEOUT).unwrap();
+        assert_eq!(events.len(), 1);
+        for event in &events {
+            let socket = if event.key() == 1 { &b1 } else { &b2 };
+            socket.recv(&mut buf).unwrap();
+            keys.push(event.key());
+        }
+    }
+    keys.sort();
+    assert_eq!(keys, [1, 2]);
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+fn edge_triggered() {
+    let (a, b) = udp_pair();
+    let poller = Poller::new().unwrap();
+    poller.add(&b, 7, Interest::READABLE, PollMode::Edge).unwrap();
+
+    a.send(b"hello").unwrap();
+    let mut events = Events::with_capacity(8);
+    poller.wait(&mut events, TIMEOUT).unwrap();
+    assert_eq!(events.len(), 1);
+
+    // The data was not read, but the readiness did not change either.
+    poller.wait(&mut events, Some(Duration::from_millis(10))).unwrap();
+    assert!(events.is_empty());
+
+    a.send(b"world").unwrap();
+    poller.wait(&mut events, TIMEOUT).unwrap();
+    assert_eq!(events.len(), 1);
+}
+
+#[test]
+#[cfg(not(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+)))]
+fn edge_triggered_unsupported() {
+    let (_a, b) = udp_pair();
+    let poller = Poller::new().unwrap();
+    let err = poller.add(&b, 7, Interest::READABLE, PollMode::Edge).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+}
+
+#[test]
+fn would_block_after_event() {
+    let (a, b) = udp_pair();
+    let poller = Poller::new().unwrap();
+    poller.add(&b, 0, Interest::READABLE, PollMode::Level).unwrap();
+    a.send(b"x").unwrap();
+
+    let mut events = Events::with_capacity(8);
+    poller.wait(&mut events, TIMEOUT).unwrap();
+    let mut buf = [0; 1];
+    b.recv(&mut buf).unwrap();
+    assert_eq!(b.recv(&mut buf).unwrap_err().kind(), ErrorKind::WouldBlock);
+}
@@ -1,7 +1,8 @@
 use super::{repeat, Cursor, SeekFrom};
 use crate::cmp::{self, min};
 use crate::io::{self, IoSlice, IoSliceMut};
-use crate::io::{BufRead, BufReader, Read, Seek, Write};
+use crate::io::{BorrowedBuf, BufRead, BufReader, Read, Seek, Write};
+use crate::mem::MaybeUninit;
 use crate::ops::Deref;
 
 #[test]
@@ -156,6 +157,110 @@ fn read_exact_slice() {
     assert_eq!(c, b"9");
 }
 
+#[test]
+fn read_buf_exact() {
+    let buf: &mut [_] = &mut [0; 4];
+    let mut buf: BorrowedBuf<'_> = buf.into();
+
+    let mut c = Cursor::new(&b""[..]);
+    assert_eq!(c.read_buf_exact(buf.unfilled()).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+
+    let mut c = Cursor::new(&b"123456789"[..]);
+    c.read_buf_exact(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), b"1234");
+
+    buf.clear();
+
+    c.read_buf_exact(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), b"5678");
+
+    buf.clear();
+
+    assert_eq!(c.read_buf_exact(buf.unfilled()).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+#[should_panic]
+fn read_to_end_bogus_read() {
+    struct Bogus;
+
+    impl Read for Bogus {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            Ok(buf.len() + 1)
+        }
+    }
+
+    let _ = Bogus.read_to_end(&mut Vec::new());
+}
+
+#[test]
+fn take_read_buf() {
+    let buf: &mut [_] = &mut [MaybeUninit::uninit(); 8];
+    let mut buf: BorrowedBuf<'_> = buf.into();
+
+    // The limit is smaller than the buffer.
+    let mut take = (&b"hello world"[..]).take(5);
+    take.read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), b"hello");
+    assert_eq!(take.limit(), 0);
+    take.read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), b"hello");
+
+    // The buffer is smaller than the limit.
+    buf.clear();
+    let mut take = (&b"hello world"[..]).take(10);
+    take.read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), b"hello wo");
+    assert_eq!(take.limit(), 2);
+}
+
+#[test]
+fn take_read_buf_keeps_init() {
+    // Initializes the whole buffer it is given, but only fills one byte.
+    struct Partial;
+
+    impl Read for Partial {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            buf[0] = 1;
+            Ok(1)
+        }
+    }
+
+    let buf: &mut [_] = &mut [MaybeUninit::uninit(); 8];
+    let mut buf: BorrowedBuf<'_> = buf.into();
+    Partial.take(4).read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), [1]);
+    assert_eq!(buf.init_len(), 4);
+}
+
+#[test]
+fn chain_read_buf() {
+    let buf: &mut [_] = &mut [MaybeUninit::uninit(); 4];
+    let mut buf: BorrowedBuf<'_> = buf.into();
+    let mut chain = (&b"ab"[..]).chain(&b"cdef"[..]);
+
+    chain.read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), b"ab");
+    chain.read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), b"abcd");
+
+    buf.clear();
+    chain.read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), b"ef");
+
+    buf.clear();
+    chain.read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), b"");
+}
+
+#[test]
+fn repeat_read_buf() {
+    let buf: &mut [_] = &mut [MaybeUninit::uninit(); 4];
+    let mut buf: BorrowedBuf<'_> = buf.into();
+    repeat(b'x').read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), b"xxxx");
+}
+
 #[test]
 fn take_eof() {
     struct R;
@@ -0,0 +1,519 @@
+#[cfg(all(test, not(any(target_os = "emscripten", target_env = "sgx"))))]
+mod tests;
+
+use crate::fmt;
+use crate::io;
+use crate::net::{SocketAddr, TcpListener, TcpStream, UdpSocket};
+use crate::sys_common::net as net_imp;
+use crate::sys_common::{AsInner, FromInner, IntoInner};
+use crate::time::Duration;
+
+/// The address family of a [`Socket`].
+#[unstable(feature = "net_socket", issue = "none")]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum Domain {
+    /// IPv4 (`AF_INET`).
+    Ipv4,
+    /// IPv6 (`AF_INET6`).
+    Ipv6,
+}
+
+impl Domain {
+    /// Returns the domain a socket needs to have to be bound or connected to
+    /// `addr`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(net_socket)]
+    /// use std::net::{Domain, SocketAddr};
+    ///
+    /// let addr: SocketAddr = "[::1]:8080".parse().unwrap();
+    /// assert_eq!(Domain::for_address(&addr), Domain::Ipv6);
+    /// ```
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn for_address(addr: &SocketAddr) -> Domain {
+        match *addr {
+            SocketAddr::V4(..) => Domain::Ipv4,
+            SocketAddr::V6(..) => Domain::Ipv6,
+        }
+    }
+}
+
+/// The communication semantics of a [`Socket`].
+#[unstable(feature = "net_socket", issue = "none")]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum Type {
+    /// A reliable, connection-based byte stream (`SOCK_STREAM`), as used by TCP.
+    Stream,
+    /// Connectionless datagrams (`SOCK_DGRAM`), as used by UDP.
+    Datagram,
+    /// Raw network protocol access (`SOCK_RAW`).
+    ///
+    /// Creating raw sockets usually requires elevated privileges.
+    Raw,
+}
+
+/// The protocol used by a [`Socket`].
+#[unstable(feature = "net_socket", issue = "none")]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum Protocol {
+    /// The Transmission Control Protocol (`IPPROTO_TCP`).
+    Tcp,
+    /// The User Datagram Protocol (`IPPROTO_UDP`).
+    Udp,
+    /// The Internet Control Message Protocol (`IPPROTO_ICMP`).
+    Icmpv4,
+    /// The Internet Control Message Protocol for IPv6 (`IPPROTO_ICMPV6`).
+    Icmpv6,
+}
+
+/// A network socket that has not necessarily been bound, connected or put in
+/// the listening state yet.
+///
+/// `TcpStream`, `TcpListener` and `UdpSocket` create, configure and bind or
+/// connect their socket in a single step, which leaves no room for options
+/// that only take effect when set beforehand, such as
+/// [`set_reuse_port`](Socket::set_reuse_port),
+/// [`set_only_v6`](Socket::set_only_v6) or the buffer sizes. A `Socket` is
+/// created with [`Socket::new`], can be configured freely and is then bound,
+/// connected or put in the listening state explicitly. Once set up, it can be
+/// converted into a [`TcpStream`], [`TcpListener`] or [`UdpSocket`] with
+/// [`From`].
+///
+/// Like the other socket types of this module, the socket is created with the
+/// close-on-exec flag set and is not inherited by child processes.
+///
+/// # Examples
+///
+/// Building a listener that shares its port with other processes:
+///
+/// ```no_run
+/// #![feature(net_socket)]
+/// use std::net::{Domain, Protocol, Socket, SocketAddr, TcpListener, Type};
+///
+/// fn main() -> std::io::Result<()> {
+///     let addr: SocketAddr = "[::]:8080".parse().unwrap();
+///     let socket = Socket::new(Domain::Ipv6, Type::Stream, Some(Protocol::Tcp))?;
+///     socket.set_only_v6(false)?;
+///     socket.set_reuse_port(true)?;
+///     socket.set_recv_buffer_size(1 << 20)?;
+///     socket.bind(&addr)?;
+///     socket.listen(1024)?;
+///
+///     let listener = TcpListener::from(socket);
+///     for stream in listener.incoming() {
+///         // ...
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "net_socket", issue = "none")]
+pub struct Socket(net_imp::GenericSocket);
+
+impl Socket {
+    /// Creates a new socket with the given domain, type and protocol.
+    ///
+    /// If `protocol` is `None`, the default protocol of the domain and type
+    /// is used, e.g. TCP for IPv4 stream sockets.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket)]
+    /// use std::net::{Domain, Socket, Type};
+    ///
+    /// let socket = Socket::new(Domain::Ipv4, Type::Datagram, None)
+    ///     .expect("couldn't create socket");
+    /// ```
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn new(domain: Domain, ty: Type, protocol: Option<Protocol>) -> io::Result<Socket> {
+        net_imp::GenericSocket::new(domain, ty, protocol).map(Socket)
+    }
+
+    /// Binds this socket to the specified address.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn bind(&self, addr: &SocketAddr) -> io::Result<()> {
+        self.0.bind(addr)
+    }
+
+    /// Marks this socket as ready to accept incoming connections, with a
+    /// queue of at most `backlog` pending connections.
+    ///
+    /// [`TcpListener::bind`] uses a backlog of 128.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn listen(&self, backlog: i32) -> io::Result<()> {
+        self.0.listen(backlog)
+    }
+
+    /// Connects this socket to the specified address.
+    ///
+    /// For datagram sockets, this sets the default destination of sent
+    /// datagrams and restricts received datagrams to that address.
+    ///
+    /// If the socket is in nonblocking mode, the connection may still be in
+    /// progress when this function returns with an error of kind
+    /// [`io::ErrorKind::WouldBlock`] or with the `EINPROGRESS` OS error.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn connect(&self, addr: &SocketAddr) -> io::Result<()> {
+        self.0.connect(addr)
+    }
+
+    /// Connects this socket to the specified address, failing if the
+    /// connection is not established within `timeout`.
+    ///
+    /// This has the same semantics as [`TcpStream::connect_timeout`]; the
+    /// socket is left in blocking mode once the connection is established.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn connect_timeout(&self, addr: &SocketAddr, timeout: Duration) -> io::Result<()> {
+        self.0.connect_timeout(addr, timeout)
+    }
+
+    /// Accepts a new incoming connection on this listening socket.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On Windows, the returned socket inherits the nonblocking mode of this
+    /// socket, whereas it is always in blocking mode on Unix. Use
+    /// [`accept_nonblocking`] or [`set_nonblocking`] to get a consistent
+    /// result.
+    ///
+    /// [`accept_nonblocking`]: Socket::accept_nonblocking
+    /// [`set_nonblocking`]: Socket::set_nonblocking
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn accept(&self) -> io::Result<(Socket, SocketAddr)> {
+        self.0.accept().map(|(socket, addr)| (Socket(socket), addr))
+    }
+
+    /// Accepts a new incoming connection on this listening socket and puts
+    /// the returned socket in nonblocking mode.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On platforms that support it, this uses `accept4` with the
+    /// `SOCK_NONBLOCK` flag, so that no separate system call is required to
+    /// change the mode of the accepted socket.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn accept_nonblocking(&self) -> io::Result<(Socket, SocketAddr)> {
+        self.0.accept_nonblocking().map(|(socket, addr)| (Socket(socket), addr))
+    }
+
+    /// Returns the local address this socket is bound to.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.0.socket_addr()
+    }
+
+    /// Returns the address of the remote peer this socket is connected to.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        self.0.peer_addr()
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// Options set on either handle affect both of them.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<Socket> {
+        self.0.duplicate().map(Socket)
+    }
+
+    /// Sets the value of the `SO_REUSEADDR` option on this socket.
+    ///
+    /// On Unix, this allows binding to an address that is still in use by a
+    /// connection in the `TIME_WAIT` state; [`TcpListener::bind`] sets it.
+    /// On Windows, this allows binding to an address that is actively in use
+    /// by another socket, which is almost never desired.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn set_reuse_address(&self, reuse: bool) -> io::Result<()> {
+        self.0.set_reuse_address(reuse)
+    }
+
+    /// Gets the value of the `SO_REUSEADDR` option on this socket.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn reuse_address(&self) -> io::Result<bool> {
+        self.0.reuse_address()
+    }
+
+    /// Sets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// This allows several sockets to be bound to the same address, and must
+    /// be set on all of them before they are bound. On Linux, incoming
+    /// connections and datagrams are then distributed among those sockets.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This option is not available on Windows and a few other platforms, on
+    /// which an error of kind [`io::ErrorKind::Unsupported`] is returned.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn set_reuse_port(&self, reuse: bool) -> io::Result<()> {
+        self.0.set_reuse_port(reuse)
+    }
+
+    /// Gets the value of the `SO_REUSEPORT` option on this socket.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn reuse_port(&self) -> io::Result<bool> {
+        self.0.reuse_port()
+    }
+
+    /// Sets the size of the receive buffer of this socket (`SO_RCVBUF`).
+    ///
+    /// The operating system may adjust the requested size; on Linux for
+    /// instance, the value reported by [`recv_buffer_size`] is doubled to
+    /// account for bookkeeping overhead.
+    ///
+    /// [`recv_buffer_size`]: Socket::recv_buffer_size
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Gets the size of the receive buffer of this socket (`SO_RCVBUF`).
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.0.recv_buffer_size()
+    }
+
+    /// Sets the size of the send buffer of this socket (`SO_SNDBUF`).
+    ///
+    /// As with [`set_recv_buffer_size`], the operating system may adjust the
+    /// requested size.
+    ///
+    /// [`set_recv_buffer_size`]: Socket::set_recv_buffer_size
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Gets the size of the send buffer of this socket (`SO_SNDBUF`).
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.0.send_buffer_size()
+    }
+
+    /// Enables or disables the periodic sending of keepalive probes on a
+    /// connection-oriented socket (`SO_KEEPALIVE`).
+    ///
+    /// The timing of the probes is controlled by [`set_keepalive_idle`],
+    /// [`set_keepalive_interval`] and [`set_keepalive_retries`].
+    ///
+    /// [`set_keepalive_idle`]: Socket::set_keepalive_idle
+    /// [`set_keepalive_interval`]: Socket::set_keepalive_interval
+    /// [`set_keepalive_retries`]: Socket::set_keepalive_retries
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn set_keepalive(&self, keepalive: bool) -> io::Result<()> {
+        self.0.set_keepalive(keepalive)
+    }
+
+    /// Gets the value of the `SO_KEEPALIVE` option on this socket.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn keepalive(&self) -> io::Result<bool> {
+        self.0.keepalive()
+    }
+
+    /// Sets how long a connection needs to be idle before the first keepalive
+    /// probe is sent (`TCP_KEEPIDLE`, or `TCP_KEEPALIVE` on Apple platforms).
+    ///
+    /// The duration is rounded down to whole seconds. An error of kind
+    /// [`io::ErrorKind::InvalidInput`] is returned if it is zero.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This option, as well as the keepalive interval and retry count, is
+    /// currently supported on Linux, Android, FreeBSD, Fuchsia, macOS, iOS and
+    /// Windows 10 version 1709 and later. On other platforms, an error of
+    /// kind [`io::ErrorKind::Unsupported`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(net_socket)]
+    /// use std::net::{Domain, Socket, TcpStream, Type};
+    /// use std::time::Duration;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let addr = "127.0.0.1:8080".parse().unwrap();
+    ///     let socket = Socket::new(Domain::for_address(&addr), Type::Stream, None)?;
+    ///     socket.set_keepalive(true)?;
+    ///     socket.set_keepalive_idle(Duration::from_secs(60))?;
+    ///     socket.set_keepalive_interval(Duration::from_secs(10))?;
+    ///     socket.set_keepalive_retries(5)?;
+    ///     socket.connect(&addr)?;
+    ///     let stream = TcpStream::from(socket);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn set_keepalive_idle(&self, idle: Duration) -> io::Result<()> {
+        self.0.set_keepalive_idle(idle)
+    }
+
+    /// Gets how long a connection needs to be idle before the first keepalive
+    /// probe is sent.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn keepalive_idle(&self) -> io::Result<Duration> {
+        self.0.keepalive_idle()
+    }
+
+    /// Sets the time between two keepalive probes (`TCP_KEEPINTVL`).
+    ///
+    /// The duration is rounded down to whole seconds. An error of kind
+    /// [`io::ErrorKind::InvalidInput`] is returned if it is zero.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        self.0.set_keepalive_interval(interval)
+    }
+
+    /// Gets the time between two keepalive probes.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn keepalive_interval(&self) -> io::Result<Duration> {
+        self.0.keepalive_interval()
+    }
+
+    /// Sets the number of unanswered keepalive probes after which the
+    /// connection is dropped (`TCP_KEEPCNT`).
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn set_keepalive_retries(&self, retries: u32) -> io::Result<()> {
+        self.0.set_keepalive_retries(retries)
+    }
+
+    /// Gets the number of unanswered keepalive probes after which the
+    /// connection is dropped.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn keepalive_retries(&self) -> io::Result<u32> {
+        self.0.keepalive_retries()
+    }
+
+    /// Sets the value of the `SO_LINGER` option on this socket.
+    ///
+    /// See [`TcpStream::set_linger`] for details.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn set_linger(&self, linger: Option<Duration>) -> io::Result<()> {
+        self.0.set_linger(linger)
+    }
+
+    /// Gets the value of the `SO_LINGER` option on this socket.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn linger(&self) -> io::Result<Option<Duration>> {
+        self.0.linger()
+    }
+
+    /// Sets the value of the `TCP_NODELAY` option on this socket.
+    ///
+    /// See [`TcpStream::set_nodelay`] for details.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        self.0.set_nodelay(nodelay)
+    }
+
+    /// Gets the value of the `TCP_NODELAY` option on this socket.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn nodelay(&self) -> io::Result<bool> {
+        self.0.nodelay()
+    }
+
+    /// Sets the value of the `IPV6_V6ONLY` option on this IPv6 socket.
+    ///
+    /// If this is set to `true`, the socket only communicates over IPv6. If
+    /// it is set to `false`, IPv4 peers can be reached through IPv4-mapped
+    /// IPv6 addresses as well. The default depends on the operating system,
+    /// and the option can only be changed before the socket is bound.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn set_only_v6(&self, only_v6: bool) -> io::Result<()> {
+        self.0.set_only_v6(only_v6)
+    }
+
+    /// Gets the value of the `IPV6_V6ONLY` option on this socket.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn only_v6(&self) -> io::Result<bool> {
+        self.0.only_v6()
+    }
+
+    /// Sets the value of the `IP_TTL` option on this socket.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn set_ttl(&self, ttl: u32) -> io::Result<()> {
+        self.0.set_ttl(ttl)
+    }
+
+    /// Gets the value of the `IP_TTL` option on this socket.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn ttl(&self) -> io::Result<u32> {
+        self.0.ttl()
+    }
+
+    /// Sets the value of the `SO_BROADCAST` option on this socket.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn set_broadcast(&self, broadcast: bool) -> io::Result<()> {
+        self.0.set_broadcast(broadcast)
+    }
+
+    /// Gets the value of the `SO_BROADCAST` option on this socket.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn broadcast(&self) -> io::Result<bool> {
+        self.0.broadcast()
+    }
+
+    /// Moves this socket into or out of nonblocking mode.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Gets the value of the `SO_ERROR` option on this socket, clearing it.
+    #[unstable(feature = "net_socket", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+}
+
+#[unstable(feature = "net_socket", issue = "none")]
+impl fmt::Debug for Socket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl AsInner<net_imp::GenericSocket> for Socket {
+    fn as_inner(&self) -> &net_imp::GenericSocket {
+        &self.0
+    }
+}
+
+impl FromInner<net_imp::GenericSocket> for Socket {
+    fn from_inner(inner: net_imp::GenericSocket) -> Socket {
+        Socket(inner)
+    }
+}
+
+impl IntoInner<net_imp::GenericSocket> for Socket {
+    fn into_inner(self) -> net_imp::GenericSocket {
+        self.0
+    }
+}
+
+#[unstable(feature = "net_socket", issue = "none")]
+impl From<Socket> for TcpStream {
+    /// Converts a connected [`Socket`] into a [`TcpStream`].
+    fn from(socket: Socket) -> TcpStream {
+        TcpStream::from_inner(socket.0.into())
+    }
+}
+
+#[unstable(feature = "net_socket", issue = "none")]
+impl From<Socket> for TcpListener {
+    /// Converts a listening [`Socket`] into a [`TcpListener`].
+    fn from(socket: Socket) -> TcpListener {
+        TcpListener::from_inner(socket.0.into())
+    }
+}
+
+#[unstable(feature = "net_socket", issue = "none")]
+impl From<Socket> for UdpSocket {
+    /// Converts a datagram [`Socket`] into a [`UdpSocket`].
+    fn from(socket: Socket) -> UdpSocket {
+        UdpSocket::from_inner(socket.0.into())
+    }
+}
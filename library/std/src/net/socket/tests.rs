@@ -0,0 +1,184 @@
+use crate::io::prelude::*;
+use crate::io::ErrorKind;
+use crate::net::test::{next_test_ip4, next_test_ip6};
+use crate::net::*;
+use crate::thread;
+use crate::time::Duration;
+
+fn each_ip(f: &mut dyn FnMut(SocketAddr)) {
+    f(next_test_ip4());
+    f(next_test_ip6());
+}
+
+macro_rules! t {
+    ($e:expr) => {
+        match $e {
+            Ok(t) => t,
+            Err(e) => panic!("received error for `{}`: {}", stringify!($e), e),
+        }
+    };
+}
+
+fn stream_socket(addr: &SocketAddr) -> Socket {
+    t!(Socket::new(Domain::for_address(addr), Type::Stream, Some(Protocol::Tcp)))
+}
+
+#[test]
+fn domain_for_address() {
+    assert_eq!(Domain::for_address(&next_test_ip4()), Domain::Ipv4);
+    assert_eq!(Domain::for_address(&next_test_ip6()), Domain::Ipv6);
+}
+
+#[test]
+fn tcp_listener_and_stream() {
+    each_ip(&mut |addr| {
+        let socket = stream_socket(&addr);
+        t!(socket.set_reuse_address(true));
+        t!(socket.bind(&addr));
+        t!(socket.listen(16));
+        assert_eq!(t!(socket.local_addr()), addr);
+        let listener = TcpListener::from(socket);
+
+        let _t = thread::spawn(move || {
+            let socket = stream_socket(&addr);
+            t!(socket.set_nodelay(true));
+            t!(socket.connect(&addr));
+            assert_eq!(t!(socket.peer_addr()), addr);
+            let mut stream = TcpStream::from(socket);
+            t!(stream.write_all(b"hello"));
+        });
+
+        let (mut stream, _) = t!(listener.accept());
+        let mut buf = String::new();
+        t!(stream.read_to_string(&mut buf));
+        assert_eq!(buf, "hello");
+    })
+}
+
+#[test]
+fn udp_socket() {
+    each_ip(&mut |addr| {
+        let server = t!(Socket::new(Domain::for_address(&addr), Type::Datagram, None));
+        t!(server.bind(&addr));
+        let server = UdpSocket::from(server);
+
+        let client = t!(Socket::new(Domain::for_address(&addr), Type::Datagram, None));
+        t!(client.connect(&addr));
+        let client = UdpSocket::from(client);
+        t!(client.send(b"ping"));
+
+        let mut buf = [0; 8];
+        let (n, from) = t!(server.recv_from(&mut buf));
+        assert_eq!(&buf[..n], b"ping");
+        assert_eq!(from, t!(client.local_addr()));
+    })
+}
+
+#[test]
+fn accept() {
+    each_ip(&mut |addr| {
+        let listener = stream_socket(&addr);
+        t!(listener.bind(&addr));
+        t!(listener.listen(16));
+
+        let _t = thread::spawn(move || {
+            let stream = t!(TcpStream::connect(&addr));
+            // keep the connection open until the other side is done
+            let _ = (&stream).read(&mut [0]);
+        });
+
+        let (socket, peer) = t!(listener.accept_nonblocking());
+        assert_eq!(t!(socket.local_addr()), addr);
+        assert_eq!(t!(socket.peer_addr()), peer);
+        let mut stream = TcpStream::from(socket);
+        match stream.read(&mut [0]) {
+            Err(e) => assert_eq!(e.kind(), ErrorKind::WouldBlock),
+            Ok(n) => panic!("unexpected read of {} bytes", n),
+        }
+        t!(stream.set_nonblocking(false));
+        t!(stream.write_all(b"x"));
+    })
+}
+
+#[test]
+fn options() {
+    each_ip(&mut |addr| {
+        let socket = stream_socket(&addr);
+
+        t!(socket.set_reuse_address(true));
+        assert!(t!(socket.reuse_address()));
+        t!(socket.set_reuse_address(false));
+        assert!(!t!(socket.reuse_address()));
+
+        t!(socket.set_nodelay(true));
+        assert!(t!(socket.nodelay()));
+
+        t!(socket.set_linger(Some(Duration::from_secs(3))));
+        assert_eq!(t!(socket.linger()), Some(Duration::from_secs(3)));
+        t!(socket.set_linger(None));
+        assert_eq!(t!(socket.linger()), None);
+
+        t!(socket.set_recv_buffer_size(1 << 16));
+        assert!(t!(socket.recv_buffer_size()) >= 1 << 16);
+        t!(socket.set_send_buffer_size(1 << 16));
+        assert!(t!(socket.send_buffer_size()) >= 1 << 16);
+
+        t!(socket.set_keepalive(true));
+        assert!(t!(socket.keepalive()));
+
+        if let SocketAddr::V4(..) = addr {
+            t!(socket.set_ttl(42));
+            assert_eq!(t!(socket.ttl()), 42);
+        } else {
+            t!(socket.set_only_v6(true));
+            assert!(t!(socket.only_v6()));
+            t!(socket.set_only_v6(false));
+            assert!(!t!(socket.only_v6()));
+        }
+
+        let socket = t!(Socket::new(Domain::for_address(&addr), Type::Datagram, None));
+        t!(socket.set_broadcast(true));
+        assert!(t!(socket.broadcast()));
+    })
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+fn keepalive_params() {
+    let socket = stream_socket(&next_test_ip4());
+    t!(socket.set_keepalive(true));
+    t!(socket.set_keepalive_idle(Duration::from_secs(30)));
+    assert_eq!(t!(socket.keepalive_idle()), Duration::from_secs(30));
+    t!(socket.set_keepalive_interval(Duration::from_millis(5500)));
+    assert_eq!(t!(socket.keepalive_interval()), Duration::from_secs(5));
+    t!(socket.set_keepalive_retries(4));
+    assert_eq!(t!(socket.keepalive_retries()), 4);
+
+    let err = socket.set_keepalive_idle(Duration::from_millis(999)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+fn reuse_port() {
+    let addr = next_test_ip4();
+    let bind = || {
+        let socket = t!(Socket::new(Domain::Ipv4, Type::Datagram, None));
+        t!(socket.set_reuse_port(true));
+        assert!(t!(socket.reuse_port()));
+        t!(socket.bind(&addr));
+        socket
+    };
+    let _first = bind();
+    let _second = bind();
+}
+
+#[test]
+fn debug() {
+    let addr = next_test_ip4();
+    let socket = stream_socket(&addr);
+    t!(socket.bind(&addr));
+    let debug = format!("{:?}", socket);
+    assert!(debug.starts_with("Socket"), "{}", debug);
+    assert!(debug.contains(&addr.to_string()), "{}", debug);
+}
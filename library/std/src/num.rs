@@ -27,6 +27,9 @@
 #[stable(feature = "int_error_matching", since = "1.55.0")]
 pub use core::num::IntErrorKind;
 
+#[unstable(feature = "int_traits", issue = "none")]
+pub use core::num::{Integer, SignedInteger, UnsignedInteger};
+
 #[cfg(test)]
 use crate::fmt;
 #[cfg(test)]
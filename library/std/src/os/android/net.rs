@@ -0,0 +1,6 @@
+//! Android-specific networking functionality.
+
+#![unstable(feature = "unix_socket_abstract", issue = "85410")]
+
+#[unstable(feature = "unix_socket_abstract", issue = "85410")]
+pub use crate::os::net::linux_ext::SocketAddrExt;
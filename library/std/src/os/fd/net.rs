@@ -44,3 +44,34 @@ fn into_raw_fd(self) -> RawFd {
     )*};
 }
 impl_into_raw_fd! { TcpStream TcpListener UdpSocket }
+
+#[cfg(unix)]
+#[unstable(feature = "net_socket", issue = "none")]
+impl AsRawFd for net::Socket {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.as_inner().socket().as_raw_fd()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "net_socket", issue = "none")]
+impl FromRawFd for net::Socket {
+    #[inline]
+    unsafe fn from_raw_fd(fd: RawFd) -> net::Socket {
+        unsafe {
+            let socket =
+                sys::net::Socket::from_inner(FromInner::from_inner(OwnedFd::from_raw_fd(fd)));
+            net::Socket::from_inner(sys_common::net::GenericSocket::from_inner(socket))
+        }
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "net_socket", issue = "none")]
+impl IntoRawFd for net::Socket {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.into_inner().into_socket().into_inner().into_inner().into_raw_fd()
+    }
+}
@@ -287,3 +287,86 @@ fn from(owned_fd: OwnedFd) -> Self {
         ))))
     }
 }
+
+#[cfg(unix)]
+#[unstable(feature = "net_socket", issue = "none")]
+impl AsFd for crate::net::Socket {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.as_inner().socket().as_fd()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "net_socket", issue = "none")]
+impl From<crate::net::Socket> for OwnedFd {
+    #[inline]
+    fn from(socket: crate::net::Socket) -> OwnedFd {
+        socket.into_inner().into_socket().into_inner().into_inner().into()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "net_socket", issue = "none")]
+impl From<OwnedFd> for crate::net::Socket {
+    #[inline]
+    fn from(owned_fd: OwnedFd) -> Self {
+        Self::from_inner(FromInner::from_inner(FromInner::from_inner(FromInner::from_inner(
+            owned_fd,
+        ))))
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsFd for crate::io::PipeReader {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.as_inner().as_fd()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<crate::io::PipeReader> for OwnedFd {
+    #[inline]
+    fn from(pipe_reader: crate::io::PipeReader) -> OwnedFd {
+        pipe_reader.into_inner().into_inner().into_inner()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<OwnedFd> for crate::io::PipeReader {
+    #[inline]
+    fn from(owned_fd: OwnedFd) -> Self {
+        Self::from_inner(FromInner::from_inner(FromInner::from_inner(owned_fd)))
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsFd for crate::io::PipeWriter {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.as_inner().as_fd()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<crate::io::PipeWriter> for OwnedFd {
+    #[inline]
+    fn from(pipe_writer: crate::io::PipeWriter) -> OwnedFd {
+        pipe_writer.into_inner().into_inner().into_inner()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<OwnedFd> for crate::io::PipeWriter {
+    #[inline]
+    fn from(owned_fd: OwnedFd) -> Self {
+        Self::from_inner(FromInner::from_inner(FromInner::from_inner(owned_fd)))
+    }
+}
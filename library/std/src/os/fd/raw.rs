@@ -208,3 +208,53 @@ fn as_raw_fd(&self) -> RawFd {
         libc::STDERR_FILENO
     }
 }
+
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsRawFd for io::PipeReader {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.as_inner().as_raw_fd()
+    }
+}
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl FromRawFd for io::PipeReader {
+    #[inline]
+    unsafe fn from_raw_fd(fd: RawFd) -> io::PipeReader {
+        unsafe { io::PipeReader::from(OwnedFd::from_raw_fd(fd)) }
+    }
+}
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl IntoRawFd for io::PipeReader {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.into_inner().into_inner().into_raw_fd()
+    }
+}
+
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsRawFd for io::PipeWriter {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.as_inner().as_raw_fd()
+    }
+}
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl FromRawFd for io::PipeWriter {
+    #[inline]
+    unsafe fn from_raw_fd(fd: RawFd) -> io::PipeWriter {
+        unsafe { io::PipeWriter::from(OwnedFd::from_raw_fd(fd)) }
+    }
+}
+#[cfg(unix)]
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl IntoRawFd for io::PipeWriter {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.into_inner().into_inner().into_raw_fd()
+    }
+}
@@ -0,0 +1,555 @@
+//! Linux-specific zero-copy and vectored I/O primitives.
+//!
+//! These functions expose the `sendfile(2)`, `splice(2)`, `tee(2)`,
+//! `copy_file_range(2)`, `preadv2(2)` and `pwritev2(2)` system calls on top of
+//! any type implementing [`AsFd`], such as [`File`], [`TcpStream`],
+//! [`UnixStream`] or the stdio handles of a child process. They allow moving
+//! data between file descriptors without copying it through user-space
+//! buffers.
+//!
+//! [`std::io::copy`] already uses these system calls internally where it can;
+//! this module is meant for callers that need precise control over offsets,
+//! lengths and flags.
+//!
+//! [`File`]: crate::fs::File
+//! [`TcpStream`]: crate::net::TcpStream
+//! [`UnixStream`]: crate::os::unix::net::UnixStream
+//! [`std::io::copy`]: crate::io::copy
+
+#![unstable(feature = "linux_zero_copy", issue = "none")]
+
+#[cfg(test)]
+mod tests;
+
+use crate::cmp;
+use crate::convert::TryFrom;
+use crate::fmt;
+use crate::io::{self, IoSlice, IoSliceMut};
+use crate::ops::{BitOr, BitOrAssign};
+use crate::os::unix::io::{AsFd, AsRawFd};
+use crate::ptr;
+use crate::sys::cvt;
+use crate::sys::weak::syscall;
+
+// The maximum number of bytes a single `sendfile`, `splice` or
+// `copy_file_range` call will transfer, see sendfile(2).
+const MAX_CHUNK: usize = 0x7fff_f000;
+
+/// Flags accepted by [`splice`] and [`tee`].
+///
+/// Flags can be combined with the `|` operator.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SpliceFlags(libc::c_uint);
+
+impl SpliceFlags {
+    /// Attempt to move pages instead of copying (`SPLICE_F_MOVE`).
+    ///
+    /// This is only a hint to the kernel.
+    pub const MOVE: SpliceFlags = SpliceFlags(libc::SPLICE_F_MOVE);
+
+    /// Do not block on I/O (`SPLICE_F_NONBLOCK`).
+    ///
+    /// This makes the pipe operations non-blocking, but the call may still
+    /// block on the other file descriptor unless it was opened in
+    /// non-blocking mode as well.
+    pub const NONBLOCK: SpliceFlags = SpliceFlags(libc::SPLICE_F_NONBLOCK);
+
+    /// More data will be coming in a subsequent splice (`SPLICE_F_MORE`).
+    ///
+    /// This is a helpful hint when the output is a socket.
+    pub const MORE: SpliceFlags = SpliceFlags(libc::SPLICE_F_MORE);
+
+    /// Unused for `splice` and `tee`, see vmsplice(2) (`SPLICE_F_GIFT`).
+    pub const GIFT: SpliceFlags = SpliceFlags(libc::SPLICE_F_GIFT);
+
+    /// Returns a set containing no flags.
+    pub const fn empty() -> SpliceFlags {
+        SpliceFlags(0)
+    }
+
+    /// Returns the raw bits of this set of flags.
+    pub const fn bits(self) -> u32 {
+        self.0 as u32
+    }
+
+    /// Returns `true` if all flags in `other` are also set in `self`.
+    pub const fn contains(self, other: SpliceFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for SpliceFlags {
+    type Output = SpliceFlags;
+
+    fn bitor(self, rhs: SpliceFlags) -> SpliceFlags {
+        SpliceFlags(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for SpliceFlags {
+    fn bitor_assign(&mut self, rhs: SpliceFlags) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl fmt::Debug for SpliceFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SpliceFlags").field(&format_args!("{:#x}", self.0)).finish()
+    }
+}
+
+/// Flags accepted by [`preadv2`] and [`pwritev2`].
+///
+/// Flags can be combined with the `|` operator. Flags which are not supported
+/// by the running kernel make the call fail with `EOPNOTSUPP`.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RwFlags(libc::c_int);
+
+impl RwFlags {
+    /// High priority read/write (`RWF_HIPRI`), available since Linux 4.6.
+    ///
+    /// Allows block-based filesystems to use polling of the device, which
+    /// provides lower latency but may use additional resources.
+    pub const HIPRI: RwFlags = RwFlags(0x01);
+
+    /// Per-I/O equivalent of `O_DSYNC` (`RWF_DSYNC`), available since Linux 4.7.
+    pub const DSYNC: RwFlags = RwFlags(0x02);
+
+    /// Per-I/O equivalent of `O_SYNC` (`RWF_SYNC`), available since Linux 4.7.
+    pub const SYNC: RwFlags = RwFlags(0x04);
+
+    /// Do not wait for data which is not immediately available (`RWF_NOWAIT`),
+    /// available since Linux 4.14.
+    ///
+    /// Only meaningful for [`preadv2`]; the call fails with `EAGAIN` instead
+    /// of blocking.
+    pub const NOWAIT: RwFlags = RwFlags(0x08);
+
+    /// Per-I/O equivalent of `O_APPEND` (`RWF_APPEND`), available since Linux 4.16.
+    ///
+    /// Only meaningful for [`pwritev2`]; the offset argument does not affect
+    /// where data is written.
+    pub const APPEND: RwFlags = RwFlags(0x10);
+
+    /// Returns a set containing no flags.
+    pub const fn empty() -> RwFlags {
+        RwFlags(0)
+    }
+
+    /// Returns the raw bits of this set of flags.
+    pub const fn bits(self) -> u32 {
+        self.0 as u32
+    }
+
+    /// Returns `true` if all flags in `other` are also set in `self`.
+    pub const fn contains(self, other: RwFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for RwFlags {
+    type Output = RwFlags;
+
+    fn bitor(self, rhs: RwFlags) -> RwFlags {
+        RwFlags(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for RwFlags {
+    fn bitor_assign(&mut self, rhs: RwFlags) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl fmt::Debug for RwFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RwFlags").field(&format_args!("{:#x}", self.0)).finish()
+    }
+}
+
+fn to_offset<T: TryFrom<u64>>(offset: u64) -> io::Result<T> {
+    T::try_from(offset).map_err(|_| {
+        io::Error::new_const(io::ErrorKind::InvalidInput, &"offset does not fit into off_t")
+    })
+}
+
+/// Copies up to `count` bytes from `input` to `output` using `sendfile(2)`.
+///
+/// `input` must support `mmap`-like operations, i.e. it cannot be a socket.
+/// If `offset` is `Some`, data is read starting at that offset without
+/// changing the file position of `input`, and the offset is advanced by the
+/// number of bytes transferred. If `offset` is `None`, data is read from the
+/// current file position of `input`, which is updated accordingly.
+///
+/// Returns the number of bytes written to `output`, which may be less than
+/// `count`; `0` indicates the end of `input` was reached. At most
+/// `0x7ffff000` bytes are transferred per call.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(linux_zero_copy)]
+/// use std::fs::File;
+/// use std::net::TcpStream;
+/// use std::os::linux::io::sendfile;
+///
+/// fn main() -> std::io::Result<()> {
+///     let file = File::open("index.html")?;
+///     let stream = TcpStream::connect("127.0.0.1:8080")?;
+///     let len = file.metadata()?.len();
+///
+///     let mut offset = 0;
+///     while offset < len {
+///         let remaining = (len - offset) as usize;
+///         if sendfile(&stream, &file, Some(&mut offset), remaining)? == 0 {
+///             break;
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn sendfile<O, I>(
+    output: &O,
+    input: &I,
+    offset: Option<&mut u64>,
+    count: usize,
+) -> io::Result<usize>
+where
+    O: AsFd + ?Sized,
+    I: AsFd + ?Sized,
+{
+    let output = output.as_fd().as_raw_fd();
+    let input = input.as_fd().as_raw_fd();
+    let count = cmp::min(count, MAX_CHUNK);
+    match offset {
+        Some(offset) => {
+            let mut off: libc::off64_t = to_offset(*offset)?;
+            let ret = cvt(unsafe { libc::sendfile64(output, input, &mut off, count) })?;
+            *offset = off as u64;
+            Ok(ret as usize)
+        }
+        None => {
+            let ret = cvt(unsafe { libc::sendfile64(output, input, ptr::null_mut(), count) })?;
+            Ok(ret as usize)
+        }
+    }
+}
+
+/// Moves up to `len` bytes from `input` to `output` using `splice(2)`.
+///
+/// At least one of `input` and `output` must refer to a pipe. The offset of
+/// a descriptor that refers to a pipe must be `None`. For the other
+/// descriptor, `Some` selects an explicit offset which is advanced by the
+/// number of bytes transferred, leaving the file position untouched, while
+/// `None` uses and updates the file position.
+///
+/// Returns the number of bytes moved; `0` indicates there was no data to
+/// transfer and blocking would not make sense, e.g. the write end of the
+/// input pipe was closed.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(io_safety, linux_zero_copy)]
+/// use std::io;
+/// use std::net::TcpStream;
+/// use std::os::linux::io::{splice, SpliceFlags};
+/// use std::os::unix::io::OwnedFd;
+///
+/// // Forwards all data from `from` to `to` through an intermediate pipe.
+/// fn forward(from: &TcpStream, to: &TcpStream, pipe: &(OwnedFd, OwnedFd)) -> io::Result<()> {
+///     let (pipe_read, pipe_write) = pipe;
+///     loop {
+///         let n = splice(from, None, pipe_write, None, 65536, SpliceFlags::MOVE)?;
+///         if n == 0 {
+///             return Ok(());
+///         }
+///         let mut left = n;
+///         while left > 0 {
+///             left -= splice(pipe_read, None, to, None, left, SpliceFlags::MOVE)?;
+///         }
+///     }
+/// }
+/// ```
+pub fn splice<I, O>(
+    input: &I,
+    off_in: Option<&mut u64>,
+    output: &O,
+    off_out: Option<&mut u64>,
+    len: usize,
+    flags: SpliceFlags,
+) -> io::Result<usize>
+where
+    I: AsFd + ?Sized,
+    O: AsFd + ?Sized,
+{
+    syscall! {
+        fn splice(
+            fd_in: libc::c_int,
+            off_in: *mut libc::loff_t,
+            fd_out: libc::c_int,
+            off_out: *mut libc::loff_t,
+            len: libc::size_t,
+            flags: libc::c_uint
+        ) -> libc::ssize_t
+    }
+
+    let mut in_pos: Option<libc::loff_t> = off_in.as_deref().copied().map(to_offset).transpose()?;
+    let mut out_pos: Option<libc::loff_t> =
+        off_out.as_deref().copied().map(to_offset).transpose()?;
+    let ret = cvt(unsafe {
+        splice(
+            input.as_fd().as_raw_fd(),
+            in_pos.as_mut().map_or(ptr::null_mut(), |p| p),
+            output.as_fd().as_raw_fd(),
+            out_pos.as_mut().map_or(ptr::null_mut(), |p| p),
+            cmp::min(len, MAX_CHUNK),
+            flags.0,
+        )
+    })?;
+    if let (Some(off), Some(pos)) = (off_in, in_pos) {
+        *off = pos as u64;
+    }
+    if let (Some(off), Some(pos)) = (off_out, out_pos) {
+        *off = pos as u64;
+    }
+    Ok(ret as usize)
+}
+
+/// Duplicates up to `len` bytes from the pipe `input` to the pipe `output`
+/// using `tee(2)`.
+///
+/// Unlike [`splice`], the data is not consumed from `input` and can still be
+/// read from it afterwards. Both descriptors must refer to pipes.
+///
+/// Returns the number of bytes duplicated; `0` indicates there was no data to
+/// duplicate and the write end of `input` was closed.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(io_safety, linux_zero_copy)]
+/// use std::os::linux::io::{tee, SpliceFlags};
+/// use std::os::unix::io::OwnedFd;
+///
+/// fn mirror(source: &OwnedFd, copy: &OwnedFd) -> std::io::Result<usize> {
+///     tee(source, copy, usize::MAX, SpliceFlags::NONBLOCK)
+/// }
+/// ```
+pub fn tee<I, O>(input: &I, output: &O, len: usize, flags: SpliceFlags) -> io::Result<usize>
+where
+    I: AsFd + ?Sized,
+    O: AsFd + ?Sized,
+{
+    syscall! {
+        fn tee(
+            fd_in: libc::c_int,
+            fd_out: libc::c_int,
+            len: libc::size_t,
+            flags: libc::c_uint
+        ) -> libc::ssize_t
+    }
+
+    let (input, output) = (input.as_fd().as_raw_fd(), output.as_fd().as_raw_fd());
+    let ret = cvt(unsafe { tee(input, output, cmp::min(len, MAX_CHUNK), flags.0) })?;
+    Ok(ret as usize)
+}
+
+/// Copies up to `len` bytes between two files using `copy_file_range(2)`.
+///
+/// The offsets behave like those of [`splice`]: `Some` selects an explicit
+/// offset which is advanced by the number of bytes copied, leaving the file
+/// position untouched, while `None` uses and updates the file position.
+/// Depending on the filesystem, the kernel may share the data blocks between
+/// both files (reflink) or perform the copy server-side.
+///
+/// Returns the number of bytes copied; `0` indicates the offset of `input`
+/// is at or past the end of the file.
+///
+/// This function requires Linux 4.5 and fails with `ENOSYS` on older
+/// kernels. Kernels before 5.3 additionally return `EXDEV` when the files
+/// reside on different filesystems.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(linux_zero_copy)]
+/// use std::fs::{File, OpenOptions};
+/// use std::os::linux::io::copy_file_range;
+///
+/// fn main() -> std::io::Result<()> {
+///     let input = File::open("foo.txt")?;
+///     let output = OpenOptions::new().write(true).create(true).open("bar.txt")?;
+///
+///     // Copy 16 bytes starting at offset 128 of `foo.txt` to the start of `bar.txt`.
+///     let (mut off_in, mut off_out) = (128, 0);
+///     copy_file_range(&input, Some(&mut off_in), &output, Some(&mut off_out), 16)?;
+///     Ok(())
+/// }
+/// ```
+pub fn copy_file_range<I, O>(
+    input: &I,
+    off_in: Option<&mut u64>,
+    output: &O,
+    off_out: Option<&mut u64>,
+    len: usize,
+) -> io::Result<usize>
+where
+    I: AsFd + ?Sized,
+    O: AsFd + ?Sized,
+{
+    syscall! {
+        fn copy_file_range(
+            fd_in: libc::c_int,
+            off_in: *mut libc::loff_t,
+            fd_out: libc::c_int,
+            off_out: *mut libc::loff_t,
+            len: libc::size_t,
+            flags: libc::c_uint
+        ) -> libc::ssize_t
+    }
+
+    let mut in_pos: Option<libc::loff_t> = off_in.as_deref().copied().map(to_offset).transpose()?;
+    let mut out_pos: Option<libc::loff_t> =
+        off_out.as_deref().copied().map(to_offset).transpose()?;
+    let ret = cvt(unsafe {
+        copy_file_range(
+            input.as_fd().as_raw_fd(),
+            in_pos.as_mut().map_or(ptr::null_mut(), |p| p),
+            output.as_fd().as_raw_fd(),
+            out_pos.as_mut().map_or(ptr::null_mut(), |p| p),
+            cmp::min(len, MAX_CHUNK),
+            0,
+        )
+    })?;
+    if let (Some(off), Some(pos)) = (off_in, in_pos) {
+        *off = pos as u64;
+    }
+    if let (Some(off), Some(pos)) = (off_out, out_pos) {
+        *off = pos as u64;
+    }
+    Ok(ret as usize)
+}
+
+// Splits the offset into the `pos_l`/`pos_h` pair expected by the raw
+// preadv2/pwritev2 syscalls. On 64-bit targets the kernel ignores `pos_h`.
+// An offset of `-1` makes the kernel use and update the file position.
+fn split_offset(offset: Option<u64>) -> io::Result<(libc::c_long, libc::c_long)> {
+    let pos: i64 = match offset {
+        Some(offset) => to_offset(offset)?,
+        None => -1,
+    };
+    Ok((pos as libc::c_long, (pos >> 32) as libc::c_long))
+}
+
+/// Reads from `fd` into multiple buffers using `preadv2(2)`.
+///
+/// If `offset` is `Some`, data is read starting at that offset and the file
+/// position is left untouched, like [`FileExt::read_at`]. If `offset` is
+/// `None`, data is read from the current file position, which is updated
+/// accordingly; this is also the only option for descriptors that are not
+/// seekable, such as sockets and pipes.
+///
+/// The system call is issued directly, bypassing the C library, since older
+/// C libraries do not provide a wrapper for it. This function requires
+/// Linux 4.6 and fails with `ENOSYS` on older kernels.
+///
+/// [`FileExt::read_at`]: crate::os::unix::fs::FileExt::read_at
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(linux_zero_copy)]
+/// use std::fs::File;
+/// use std::io::IoSliceMut;
+/// use std::os::linux::io::{preadv2, RwFlags};
+///
+/// fn main() -> std::io::Result<()> {
+///     let file = File::open("foo.txt")?;
+///     let (mut header, mut body) = ([0; 8], [0; 64]);
+///     let mut bufs = [IoSliceMut::new(&mut header), IoSliceMut::new(&mut body)];
+///
+///     // Only read data that is already in the page cache.
+///     let n = preadv2(&file, &mut bufs, Some(0), RwFlags::NOWAIT)?;
+///     println!("read {} bytes", n);
+///     Ok(())
+/// }
+/// ```
+pub fn preadv2<F>(
+    fd: &F,
+    bufs: &mut [IoSliceMut<'_>],
+    offset: Option<u64>,
+    flags: RwFlags,
+) -> io::Result<usize>
+where
+    F: AsFd + ?Sized,
+{
+    let (pos_l, pos_h) = split_offset(offset)?;
+    let ret = cvt(unsafe {
+        libc::syscall(
+            libc::SYS_preadv2,
+            fd.as_fd().as_raw_fd(),
+            bufs.as_ptr() as *const libc::iovec,
+            cmp::min(bufs.len(), libc::UIO_MAXIOV as usize) as libc::c_int,
+            pos_l,
+            pos_h,
+            flags.0,
+        )
+    })?;
+    Ok(ret as usize)
+}
+
+/// Writes to `fd` from multiple buffers using `pwritev2(2)`.
+///
+/// If `offset` is `Some`, data is written starting at that offset and the
+/// file position is left untouched, like [`FileExt::write_at`]. If `offset`
+/// is `None`, data is written at the current file position, which is updated
+/// accordingly; this is also the only option for descriptors that are not
+/// seekable, such as sockets and pipes.
+///
+/// The system call is issued directly, bypassing the C library, since older
+/// C libraries do not provide a wrapper for it. This function requires
+/// Linux 4.6 and fails with `ENOSYS` on older kernels.
+///
+/// [`FileExt::write_at`]: crate::os::unix::fs::FileExt::write_at
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(linux_zero_copy)]
+/// use std::fs::OpenOptions;
+/// use std::io::IoSlice;
+/// use std::os::linux::io::{pwritev2, RwFlags};
+///
+/// fn main() -> std::io::Result<()> {
+///     let log = OpenOptions::new().write(true).create(true).open("journal.log")?;
+///     let bufs = [IoSlice::new(b"entry: "), IoSlice::new(b"committed\n")];
+///
+///     // Append the record and make sure it reached the disk before returning.
+///     pwritev2(&log, &bufs, None, RwFlags::APPEND | RwFlags::DSYNC)?;
+///     Ok(())
+/// }
+/// ```
+pub fn pwritev2<F>(
+    fd: &F,
+    bufs: &[IoSlice<'_>],
+    offset: Option<u64>,
+    flags: RwFlags,
+) -> io::Result<usize>
+where
+    F: AsFd + ?Sized,
+{
+    let (pos_l, pos_h) = split_offset(offset)?;
+    let ret = cvt(unsafe {
+        libc::syscall(
+            libc::SYS_pwritev2,
+            fd.as_fd().as_raw_fd(),
+            bufs.as_ptr() as *const libc::iovec,
+            cmp::min(bufs.len(), libc::UIO_MAXIOV as usize) as libc::c_int,
+            pos_l,
+            pos_h,
+            flags.0,
+        )
+    })?;
+    Ok(ret as usize)
+}
@@ -0,0 +1,172 @@
+use super::*;
+use crate::fs::{File, OpenOptions};
+use crate::io::{Read, Seek, SeekFrom, Write};
+use crate::os::unix::io::FromRawFd;
+use crate::os::unix::net::UnixStream;
+use crate::sys_common::io::test::tmpdir;
+
+fn pipe() -> (File, File) {
+    let mut fds = [0; 2];
+    cvt(unsafe { libc::pipe2(fds.as_mut_ptr(), libc::O_CLOEXEC) }).unwrap();
+    unsafe { (File::from_raw_fd(fds[0]), File::from_raw_fd(fds[1])) }
+}
+
+fn file_with(path: &crate::path::Path, contents: &[u8]) -> File {
+    let mut file =
+        OpenOptions::new().read(true).write(true).create(true).truncate(true).open(path).unwrap();
+    file.write_all(contents).unwrap();
+    file.seek(SeekFrom::Start(0)).unwrap();
+    file
+}
+
+fn is_enosys(err: &io::Error) -> bool {
+    err.raw_os_error() == Some(libc::ENOSYS)
+}
+
+#[test]
+fn copy_file_range_offsets() {
+    let tmp = tmpdir();
+    let mut source = file_with(&tmp.join("source"), b"0123456789");
+    let mut sink = file_with(&tmp.join("sink"), b"abcdefgh");
+
+    let (mut off_in, mut off_out) = (2, 3);
+    match copy_file_range(&source, Some(&mut off_in), &sink, Some(&mut off_out), 4) {
+        Ok(copied) => assert_eq!(copied, 4),
+        Err(ref e) if is_enosys(e) => return,
+        Err(e) => panic!("copy_file_range failed: {}", e),
+    }
+    assert_eq!((off_in, off_out), (6, 7));
+
+    // explicit offsets leave the file positions alone
+    assert_eq!(source.seek(SeekFrom::Current(0)).unwrap(), 0);
+    assert_eq!(sink.seek(SeekFrom::Current(0)).unwrap(), 0);
+
+    // without offsets the file positions are used and advanced
+    source.seek(SeekFrom::Start(8)).unwrap();
+    sink.seek(SeekFrom::Start(0)).unwrap();
+    assert_eq!(copy_file_range(&source, None, &sink, None, 100).unwrap(), 2);
+    assert_eq!(copy_file_range(&source, None, &sink, None, 100).unwrap(), 0);
+    assert_eq!(source.seek(SeekFrom::Current(0)).unwrap(), 10);
+
+    let mut contents = String::new();
+    sink.seek(SeekFrom::Start(0)).unwrap();
+    sink.read_to_string(&mut contents).unwrap();
+    assert_eq!(contents, "89c2345h");
+}
+
+#[test]
+fn sendfile_to_socket() {
+    let tmp = tmpdir();
+    let mut file = file_with(&tmp.join("sendfile"), b"hello world");
+    let (tx, mut rx) = UnixStream::pair().unwrap();
+
+    let mut offset = 6;
+    assert_eq!(sendfile(&tx, &file, Some(&mut offset), 100).unwrap(), 5);
+    assert_eq!(offset, 11);
+    assert_eq!(file.seek(SeekFrom::Current(0)).unwrap(), 0);
+
+    assert_eq!(sendfile(&tx, &file, None, 5).unwrap(), 5);
+    assert_eq!(file.seek(SeekFrom::Current(0)).unwrap(), 5);
+    drop(tx);
+
+    let mut received = String::new();
+    rx.read_to_string(&mut received).unwrap();
+    assert_eq!(received, "worldhello");
+}
+
+#[test]
+fn sendfile_past_4gib() {
+    // offsets beyond `i32::MAX` must not be truncated on 32-bit targets
+    let tmp = tmpdir();
+    let mut file = file_with(&tmp.join("sparse"), b"");
+    let start = 5 << 30;
+    file.seek(SeekFrom::Start(start)).unwrap();
+    file.write_all(b"tail").unwrap();
+    let (tx, mut rx) = UnixStream::pair().unwrap();
+
+    let mut offset = start;
+    assert_eq!(sendfile(&tx, &file, Some(&mut offset), 100).unwrap(), 4);
+    assert_eq!(offset, start + 4);
+    drop(tx);
+
+    let mut received = String::new();
+    rx.read_to_string(&mut received).unwrap();
+    assert_eq!(received, "tail");
+}
+
+#[test]
+fn splice_and_tee_through_pipes() {
+    let tmp = tmpdir();
+    let file = file_with(&tmp.join("splice"), b"zero copy");
+    let (first_rx, first_tx) = pipe();
+    let (mut second_rx, second_tx) = pipe();
+    let (tx, mut rx) = UnixStream::pair().unwrap();
+
+    let mut offset = 5;
+    let moved = splice(&file, Some(&mut offset), &first_tx, None, 100, SpliceFlags::MOVE).unwrap();
+    assert_eq!(moved, 4);
+    assert_eq!(offset, 9);
+
+    assert_eq!(tee(&first_rx, &second_tx, 100, SpliceFlags::NONBLOCK).unwrap(), 4);
+    assert_eq!(splice(&first_rx, None, &tx, None, 100, SpliceFlags::empty()).unwrap(), 4);
+    drop(tx);
+    drop(second_tx);
+
+    let mut received = String::new();
+    rx.read_to_string(&mut received).unwrap();
+    assert_eq!(received, "copy");
+    received.clear();
+    second_rx.read_to_string(&mut received).unwrap();
+    assert_eq!(received, "copy");
+
+    // pipes cannot be used with explicit offsets
+    let mut offset = 0;
+    let err = splice(&first_rx, Some(&mut offset), &file, None, 1, SpliceFlags::empty());
+    assert_eq!(err.unwrap_err().raw_os_error(), Some(libc::ESPIPE));
+}
+
+#[test]
+fn vectored_with_flags() {
+    let tmp = tmpdir();
+    let mut file = file_with(&tmp.join("rwv2"), b"");
+
+    let bufs = [IoSlice::new(b"abc"), IoSlice::new(b"defg")];
+    match pwritev2(&file, &bufs, Some(2), RwFlags::DSYNC) {
+        Ok(written) => assert_eq!(written, 7),
+        // kernel < 4.6 or < 4.7 respectively
+        Err(ref e) if is_enosys(e) || e.raw_os_error() == Some(libc::EOPNOTSUPP) => return,
+        Err(e) => panic!("pwritev2 failed: {}", e),
+    }
+    assert_eq!(file.seek(SeekFrom::Current(0)).unwrap(), 0);
+
+    let (mut first, mut second) = ([0; 4], [0; 8]);
+    let mut bufs = [IoSliceMut::new(&mut first), IoSliceMut::new(&mut second)];
+    assert_eq!(preadv2(&file, &mut bufs, Some(1), RwFlags::empty()).unwrap(), 8);
+    assert_eq!(&first, b"\0abc");
+    assert_eq!(&second[..4], b"defg");
+
+    // without an offset the file position is used and advanced
+    let bufs = [IoSlice::new(b"xy")];
+    assert_eq!(pwritev2(&file, &bufs, None, RwFlags::empty()).unwrap(), 2);
+    assert_eq!(file.seek(SeekFrom::Current(0)).unwrap(), 2);
+    let mut buf = [0; 16];
+    let mut bufs = [IoSliceMut::new(&mut buf)];
+    assert_eq!(preadv2(&file, &mut bufs, None, RwFlags::empty()).unwrap(), 7);
+    assert_eq!(&buf[..7], b"abcdefg");
+    assert_eq!(file.seek(SeekFrom::Current(0)).unwrap(), 9);
+}
+
+#[test]
+fn flags_ops() {
+    let mut flags = SpliceFlags::MOVE | SpliceFlags::MORE;
+    assert!(flags.contains(SpliceFlags::MOVE));
+    assert!(!flags.contains(SpliceFlags::NONBLOCK));
+    flags |= SpliceFlags::NONBLOCK;
+    assert!(flags.contains(SpliceFlags::MOVE | SpliceFlags::NONBLOCK));
+    assert_eq!(SpliceFlags::default(), SpliceFlags::empty());
+
+    let flags = RwFlags::HIPRI | RwFlags::NOWAIT;
+    assert_eq!(flags.bits(), 0x09);
+    assert!(flags.contains(RwFlags::NOWAIT));
+    assert!(!flags.contains(RwFlags::APPEND));
+}
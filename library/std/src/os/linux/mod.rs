@@ -4,5 +4,10 @@
 #![doc(cfg(target_os = "linux"))]
 
 pub mod fs;
+#[cfg(target_os = "linux")]
+pub mod io;
+#[cfg(any(target_os = "linux", doc))]
+pub mod net;
+#[cfg(any(target_os = "linux", doc))]
 pub mod process;
 pub mod raw;
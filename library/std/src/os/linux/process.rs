@@ -9,11 +9,13 @@
 use crate::process;
 use crate::sealed::Sealed;
 #[cfg(not(doc))]
-use crate::sys::fd::FileDesc;
+use crate::sys::{fd::FileDesc, process::pidfd::PidFd as InnerPidFd};
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
 
 #[cfg(doc)]
 struct FileDesc;
+#[cfg(doc)]
+struct InnerPidFd;
 
 /// This type represents a file descriptor that refers to a process.
 ///
@@ -21,6 +23,16 @@
 /// with [`create_pidfd`]. Subsequently, the created pidfd can be retrieved
 /// from the [`Child`] by calling [`pidfd`] or [`take_pidfd`].
 ///
+/// Unlike a PID, a pidfd keeps referring to the same process even after that
+/// process has exited and its PID has been reused by the system, so signalling
+/// and waiting for the process through a `PidFd` cannot accidentally affect an
+/// unrelated process.
+///
+/// A pidfd becomes readable once the process it refers to has terminated, so
+/// it can be registered with `poll(2)`, `epoll(7)` or similar APIs through
+/// [`AsFd`] to be notified of the exit of a child process alongside other
+/// events, after which [`try_wait`] can be used to collect its exit status.
+///
 /// Example:
 /// ```no_run
 /// #![feature(linux_pidfd)]
@@ -45,26 +57,96 @@
 /// [`Child`]: process::Child
 /// [`pidfd`]: fn@ChildExt::pidfd
 /// [`take_pidfd`]: ChildExt::take_pidfd
+/// [`try_wait`]: PidFd::try_wait
 /// [`pidfd_open(2)`]: https://man7.org/linux/man-pages/man2/pidfd_open.2.html
 #[derive(Debug)]
 pub struct PidFd {
-    inner: FileDesc,
+    inner: InnerPidFd,
+}
+
+impl PidFd {
+    /// Forces the child process to exit.
+    ///
+    /// This is equivalent to sending a `SIGKILL` with [`send_signal`].
+    ///
+    /// [`send_signal`]: PidFd::send_signal
+    pub fn kill(&self) -> Result<()> {
+        self.inner.kill()
+    }
+
+    /// Sends the signal `signal` to the process, using `pidfd_send_signal(2)`.
+    ///
+    /// Since the pidfd refers to the process itself rather than to its PID,
+    /// the signal is never delivered to another process that happens to have
+    /// been given the same PID after this one exited. Sending a signal to a
+    /// process that has already exited fails with `ESRCH`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(linux_pidfd)]
+    /// # #![feature(rustc_private)]
+    /// extern crate libc;
+    ///
+    /// use std::os::linux::process::{ChildExt, CommandExt};
+    /// use std::process::Command;
+    ///
+    /// let mut child = Command::new("sleep").arg("60").create_pidfd(true).spawn()?;
+    /// let pidfd = child.take_pidfd()?;
+    ///
+    /// pidfd.send_signal(libc::SIGTERM)?;
+    /// let status = pidfd.wait()?;
+    /// println!("child terminated with {}", status);
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn send_signal(&self, signal: i32) -> Result<()> {
+        self.inner.send_signal(signal)
+    }
+
+    /// Waits for the child to exit completely, returning the status that it
+    /// exited with.
+    ///
+    /// Like [`Child::wait`], this reaps the child process, so it may only be
+    /// called once for a given process: any later attempt to wait for it,
+    /// including through the [`Child`] it was obtained from, fails with
+    /// `ECHILD`.
+    ///
+    /// Waiting on a pidfd requires Linux 5.4 or later; older kernels report
+    /// an `EINVAL` error.
+    ///
+    /// [`Child`]: process::Child
+    /// [`Child::wait`]: process::Child::wait
+    pub fn wait(&self) -> Result<process::ExitStatus> {
+        self.inner.wait().map(FromInner::from_inner)
+    }
+
+    /// Attempts to collect the exit status of the child if it has already
+    /// exited.
+    ///
+    /// This function does not block. If the child has exited, it is reaped
+    /// and `Ok(Some(status))` is returned, with the same caveats as [`wait`].
+    /// If it is still running, `Ok(None)` is returned.
+    ///
+    /// [`wait`]: PidFd::wait
+    pub fn try_wait(&self) -> Result<Option<process::ExitStatus>> {
+        Ok(self.inner.try_wait()?.map(FromInner::from_inner))
+    }
 }
 
-impl AsInner<FileDesc> for PidFd {
-    fn as_inner(&self) -> &FileDesc {
+impl AsInner<InnerPidFd> for PidFd {
+    fn as_inner(&self) -> &InnerPidFd {
         &self.inner
     }
 }
 
-impl FromInner<FileDesc> for PidFd {
-    fn from_inner(inner: FileDesc) -> PidFd {
+impl FromInner<InnerPidFd> for PidFd {
+    fn from_inner(inner: InnerPidFd) -> PidFd {
         PidFd { inner }
     }
 }
 
-impl IntoInner<FileDesc> for PidFd {
-    fn into_inner(self) -> FileDesc {
+impl IntoInner<InnerPidFd> for PidFd {
+    fn into_inner(self) -> InnerPidFd {
         self.inner
     }
 }
@@ -77,7 +159,7 @@ fn as_raw_fd(&self) -> RawFd {
 
 impl FromRawFd for PidFd {
     unsafe fn from_raw_fd(fd: RawFd) -> Self {
-        Self::from_inner(FileDesc::from_raw_fd(fd))
+        Self::from_inner(InnerPidFd::from_raw_fd(fd))
     }
 }
 
@@ -95,13 +177,13 @@ fn as_fd(&self) -> BorrowedFd<'_> {
 
 impl From<OwnedFd> for PidFd {
     fn from(fd: OwnedFd) -> Self {
-        Self::from_inner(FileDesc::from_inner(fd))
+        Self::from_inner(InnerPidFd::from_inner(FileDesc::from_inner(fd)))
     }
 }
 
 impl From<PidFd> for OwnedFd {
     fn from(pid_fd: PidFd) -> Self {
-        pid_fd.into_inner().into_inner()
+        pid_fd.into_inner().into_inner().into_inner()
     }
 }
 
@@ -145,3 +145,6 @@ pub mod windows {}
 
 #[cfg(any(unix, target_os = "wasi", doc))]
 mod fd;
+
+#[cfg(any(target_os = "android", target_os = "linux", doc))]
+mod net;
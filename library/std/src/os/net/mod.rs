@@ -0,0 +1,4 @@
+//! OS-specific networking functionality shared between several platform modules.
+
+#[cfg(any(target_os = "android", target_os = "linux", doc))]
+pub(super) mod linux_ext;
@@ -1,5 +1,8 @@
 use super::{sockaddr_un, SocketAddr};
+use crate::cmp;
 use crate::convert::TryFrom;
+use crate::error::Error;
+use crate::fmt;
 use crate::io::{self, IoSlice, IoSliceMut};
 use crate::marker::PhantomData;
 use crate::mem::{size_of, zeroed};
@@ -145,6 +148,13 @@ fn add_to_ancillary_data<T>(
     true
 }
 
+fn space_for<T>(count: usize) -> usize {
+    match count.checked_mul(size_of::<T>()).and_then(|len| u32::try_from(len).ok()) {
+        Some(len) => unsafe { libc::CMSG_SPACE(len) as usize },
+        None => usize::MAX,
+    }
+}
+
 struct AncillaryDataIter<'a, T> {
     data: &'a [u8],
     phantom: PhantomData<T>,
@@ -194,6 +204,23 @@ pub fn new() -> SocketCred {
         SocketCred(libc::ucred { pid: 0, uid: 0, gid: 0 })
     }
 
+    /// Create a Unix credential struct with the PID, effective UID and effective GID of the
+    /// current process.
+    ///
+    /// Unless the process is privileged, these are the only credentials the kernel accepts
+    /// when they are sent with [`SocketAncillary::add_creds`].
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    #[must_use]
+    pub fn from_process() -> SocketCred {
+        unsafe {
+            SocketCred(libc::ucred {
+                pid: libc::getpid(),
+                uid: libc::geteuid(),
+                gid: libc::getegid(),
+            })
+        }
+    }
+
     /// Set the PID.
     #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
     pub fn set_pid(&mut self, pid: libc::pid_t) {
@@ -234,6 +261,26 @@ pub fn get_gid(&self) -> libc::gid_t {
     }
 }
 
+#[cfg(any(doc, target_os = "android", target_os = "linux",))]
+#[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+impl Default for SocketCred {
+    fn default() -> SocketCred {
+        SocketCred::new()
+    }
+}
+
+#[cfg(any(doc, target_os = "android", target_os = "linux",))]
+#[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+impl fmt::Debug for SocketCred {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SocketCred")
+            .field("pid", &self.0.pid)
+            .field("uid", &self.0.uid)
+            .field("gid", &self.0.gid)
+            .finish()
+    }
+}
+
 /// This control message contains file descriptors.
 ///
 /// The level is equal to `SOL_SOCKET` and the type is equal to `SCM_RIGHTS`.
@@ -274,6 +321,20 @@ pub enum AncillaryError {
     Unknown { cmsg_level: i32, cmsg_type: i32 },
 }
 
+#[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+impl fmt::Display for AncillaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            AncillaryError::Unknown { cmsg_level, cmsg_type } => {
+                write!(f, "unknown control message (level {}, type {})", cmsg_level, cmsg_type)
+            }
+        }
+    }
+}
+
+#[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+impl Error for AncillaryError {}
+
 /// This enum represent one control message of variable type.
 #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
 pub enum AncillaryData<'a> {
@@ -308,11 +369,17 @@ unsafe fn as_credentials(data: &'a [u8]) -> Self {
         AncillaryData::ScmCredentials(scm_credentials)
     }
 
-    fn try_from_cmsghdr(cmsg: &'a libc::cmsghdr) -> Result<Self, AncillaryError> {
+    /// Create an `AncillaryData` from a control message inside `buffer`.
+    ///
+    /// If the control data was truncated, some platforms report the original length of the
+    /// last control message, so its payload is limited to the part that is inside `buffer`.
+    fn try_from_cmsghdr(cmsg: &'a libc::cmsghdr, buffer: &'a [u8]) -> Result<Self, AncillaryError> {
         unsafe {
             let cmsg_len_zero = libc::CMSG_LEN(0) as usize;
-            let data_len = (*cmsg).cmsg_len as usize - cmsg_len_zero;
-            let data = libc::CMSG_DATA(cmsg).cast();
+            let data = libc::CMSG_DATA(cmsg) as *const u8;
+            let buffer_end = buffer.as_ptr() as usize + buffer.len();
+            let available = buffer_end.saturating_sub(data as usize);
+            let data_len = cmp::min((*cmsg).cmsg_len as usize - cmsg_len_zero, available);
             let data = from_raw_parts(data, data_len);
 
             match (*cmsg).cmsg_level {
@@ -368,7 +435,7 @@ fn next(&mut self) -> Option<Self::Item> {
             }
 
             self.current = Some(cmsg);
-            let ancillary_result = AncillaryData::try_from_cmsghdr(cmsg);
+            let ancillary_result = AncillaryData::try_from_cmsghdr(cmsg, self.buffer);
             Some(ancillary_result)
         }
     }
@@ -428,6 +495,38 @@ pub fn new(buffer: &'a mut [u8]) -> Self {
         SocketAncillary { buffer, length: 0, truncated: false }
     }
 
+    /// Returns the number of bytes of buffer space needed for a control message carrying
+    /// `count` file descriptors.
+    ///
+    /// Add the results for all control messages that are expected to size the buffer
+    /// passed to [`SocketAncillary::new`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data)]
+    /// use std::os::unix::net::SocketAncillary;
+    /// let mut ancillary_buffer = vec![0; SocketAncillary::space_for_fds(4)];
+    /// let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    /// assert!(ancillary.add_fds(&[0, 1, 2, 3][..]));
+    /// ```
+    #[must_use]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn space_for_fds(count: usize) -> usize {
+        space_for::<RawFd>(count)
+    }
+
+    /// Returns the number of bytes of buffer space needed for a control message carrying
+    /// `count` credentials.
+    ///
+    /// See [`SocketAncillary::space_for_fds`] for more details.
+    #[cfg(any(doc, target_os = "android", target_os = "linux",))]
+    #[must_use]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn space_for_creds(count: usize) -> usize {
+        space_for::<SocketCred>(count)
+    }
+
     /// Returns the capacity of the buffer.
     #[must_use]
     #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
@@ -457,6 +556,10 @@ pub fn messages(&self) -> Messages<'_> {
 
     /// Is `true` if during a recv operation the ancillary was truncated.
     ///
+    /// The control messages that fit into the buffer are still returned by
+    /// [`SocketAncillary::messages`], but any file descriptors that did not fit were closed by
+    /// the operating system and are lost.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -489,7 +592,8 @@ pub fn truncated(&self) -> bool {
     /// The function returns `true` if there was enough space in the buffer.
     /// If there was not enough space then no file descriptors was appended.
     /// Technically, that means this operation adds a control message with the level `SOL_SOCKET`
-    /// and type `SCM_RIGHTS`.
+    /// and type `SCM_RIGHTS`. Calling it several times adds one control message per call, but
+    /// the receiver may see all file descriptors merged into a single [`ScmRights`] message.
     ///
     /// # Example
     ///
@@ -0,0 +1,946 @@
+use super::{recv_vectored_with_ancillary_from, send_vectored_with_ancillary_to, SocketAncillary};
+use super::{sockaddr_un, SocketAddr};
+use crate::io::{IoSlice, IoSliceMut};
+use crate::net::Shutdown;
+use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
+use crate::path::Path;
+use crate::sys::cvt;
+use crate::sys::net::Socket;
+use crate::sys_common::{AsInner, FromInner, IntoInner};
+use crate::time::Duration;
+use crate::{fmt, io, mem};
+
+/// A structure representing a Unix domain sequenced-packet socket server.
+///
+/// A `SOCK_SEQPACKET` socket is connection-oriented like a [`UnixStream`],
+/// but preserves message boundaries like a [`UnixDatagram`]: every call to
+/// [`UnixSeqpacket::send`] is delivered as exactly one message to a single
+/// call to [`UnixSeqpacket::recv`] on the peer.
+///
+/// [`UnixStream`]: crate::os::unix::net::UnixStream
+/// [`UnixDatagram`]: crate::os::unix::net::UnixDatagram
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_seqpacket)]
+/// use std::thread;
+/// use std::os::unix::net::{UnixSeqpacket, UnixSeqpacketListener};
+///
+/// fn handle_client(socket: UnixSeqpacket) {
+///     // ...
+/// }
+///
+/// fn main() -> std::io::Result<()> {
+///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+///
+///     // accept connections and process them, spawning a new thread for each one
+///     for socket in listener.incoming() {
+///         match socket {
+///             Ok(socket) => {
+///                 /* connection succeeded */
+///                 thread::spawn(|| handle_client(socket));
+///             }
+///             Err(err) => {
+///                 /* connection failed */
+///                 break;
+///             }
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+pub struct UnixSeqpacketListener(Socket);
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl fmt::Debug for UnixSeqpacketListener {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixSeqpacketListener");
+        builder.field("fd", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixSeqpacketListener {
+    /// Creates a new `UnixSeqpacketListener` bound to the specified socket.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// let listener = match UnixSeqpacketListener::bind("/path/to/the/socket") {
+    ///     Ok(sock) => sock,
+    ///     Err(e) => {
+    ///         println!("Couldn't bind: {:?}", e);
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn bind<P: AsRef<Path>>(path: P) -> io::Result<UnixSeqpacketListener> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            let (addr, len) = sockaddr_un(path.as_ref())?;
+
+            cvt(libc::bind(inner.as_raw_fd(), &addr as *const _ as *const _, len as _))?;
+            cvt(libc::listen(inner.as_raw_fd(), 128))?;
+
+            Ok(UnixSeqpacketListener(inner))
+        }
+    }
+
+    /// Creates a new `UnixSeqpacketListener` bound to the specified [`socket address`].
+    ///
+    /// [`socket address`]: crate::os::unix::net::SocketAddr
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket, unix_socket_abstract)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener1 = UnixSeqpacketListener::bind("path/to/socket")?;
+    ///     let addr = listener1.local_addr()?;
+    ///
+    ///     let listener2 = match UnixSeqpacketListener::bind_addr(&addr) {
+    ///         Ok(sock) => sock,
+    ///         Err(err) => {
+    ///             println!("Couldn't bind: {:?}", err);
+    ///             return Err(err);
+    ///         }
+    ///     };
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn bind_addr(socket_addr: &SocketAddr) -> io::Result<UnixSeqpacketListener> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            cvt(libc::bind(
+                inner.as_raw_fd(),
+                &socket_addr.addr as *const _ as *const _,
+                socket_addr.len as _,
+            ))?;
+            cvt(libc::listen(inner.as_raw_fd(), 128))?;
+            Ok(UnixSeqpacketListener(inner))
+        }
+    }
+
+    /// Accepts a new incoming connection to this listener.
+    ///
+    /// This function will block the calling thread until a new connection
+    /// is established. When established, the corresponding [`UnixSeqpacket`]
+    /// and the remote peer's address will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+    ///
+    ///     match listener.accept() {
+    ///         Ok((socket, addr)) => println!("Got a client: {:?}", addr),
+    ///         Err(e) => println!("accept function failed: {:?}", e),
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn accept(&self) -> io::Result<(UnixSeqpacket, SocketAddr)> {
+        let mut storage: libc::sockaddr_un = unsafe { mem::zeroed() };
+        let mut len = mem::size_of_val(&storage) as libc::socklen_t;
+        let sock = self.0.accept(&mut storage as *mut _ as *mut _, &mut len)?;
+        let addr = SocketAddr::from_parts(storage, len)?;
+        Ok((UnixSeqpacket(sock), addr))
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The returned `UnixSeqpacketListener` is a reference to the same socket
+    /// that this object references. Both handles can be used to accept incoming
+    /// connections and options set on one listener will affect the other.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+    ///     let listener_copy = listener.try_clone().expect("try_clone failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<UnixSeqpacketListener> {
+        self.0.duplicate().map(UnixSeqpacketListener)
+    }
+
+    /// Returns the local socket address of this listener.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+    ///     let addr = listener.local_addr().expect("Couldn't get local address");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getsockname(self.as_raw_fd(), addr, len) })
+    }
+
+    /// Moves the socket into or out of nonblocking mode.
+    ///
+    /// This will result in the `accept` operation becoming nonblocking,
+    /// i.e., immediately returning from their calls. If the IO operation is
+    /// successful, `Ok` is returned and no further action is required. If the
+    /// IO operation could not be completed and needs to be retried, an error
+    /// with kind [`io::ErrorKind::WouldBlock`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+    ///     listener.set_nonblocking(true).expect("Couldn't set non blocking");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Returns the value of the `SO_ERROR` option.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacketListener;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixSeqpacketListener::bind("/tmp/sock")?;
+    ///
+    ///     if let Ok(Some(err)) = listener.take_error() {
+    ///         println!("Got error: {:?}", err);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+
+    /// Returns an iterator over incoming connections.
+    ///
+    /// The iterator will never return [`None`] and will also not yield the
+    /// peer's [`SocketAddr`] structure.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::thread;
+    /// use std::os::unix::net::{UnixSeqpacket, UnixSeqpacketListener};
+    ///
+    /// fn handle_client(socket: UnixSeqpacket) {
+    ///     // ...
+    /// }
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+    ///
+    ///     for socket in listener.incoming() {
+    ///         match socket {
+    ///             Ok(socket) => {
+    ///                 thread::spawn(|| handle_client(socket));
+    ///             }
+    ///             Err(err) => {
+    ///                 break;
+    ///             }
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn incoming(&self) -> IncomingSeqpacket<'_> {
+        IncomingSeqpacket { listener: self }
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl AsRawFd for UnixSeqpacketListener {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.0.as_inner().as_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl FromRawFd for UnixSeqpacketListener {
+    #[inline]
+    unsafe fn from_raw_fd(fd: RawFd) -> UnixSeqpacketListener {
+        UnixSeqpacketListener(Socket::from_inner(FromInner::from_inner(OwnedFd::from_raw_fd(fd))))
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl IntoRawFd for UnixSeqpacketListener {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.0.into_inner().into_inner().into_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl AsFd for UnixSeqpacketListener {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.0.as_inner().as_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl From<OwnedFd> for UnixSeqpacketListener {
+    #[inline]
+    fn from(fd: OwnedFd) -> UnixSeqpacketListener {
+        UnixSeqpacketListener(Socket::from_inner(FromInner::from_inner(fd)))
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl From<UnixSeqpacketListener> for OwnedFd {
+    #[inline]
+    fn from(listener: UnixSeqpacketListener) -> OwnedFd {
+        listener.0.into_inner().into_inner()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl<'a> IntoIterator for &'a UnixSeqpacketListener {
+    type Item = io::Result<UnixSeqpacket>;
+    type IntoIter = IncomingSeqpacket<'a>;
+
+    fn into_iter(self) -> IncomingSeqpacket<'a> {
+        self.incoming()
+    }
+}
+
+/// An iterator over incoming connections to a [`UnixSeqpacketListener`].
+///
+/// It will never return [`None`].
+///
+/// This `struct` is created by the [`incoming`] method on
+/// [`UnixSeqpacketListener`]. See its documentation for more.
+///
+/// [`incoming`]: UnixSeqpacketListener::incoming
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+pub struct IncomingSeqpacket<'a> {
+    listener: &'a UnixSeqpacketListener,
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl<'a> Iterator for IncomingSeqpacket<'a> {
+    type Item = io::Result<UnixSeqpacket>;
+
+    fn next(&mut self) -> Option<io::Result<UnixSeqpacket>> {
+        Some(self.listener.accept().map(|s| s.0))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// A Unix domain sequenced-packet socket.
+///
+/// Unlike a [`UnixStream`], the data is not a byte stream: each
+/// [`send`] is received as one message, and a [`recv`] into a buffer that is
+/// too small for the message discards the rest of it.
+///
+/// [`UnixStream`]: crate::os::unix::net::UnixStream
+/// [`send`]: UnixSeqpacket::send
+/// [`recv`]: UnixSeqpacket::recv
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_seqpacket)]
+/// use std::os::unix::net::UnixSeqpacket;
+///
+/// fn main() -> std::io::Result<()> {
+///     let socket = UnixSeqpacket::connect("/path/to/my/socket")?;
+///     socket.send(b"hello world")?;
+///     let mut buf = [0; 128];
+///     let count = socket.recv(&mut buf)?;
+///     println!("socket received {:?}", &buf[..count]);
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+pub struct UnixSeqpacket(Socket);
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl fmt::Debug for UnixSeqpacket {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixSeqpacket");
+        builder.field("fd", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        if let Ok(addr) = self.peer_addr() {
+            builder.field("peer", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixSeqpacket {
+    /// Connects to the socket named by `path`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// let socket = match UnixSeqpacket::connect("/tmp/sock") {
+    ///     Ok(sock) => sock,
+    ///     Err(e) => {
+    ///         println!("Couldn't connect: {:?}", e);
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn connect<P: AsRef<Path>>(path: P) -> io::Result<UnixSeqpacket> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            let (addr, len) = sockaddr_un(path.as_ref())?;
+
+            cvt(libc::connect(inner.as_raw_fd(), &addr as *const _ as *const _, len))?;
+            Ok(UnixSeqpacket(inner))
+        }
+    }
+
+    /// Connects to the socket specified by [`address`].
+    ///
+    /// [`address`]: crate::os::unix::net::SocketAddr
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket, unix_socket_abstract)]
+    /// use std::os::unix::net::{UnixSeqpacket, UnixSeqpacketListener};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+    ///     let addr = listener.local_addr()?;
+    ///
+    ///     let sock = match UnixSeqpacket::connect_addr(&addr) {
+    ///         Ok(sock) => sock,
+    ///         Err(e) => {
+    ///             println!("Couldn't connect: {:?}", e);
+    ///             return Err(e)
+    ///         }
+    ///     };
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn connect_addr(socket_addr: &SocketAddr) -> io::Result<UnixSeqpacket> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            cvt(libc::connect(
+                inner.as_raw_fd(),
+                &socket_addr.addr as *const _ as *const _,
+                socket_addr.len,
+            ))?;
+            Ok(UnixSeqpacket(inner))
+        }
+    }
+
+    /// Creates an unnamed pair of connected sockets.
+    ///
+    /// Returns two `UnixSeqpacket`s which are connected to each other.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// let (sock1, sock2) = match UnixSeqpacket::pair() {
+    ///     Ok((sock1, sock2)) => (sock1, sock2),
+    ///     Err(e) => {
+    ///         println!("Couldn't create a pair of sockets: {:?}", e);
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn pair() -> io::Result<(UnixSeqpacket, UnixSeqpacket)> {
+        let (i1, i2) = Socket::new_pair(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+        Ok((UnixSeqpacket(i1), UnixSeqpacket(i2)))
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The returned `UnixSeqpacket` is a reference to the same socket that this
+    /// object references. Both handles will send and receive the same messages,
+    /// and options set on one socket will be propagated to the other.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let sock_copy = socket.try_clone().expect("Couldn't clone socket");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<UnixSeqpacket> {
+        self.0.duplicate().map(UnixSeqpacket)
+    }
+
+    /// Returns the socket address of the local half of this connection.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let addr = socket.local_addr().expect("Couldn't get local address");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getsockname(self.as_raw_fd(), addr, len) })
+    }
+
+    /// Returns the socket address of the remote half of this connection.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let addr = socket.peer_addr().expect("Couldn't get peer address");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getpeername(self.as_raw_fd(), addr, len) })
+    }
+
+    /// Receives a single message from the socket.
+    ///
+    /// On success, returns the number of bytes read. If the message is longer
+    /// than `buf`, the excess bytes are discarded; use
+    /// [`recv_vectored_with_ancillary`] to find out whether this happened.
+    ///
+    /// [`recv_vectored_with_ancillary`]: UnixSeqpacket::recv_vectored_with_ancillary
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let mut buf = vec![0; 10];
+    ///     socket.recv(buf.as_mut_slice()).expect("recv function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn recv(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    /// Like [`recv`], except that it receives into a slice of buffers.
+    ///
+    /// [`recv`]: UnixSeqpacket::recv
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn recv_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
+    /// Receives a single message from the socket without removing it from the queue.
+    ///
+    /// On success, returns the number of bytes peeked.
+    ///
+    /// Successive calls return the same message. This is accomplished by
+    /// passing `MSG_PEEK` as a flag to the underlying `recv` system call.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let mut buf = [0; 10];
+    ///     let len = socket.peek(&mut buf).expect("peek failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.peek(buf)
+    }
+
+    /// Sends `buf` as a single message on the socket.
+    ///
+    /// On success, returns the number of bytes written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.send(b"omelette au fromage").expect("send function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn send(&self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    /// Like [`send`], except that the message is gathered from a slice of buffers.
+    ///
+    /// [`send`]: UnixSeqpacket::send
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn send_vectored(&self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
+    /// Receives a single message and its ancillary data from the socket.
+    ///
+    /// On success, returns the number of bytes read and if the message was
+    /// truncated because it did not fit into `bufs`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket, unix_socket_ancillary_data)]
+    /// use std::os::unix::net::{UnixSeqpacket, SocketAncillary, AncillaryData};
+    /// use std::io::IoSliceMut;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let mut buf = [0; 128];
+    ///     let bufs = &mut [IoSliceMut::new(&mut buf)][..];
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///     let (size, truncated) = socket.recv_vectored_with_ancillary(bufs, &mut ancillary)?;
+    ///     println!("received {} (truncated: {})", size, truncated);
+    ///     for ancillary_result in ancillary.messages() {
+    ///         if let AncillaryData::ScmRights(scm_rights) = ancillary_result.unwrap() {
+    ///             for fd in scm_rights {
+    ///                 println!("receive file descriptor: {}", fd);
+    ///             }
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn recv_vectored_with_ancillary(
+        &self,
+        bufs: &mut [IoSliceMut<'_>],
+        ancillary: &mut SocketAncillary<'_>,
+    ) -> io::Result<(usize, bool)> {
+        let (count, truncated, _) = recv_vectored_with_ancillary_from(&self.0, bufs, ancillary)?;
+
+        Ok((count, truncated))
+    }
+
+    /// Sends a single message and ancillary data on the socket.
+    ///
+    /// On success, returns the number of bytes written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket, unix_socket_ancillary_data)]
+    /// use std::os::unix::net::{UnixSeqpacket, SocketAncillary};
+    /// use std::io::IoSlice;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let buf = [1; 8];
+    ///     let bufs = &[IoSlice::new(&buf)][..];
+    ///     let fds = [0, 1, 2];
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///     ancillary.add_fds(&fds[..]);
+    ///     socket.send_vectored_with_ancillary(bufs, &mut ancillary)
+    ///         .expect("send_vectored_with_ancillary function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn send_vectored_with_ancillary(
+        &self,
+        bufs: &[IoSlice<'_>],
+        ancillary: &mut SocketAncillary<'_>,
+    ) -> io::Result<usize> {
+        send_vectored_with_ancillary_to(&self.0, None, bufs, ancillary)
+    }
+
+    /// Sets the read timeout for the socket.
+    ///
+    /// If the provided value is [`None`], then [`recv`] calls will block
+    /// indefinitely. An [`Err`] is returned if the zero [`Duration`] is passed
+    /// to this method.
+    ///
+    /// [`recv`]: UnixSeqpacket::recv
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    /// use std::time::Duration;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.set_read_timeout(Some(Duration::new(1, 0))).expect("Couldn't set read timeout");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_RCVTIMEO)
+    }
+
+    /// Sets the write timeout for the socket.
+    ///
+    /// If the provided value is [`None`], then [`send`] calls will block
+    /// indefinitely. An [`Err`] is returned if the zero [`Duration`] is
+    /// passed to this method.
+    ///
+    /// [`send`]: UnixSeqpacket::send
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    /// use std::time::Duration;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.set_write_timeout(Some(Duration::new(1, 0)))
+    ///         .expect("Couldn't set write timeout");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_write_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_SNDTIMEO)
+    }
+
+    /// Returns the read timeout of this socket.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn read_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_RCVTIMEO)
+    }
+
+    /// Returns the write timeout of this socket.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn write_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_SNDTIMEO)
+    }
+
+    /// Moves the socket into or out of nonblocking mode.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.set_nonblocking(true).expect("Couldn't set nonblocking");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Moves the socket to pass unix credentials as control message in [`SocketAncillary`].
+    ///
+    /// Set the socket option `SO_PASSCRED`.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_seqpacket, unix_socket_ancillary_data)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.set_passcred(true).expect("Couldn't set passcred");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux",))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn set_passcred(&self, passcred: bool) -> io::Result<()> {
+        self.0.set_passcred(passcred)
+    }
+
+    /// Get the current value of the socket for passing unix credentials in [`SocketAncillary`].
+    /// This value can be change by [`set_passcred`].
+    ///
+    /// Get the socket option `SO_PASSCRED`.
+    ///
+    /// [`set_passcred`]: UnixSeqpacket::set_passcred
+    #[cfg(any(doc, target_os = "android", target_os = "linux",))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn passcred(&self) -> io::Result<bool> {
+        self.0.passcred()
+    }
+
+    /// Returns the value of the `SO_ERROR` option.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     if let Ok(Some(err)) = socket.take_error() {
+    ///         println!("Got error: {:?}", err);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+
+    /// Shuts down the read, write, or both halves of this connection.
+    ///
+    /// This function will cause all pending and future I/O calls on the
+    /// specified portions to immediately return with an appropriate value
+    /// (see the documentation of [`Shutdown`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    /// use std::net::Shutdown;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.shutdown(Shutdown::Both).expect("shutdown function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+        self.0.shutdown(how)
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl AsRawFd for UnixSeqpacket {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.0.as_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl FromRawFd for UnixSeqpacket {
+    #[inline]
+    unsafe fn from_raw_fd(fd: RawFd) -> UnixSeqpacket {
+        UnixSeqpacket(Socket::from_inner(FromInner::from_inner(OwnedFd::from_raw_fd(fd))))
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl IntoRawFd for UnixSeqpacket {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.0.into_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl AsFd for UnixSeqpacket {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.0.as_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl From<UnixSeqpacket> for OwnedFd {
+    #[inline]
+    fn from(unix_seqpacket: UnixSeqpacket) -> OwnedFd {
+        unix_seqpacket.0.into_inner().into_inner()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl From<OwnedFd> for UnixSeqpacket {
+    #[inline]
+    fn from(owned: OwnedFd) -> Self {
+        UnixSeqpacket(Socket::from_inner(FromInner::from_inner(owned)))
+    }
+}
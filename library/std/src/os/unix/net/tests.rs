@@ -11,6 +11,10 @@
     target_os = "openbsd",
 ))]
 use crate::iter::FromIterator;
+#[cfg(target_os = "android")]
+use crate::os::android::net::SocketAddrExt;
+#[cfg(target_os = "linux")]
+use crate::os::linux::net::SocketAddrExt;
 #[cfg(any(
     target_os = "android",
     target_os = "dragonfly",
@@ -422,7 +426,7 @@ fn test_abstract_stream_connect() {
     let msg1 = b"hello";
     let msg2 = b"world";
 
-    let socket_addr = or_panic!(SocketAddr::from_abstract_namespace(b"namespace"));
+    let socket_addr = or_panic!(SocketAddr::from_abstract_name(b"namespace"));
     let listener = or_panic!(UnixListener::bind_addr(&socket_addr));
 
     let thread = thread::spawn(move || {
@@ -436,7 +440,7 @@ fn test_abstract_stream_connect() {
     let mut stream = or_panic!(UnixStream::connect_addr(&socket_addr));
 
     let peer = or_panic!(stream.peer_addr());
-    assert_eq!(peer.as_abstract_namespace().unwrap(), b"namespace");
+    assert_eq!(peer.as_abstract_name().unwrap(), b"namespace");
 
     or_panic!(stream.write_all(msg1));
     let mut buf = vec![];
@@ -450,7 +454,7 @@ fn test_abstract_stream_connect() {
 #[cfg(any(target_os = "android", target_os = "linux"))]
 #[test]
 fn test_abstract_stream_iter() {
-    let addr = or_panic!(SocketAddr::from_abstract_namespace(b"hidden"));
+    let addr = or_panic!(SocketAddr::from_abstract_name(b"hidden"));
     let listener = or_panic!(UnixListener::bind_addr(&addr));
 
     let thread = thread::spawn(move || {
@@ -472,13 +476,13 @@ fn test_abstract_stream_iter() {
 #[cfg(any(target_os = "android", target_os = "linux"))]
 #[test]
 fn test_abstract_datagram_bind_send_to_addr() {
-    let addr1 = or_panic!(SocketAddr::from_abstract_namespace(b"ns1"));
+    let addr1 = or_panic!(SocketAddr::from_abstract_name(b"ns1"));
     let sock1 = or_panic!(UnixDatagram::bind_addr(&addr1));
 
     let local = or_panic!(sock1.local_addr());
-    assert_eq!(local.as_abstract_namespace().unwrap(), b"ns1");
+    assert_eq!(local.as_abstract_name().unwrap(), b"ns1");
 
-    let addr2 = or_panic!(SocketAddr::from_abstract_namespace(b"ns2"));
+    let addr2 = or_panic!(SocketAddr::from_abstract_name(b"ns2"));
     let sock2 = or_panic!(UnixDatagram::bind_addr(&addr2));
 
     let msg = b"hello world";
@@ -487,13 +491,13 @@ fn test_abstract_datagram_bind_send_to_addr() {
     let (len, addr) = or_panic!(sock2.recv_from(&mut buf));
     assert_eq!(msg, &buf[..]);
     assert_eq!(len, 11);
-    assert_eq!(addr.as_abstract_namespace().unwrap(), b"ns1");
+    assert_eq!(addr.as_abstract_name().unwrap(), b"ns1");
 }
 
 #[cfg(any(target_os = "android", target_os = "linux"))]
 #[test]
 fn test_abstract_datagram_connect_addr() {
-    let addr1 = or_panic!(SocketAddr::from_abstract_namespace(b"ns3"));
+    let addr1 = or_panic!(SocketAddr::from_abstract_name(b"ns3"));
     let bsock1 = or_panic!(UnixDatagram::bind_addr(&addr1));
 
     let sock = or_panic!(UnixDatagram::unbound());
@@ -507,7 +511,7 @@ fn test_abstract_datagram_connect_addr() {
     assert_eq!(addr.is_unnamed(), true);
     assert_eq!(msg, &buf[..]);
 
-    let addr2 = or_panic!(SocketAddr::from_abstract_namespace(b"ns4"));
+    let addr2 = or_panic!(SocketAddr::from_abstract_name(b"ns4"));
     let bsock2 = or_panic!(UnixDatagram::bind_addr(&addr2));
 
     or_panic!(sock.connect_addr(&addr2));
@@ -518,7 +522,7 @@ fn test_abstract_datagram_connect_addr() {
 #[cfg(any(target_os = "android", target_os = "linux"))]
 #[test]
 fn test_abstract_namespace_too_long() {
-    match SocketAddr::from_abstract_namespace(
+    match SocketAddr::from_abstract_name(
         b"abcdefghijklmnopqrstuvwxyzabcdefghijklmn\
         opqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghi\
         jklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyz",
@@ -533,9 +537,9 @@ fn test_abstract_namespace_too_long() {
 #[test]
 fn test_abstract_namespace_no_pathname_and_not_unnamed() {
     let namespace = b"local";
-    let addr = or_panic!(SocketAddr::from_abstract_namespace(&namespace[..]));
+    let addr = or_panic!(SocketAddr::from_abstract_name(&namespace[..]));
     assert_eq!(addr.as_pathname(), None);
-    assert_eq!(addr.as_abstract_namespace(), Some(&namespace[..]));
+    assert_eq!(addr.as_abstract_name(), Some(&namespace[..]));
     assert_eq!(addr.is_unnamed(), false);
 }
 
@@ -785,3 +789,206 @@ fn test_send_vectored_with_ancillary_unix_datagram() {
         unreachable!("must be ScmRights");
     }
 }
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[test]
+fn test_unix_seqpacket() {
+    let dir = tmpdir();
+    let socket_path = dir.path().join("sock");
+
+    let listener = or_panic!(UnixSeqpacketListener::bind(&socket_path));
+    let thread = thread::spawn(move || {
+        let (socket, _) = or_panic!(listener.accept());
+        let mut buf = [0; 16];
+        // Each message is received on its own.
+        assert_eq!(or_panic!(socket.recv(&mut buf)), 5);
+        assert_eq!(&buf[..5], b"hello");
+        assert_eq!(or_panic!(socket.peek(&mut buf)), 5);
+        assert_eq!(or_panic!(socket.recv(&mut buf)), 5);
+        assert_eq!(&buf[..5], b"world");
+        or_panic!(socket.send(b"bye"));
+    });
+
+    let socket = or_panic!(UnixSeqpacket::connect(&socket_path));
+    assert_eq!(or_panic!(socket.peer_addr()).as_pathname(), Some(&*socket_path));
+    or_panic!(socket.send(b"hello"));
+    or_panic!(socket.send_vectored(&[IoSlice::new(b"wor"), IoSlice::new(b"ld")]));
+
+    let mut buf = [0; 16];
+    assert_eq!(or_panic!(socket.recv(&mut buf)), 3);
+    assert_eq!(&buf[..3], b"bye");
+    thread.join().unwrap();
+
+    // The peer is gone.
+    assert_eq!(or_panic!(socket.recv(&mut buf)), 0);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_abstract_unix_seqpacket() {
+    let addr = or_panic!(SocketAddr::from_abstract_name(b"seqpacket"));
+    let listener = or_panic!(UnixSeqpacketListener::bind_addr(&addr));
+    assert_eq!(or_panic!(listener.local_addr()).as_abstract_name(), Some(&b"seqpacket"[..]));
+
+    let client = or_panic!(UnixSeqpacket::connect_addr(&addr));
+    let server = or_panic!(listener.incoming().next().unwrap());
+    assert_eq!(or_panic!(client.peer_addr()).as_abstract_name(), Some(&b"seqpacket"[..]));
+
+    or_panic!(server.send(b"ping"));
+    let mut buf = [0; 4];
+    assert_eq!(or_panic!(client.recv(&mut buf)), 4);
+    assert_eq!(&buf, b"ping");
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[test]
+fn test_unix_seqpacket_truncated() {
+    let (s1, s2) = or_panic!(UnixSeqpacket::pair());
+    or_panic!(s1.send(b"hello world"));
+    or_panic!(s1.send(b"next"));
+
+    let mut buf = [0; 5];
+    let mut bufs = [IoSliceMut::new(&mut buf[..])];
+    let mut ancillary = SocketAncillary::new(&mut []);
+    let (count, truncated) = or_panic!(s2.recv_vectored_with_ancillary(&mut bufs, &mut ancillary));
+    assert_eq!(count, 5);
+    assert!(truncated);
+    assert_eq!(&buf, b"hello");
+
+    // The rest of the truncated message was discarded.
+    let mut buf = [0; 16];
+    assert_eq!(or_panic!(s2.recv(&mut buf)), 4);
+    assert_eq!(&buf[..4], b"next");
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "emscripten",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[test]
+fn test_send_multiple_fds_unix_stream() {
+    let (s1, s2) = or_panic!(UnixStream::pair());
+
+    let mut ancillary1_buffer = [0; 128];
+    let mut ancillary1 = SocketAncillary::new(&mut ancillary1_buffer[..]);
+    assert!(ancillary1.add_fds(&[s1.as_raw_fd(), s2.as_raw_fd()][..]));
+    assert!(ancillary1.add_fds(&[s1.as_raw_fd()][..]));
+    or_panic!(s1.send_vectored_with_ancillary(&[IoSlice::new(&[1])], &mut ancillary1));
+
+    let mut buf = [0; 1];
+    let mut ancillary2_buffer = [0; 128];
+    let mut ancillary2 = SocketAncillary::new(&mut ancillary2_buffer[..]);
+    let bufs = &mut [IoSliceMut::new(&mut buf[..])][..];
+    or_panic!(s2.recv_vectored_with_ancillary(bufs, &mut ancillary2));
+    assert!(!ancillary2.truncated());
+
+    let mut fds = Vec::new();
+    for message in ancillary2.messages() {
+        if let AncillaryData::ScmRights(scm_rights) = or_panic!(message) {
+            fds.extend(scm_rights);
+        } else {
+            unreachable!("must be ScmRights");
+        }
+    }
+    assert_eq!(fds.len(), 3);
+    for fd in fds {
+        unsafe {
+            libc::close(fd);
+        }
+    }
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "emscripten",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[test]
+fn test_ancillary_truncated_fds() {
+    let (s1, s2) = or_panic!(UnixDatagram::pair());
+
+    let fds = [s1.as_raw_fd(), s2.as_raw_fd(), s1.as_raw_fd()];
+    let mut ancillary1_buffer = vec![0; SocketAncillary::space_for_fds(3)];
+    let mut ancillary1 = SocketAncillary::new(&mut ancillary1_buffer[..]);
+    assert!(ancillary1.add_fds(&fds[..]));
+    assert!(!ancillary1.add_fds(&fds[..1]));
+    or_panic!(s1.send_vectored_with_ancillary(&[IoSlice::new(&[1])], &mut ancillary1));
+
+    let mut buf = [0; 1];
+    let mut ancillary2_buffer = vec![0; SocketAncillary::space_for_fds(1)];
+    let mut ancillary2 = SocketAncillary::new(&mut ancillary2_buffer[..]);
+    let bufs = &mut [IoSliceMut::new(&mut buf[..])][..];
+    let (_, truncated) = or_panic!(s2.recv_vectored_with_ancillary(bufs, &mut ancillary2));
+    assert!(!truncated);
+    assert!(ancillary2.truncated());
+
+    let mut fds = Vec::new();
+    for message in ancillary2.messages() {
+        if let AncillaryData::ScmRights(scm_rights) = or_panic!(message) {
+            fds.extend(scm_rights);
+        }
+    }
+    assert!(fds.len() < 3);
+    for fd in fds {
+        unsafe {
+            libc::close(fd);
+        }
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_send_creds_unix_seqpacket() {
+    let (s1, s2) = or_panic!(UnixSeqpacket::pair());
+    or_panic!(s2.set_passcred(true));
+    assert!(or_panic!(s2.passcred()));
+
+    let cred = SocketCred::from_process();
+    let mut ancillary1_buffer = vec![0; SocketAncillary::space_for_creds(1)];
+    let mut ancillary1 = SocketAncillary::new(&mut ancillary1_buffer[..]);
+    assert!(ancillary1.add_creds(&[cred.clone()][..]));
+    or_panic!(s1.send_vectored_with_ancillary(&[IoSlice::new(&[1])], &mut ancillary1));
+
+    let mut buf = [0; 1];
+    let mut ancillary2_buffer = [0; 128];
+    let mut ancillary2 = SocketAncillary::new(&mut ancillary2_buffer[..]);
+    let bufs = &mut [IoSliceMut::new(&mut buf[..])][..];
+    or_panic!(s2.recv_vectored_with_ancillary(bufs, &mut ancillary2));
+
+    let mut ancillary_data_vec = Vec::from_iter(ancillary2.messages());
+    assert_eq!(ancillary_data_vec.len(), 1);
+    if let AncillaryData::ScmCredentials(scm_credentials) =
+        ancillary_data_vec.pop().unwrap().unwrap()
+    {
+        let cred_vec = Vec::from_iter(scm_credentials);
+        assert_eq!(cred_vec.len(), 1);
+        assert_eq!(cred.get_pid(), cred_vec[0].get_pid());
+        assert_eq!(cred.get_uid(), cred_vec[0].get_uid());
+        assert_eq!(cred.get_gid(), cred_vec[0].get_gid());
+    } else {
+        unreachable!("must be ScmCredentials");
+    }
+}
@@ -322,6 +322,54 @@ fn from(owned: OwnedHandle) -> Self {
     }
 }
 
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsHandle for crate::io::PipeReader {
+    #[inline]
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        self.as_inner().handle().as_handle()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<crate::io::PipeReader> for OwnedHandle {
+    #[inline]
+    fn from(pipe_reader: crate::io::PipeReader) -> OwnedHandle {
+        pipe_reader.into_inner().into_handle().into_inner()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<OwnedHandle> for crate::io::PipeReader {
+    #[inline]
+    fn from(owned: OwnedHandle) -> Self {
+        Self::from_inner(FromInner::from_inner(FromInner::from_inner(owned)))
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsHandle for crate::io::PipeWriter {
+    #[inline]
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        self.as_inner().handle().as_handle()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<crate::io::PipeWriter> for OwnedHandle {
+    #[inline]
+    fn from(pipe_writer: crate::io::PipeWriter) -> OwnedHandle {
+        pipe_writer.into_inner().into_handle().into_inner()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<OwnedHandle> for crate::io::PipeWriter {
+    #[inline]
+    fn from(owned: OwnedHandle) -> Self {
+        Self::from_inner(FromInner::from_inner(FromInner::from_inner(owned)))
+    }
+}
+
 impl AsHandle for crate::io::Stdin {
     #[inline]
     fn as_handle(&self) -> BorrowedHandle<'_> {
@@ -127,6 +127,54 @@ fn into_raw_handle(self) -> RawHandle {
     }
 }
 
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsRawHandle for io::PipeReader {
+    #[inline]
+    fn as_raw_handle(&self) -> RawHandle {
+        self.as_inner().handle().as_raw_handle() as RawHandle
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl FromRawHandle for io::PipeReader {
+    #[inline]
+    unsafe fn from_raw_handle(handle: RawHandle) -> io::PipeReader {
+        io::PipeReader::from(OwnedHandle::from_raw_handle(handle as c::HANDLE))
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl IntoRawHandle for io::PipeReader {
+    #[inline]
+    fn into_raw_handle(self) -> RawHandle {
+        self.into_inner().into_handle().into_raw_handle() as *mut _
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsRawHandle for io::PipeWriter {
+    #[inline]
+    fn as_raw_handle(&self) -> RawHandle {
+        self.as_inner().handle().as_raw_handle() as RawHandle
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl FromRawHandle for io::PipeWriter {
+    #[inline]
+    unsafe fn from_raw_handle(handle: RawHandle) -> io::PipeWriter {
+        io::PipeWriter::from(OwnedHandle::from_raw_handle(handle as c::HANDLE))
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl IntoRawHandle for io::PipeWriter {
+    #[inline]
+    fn into_raw_handle(self) -> RawHandle {
+        self.into_inner().into_handle().into_raw_handle() as *mut _
+    }
+}
+
 /// Extracts raw sockets.
 #[stable(feature = "rust1", since = "1.0.0")]
 pub trait AsRawSocket {
@@ -235,3 +283,28 @@ fn into_raw_socket(self) -> RawSocket {
         self.into_inner().into_socket().into_inner().into_raw_socket()
     }
 }
+
+#[unstable(feature = "net_socket", issue = "none")]
+impl AsRawSocket for net::Socket {
+    #[inline]
+    fn as_raw_socket(&self) -> RawSocket {
+        self.as_inner().socket().as_raw_socket()
+    }
+}
+
+#[unstable(feature = "net_socket", issue = "none")]
+impl FromRawSocket for net::Socket {
+    #[inline]
+    unsafe fn from_raw_socket(sock: RawSocket) -> net::Socket {
+        let sock = sys::net::Socket::from_inner(OwnedSocket::from_raw_socket(sock));
+        net::Socket::from_inner(sys_common::net::GenericSocket::from_inner(sock))
+    }
+}
+
+#[unstable(feature = "net_socket", issue = "none")]
+impl IntoRawSocket for net::Socket {
+    #[inline]
+    fn into_raw_socket(self) -> RawSocket {
+        self.into_inner().into_socket().into_inner().into_raw_socket()
+    }
+}
@@ -214,3 +214,27 @@ fn from(owned: OwnedSocket) -> Self {
         unsafe { Self::from_raw_socket(owned.into_raw_socket()) }
     }
 }
+
+#[unstable(feature = "net_socket", issue = "none")]
+impl AsSocket for crate::net::Socket {
+    #[inline]
+    fn as_socket(&self) -> BorrowedSocket<'_> {
+        unsafe { BorrowedSocket::borrow_raw_socket(self.as_raw_socket()) }
+    }
+}
+
+#[unstable(feature = "net_socket", issue = "none")]
+impl From<crate::net::Socket> for OwnedSocket {
+    #[inline]
+    fn from(socket: crate::net::Socket) -> OwnedSocket {
+        unsafe { OwnedSocket::from_raw_socket(socket.into_raw_socket()) }
+    }
+}
+
+#[unstable(feature = "net_socket", issue = "none")]
+impl From<OwnedSocket> for crate::net::Socket {
+    #[inline]
+    fn from(owned: OwnedSocket) -> Self {
+        unsafe { Self::from_raw_socket(owned.into_raw_socket()) }
+    }
+}
@@ -203,6 +203,81 @@ fn test_wait_with_output_once() {
     assert_eq!(stderr, Vec::new());
 }
 
+#[test]
+#[cfg_attr(any(target_os = "vxworks", target_os = "android"), ignore)]
+fn pipeline_through_anonymous_pipe() {
+    use crate::io::pipe;
+
+    let (reader, writer) = pipe().unwrap();
+    let (mut output, output_writer) = pipe().unwrap();
+    let (mut first, mut second) = if cfg!(target_os = "windows") {
+        (
+            Command::new("cmd").args(&["/C", "echo pipeline"]).stdout(writer).spawn().unwrap(),
+            Command::new("findstr")
+                .arg("pipe")
+                .stdin(reader)
+                .stdout(output_writer)
+                .spawn()
+                .unwrap(),
+        )
+    } else {
+        (
+            Command::new("echo").arg("pipeline").stdout(writer).spawn().unwrap(),
+            Command::new("cat").stdin(reader).stdout(output_writer).spawn().unwrap(),
+        )
+    };
+
+    let mut buf = String::new();
+    output.read_to_string(&mut buf).unwrap();
+    assert!(first.wait().unwrap().success());
+    assert!(second.wait().unwrap().success());
+    assert_eq!(buf.trim(), "pipeline");
+}
+
+#[test]
+#[cfg_attr(any(target_os = "vxworks", target_os = "android"), ignore)]
+fn merged_stdout_and_stderr() {
+    use crate::io::pipe;
+
+    let (mut reader, writer) = pipe().unwrap();
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("cmd");
+        cmd.args(&["/C", "echo out&& echo err 1>&2"]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(&["-c", "echo out; echo err >&2"]);
+        cmd
+    };
+    let mut child = cmd.stdout(writer.try_clone().unwrap()).stderr(writer).spawn().unwrap();
+    // `cmd` still holds the write ends, drop them so `reader` sees EOF.
+    drop(cmd);
+
+    let mut buf = String::new();
+    reader.read_to_string(&mut buf).unwrap();
+    assert!(child.wait().unwrap().success());
+    let lines: Vec<_> = buf.lines().map(str::trim).collect();
+    assert_eq!(lines, ["out", "err"]);
+}
+
+#[test]
+#[cfg_attr(any(target_os = "vxworks", target_os = "android"), ignore)]
+fn child_stdout_into_pipe_reader() {
+    use crate::io::PipeReader;
+
+    let mut child = if cfg!(target_os = "windows") {
+        Command::new("cmd").args(&["/C", "echo hello"]).stdout(Stdio::piped()).spawn().unwrap()
+    } else {
+        Command::new("echo").arg("hello").stdout(Stdio::piped()).spawn().unwrap()
+    };
+    let mut reader = PipeReader::from(child.stdout.take().unwrap());
+
+    let mut buf = String::new();
+    reader.read_to_string(&mut buf).unwrap();
+    assert!(child.wait().unwrap().success());
+    assert_eq!(buf.trim(), "hello");
+}
+
 #[cfg(all(unix, not(target_os = "android")))]
 pub fn env_cmd() -> Command {
     Command::new("env")
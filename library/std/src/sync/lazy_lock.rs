@@ -0,0 +1,299 @@
+#[cfg(test)]
+mod tests;
+
+use crate::cell::Cell;
+use crate::fmt;
+use crate::ops::Deref;
+use crate::panic::{RefUnwindSafe, UnwindSafe};
+use crate::sync::OnceLock;
+
+/// A value which is initialized on the first access.
+///
+/// This type is a thread-safe [`LazyCell`], and can be used in statics.
+///
+/// If the initialization function panics, the `LazyLock` is poisoned: the
+/// function has been consumed, so every later access panics as well. Use
+/// [`LazyLock::try_force`] with an [`FnMut`] initializing function to retry
+/// instead.
+///
+/// [`LazyCell`]: crate::cell::LazyCell
+///
+/// # Examples
+///
+/// ```
+/// #![feature(once_cell)]
+///
+/// use std::collections::HashMap;
+///
+/// use std::sync::LazyLock;
+///
+/// static HASHMAP: LazyLock<HashMap<i32, String>> = LazyLock::new(|| {
+///     println!("initializing");
+///     let mut m = HashMap::new();
+///     m.insert(13, "Spica".to_string());
+///     m.insert(74, "Hoyten".to_string());
+///     m
+/// });
+///
+/// fn main() {
+///     println!("ready");
+///     std::thread::spawn(|| {
+///         println!("{:?}", HASHMAP.get(&13));
+///     }).join().unwrap();
+///     println!("{:?}", HASHMAP.get(&74));
+///
+///     // Prints:
+///     //   ready
+///     //   initializing
+///     //   Some("Spica")
+///     //   Some("Hoyten")
+/// }
+/// ```
+#[unstable(feature = "once_cell", issue = "74465")]
+pub struct LazyLock<T, F = fn() -> T> {
+    cell: OnceLock<T>,
+    init: Cell<Option<F>>,
+}
+
+#[unstable(feature = "once_cell", issue = "74465")]
+impl<T: fmt::Debug, F> fmt::Debug for LazyLock<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyLock").field("cell", &self.cell).finish_non_exhaustive()
+    }
+}
+
+// We never create a `&F` from a `&LazyLock<T, F>` so it is fine
+// to not impl `Sync` for `F`
+// we do take `F` out of (and put it back into) the `Cell` in `force`,
+// but only while initializing the `OnceLock`, which is properly
+// synchronized, so it also does not contribute to this impl.
+#[unstable(feature = "once_cell", issue = "74465")]
+unsafe impl<T, F: Send> Sync for LazyLock<T, F> where OnceLock<T>: Sync {}
+// auto-derived `Send` impl is OK.
+
+#[unstable(feature = "once_cell", issue = "74465")]
+impl<T, F: UnwindSafe> RefUnwindSafe for LazyLock<T, F> where OnceLock<T>: RefUnwindSafe {}
+#[unstable(feature = "once_cell", issue = "74465")]
+impl<T, F: UnwindSafe> UnwindSafe for LazyLock<T, F> where OnceLock<T>: UnwindSafe {}
+
+impl<T, F> LazyLock<T, F> {
+    /// Creates a new lazy value with the given initializing
+    /// function.
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub const fn new(f: F) -> LazyLock<T, F> {
+        LazyLock { cell: OnceLock::new(), init: Cell::new(Some(f)) }
+    }
+
+    /// Consumes this `LazyLock` returning the stored value.
+    ///
+    /// Returns `Ok(value)` if the value was already initialized, and
+    /// `Err(f)` with the initializing function otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `LazyLock` is poisoned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    ///
+    /// use std::sync::LazyLock;
+    ///
+    /// let hello = "Hello, World!".to_string();
+    ///
+    /// let lazy = LazyLock::new(|| hello.to_uppercase());
+    ///
+    /// assert_eq!(&*lazy, "HELLO, WORLD!");
+    /// assert_eq!(LazyLock::into_value(lazy).ok(), Some("HELLO, WORLD!".to_string()));
+    /// ```
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub fn into_value(this: LazyLock<T, F>) -> Result<T, F> {
+        let LazyLock { cell, init } = this;
+        match cell.into_inner() {
+            Some(value) => Ok(value),
+            None => match init.into_inner() {
+                Some(f) => Err(f),
+                None => panic!("`LazyLock` instance has previously been poisoned"),
+            },
+        }
+    }
+
+    /// Returns a reference to the value if it is initialized, or `None` if not.
+    ///
+    /// This method never blocks.
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub fn get(this: &LazyLock<T, F>) -> Option<&T> {
+        this.cell.get()
+    }
+
+    /// Returns a mutable reference to the value if it is initialized, or
+    /// `None` if not.
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub fn get_mut(this: &mut LazyLock<T, F>) -> Option<&mut T> {
+        this.cell.get_mut()
+    }
+}
+
+impl<T, F: FnOnce() -> T> LazyLock<T, F> {
+    /// Forces the evaluation of this lazy value and
+    /// returns a reference to result. This is equivalent
+    /// to the `Deref` impl, but is explicit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    ///
+    /// use std::sync::LazyLock;
+    ///
+    /// let lazy = LazyLock::new(|| 92);
+    ///
+    /// assert_eq!(LazyLock::force(&lazy), &92);
+    /// assert_eq!(&*lazy, &92);
+    /// ```
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub fn force(this: &LazyLock<T, F>) -> &T {
+        this.cell.get_or_init(|| match this.init.take() {
+            Some(f) => f(),
+            None => panic!("`LazyLock` instance has previously been poisoned"),
+        })
+    }
+
+    /// Forces the evaluation of this lazy value and returns a mutable
+    /// reference to the result.
+    ///
+    /// Exclusive access means that no other thread can be initializing the
+    /// value, so this never blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    ///
+    /// use std::sync::LazyLock;
+    ///
+    /// let mut lazy = LazyLock::new(|| 92);
+    ///
+    /// let p = LazyLock::force_mut(&mut lazy);
+    /// assert_eq!(*p, 92);
+    /// *p = 44;
+    /// assert_eq!(*lazy, 44);
+    /// ```
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub fn force_mut(this: &mut LazyLock<T, F>) -> &mut T {
+        if this.cell.get_mut().is_none() {
+            let value = match this.init.get_mut().take() {
+                Some(f) => f(),
+                None => panic!("`LazyLock` instance has previously been poisoned"),
+            };
+            // The cell is empty and we hold a unique reference, so this
+            // cannot fail.
+            let _ = this.cell.set(value);
+        }
+        this.cell.get_mut().unwrap()
+    }
+}
+
+impl<T, E, F: FnMut() -> Result<T, E>> LazyLock<T, F> {
+    /// Forces the evaluation of this lazy value with a fallible initializing
+    /// function and returns a reference to the result.
+    ///
+    /// If the function returns an error, the error is passed on and the
+    /// `LazyLock` stays uninitialized, so the next access calls the function
+    /// again. Unlike [`force`], a panicking function is not consumed either:
+    /// the panic is propagated and the `LazyLock` can be forced again later.
+    ///
+    /// As with `force`, threads that access the value concurrently block
+    /// until the initialization attempt finished.
+    ///
+    /// [`force`]: LazyLock::force
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    ///
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::LazyLock;
+    ///
+    /// static ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+    /// static LAZY: LazyLock<usize, fn() -> Result<usize, &'static str>> = LazyLock::new(|| {
+    ///     match ATTEMPTS.fetch_add(1, Ordering::Relaxed) {
+    ///         0 => Err("not yet"),
+    ///         _ => Ok(92),
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(LazyLock::try_force(&LAZY), Err("not yet"));
+    /// assert_eq!(LazyLock::get(&LAZY), None);
+    /// assert_eq!(LazyLock::try_force(&LAZY), Ok(&92));
+    /// ```
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub fn try_force(this: &LazyLock<T, F>) -> Result<&T, E> {
+        this.cell.get_or_try_init(|| {
+            // `force` needs `F: FnOnce() -> T`, so it never consumes a function
+            // returning `Result<T, E>`. The function is only missing while an
+            // outer call is running it.
+            let mut init = match Restore::take(&this.init) {
+                Some(init) => init,
+                None => panic!("reentrant init"),
+            };
+            let value = init.call()?;
+            init.finish();
+            Ok(value)
+        })
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "74465")]
+impl<T, F: FnOnce() -> T> Deref for LazyLock<T, F> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        LazyLock::force(self)
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "74465")]
+impl<T: Default> Default for LazyLock<T> {
+    /// Creates a new lazy value using `Default` as the initializing function.
+    fn default() -> LazyLock<T> {
+        LazyLock::new(T::default)
+    }
+}
+
+/// Holds the initializing function while [`LazyLock::try_force`] runs it, and
+/// puts it back into the `LazyLock` unless it produced the value, so that a
+/// panicking or failing function is called again on the next access.
+struct Restore<'a, F> {
+    init: &'a Cell<Option<F>>,
+    f: Option<F>,
+}
+
+impl<'a, F> Restore<'a, F> {
+    /// Takes the function out of `init`, or returns `None` if it is missing.
+    fn take(init: &'a Cell<Option<F>>) -> Option<Restore<'a, F>> {
+        let f = init.take()?;
+        Some(Restore { init, f: Some(f) })
+    }
+
+    fn call<R>(&mut self) -> R
+    where
+        F: FnMut() -> R,
+    {
+        (self.f.as_mut().unwrap())()
+    }
+
+    /// Drops the function once it produced the value.
+    fn finish(mut self) {
+        self.f = None;
+    }
+}
+
+impl<F> Drop for Restore<'_, F> {
+    fn drop(&mut self) {
+        if let Some(f) = self.f.take() {
+            self.init.set(Some(f));
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{
+    cell::{Cell, LazyCell},
+    panic, ptr,
+    sync::{
+        atomic::{AtomicUsize, Ordering::SeqCst},
+        LazyLock, Mutex,
+    },
+    thread,
+};
+
+#[test]
+fn lazy_cell_default() {
+    static CALLED: AtomicUsize = AtomicUsize::new(0);
+
+    struct Foo(u8);
+    impl Default for Foo {
+        fn default() -> Self {
+            CALLED.fetch_add(1, SeqCst);
+            Foo(42)
+        }
+    }
+
+    let lazy: LazyCell<Mutex<Foo>> = <_>::default();
+
+    assert_eq!(CALLED.load(SeqCst), 0);
+
+    assert_eq!(lazy.lock().unwrap().0, 42);
+    assert_eq!(CALLED.load(SeqCst), 1);
+
+    lazy.lock().unwrap().0 = 21;
+
+    assert_eq!(lazy.lock().unwrap().0, 21);
+    assert_eq!(CALLED.load(SeqCst), 1);
+}
+
+#[test]
+fn lazy_cell_poisoning() {
+    let x: LazyCell<String> = LazyCell::new(|| panic!("kaboom"));
+    for _ in 0..2 {
+        let res = panic::catch_unwind(panic::AssertUnwindSafe(|| x.len()));
+        assert!(res.is_err());
+    }
+}
+
+#[test]
+fn lazy_cell_try_force_retries_after_panic() {
+    let calls = Cell::new(0);
+    let x = LazyCell::new(|| {
+        calls.set(calls.get() + 1);
+        if calls.get() == 1 {
+            panic!("kaboom");
+        }
+        Ok::<_, ()>(String::from("ok"))
+    });
+    let res = panic::catch_unwind(panic::AssertUnwindSafe(|| LazyCell::try_force(&x).is_ok()));
+    assert!(res.is_err());
+    assert_eq!(LazyCell::get(&x), None);
+    assert_eq!(LazyCell::try_force(&x).map(String::as_str), Ok("ok"));
+    assert_eq!(calls.get(), 2);
+}
+
+#[test]
+fn lazy_cell_try_force_after_reentrant_panic() {
+    type Init<'a> = Box<dyn FnMut() -> Result<i32, ()> + 'a>;
+    let this: Cell<*const LazyCell<i32, Init<'_>>> = Cell::new(ptr::null());
+    let reenter = Cell::new(true);
+    let x: LazyCell<i32, Init<'_>> = LazyCell::new(Box::new(|| {
+        if reenter.replace(false) {
+            let _ = LazyCell::try_force(unsafe { &*this.get() });
+        }
+        Ok(92)
+    }));
+    this.set(&x);
+
+    // The reentrant call panics, but the function is put back rather than
+    // poisoning the cell.
+    let res = panic::catch_unwind(panic::AssertUnwindSafe(|| LazyCell::try_force(&x).is_ok()));
+    assert!(res.is_err());
+    assert_eq!(LazyCell::get(&x), None);
+    assert_eq!(LazyCell::try_force(&x), Ok(&92));
+}
+
+fn spawn_and_wait<R: Send + 'static>(f: impl FnOnce() -> R + Send + 'static) -> R {
+    thread::spawn(f).join().unwrap()
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn lazy_lock_new() {
+    static CALLED: AtomicUsize = AtomicUsize::new(0);
+    static LAZY_LOCK: LazyLock<i32> = LazyLock::new(|| {
+        CALLED.fetch_add(1, SeqCst);
+        92
+    });
+
+    assert_eq!(CALLED.load(SeqCst), 0);
+
+    spawn_and_wait(|| {
+        let y = *LAZY_LOCK - 30;
+        assert_eq!(y, 62);
+        assert_eq!(CALLED.load(SeqCst), 1);
+    });
+
+    let y = *LAZY_LOCK - 30;
+    assert_eq!(y, 62);
+    assert_eq!(CALLED.load(SeqCst), 1);
+}
+
+#[test]
+fn lazy_lock_default() {
+    static CALLED: AtomicUsize = AtomicUsize::new(0);
+
+    struct Foo(u8);
+    impl Default for Foo {
+        fn default() -> Self {
+            CALLED.fetch_add(1, SeqCst);
+            Foo(42)
+        }
+    }
+
+    let lazy: LazyLock<Mutex<Foo>> = <_>::default();
+
+    assert_eq!(CALLED.load(SeqCst), 0);
+
+    assert_eq!(lazy.lock().unwrap().0, 42);
+    assert_eq!(CALLED.load(SeqCst), 1);
+
+    lazy.lock().unwrap().0 = 21;
+
+    assert_eq!(lazy.lock().unwrap().0, 21);
+    assert_eq!(CALLED.load(SeqCst), 1);
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn static_lazy_lock() {
+    static XS: LazyLock<Vec<i32>> = LazyLock::new(|| {
+        let mut xs = Vec::new();
+        xs.push(1);
+        xs.push(2);
+        xs.push(3);
+        xs
+    });
+
+    spawn_and_wait(|| {
+        assert_eq!(&*XS, &vec![1, 2, 3]);
+    });
+
+    assert_eq!(&*XS, &vec![1, 2, 3]);
+}
+
+#[test]
+fn lazy_lock_poisoning() {
+    let x: LazyLock<String> = LazyLock::new(|| panic!("kaboom"));
+    for _ in 0..2 {
+        let res = panic::catch_unwind(|| x.len());
+        assert!(res.is_err());
+    }
+}
+
+#[test]
+fn lazy_lock_try_force_retries_after_panic() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    static X: LazyLock<String, fn() -> Result<String, ()>> = LazyLock::new(|| {
+        if CALLS.fetch_add(1, SeqCst) == 0 {
+            panic!("kaboom");
+        }
+        Ok(String::from("ok"))
+    });
+    let res = panic::catch_unwind(|| LazyLock::try_force(&X).is_ok());
+    assert!(res.is_err());
+    assert_eq!(LazyLock::get(&X), None);
+    assert_eq!(LazyLock::try_force(&X).map(String::as_str), Ok("ok"));
+    assert_eq!(CALLS.load(SeqCst), 2);
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn lazy_lock_try_force() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    static X: LazyLock<i32, fn() -> Result<i32, ()>> =
+        LazyLock::new(|| if CALLS.fetch_add(1, SeqCst) == 0 { Err(()) } else { Ok(92) });
+
+    assert_eq!(spawn_and_wait(|| LazyLock::try_force(&X).copied()), Err(()));
+    assert_eq!(LazyLock::get(&X), None);
+    assert_eq!(spawn_and_wait(|| LazyLock::try_force(&X).copied()), Ok(92));
+    assert_eq!(LazyLock::try_force(&X), Ok(&92));
+    assert_eq!(CALLS.load(SeqCst), 2);
+}
+
+#[test]
+fn is_sync_send() {
+    fn assert_traits<T: Send + Sync>() {}
+    assert_traits::<LazyLock<String>>();
+}
+
+#[test]
+fn lazy_lock_force_mut() {
+    let mut lazy = LazyLock::new(|| vec![1, 2, 3]);
+    assert!(LazyLock::get(&lazy).is_none());
+    LazyLock::force_mut(&mut lazy).push(4);
+    assert_eq!(LazyLock::get_mut(&mut lazy), Some(&mut vec![1, 2, 3, 4]));
+}
+
+#[test]
+fn lazy_lock_into_value() {
+    let lazy: LazyLock<i32> = LazyLock::new(|| 92);
+    let f = LazyLock::into_value(lazy).unwrap_err();
+    assert_eq!(f(), 92);
+
+    let lazy = LazyLock::new(|| 92);
+    assert_eq!(*lazy, 92);
+    assert_eq!(LazyLock::into_value(lazy).ok(), Some(92));
+}
@@ -0,0 +1,598 @@
+//! Multi-producer, multi-consumer FIFO queue communication primitives.
+//!
+//! This module provides message-based communication over channels in the
+//! same spirit as [`mpsc`], with two differences:
+//!
+//! * Both halves of a channel can be cloned. Every message is received by
+//!   exactly one of the [`Receiver`]s, which makes these channels suitable
+//!   for distributing work across a pool of threads.
+//! * The [`Select`] type can block on several send and receive operations at
+//!   once, optionally with a timeout, and completes whichever one becomes
+//!   ready first.
+//!
+//! Channels come in two flavors, both using a single [`Sender`] type:
+//!
+//! 1. An asynchronous, infinitely buffered channel created by [`channel`].
+//!    Sending never blocks.
+//!
+//! 2. A synchronous, bounded channel created by [`sync_channel`]. Sending
+//!    blocks while the buffer is full. A bound of zero turns the channel into
+//!    a "rendezvous" channel on which a blocking [`send`] only returns once
+//!    the message was handed to a receiver.
+//!
+//! The error types are shared with [`mpsc`].
+//!
+//! [`mpsc`]: crate::sync::mpsc
+//! [`send`]: Sender::send
+//!
+//! # Examples
+//!
+//! A pool of workers pulling jobs from a single queue:
+//!
+//! ```
+//! #![feature(mpmc_channel)]
+//! use std::sync::mpmc::channel;
+//! use std::thread;
+//!
+//! let (jobs_tx, jobs_rx) = channel();
+//! let (results_tx, results_rx) = channel();
+//!
+//! for _ in 0..4 {
+//!     let jobs_rx = jobs_rx.clone();
+//!     let results_tx = results_tx.clone();
+//!     thread::spawn(move || {
+//!         for job in jobs_rx {
+//!             results_tx.send(job * 2).unwrap();
+//!         }
+//!     });
+//! }
+//! drop(results_tx);
+//!
+//! for job in 0..10 {
+//!     jobs_tx.send(job).unwrap();
+//! }
+//! drop(jobs_tx);
+//!
+//! let total: i32 = results_rx.iter().sum();
+//! assert_eq!(total, 90);
+//! ```
+
+#![unstable(feature = "mpmc_channel", issue = "none")]
+
+#[cfg(all(test, not(target_os = "emscripten")))]
+mod tests;
+
+// This module is a port of the channels from `crossbeam-channel`. Sending and
+// receiving on the array and list flavors is lock-free; only blocking
+// threads register themselves with a `SyncWaker`, which is locked when a
+// thread has to be woken up. The zero-capacity flavor pairs up senders and
+// receivers under a lock.
+
+mod array;
+mod context;
+mod counter;
+mod list;
+mod select;
+mod utils;
+mod waker;
+mod zero;
+
+use crate::fmt;
+use crate::panic::{RefUnwindSafe, UnwindSafe};
+use crate::time::{Duration, Instant};
+
+use self::select::Token;
+
+pub use self::select::{Select, SelectTimeoutError, SelectedOperation, TrySelectError};
+pub use crate::sync::mpsc::{RecvError, RecvTimeoutError, SendError, TryRecvError, TrySendError};
+
+/// Creates a new asynchronous channel, returning the sender/receiver halves.
+///
+/// All data sent on the [`Sender`] will become available on exactly one of
+/// the [`Receiver`]s in the same order as it was sent, and no [`send`] will
+/// block the calling thread. [`recv`] will block until a message is
+/// available while there is at least one [`Sender`] alive.
+///
+/// Both halves can be cloned to create additional producers and consumers.
+///
+/// [`send`]: Sender::send
+/// [`recv`]: Receiver::recv
+///
+/// # Examples
+///
+/// ```
+/// #![feature(mpmc_channel)]
+/// use std::sync::mpmc::channel;
+/// use std::thread;
+///
+/// let (sender, receiver) = channel();
+/// let receiver2 = receiver.clone();
+///
+/// thread::spawn(move || {
+///     sender.send(1).unwrap();
+///     sender.send(2).unwrap();
+/// });
+///
+/// let a = receiver.recv().unwrap();
+/// let b = receiver2.recv().unwrap();
+/// assert_eq!(a + b, 3);
+/// ```
+#[must_use]
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let (s, r) = counter::new(list::Channel::new());
+    let s = Sender { flavor: SenderFlavor::List(s) };
+    let r = Receiver { flavor: ReceiverFlavor::List(r) };
+    (s, r)
+}
+
+/// Creates a new synchronous, bounded channel.
+///
+/// Messages are buffered up to `bound`, after which [`send`] blocks until
+/// a receiver makes room. A `bound` of 0 creates a rendezvous channel: a
+/// blocking [`send`] waits until its message has been taken by a receiver.
+///
+/// [`send`]: Sender::send
+///
+/// # Examples
+///
+/// ```
+/// #![feature(mpmc_channel)]
+/// use std::sync::mpmc::sync_channel;
+/// use std::thread;
+///
+/// let (sender, receiver) = sync_channel(1);
+///
+/// // this returns immediately
+/// sender.send(1).unwrap();
+///
+/// thread::spawn(move || {
+///     // this will block until the previous message has been received
+///     sender.send(2).unwrap();
+/// });
+///
+/// assert_eq!(receiver.recv().unwrap(), 1);
+/// assert_eq!(receiver.recv().unwrap(), 2);
+/// ```
+#[must_use]
+pub fn sync_channel<T>(bound: usize) -> (Sender<T>, Receiver<T>) {
+    if bound == 0 {
+        let (s, r) = counter::new(zero::Channel::new());
+        let s = Sender { flavor: SenderFlavor::Zero(s) };
+        let r = Receiver { flavor: ReceiverFlavor::Zero(r) };
+        (s, r)
+    } else {
+        let (s, r) = counter::new(array::Channel::with_capacity(bound));
+        let s = Sender { flavor: SenderFlavor::Array(s) };
+        let r = Receiver { flavor: ReceiverFlavor::Array(r) };
+        (s, r)
+    }
+}
+
+/// The sending half of a channel created by [`channel`] or [`sync_channel`].
+///
+/// Messages can be sent through this channel with [`send`]. The sender can
+/// be cloned to send to the same channel from multiple threads.
+///
+/// [`send`]: Sender::send
+pub struct Sender<T> {
+    flavor: SenderFlavor<T>,
+}
+
+/// Sender flavors.
+enum SenderFlavor<T> {
+    /// Bounded channel based on a preallocated array.
+    Array(counter::Sender<array::Channel<T>>),
+
+    /// Unbounded channel implemented as a linked list.
+    List(counter::Sender<list::Channel<T>>),
+
+    /// Zero-capacity channel.
+    Zero(counter::Sender<zero::Channel<T>>),
+}
+
+unsafe impl<T: Send> Send for Sender<T> {}
+unsafe impl<T: Send> Sync for Sender<T> {}
+
+impl<T> UnwindSafe for Sender<T> {}
+impl<T> RefUnwindSafe for Sender<T> {}
+
+/// The receiving half of a channel created by [`channel`] or [`sync_channel`].
+///
+/// Messages sent to the channel can be retrieved using [`recv`]. The receiver
+/// can be cloned; every message is delivered to exactly one receiver.
+///
+/// [`recv`]: Receiver::recv
+pub struct Receiver<T> {
+    flavor: ReceiverFlavor<T>,
+}
+
+/// Receiver flavors.
+enum ReceiverFlavor<T> {
+    /// Bounded channel based on a preallocated array.
+    Array(counter::Receiver<array::Channel<T>>),
+
+    /// Unbounded channel implemented as a linked list.
+    List(counter::Receiver<list::Channel<T>>),
+
+    /// Zero-capacity channel.
+    Zero(counter::Receiver<zero::Channel<T>>),
+}
+
+unsafe impl<T: Send> Send for Receiver<T> {}
+unsafe impl<T: Send> Sync for Receiver<T> {}
+
+impl<T> UnwindSafe for Receiver<T> {}
+impl<T> RefUnwindSafe for Receiver<T> {}
+
+/// An error returned from the internal blocking send of every flavor.
+pub(crate) enum SendTimeoutError<T> {
+    /// The deadline passed before the message could be sent.
+    Timeout(T),
+    /// Every receiver is gone.
+    Disconnected(T),
+}
+
+/// An iterator over messages on a [`Receiver`], created by [`iter`].
+///
+/// This iterator will block whenever [`next`] is called, waiting for a new
+/// message, and [`None`] will be returned once all senders are dropped.
+///
+/// [`iter`]: Receiver::iter
+/// [`next`]: Iterator::next
+#[derive(Debug)]
+pub struct Iter<'a, T: 'a> {
+    rx: &'a Receiver<T>,
+}
+
+/// An iterator that attempts to yield all pending values for a [`Receiver`],
+/// created by [`try_iter`].
+///
+/// [`None`] will be returned when there are no pending values remaining or
+/// if the corresponding channel has hung up.
+///
+/// [`try_iter`]: Receiver::try_iter
+#[derive(Debug)]
+pub struct TryIter<'a, T: 'a> {
+    rx: &'a Receiver<T>,
+}
+
+/// An owning iterator over messages on a [`Receiver`],
+/// created by [`into_iter`].
+///
+/// [`into_iter`]: Receiver::into_iter
+#[derive(Debug)]
+pub struct IntoIter<T> {
+    rx: Receiver<T>,
+}
+
+impl<T> Sender<T> {
+    /// Sends a value on this channel, blocking while a bounded channel is full.
+    ///
+    /// A successful send only means that the message was queued (or, for a
+    /// zero-capacity channel, taken by a receiver). An unsuccessful send
+    /// returns the value back if every [`Receiver`] has been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpmc_channel)]
+    /// use std::sync::mpmc::channel;
+    ///
+    /// let (tx, rx) = channel();
+    ///
+    /// // This send is always successful
+    /// tx.send(1).unwrap();
+    ///
+    /// // This send will fail because the receiver is gone
+    /// drop(rx);
+    /// assert_eq!(tx.send(1).unwrap_err().0, 1);
+    /// ```
+    pub fn send(&self, t: T) -> Result<(), SendError<T>> {
+        let res = match &self.flavor {
+            SenderFlavor::Array(chan) => chan.send(t, None),
+            SenderFlavor::List(chan) => chan.send(t, None),
+            SenderFlavor::Zero(chan) => chan.send(t, None),
+        };
+        res.map_err(|err| match err {
+            SendTimeoutError::Disconnected(t) => SendError(t),
+            SendTimeoutError::Timeout(_) => unreachable!(),
+        })
+    }
+
+    /// Attempts to send a value on this channel without blocking.
+    ///
+    /// Returns [`TrySendError::Full`] if the channel has no room for the
+    /// message, which for a zero-capacity channel means that no receiver is
+    /// currently waiting, and [`TrySendError::Disconnected`] if every
+    /// [`Receiver`] has been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpmc_channel)]
+    /// use std::sync::mpmc::{sync_channel, TrySendError};
+    ///
+    /// let (tx, rx) = sync_channel(1);
+    /// tx.try_send(1).unwrap();
+    /// assert_eq!(tx.try_send(2), Err(TrySendError::Full(2)));
+    /// assert_eq!(rx.recv(), Ok(1));
+    /// ```
+    pub fn try_send(&self, t: T) -> Result<(), TrySendError<T>> {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.try_send(t),
+            SenderFlavor::List(chan) => chan.try_send(t),
+            SenderFlavor::Zero(chan) => chan.try_send(t),
+        }
+    }
+
+    /// Returns the number of messages currently in the channel.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.len(),
+            SenderFlavor::List(chan) => chan.len(),
+            SenderFlavor::Zero(chan) => chan.len(),
+        }
+    }
+
+    /// Returns `true` if the channel currently holds no messages.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the capacity of the channel, or `None` if it is unbounded.
+    #[must_use]
+    pub fn capacity(&self) -> Option<usize> {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.capacity(),
+            SenderFlavor::List(chan) => chan.capacity(),
+            SenderFlavor::Zero(chan) => chan.capacity(),
+        }
+    }
+
+    /// Writes a message for a send operation selected with `token`.
+    unsafe fn write(&self, token: &mut Token, msg: T) -> Result<(), T> {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.write(token, msg),
+            SenderFlavor::List(chan) => chan.write(token, msg),
+            SenderFlavor::Zero(chan) => chan.write(token, msg),
+        }
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Sender<T> {
+        let flavor = match &self.flavor {
+            SenderFlavor::Array(chan) => SenderFlavor::Array(chan.acquire()),
+            SenderFlavor::List(chan) => SenderFlavor::List(chan.acquire()),
+            SenderFlavor::Zero(chan) => SenderFlavor::Zero(chan.acquire()),
+        };
+
+        Sender { flavor }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        unsafe {
+            match &self.flavor {
+                SenderFlavor::Array(chan) => chan.release(|c| c.disconnect()),
+                SenderFlavor::List(chan) => chan.release(|c| c.disconnect_senders()),
+                SenderFlavor::Zero(chan) => chan.release(|c| c.disconnect()),
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender").finish_non_exhaustive()
+    }
+}
+
+impl<T> Receiver<T> {
+    /// Attempts to receive a message without blocking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpmc_channel)]
+    /// use std::sync::mpmc::{channel, TryRecvError};
+    ///
+    /// let (tx, rx) = channel::<i32>();
+    /// assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+    /// tx.send(5).unwrap();
+    /// assert_eq!(rx.try_recv(), Ok(5));
+    /// drop(tx);
+    /// assert_eq!(rx.try_recv(), Err(TryRecvError::Disconnected));
+    /// ```
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.try_recv(),
+            ReceiverFlavor::List(chan) => chan.try_recv(),
+            ReceiverFlavor::Zero(chan) => chan.try_recv(),
+        }
+    }
+
+    /// Blocks the current thread until a message is received.
+    ///
+    /// Returns [`RecvError`] once the channel is empty and every [`Sender`]
+    /// has been dropped. Messages sent before the last sender was dropped are
+    /// still delivered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpmc_channel)]
+    /// use std::sync::mpmc::{channel, RecvError};
+    /// use std::thread;
+    ///
+    /// let (tx, rx) = channel();
+    /// let handle = thread::spawn(move || {
+    ///     tx.send(1).unwrap();
+    /// });
+    ///
+    /// assert_eq!(rx.recv(), Ok(1));
+    /// handle.join().unwrap();
+    /// assert_eq!(rx.recv(), Err(RecvError));
+    /// ```
+    pub fn recv(&self) -> Result<T, RecvError> {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.recv(None),
+            ReceiverFlavor::List(chan) => chan.recv(None),
+            ReceiverFlavor::Zero(chan) => chan.recv(None),
+        }
+        .map_err(|_| RecvError)
+    }
+
+    /// Waits for a message for at most `timeout`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpmc_channel)]
+    /// use std::sync::mpmc::{channel, RecvTimeoutError};
+    /// use std::time::Duration;
+    ///
+    /// let (tx, rx) = channel::<i32>();
+    /// assert_eq!(rx.recv_timeout(Duration::from_millis(10)), Err(RecvTimeoutError::Timeout));
+    /// drop(tx);
+    /// assert_eq!(rx.recv_timeout(Duration::from_millis(10)), Err(RecvTimeoutError::Disconnected));
+    /// ```
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.recv_deadline(deadline),
+            // So far in the future that it's practically the same as waiting indefinitely.
+            None => self.recv().map_err(RecvTimeoutError::from),
+        }
+    }
+
+    /// Waits for a message until `deadline` is reached.
+    pub fn recv_deadline(&self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.recv(Some(deadline)),
+            ReceiverFlavor::List(chan) => chan.recv(Some(deadline)),
+            ReceiverFlavor::Zero(chan) => chan.recv(Some(deadline)),
+        }
+    }
+
+    /// Returns an iterator that blocks waiting for messages and ends once the
+    /// channel is empty and disconnected.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { rx: self }
+    }
+
+    /// Returns an iterator over the messages that are available without
+    /// blocking.
+    pub fn try_iter(&self) -> TryIter<'_, T> {
+        TryIter { rx: self }
+    }
+
+    /// Returns the number of messages currently in the channel.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.len(),
+            ReceiverFlavor::List(chan) => chan.len(),
+            ReceiverFlavor::Zero(chan) => chan.len(),
+        }
+    }
+
+    /// Returns `true` if the channel currently holds no messages.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the capacity of the channel, or `None` if it is unbounded.
+    #[must_use]
+    pub fn capacity(&self) -> Option<usize> {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.capacity(),
+            ReceiverFlavor::List(chan) => chan.capacity(),
+            ReceiverFlavor::Zero(chan) => chan.capacity(),
+        }
+    }
+
+    /// Reads a message for a receive operation selected with `token`.
+    unsafe fn read(&self, token: &mut Token) -> Result<T, ()> {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.read(token),
+            ReceiverFlavor::List(chan) => chan.read(token),
+            ReceiverFlavor::Zero(chan) => chan.read(token),
+        }
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Receiver<T> {
+        let flavor = match &self.flavor {
+            ReceiverFlavor::Array(chan) => ReceiverFlavor::Array(chan.acquire()),
+            ReceiverFlavor::List(chan) => ReceiverFlavor::List(chan.acquire()),
+            ReceiverFlavor::Zero(chan) => ReceiverFlavor::Zero(chan.acquire()),
+        };
+
+        Receiver { flavor }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        unsafe {
+            match &self.flavor {
+                ReceiverFlavor::Array(chan) => chan.release(|c| c.disconnect()),
+                ReceiverFlavor::List(chan) => chan.release(|c| c.disconnect_receivers()),
+                ReceiverFlavor::Zero(chan) => chan.release(|c| c.disconnect()),
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver").finish_non_exhaustive()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.rx.recv().ok()
+    }
+}
+
+impl<'a, T> Iterator for TryIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.rx.try_recv().ok()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Receiver<T> {
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.rx.recv().ok()
+    }
+}
+
+impl<T> IntoIterator for Receiver<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { rx: self }
+    }
+}
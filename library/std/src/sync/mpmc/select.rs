@@ -0,0 +1,656 @@
+//! Waiting on several channel operations at once.
+
+use super::context::Context;
+use super::{array, list, zero};
+use super::{Receiver, ReceiverFlavor, RecvError, SendError, Sender, SenderFlavor};
+use crate::error;
+use crate::fmt;
+use crate::mem;
+use crate::time::{Duration, Instant};
+
+/// Temporary data that gets initialized during a select or a blocking operation, and is consumed
+/// by `read` or `write`.
+///
+/// Each field contains data associated with a specific channel flavor.
+#[derive(Debug, Default)]
+pub struct Token {
+    pub(crate) array: array::ArrayToken,
+    pub(crate) list: list::ListToken,
+    pub(crate) zero: zero::ZeroToken,
+}
+
+/// Identifier associated with an operation by a specific thread on a specific channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Operation(usize);
+
+impl Operation {
+    /// Creates an operation identifier from a mutable reference.
+    ///
+    /// This function essentially just turns the address of the reference into a number. The
+    /// reference should point to a variable that is specific to the thread and the operation,
+    /// and is alive for the entire duration of a blocking operation.
+    #[inline]
+    pub fn hook<T>(r: &mut T) -> Operation {
+        let val = r as *mut T as usize;
+        // Make sure that the pointer address doesn't equal the numerical representation of
+        // `Selected::{Waiting, Aborted, Disconnected}`.
+        assert!(val > 2);
+        Operation(val)
+    }
+}
+
+/// Current state of a blocking operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selected {
+    /// Still waiting for an operation.
+    Waiting,
+
+    /// The attempt to block the current thread has been aborted.
+    Aborted,
+
+    /// An operation became ready because a channel is disconnected.
+    Disconnected,
+
+    /// An operation became ready because a message can be sent or received.
+    Operation(Operation),
+}
+
+impl From<usize> for Selected {
+    #[inline]
+    fn from(val: usize) -> Selected {
+        match val {
+            0 => Selected::Waiting,
+            1 => Selected::Aborted,
+            2 => Selected::Disconnected,
+            oper => Selected::Operation(Operation(oper)),
+        }
+    }
+}
+
+impl Into<usize> for Selected {
+    #[inline]
+    fn into(self) -> usize {
+        match self {
+            Selected::Waiting => 0,
+            Selected::Aborted => 1,
+            Selected::Disconnected => 2,
+            Selected::Operation(Operation(val)) => val,
+        }
+    }
+}
+
+/// How long `run_select` may block.
+#[derive(Clone, Copy, Debug)]
+enum Timeout {
+    Now,
+    Never,
+    At(Instant),
+}
+
+/// One side of a channel flavor that can take part in a selection.
+pub(crate) trait SelectHandle {
+    /// Attempts to select an operation and returns `true` on success.
+    fn try_select(&self, token: &mut Token) -> bool;
+
+    /// Registers an operation for execution and returns `true` if it is now ready.
+    fn register(&self, oper: Operation, cx: &Context) -> bool;
+
+    /// Unregisters an operation for execution.
+    fn unregister(&self, oper: Operation);
+
+    /// Attempts to select an operation the thread got woken up for and returns `true` on success.
+    fn accept(&self, token: &mut Token, cx: &Context) -> bool;
+
+    /// Returns `true` if an operation can be executed without blocking.
+    fn is_ready(&self) -> bool;
+
+    /// Releases a selected operation that is not going to be completed.
+    ///
+    /// A receive operation drops the message it claimed. A send operation gives up its slot
+    /// without a message, and receivers skip over it.
+    ///
+    /// # Safety
+    ///
+    /// `token` must have been filled in by selecting an operation on this handle.
+    unsafe fn release(&self, token: &mut Token);
+}
+
+impl<T> SelectHandle for Sender<T> {
+    fn try_select(&self, token: &mut Token) -> bool {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.sender().try_select(token),
+            SenderFlavor::List(chan) => chan.sender().try_select(token),
+            SenderFlavor::Zero(chan) => chan.sender().try_select(token),
+        }
+    }
+
+    fn register(&self, oper: Operation, cx: &Context) -> bool {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.sender().register(oper, cx),
+            SenderFlavor::List(chan) => chan.sender().register(oper, cx),
+            SenderFlavor::Zero(chan) => chan.sender().register(oper, cx),
+        }
+    }
+
+    fn unregister(&self, oper: Operation) {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.sender().unregister(oper),
+            SenderFlavor::List(chan) => chan.sender().unregister(oper),
+            SenderFlavor::Zero(chan) => chan.sender().unregister(oper),
+        }
+    }
+
+    fn accept(&self, token: &mut Token, cx: &Context) -> bool {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.sender().accept(token, cx),
+            SenderFlavor::List(chan) => chan.sender().accept(token, cx),
+            SenderFlavor::Zero(chan) => chan.sender().accept(token, cx),
+        }
+    }
+
+    fn is_ready(&self) -> bool {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.sender().is_ready(),
+            SenderFlavor::List(chan) => chan.sender().is_ready(),
+            SenderFlavor::Zero(chan) => chan.sender().is_ready(),
+        }
+    }
+
+    unsafe fn release(&self, token: &mut Token) {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.sender().release(token),
+            SenderFlavor::List(chan) => chan.sender().release(token),
+            SenderFlavor::Zero(chan) => chan.sender().release(token),
+        }
+    }
+}
+
+impl<T> SelectHandle for Receiver<T> {
+    fn try_select(&self, token: &mut Token) -> bool {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.receiver().try_select(token),
+            ReceiverFlavor::List(chan) => chan.receiver().try_select(token),
+            ReceiverFlavor::Zero(chan) => chan.receiver().try_select(token),
+        }
+    }
+
+    fn register(&self, oper: Operation, cx: &Context) -> bool {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.receiver().register(oper, cx),
+            ReceiverFlavor::List(chan) => chan.receiver().register(oper, cx),
+            ReceiverFlavor::Zero(chan) => chan.receiver().register(oper, cx),
+        }
+    }
+
+    fn unregister(&self, oper: Operation) {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.receiver().unregister(oper),
+            ReceiverFlavor::List(chan) => chan.receiver().unregister(oper),
+            ReceiverFlavor::Zero(chan) => chan.receiver().unregister(oper),
+        }
+    }
+
+    fn accept(&self, token: &mut Token, cx: &Context) -> bool {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.receiver().accept(token, cx),
+            ReceiverFlavor::List(chan) => chan.receiver().accept(token, cx),
+            ReceiverFlavor::Zero(chan) => chan.receiver().accept(token, cx),
+        }
+    }
+
+    fn is_ready(&self) -> bool {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.receiver().is_ready(),
+            ReceiverFlavor::List(chan) => chan.receiver().is_ready(),
+            ReceiverFlavor::Zero(chan) => chan.receiver().is_ready(),
+        }
+    }
+
+    unsafe fn release(&self, token: &mut Token) {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.receiver().release(token),
+            ReceiverFlavor::List(chan) => chan.receiver().release(token),
+            ReceiverFlavor::Zero(chan) => chan.receiver().release(token),
+        }
+    }
+}
+
+type Handle<'a> = (&'a dyn SelectHandle, usize, *const u8);
+
+/// Selects one of `handles`, blocking according to `timeout`.
+///
+/// Operations are attempted starting at `start` so that callers can rotate it and avoid always
+/// favoring the first operation. On success, returns the token for the follow-up `read` or
+/// `write` together with the index and the address of the selected channel half.
+fn run_select(
+    handles: &mut [Handle<'_>],
+    start: usize,
+    timeout: Timeout,
+) -> Option<(Token, usize, *const u8)> {
+    if handles.is_empty() {
+        // Wait until the timeout and return.
+        match timeout {
+            Timeout::Now => return None,
+            Timeout::Never => loop {
+                crate::thread::park();
+            },
+            Timeout::At(when) => {
+                let now = Instant::now();
+                if now < when {
+                    crate::thread::sleep(when - now);
+                }
+                return None;
+            }
+        }
+    }
+
+    // Visit the operations starting at the rotated position so that none of them is starved.
+    let len = handles.len();
+    let order = move || (0..len).map(move |k| (start + k) % len);
+
+    // Create a token, which serves as a temporary variable that gets initialized in this function
+    // and is later used by a call to `read` or `write` that completes the selected operation.
+    let mut token = Token::default();
+
+    // Try selecting one of the operations without blocking.
+    for j in order() {
+        let (handle, i, ptr) = handles[j];
+        if handle.try_select(&mut token) {
+            return Some((token, i, ptr));
+        }
+    }
+
+    loop {
+        // Prepare for blocking.
+        let res = Context::with(|cx| {
+            let mut sel = Selected::Waiting;
+            let mut registered_count = 0;
+            let mut index_ready = None;
+
+            if let Timeout::Now = timeout {
+                cx.try_select(Selected::Aborted).unwrap();
+            }
+
+            // Register all operations.
+            for j in order() {
+                registered_count += 1;
+                let (handle, i, _) = &mut handles[j];
+
+                // If registration returns `true`, that means the operation has just become ready.
+                if handle.register(Operation::hook::<&dyn SelectHandle>(handle), cx) {
+                    // Try aborting select.
+                    sel = match cx.try_select(Selected::Aborted) {
+                        Ok(()) => {
+                            index_ready = Some(*i);
+                            Selected::Aborted
+                        }
+                        Err(s) => s,
+                    };
+                    break;
+                }
+
+                // If another thread has already selected one of the operations, stop registration.
+                sel = cx.selected();
+                if sel != Selected::Waiting {
+                    break;
+                }
+            }
+
+            if sel == Selected::Waiting {
+                // Block the current thread.
+                let deadline = match timeout {
+                    Timeout::Now => unreachable!(),
+                    Timeout::Never => None,
+                    Timeout::At(when) => Some(when),
+                };
+                sel = cx.wait_until(deadline);
+            }
+
+            // Unregister all registered operations.
+            for j in order().take(registered_count) {
+                let (handle, _, _) = &mut handles[j];
+                handle.unregister(Operation::hook::<&dyn SelectHandle>(handle));
+            }
+
+            match sel {
+                Selected::Waiting => unreachable!(),
+                Selected::Aborted => {
+                    // If an operation became ready during registration, try selecting it.
+                    if let Some(index_ready) = index_ready {
+                        for &(handle, i, ptr) in handles.iter() {
+                            if i == index_ready && handle.try_select(&mut token) {
+                                return Some((i, ptr));
+                            }
+                        }
+                    }
+                }
+                Selected::Disconnected => {}
+                Selected::Operation(_) => {
+                    // Find the selected operation.
+                    for (handle, i, ptr) in handles.iter_mut() {
+                        // Is this the selected operation?
+                        if sel == Selected::Operation(Operation::hook::<&dyn SelectHandle>(handle))
+                        {
+                            // Try selecting this operation.
+                            if handle.accept(&mut token, cx) {
+                                return Some((*i, *ptr));
+                            }
+                        }
+                    }
+                }
+            }
+
+            None
+        });
+
+        // Return if an operation was selected.
+        if let Some((i, ptr)) = res {
+            return Some((token, i, ptr));
+        }
+
+        // Try selecting one of the operations without blocking.
+        for j in order() {
+            let (handle, i, ptr) = handles[j];
+            if handle.try_select(&mut token) {
+                return Some((token, i, ptr));
+            }
+        }
+
+        match timeout {
+            Timeout::Now => return None,
+            Timeout::Never => {}
+            Timeout::At(when) => {
+                if Instant::now() >= when {
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+/// Selects over a set of channel operations.
+///
+/// `Select` lets you register send and receive operations on any number of
+/// [`Sender`]s and [`Receiver`]s, then block until one of them is ready. The
+/// selected operation then has to be completed by calling
+/// [`SelectedOperation::send`] or [`SelectedOperation::recv`] with the same
+/// channel half that was registered.
+///
+/// Once an operation is selected, a message or a slot in the channel has been
+/// claimed for it, or, on a zero-capacity channel, the thread on the other
+/// side has been paired with it and waits for it to complete. Dropping a
+/// [`SelectedOperation`] without completing it is therefore a bug and panics.
+/// The operation is released first, so the channel stays usable: a claimed
+/// message is dropped, and a claimed slot or paired receiver is passed over
+/// to the next sender.
+///
+/// If several operations are ready at the same time, successive selections
+/// rotate through them so that no operation is starved.
+///
+/// An operation on a disconnected channel is always ready; completing it
+/// returns an error.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(mpmc_channel)]
+/// use std::sync::mpmc::{channel, Select};
+/// use std::thread;
+/// use std::time::Duration;
+///
+/// let (tx1, rx1) = channel();
+/// let (tx2, rx2) = channel();
+///
+/// thread::spawn(move || {
+///     thread::sleep(Duration::from_millis(10));
+///     tx1.send(10).unwrap();
+/// });
+/// thread::spawn(move || {
+///     thread::sleep(Duration::from_millis(20));
+///     tx2.send(20).unwrap();
+/// });
+///
+/// let mut sel = Select::new();
+/// let oper1 = sel.recv(&rx1);
+/// let oper2 = sel.recv(&rx2);
+///
+/// // Both operations are initially not ready, so `select` will block.
+/// let oper = sel.select();
+/// match oper.index() {
+///     i if i == oper1 => assert_eq!(oper.recv(&rx1), Ok(10)),
+///     i if i == oper2 => assert_eq!(oper.recv(&rx2), Ok(20)),
+///     _ => unreachable!(),
+/// }
+/// ```
+pub struct Select<'a> {
+    /// Registered operations, each tagged with its index and the address of
+    /// the channel half it was registered with.
+    handles: Vec<Handle<'a>>,
+    next_index: usize,
+    start: usize,
+}
+
+/// A selected operation that has to be completed.
+///
+/// The operation must be completed with [`send`] or [`recv`], passing the
+/// same [`Sender`] or [`Receiver`] it was registered with.
+///
+/// # Panics
+///
+/// Dropping a `SelectedOperation` without completing it panics, unless the
+/// thread is already panicking. The operation is released before that.
+///
+/// [`send`]: SelectedOperation::send
+/// [`recv`]: SelectedOperation::recv
+#[must_use = "a selected operation must be completed with `send` or `recv`"]
+pub struct SelectedOperation<'a> {
+    token: Token,
+    index: usize,
+    /// The channel half the operation was registered with.
+    handle: &'a dyn SelectHandle,
+    ptr: *const u8,
+}
+
+/// An error returned from [`Select::try_select`] when no operation is ready.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct TrySelectError;
+
+/// An error returned from [`Select::select_timeout`] when the timeout
+/// elapsed before any operation became ready.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct SelectTimeoutError;
+
+impl<'a> Select<'a> {
+    /// Creates an empty list of operations.
+    #[must_use]
+    pub fn new() -> Select<'a> {
+        Select { handles: Vec::new(), next_index: 0, start: 0 }
+    }
+
+    /// Adds a send operation, returning its index.
+    pub fn send<T>(&mut self, s: &'a Sender<T>) -> usize {
+        self.add(s, s as *const Sender<T> as *const u8)
+    }
+
+    /// Adds a receive operation, returning its index.
+    pub fn recv<T>(&mut self, r: &'a Receiver<T>) -> usize {
+        self.add(r, r as *const Receiver<T> as *const u8)
+    }
+
+    /// Removes a previously added operation.
+    ///
+    /// This is useful when an operation becomes permanently ready, for example
+    /// because its channel got disconnected, and should no longer be selected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no operation with the given index was added.
+    pub fn remove(&mut self, index: usize) {
+        let i = self
+            .handles
+            .iter()
+            .position(|&(_, oper, _)| oper == index)
+            .expect("no operation with this index");
+        self.handles.swap_remove(i);
+    }
+
+    /// Attempts to select an operation without blocking.
+    pub fn try_select(&mut self) -> Result<SelectedOperation<'a>, TrySelectError> {
+        self.run(Timeout::Now).ok_or(TrySelectError)
+    }
+
+    /// Blocks until one of the operations is ready and selects it.
+    ///
+    /// Blocks forever if no operation was added.
+    pub fn select(&mut self) -> SelectedOperation<'a> {
+        self.run(Timeout::Never).expect("a blocking select always completes")
+    }
+
+    /// Blocks for at most `timeout` until one of the operations is ready and
+    /// selects it.
+    pub fn select_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<SelectedOperation<'a>, SelectTimeoutError> {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.select_deadline(deadline),
+            None => Ok(self.select()),
+        }
+    }
+
+    /// Blocks until `deadline` for one of the operations to be ready and
+    /// selects it.
+    pub fn select_deadline(
+        &mut self,
+        deadline: Instant,
+    ) -> Result<SelectedOperation<'a>, SelectTimeoutError> {
+        self.run(Timeout::At(deadline)).ok_or(SelectTimeoutError)
+    }
+
+    /// Returns the index of an operation that is ready, without selecting it.
+    ///
+    /// Another thread may complete the operation before the caller gets to
+    /// it, so the returned operation might block after all. Returns `None` if
+    /// no operation is currently ready.
+    pub fn try_ready(&mut self) -> Option<usize> {
+        let len = self.handles.len();
+        let start = self.rotate();
+        (0..len)
+            .map(|k| self.handles[(start + k) % len])
+            .find(|&(handle, _, _)| handle.is_ready())
+            .map(|(_, index, _)| index)
+    }
+
+    fn add(&mut self, handle: &'a dyn SelectHandle, ptr: *const u8) -> usize {
+        let index = self.next_index;
+        self.next_index += 1;
+        self.handles.push((handle, index, ptr));
+        index
+    }
+
+    fn rotate(&mut self) -> usize {
+        let start = if self.handles.is_empty() { 0 } else { self.start % self.handles.len() };
+        self.start = self.start.wrapping_add(1);
+        start
+    }
+
+    fn run(&mut self, timeout: Timeout) -> Option<SelectedOperation<'a>> {
+        let start = self.rotate();
+        let (token, index, ptr) = run_select(&mut self.handles, start, timeout)?;
+        let (handle, _, _) = *self.handles.iter().find(|&&(_, i, _)| i == index)?;
+        Some(SelectedOperation { token, index, handle, ptr })
+    }
+}
+
+impl<'a> Default for Select<'a> {
+    fn default() -> Select<'a> {
+        Select::new()
+    }
+}
+
+impl fmt::Debug for Select<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Select").finish_non_exhaustive()
+    }
+}
+
+impl SelectedOperation<'_> {
+    /// Returns the index of the selected operation.
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Completes the selected send operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` is not the [`Sender`] the operation was registered with.
+    /// The operation is released before panicking.
+    pub fn send<T>(mut self, s: &Sender<T>, msg: T) -> Result<(), SendError<T>> {
+        if s as *const Sender<T> as *const u8 != self.ptr {
+            self.release();
+            panic!("passed a sender that wasn't selected");
+        }
+        let res = unsafe { s.write(&mut self.token, msg) };
+        mem::forget(self);
+        res.map_err(SendError)
+    }
+
+    /// Completes the selected receive operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `r` is not the [`Receiver`] the operation was registered with.
+    /// The operation is released before panicking.
+    pub fn recv<T>(mut self, r: &Receiver<T>) -> Result<T, RecvError> {
+        if r as *const Receiver<T> as *const u8 != self.ptr {
+            self.release();
+            panic!("passed a receiver that wasn't selected");
+        }
+        let res = unsafe { r.read(&mut self.token) };
+        mem::forget(self);
+        res.map_err(|_| RecvError)
+    }
+
+    /// Releases the operation without completing it.
+    fn release(self) {
+        let mut this = mem::ManuallyDrop::new(self);
+        let SelectedOperation { handle, token, .. } = &mut *this;
+        unsafe { handle.release(token) };
+    }
+}
+
+impl Drop for SelectedOperation<'_> {
+    fn drop(&mut self) {
+        // Completing or releasing the operation forgets `self`, so this only
+        // runs if it was abandoned. Release it so that the channel and a
+        // paired thread are not left waiting for it.
+        unsafe { self.handle.release(&mut self.token) };
+        if !crate::thread::panicking() {
+            panic!("dropped `SelectedOperation` without completing the operation");
+        }
+    }
+}
+
+impl fmt::Debug for SelectedOperation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SelectedOperation").field("index", &self.index).finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for TrySelectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "all operations in select would block".fmt(f)
+    }
+}
+
+impl error::Error for TrySelectError {}
+
+impl fmt::Display for SelectTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "timed out waiting on select".fmt(f)
+    }
+}
+
+impl error::Error for SelectTimeoutError {}
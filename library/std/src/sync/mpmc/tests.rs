@@ -0,0 +1,336 @@
+use super::*;
+use crate::panic::{self, AssertUnwindSafe};
+use crate::sync::atomic::{AtomicUsize, Ordering};
+use crate::sync::Arc;
+use crate::thread;
+use crate::time::{Duration, Instant};
+
+#[test]
+fn smoke() {
+    let (tx, rx) = channel::<i32>();
+    tx.send(1).unwrap();
+    assert_eq!(rx.recv().unwrap(), 1);
+}
+
+#[test]
+fn smoke_sync() {
+    let (tx, rx) = sync_channel::<i32>(1);
+    tx.send(1).unwrap();
+    assert_eq!(tx.try_send(2), Err(TrySendError::Full(2)));
+    assert_eq!(rx.recv().unwrap(), 1);
+    tx.try_send(2).unwrap();
+    assert_eq!(rx.try_recv(), Ok(2));
+}
+
+#[test]
+fn disconnect_receivers() {
+    let (tx, rx) = channel::<i32>();
+    let rx2 = rx.clone();
+    drop(rx);
+    tx.send(1).unwrap();
+    drop(rx2);
+    assert_eq!(tx.send(2), Err(SendError(2)));
+}
+
+#[test]
+fn disconnect_senders_drains_messages() {
+    let (tx, rx) = channel::<i32>();
+    let tx2 = tx.clone();
+    tx.send(1).unwrap();
+    tx2.send(2).unwrap();
+    drop(tx);
+    drop(tx2);
+    assert_eq!(rx.recv(), Ok(1));
+    assert_eq!(rx.recv(), Ok(2));
+    assert_eq!(rx.recv(), Err(RecvError));
+    assert_eq!(rx.try_recv(), Err(TryRecvError::Disconnected));
+}
+
+#[test]
+fn recv_timeout() {
+    let (tx, rx) = channel::<i32>();
+    let start = Instant::now();
+    assert_eq!(rx.recv_timeout(Duration::from_millis(10)), Err(RecvTimeoutError::Timeout));
+    assert!(start.elapsed() >= Duration::from_millis(10));
+    tx.send(1).unwrap();
+    assert_eq!(rx.recv_timeout(Duration::from_millis(10)), Ok(1));
+}
+
+#[test]
+fn blocked_recv_wakes_on_disconnect() {
+    let (tx, rx) = channel::<i32>();
+    let t = thread::spawn(move || rx.recv());
+    thread::sleep(Duration::from_millis(10));
+    drop(tx);
+    assert_eq!(t.join().unwrap(), Err(RecvError));
+}
+
+#[test]
+fn blocked_send_wakes_on_disconnect() {
+    let (tx, rx) = sync_channel::<i32>(1);
+    tx.send(1).unwrap();
+    let t = thread::spawn(move || tx.send(2));
+    thread::sleep(Duration::from_millis(10));
+    drop(rx);
+    assert_eq!(t.join().unwrap(), Err(SendError(2)));
+}
+
+#[test]
+fn rendezvous() {
+    let (tx, rx) = sync_channel::<i32>(0);
+    assert_eq!(tx.try_send(1), Err(TrySendError::Full(1)));
+    let t = thread::spawn(move || {
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+    });
+    assert_eq!(rx.recv(), Ok(1));
+    assert_eq!(rx.recv(), Ok(2));
+    t.join().unwrap();
+    assert_eq!(rx.recv(), Err(RecvError));
+}
+
+#[test]
+fn rendezvous_send_returns_message_on_disconnect() {
+    let (tx, rx) = sync_channel::<i32>(0);
+    let t = thread::spawn(move || tx.send(1));
+    thread::sleep(Duration::from_millis(10));
+    drop(rx);
+    assert_eq!(t.join().unwrap(), Err(SendError(1)));
+}
+
+#[test]
+fn rendezvous_is_never_buffered() {
+    let (tx, rx) = sync_channel::<i32>(0);
+    let t = thread::spawn(move || tx.send(1).unwrap());
+    thread::sleep(Duration::from_millis(10));
+    // The blocked sender holds on to its message until a receiver takes it.
+    assert_eq!(rx.len(), 0);
+    assert_eq!(rx.recv(), Ok(1));
+    t.join().unwrap();
+}
+
+#[test]
+fn rendezvous_select_timeout_races_send() {
+    const COUNT: i32 = 1000;
+
+    let (tx, rx) = sync_channel::<i32>(0);
+    let t = thread::spawn(move || {
+        for i in 0..COUNT {
+            tx.send(i).unwrap();
+        }
+    });
+
+    let mut next = 0;
+    while next < COUNT {
+        let mut sel = Select::new();
+        sel.recv(&rx);
+        // Selections keep timing out while the sender is pairing up with
+        // them; a send must never be lost to a receiver that gave up.
+        if let Ok(oper) = sel.select_timeout(Duration::from_micros(50)) {
+            assert_eq!(oper.recv(&rx), Ok(next));
+            next += 1;
+        }
+    }
+    t.join().unwrap();
+}
+
+#[test]
+fn multiple_consumers_receive_each_message_once() {
+    const AMT: usize = 10000;
+    const NTHREADS: usize = 4;
+    let (tx, rx) = sync_channel::<usize>(16);
+    let total = Arc::new(AtomicUsize::new(0));
+    let count = Arc::new(AtomicUsize::new(0));
+
+    let consumers: Vec<_> = (0..NTHREADS)
+        .map(|_| {
+            let rx = rx.clone();
+            let total = total.clone();
+            let count = count.clone();
+            thread::spawn(move || {
+                for i in rx {
+                    total.fetch_add(i, Ordering::Relaxed);
+                    count.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+        })
+        .collect();
+    drop(rx);
+
+    let producers: Vec<_> = (0..NTHREADS)
+        .map(|_| {
+            let tx = tx.clone();
+            thread::spawn(move || {
+                for i in 0..AMT {
+                    tx.send(i).unwrap();
+                }
+            })
+        })
+        .collect();
+    drop(tx);
+
+    for t in producers.into_iter().chain(consumers) {
+        t.join().unwrap();
+    }
+    assert_eq!(count.load(Ordering::Relaxed), AMT * NTHREADS);
+    assert_eq!(total.load(Ordering::Relaxed), NTHREADS * AMT * (AMT - 1) / 2);
+}
+
+#[test]
+fn select_recv() {
+    let (tx1, rx1) = channel::<i32>();
+    let (tx2, rx2) = channel::<i32>();
+    tx2.send(2).unwrap();
+
+    let mut sel = Select::new();
+    let oper1 = sel.recv(&rx1);
+    let oper2 = sel.recv(&rx2);
+    let oper = sel.select();
+    assert_eq!(oper.index(), oper2);
+    assert_eq!(oper.recv(&rx2), Ok(2));
+
+    assert_eq!(sel.try_select().unwrap_err(), TrySelectError);
+    tx1.send(1).unwrap();
+    let oper = sel.try_select().unwrap();
+    assert_eq!(oper.index(), oper1);
+    assert_eq!(oper.recv(&rx1), Ok(1));
+}
+
+#[test]
+fn select_send() {
+    let (tx1, rx1) = sync_channel::<i32>(1);
+    let (tx2, rx2) = sync_channel::<i32>(1);
+    tx1.send(0).unwrap();
+
+    let mut sel = Select::new();
+    let _oper1 = sel.send(&tx1);
+    let oper2 = sel.send(&tx2);
+    let oper = sel.select();
+    assert_eq!(oper.index(), oper2);
+    oper.send(&tx2, 2).unwrap();
+
+    assert_eq!(rx1.recv(), Ok(0));
+    assert_eq!(rx2.recv(), Ok(2));
+}
+
+#[test]
+fn select_timeout() {
+    let (_tx, rx) = channel::<i32>();
+    let mut sel = Select::new();
+    sel.recv(&rx);
+    let start = Instant::now();
+    assert_eq!(sel.select_timeout(Duration::from_millis(10)).unwrap_err(), SelectTimeoutError);
+    assert!(start.elapsed() >= Duration::from_millis(10));
+}
+
+#[test]
+fn select_blocks_until_ready() {
+    let (tx1, rx1) = channel::<i32>();
+    let (_tx2, rx2) = channel::<i32>();
+    let t = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        tx1.send(1).unwrap();
+    });
+
+    let mut sel = Select::new();
+    let oper1 = sel.recv(&rx1);
+    sel.recv(&rx2);
+    let oper = sel.select();
+    assert_eq!(oper.index(), oper1);
+    assert_eq!(oper.recv(&rx1), Ok(1));
+    t.join().unwrap();
+}
+
+#[test]
+fn select_disconnected_is_ready() {
+    let (tx, rx) = channel::<i32>();
+    drop(tx);
+    let mut sel = Select::new();
+    sel.recv(&rx);
+    let oper = sel.select();
+    assert_eq!(oper.recv(&rx), Err(RecvError));
+}
+
+#[test]
+#[should_panic = "without completing the operation"]
+fn dropped_selection_panics() {
+    let (tx, rx) = channel::<i32>();
+    tx.send(1).unwrap();
+    let mut sel = Select::new();
+    sel.recv(&rx);
+    // The message was claimed for `oper`, so it must not be dropped.
+    let oper = sel.select();
+    drop(oper);
+}
+
+#[test]
+fn select_rendezvous_send() {
+    let (tx, rx) = sync_channel::<i32>(0);
+    let t = thread::spawn(move || rx.recv());
+
+    let mut sel = Select::new();
+    let oper1 = sel.send(&tx);
+    let oper = sel.select();
+    assert_eq!(oper.index(), oper1);
+    oper.send(&tx, 1).unwrap();
+    assert_eq!(t.join().unwrap(), Ok(1));
+}
+
+#[test]
+#[should_panic]
+fn select_wrong_receiver() {
+    let (tx, rx1) = channel::<i32>();
+    let rx2 = rx1.clone();
+    tx.send(1).unwrap();
+    let mut sel = Select::new();
+    sel.recv(&rx1);
+    let _ = sel.select().recv(&rx2);
+}
+
+#[test]
+fn select_wrong_sender_releases_slot() {
+    for (tx, rx) in [sync_channel::<i32>(2), channel::<i32>()] {
+        let other = tx.clone();
+        let mut sel = Select::new();
+        sel.send(&tx);
+        let oper = sel.select();
+        assert!(panic::catch_unwind(AssertUnwindSafe(move || oper.send(&other, 1))).is_err());
+
+        // The slot reserved for the abandoned send is skipped.
+        tx.send(2).unwrap();
+        assert_eq!(rx.try_recv(), Ok(2));
+        tx.send(3).unwrap();
+        assert_eq!(rx.try_recv(), Ok(3));
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+    }
+}
+
+#[test]
+fn select_wrong_sender_releases_rendezvous() {
+    let (tx, rx) = sync_channel::<i32>(0);
+    let other = tx.clone();
+    let t = thread::spawn(move || rx.recv());
+
+    let mut sel = Select::new();
+    sel.send(&tx);
+    let oper = sel.select();
+    assert!(panic::catch_unwind(AssertUnwindSafe(move || oper.send(&other, 1))).is_err());
+
+    // The paired receiver is not left waiting for the abandoned send.
+    tx.send(2).unwrap();
+    assert_eq!(t.join().unwrap(), Ok(2));
+}
+
+#[test]
+fn select_wrong_receiver_drops_message() {
+    let (tx, rx1) = channel::<i32>();
+    let rx2 = rx1.clone();
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+
+    let mut sel = Select::new();
+    sel.recv(&rx1);
+    let oper = sel.select();
+    assert!(panic::catch_unwind(AssertUnwindSafe(move || oper.recv(&rx2))).is_err());
+    assert_eq!(rx1.try_recv(), Ok(2));
+}
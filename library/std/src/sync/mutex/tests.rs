@@ -236,3 +236,21 @@ fn test_mutex_unsized() {
     let comp: &[i32] = &[4, 2, 5];
     assert_eq!(&*mutex.lock().unwrap(), comp);
 }
+
+#[test]
+fn test_const_new() {
+    static M: Mutex<i32> = Mutex::new(0);
+    static C: Condvar = Condvar::new();
+
+    let t = thread::spawn(|| {
+        *M.lock().unwrap() += 1;
+        C.notify_one();
+    });
+    let mut g = M.lock().unwrap();
+    while *g == 0 {
+        g = C.wait(g).unwrap();
+    }
+    drop(g);
+    t.join().unwrap();
+    assert_eq!(*M.lock().unwrap(), 1);
+}
@@ -49,6 +49,9 @@
 // allocated on the stack, and whenever the running closure finishes it will
 // consume the entire queue and notify all waiters they should try again.
 //
+// Threads calling `wait` may also queue up while the state is INCOMPLETE or
+// POISONED, so the transition to RUNNING keeps the queue bits intact.
+//
 // You'll find a few more details in the implementation, but that's the gist of
 // it!
 //
@@ -65,8 +68,8 @@
 //       must do so with Release ordering to make the result available.
 //     - `wait` inserts `Waiter` nodes as a pointer in `state_and_queue`, and
 //       needs to make the nodes available with Release ordering. The load in
-//       its `compare_exchange` can be Relaxed because it only has to compare
-//       the atomic, not to read other data.
+//       its `compare_exchange` has to be Acquire because the state it reads
+//       may be COMPLETE, in which case `wait` returns it to the caller.
 //     - `WaiterQueue::Drop` must see the `Waiter` nodes, so it must load
 //       `state_and_queue` with Acquire ordering.
 //     - There is just one store where `state_and_queue` is used only as a
@@ -157,11 +160,11 @@ pub struct OnceState {
 const COMPLETE: usize = 0x3;
 
 // Mask to learn about the state. All other bits are the queue of waiters if
-// this is in the RUNNING state.
+// this is not in the COMPLETE state.
 const STATE_MASK: usize = 0x3;
 
-// Representation of a node in the linked list of waiters, used while in the
-// RUNNING state.
+// Representation of a node in the linked list of waiters, used while not in
+// the COMPLETE state.
 // Note: `Waiter` can't hold a mutable pointer to the next thread, because then
 // `wait` would both hand out a mutable reference to its `Waiter` node, and keep
 // a shared reference to check `signaled`. Instead we hold shared references and
@@ -191,6 +194,51 @@ pub const fn new() -> Once {
         Once { state_and_queue: AtomicUsize::new(INCOMPLETE), _marker: marker::PhantomData }
     }
 
+    /// Blocks the current thread until initialization has completed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(once_wait)]
+    ///
+    /// use std::sync::Once;
+    /// use std::thread;
+    ///
+    /// static READY: Once = Once::new();
+    ///
+    /// let thread = thread::spawn(|| {
+    ///     READY.wait();
+    ///     println!("everything is ready");
+    /// });
+    ///
+    /// READY.call_once(|| println!("performing setup"));
+    /// thread.join().unwrap();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If this [`Once`] has been poisoned because an initialization closure has
+    /// panicked, this method will also panic. Use [`wait_force`](Self::wait_force)
+    /// if this behaviour is not desired.
+    #[unstable(feature = "once_wait", issue = "none")]
+    pub fn wait(&self) {
+        if !self.is_completed() {
+            self.wait_inner(false);
+        }
+    }
+
+    /// Blocks the current thread until initialization has completed, ignoring
+    /// poisoning.
+    ///
+    /// Failed initialization attempts do not wake up the caller: it keeps
+    /// waiting until some thread completes initialization successfully.
+    #[unstable(feature = "once_wait", issue = "none")]
+    pub fn wait_force(&self) {
+        if !self.is_completed() {
+            self.wait_inner(true);
+        }
+    }
+
     /// Performs an initialization routine once and only once. The given closure
     /// will be executed if this is the first time `call_once` has been called,
     /// and otherwise the routine will *not* be invoked.
@@ -386,17 +434,19 @@ pub fn is_completed(&self) -> bool {
     fn call_inner(&self, ignore_poisoning: bool, init: &mut dyn FnMut(&OnceState)) {
         let mut state_and_queue = self.state_and_queue.load(Ordering::Acquire);
         loop {
-            match state_and_queue {
+            let state = state_and_queue & STATE_MASK;
+            match state {
                 COMPLETE => break,
                 POISONED if !ignore_poisoning => {
                     // Panic to propagate the poison.
                     panic!("Once instance has previously been poisoned");
                 }
                 POISONED | INCOMPLETE => {
-                    // Try to register this thread as the one RUNNING.
+                    // Try to register this thread as the one RUNNING, keeping
+                    // any threads already queued up by `wait`.
                     let exchange_result = self.state_and_queue.compare_exchange(
                         state_and_queue,
-                        RUNNING,
+                        (state_and_queue & !STATE_MASK) | RUNNING,
                         Ordering::Acquire,
                         Ordering::Acquire,
                     );
@@ -413,7 +463,7 @@ fn call_inner(&self, ignore_poisoning: bool, init: &mut dyn FnMut(&OnceState)) {
                     // Run the initialization function, letting it know if we're
                     // poisoned or not.
                     let init_state = OnceState {
-                        poisoned: state_and_queue == POISONED,
+                        poisoned: state == POISONED,
                         set_state_on_drop_to: Cell::new(COMPLETE),
                     };
                     init(&init_state);
@@ -423,23 +473,48 @@ fn call_inner(&self, ignore_poisoning: bool, init: &mut dyn FnMut(&OnceState)) {
                 _ => {
                     // All other values must be RUNNING with possibly a
                     // pointer to the waiter queue in the more significant bits.
-                    assert!(state_and_queue & STATE_MASK == RUNNING);
-                    wait(&self.state_and_queue, state_and_queue);
-                    state_and_queue = self.state_and_queue.load(Ordering::Acquire);
+                    assert!(state == RUNNING);
+                    state_and_queue = wait(&self.state_and_queue, state_and_queue, true);
+                }
+            }
+        }
+    }
+
+    #[cold]
+    fn wait_inner(&self, ignore_poisoning: bool) {
+        let mut state_and_queue = self.state_and_queue.load(Ordering::Acquire);
+        loop {
+            match state_and_queue & STATE_MASK {
+                COMPLETE => return,
+                POISONED if !ignore_poisoning => {
+                    // Panic to propagate the poison.
+                    panic!("Once instance has previously been poisoned");
+                }
+                _ => {
+                    state_and_queue =
+                        wait(&self.state_and_queue, state_and_queue, !ignore_poisoning);
                 }
             }
         }
     }
 }
 
-fn wait(state_and_queue: &AtomicUsize, mut current_state: usize) {
+// Queues the current thread and blocks until the running initialization
+// finishes, returning the new state. Returns right away if the state is
+// COMPLETE, or POISONED and `return_on_poisoned` is set.
+fn wait(
+    state_and_queue: &AtomicUsize,
+    mut current_state: usize,
+    return_on_poisoned: bool,
+) -> usize {
     // Note: the following code was carefully written to avoid creating a
     // mutable reference to `node` that gets aliased.
     loop {
-        // Don't queue this thread if the status is no longer running,
+        // Don't queue this thread if initialization has already finished,
         // otherwise we will not be woken up.
-        if current_state & STATE_MASK != RUNNING {
-            return;
+        let state = current_state & STATE_MASK;
+        if state == COMPLETE || (state == POISONED && return_on_poisoned) {
+            return current_state;
         }
 
         // Create the node for our current thread.
@@ -454,9 +529,9 @@ fn wait(state_and_queue: &AtomicUsize, mut current_state: usize) {
         // that another thread didn't just replace the head of the linked list.
         let exchange_result = state_and_queue.compare_exchange(
             current_state,
-            me | RUNNING,
+            me | state,
             Ordering::Release,
-            Ordering::Relaxed,
+            Ordering::Acquire,
         );
         if let Err(old) = exchange_result {
             current_state = old;
@@ -475,7 +550,7 @@ fn wait(state_and_queue: &AtomicUsize, mut current_state: usize) {
             // an `unpark` just before on an unparked thread it does not park.
             thread::park();
         }
-        break;
+        return state_and_queue.load(Ordering::Acquire);
     }
 }
 
@@ -1,35 +1,34 @@
-//! Lazy values and one-time initialization of static data.
-
 #[cfg(test)]
 mod tests;
 
-use crate::{
-    cell::{Cell, UnsafeCell},
-    fmt,
-    marker::PhantomData,
-    mem::MaybeUninit,
-    ops::{Deref, Drop},
-    panic::{RefUnwindSafe, UnwindSafe},
-    pin::Pin,
-    sync::Once,
-};
-
-#[doc(inline)]
-#[unstable(feature = "once_cell", issue = "74465")]
-pub use core::lazy::*;
+use crate::cell::UnsafeCell;
+use crate::fmt;
+use crate::marker::PhantomData;
+use crate::mem::MaybeUninit;
+use crate::panic::{RefUnwindSafe, UnwindSafe};
+use crate::pin::Pin;
+use crate::sync::Once;
 
 /// A synchronization primitive which can be written to only once.
 ///
-/// This type is a thread-safe `OnceCell`.
+/// This type is a thread-safe [`OnceCell`], and can be used in statics.
+///
+/// Initialization is not poisoned by failures: if an initializing function
+/// panics or returns an error, the cell stays empty and the next caller of
+/// [`get_or_init`] or [`get_or_try_init`] runs its own function instead.
+///
+/// [`OnceCell`]: crate::cell::OnceCell
+/// [`get_or_init`]: OnceLock::get_or_init
+/// [`get_or_try_init`]: OnceLock::get_or_try_init
 ///
 /// # Examples
 ///
 /// ```
 /// #![feature(once_cell)]
 ///
-/// use std::lazy::SyncOnceCell;
+/// use std::sync::OnceLock;
 ///
-/// static CELL: SyncOnceCell<String> = SyncOnceCell::new();
+/// static CELL: OnceLock<String> = OnceLock::new();
 /// assert!(CELL.get().is_none());
 ///
 /// std::thread::spawn(|| {
@@ -44,7 +43,7 @@
 /// assert_eq!(value.unwrap().as_str(), "Hello, World!");
 /// ```
 #[unstable(feature = "once_cell", issue = "74465")]
-pub struct SyncOnceCell<T> {
+pub struct OnceLock<T> {
     once: Once,
     // Whether or not the value is initialized is tracked by `state_and_queue`.
     value: UnsafeCell<MaybeUninit<T>>,
@@ -53,7 +52,7 @@ pub struct SyncOnceCell<T> {
     /// ```compile_fail,E0597
     /// #![feature(once_cell)]
     ///
-    /// use std::lazy::SyncOnceCell;
+    /// use std::sync::OnceLock;
     ///
     /// struct A<'a>(&'a str);
     ///
@@ -61,7 +60,7 @@ pub struct SyncOnceCell<T> {
     ///     fn drop(&mut self) {}
     /// }
     ///
-    /// let cell = SyncOnceCell::new();
+    /// let cell = OnceLock::new();
     /// {
     ///     let s = String::new();
     ///     let _ = cell.set(A(&s));
@@ -71,23 +70,23 @@ pub struct SyncOnceCell<T> {
 }
 
 // Why do we need `T: Send`?
-// Thread A creates a `SyncOnceCell` and shares it with
+// Thread A creates a `OnceLock` and shares it with
 // scoped thread B, which fills the cell, which is
 // then destroyed by A. That is, destructor observes
 // a sent value.
 #[unstable(feature = "once_cell", issue = "74465")]
-unsafe impl<T: Sync + Send> Sync for SyncOnceCell<T> {}
+unsafe impl<T: Sync + Send> Sync for OnceLock<T> {}
 #[unstable(feature = "once_cell", issue = "74465")]
-unsafe impl<T: Send> Send for SyncOnceCell<T> {}
+unsafe impl<T: Send> Send for OnceLock<T> {}
 
 #[unstable(feature = "once_cell", issue = "74465")]
-impl<T: RefUnwindSafe + UnwindSafe> RefUnwindSafe for SyncOnceCell<T> {}
+impl<T: RefUnwindSafe + UnwindSafe> RefUnwindSafe for OnceLock<T> {}
 #[unstable(feature = "once_cell", issue = "74465")]
-impl<T: UnwindSafe> UnwindSafe for SyncOnceCell<T> {}
+impl<T: UnwindSafe> UnwindSafe for OnceLock<T> {}
 
 #[unstable(feature = "once_cell", issue = "74465")]
 #[rustc_const_unstable(feature = "const_default_impls", issue = "87864")]
-impl<T> const Default for SyncOnceCell<T> {
+impl<T> const Default for OnceLock<T> {
     /// Creates a new empty cell.
     ///
     /// # Example
@@ -95,30 +94,30 @@ impl<T> const Default for SyncOnceCell<T> {
     /// ```
     /// #![feature(once_cell)]
     ///
-    /// use std::lazy::SyncOnceCell;
+    /// use std::sync::OnceLock;
     ///
     /// fn main() {
-    ///     assert_eq!(SyncOnceCell::<()>::new(), SyncOnceCell::default());
+    ///     assert_eq!(OnceLock::<()>::new(), OnceLock::default());
     /// }
     /// ```
-    fn default() -> SyncOnceCell<T> {
-        SyncOnceCell::new()
+    fn default() -> OnceLock<T> {
+        OnceLock::new()
     }
 }
 
 #[unstable(feature = "once_cell", issue = "74465")]
-impl<T: fmt::Debug> fmt::Debug for SyncOnceCell<T> {
+impl<T: fmt::Debug> fmt::Debug for OnceLock<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.get() {
-            Some(v) => f.debug_tuple("Once").field(v).finish(),
-            None => f.write_str("Once(Uninit)"),
+            Some(v) => f.debug_tuple("OnceLock").field(v).finish(),
+            None => f.write_str("OnceLock(Uninit)"),
         }
     }
 }
 
 #[unstable(feature = "once_cell", issue = "74465")]
-impl<T: Clone> Clone for SyncOnceCell<T> {
-    fn clone(&self) -> SyncOnceCell<T> {
+impl<T: Clone> Clone for OnceLock<T> {
+    fn clone(&self) -> OnceLock<T> {
         let cell = Self::new();
         if let Some(value) = self.get() {
             match cell.set(value.clone()) {
@@ -131,7 +130,7 @@ fn clone(&self) -> SyncOnceCell<T> {
 }
 
 #[unstable(feature = "once_cell", issue = "74465")]
-impl<T> From<T> for SyncOnceCell<T> {
+impl<T> From<T> for OnceLock<T> {
     /// Create a new cell with its contents set to `value`.
     ///
     /// # Example
@@ -139,11 +138,11 @@ impl<T> From<T> for SyncOnceCell<T> {
     /// ```
     /// #![feature(once_cell)]
     ///
-    /// use std::lazy::SyncOnceCell;
+    /// use std::sync::OnceLock;
     ///
     /// # fn main() -> Result<(), i32> {
-    /// let a = SyncOnceCell::from(3);
-    /// let b = SyncOnceCell::new();
+    /// let a = OnceLock::from(3);
+    /// let b = OnceLock::new();
     /// b.set(3)?;
     /// assert_eq!(a, b);
     /// Ok(())
@@ -159,21 +158,21 @@ fn from(value: T) -> Self {
 }
 
 #[unstable(feature = "once_cell", issue = "74465")]
-impl<T: PartialEq> PartialEq for SyncOnceCell<T> {
-    fn eq(&self, other: &SyncOnceCell<T>) -> bool {
+impl<T: PartialEq> PartialEq for OnceLock<T> {
+    fn eq(&self, other: &OnceLock<T>) -> bool {
         self.get() == other.get()
     }
 }
 
 #[unstable(feature = "once_cell", issue = "74465")]
-impl<T: Eq> Eq for SyncOnceCell<T> {}
+impl<T: Eq> Eq for OnceLock<T> {}
 
-impl<T> SyncOnceCell<T> {
+impl<T> OnceLock<T> {
     /// Creates a new empty cell.
     #[unstable(feature = "once_cell", issue = "74465")]
     #[must_use]
-    pub const fn new() -> SyncOnceCell<T> {
-        SyncOnceCell {
+    pub const fn new() -> OnceLock<T> {
+        OnceLock {
             once: Once::new(),
             value: UnsafeCell::new(MaybeUninit::uninit()),
             _marker: PhantomData,
@@ -207,6 +206,35 @@ pub fn get_mut(&mut self) -> Option<&mut T> {
         }
     }
 
+    /// Blocks the current thread until the cell is initialized, then returns
+    /// a reference to its value.
+    ///
+    /// Failed or panicking initialization attempts do not end the wait; the
+    /// caller keeps waiting until some thread stores a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    ///
+    /// use std::sync::OnceLock;
+    /// use std::thread;
+    ///
+    /// static VALUE: OnceLock<u32> = OnceLock::new();
+    ///
+    /// let waiter = thread::spawn(|| *VALUE.wait());
+    /// VALUE.set(92).unwrap();
+    /// assert_eq!(waiter.join().unwrap(), 92);
+    /// ```
+    #[unstable(feature = "once_cell", issue = "74465")]
+    pub fn wait(&self) -> &T {
+        self.once.wait_force();
+
+        // SAFETY: `wait_force` only returns once the inner value has been
+        // initialized.
+        unsafe { self.get_unchecked() }
+    }
+
     /// Sets the contents of this cell to `value`.
     ///
     /// May block if another thread is currently attempting to initialize the cell. The cell is
@@ -219,9 +247,9 @@ pub fn get_mut(&mut self) -> Option<&mut T> {
     /// ```
     /// #![feature(once_cell)]
     ///
-    /// use std::lazy::SyncOnceCell;
+    /// use std::sync::OnceLock;
     ///
-    /// static CELL: SyncOnceCell<i32> = SyncOnceCell::new();
+    /// static CELL: OnceLock<i32> = OnceLock::new();
     ///
     /// fn main() {
     ///     assert!(CELL.get().is_none());
@@ -265,9 +293,9 @@ pub fn set(&self, value: T) -> Result<(), T> {
     /// ```
     /// #![feature(once_cell)]
     ///
-    /// use std::lazy::SyncOnceCell;
+    /// use std::sync::OnceLock;
     ///
-    /// let cell = SyncOnceCell::new();
+    /// let cell = OnceLock::new();
     /// let value = cell.get_or_init(|| 92);
     /// assert_eq!(value, &92);
     /// let value = cell.get_or_init(|| unreachable!());
@@ -301,9 +329,9 @@ pub fn get_or_init<F>(&self, f: F) -> &T
     /// ```
     /// #![feature(once_cell)]
     ///
-    /// use std::lazy::SyncOnceCell;
+    /// use std::sync::OnceLock;
     ///
-    /// let cell = SyncOnceCell::new();
+    /// let cell = OnceLock::new();
     /// assert_eq!(cell.get_or_try_init(|| Err(())), Err(()));
     /// assert!(cell.get().is_none());
     /// let value = cell.get_or_try_init(|| -> Result<i32, ()> {
@@ -319,7 +347,7 @@ pub fn get_or_try_init<F, E>(&self, f: F) -> Result<&T, E>
     {
         // Fast path check
         // NOTE: We need to perform an acquire on the state in this method
-        // in order to correctly synchronize `SyncLazy::force`. This is
+        // in order to correctly synchronize `LazyLock::force`. This is
         // currently done by calling `self.get()`, which in turn calls
         // `self.is_initialized()`, which in turn performs the acquire.
         if let Some(value) = self.get() {
@@ -387,7 +415,7 @@ pub(crate) fn get_or_init_pin<F, G>(self: Pin<&Self>, f: F, g: G) -> Pin<&T>
         unsafe { Pin::new_unchecked(self.get_ref().get_unchecked()) }
     }
 
-    /// Consumes the `SyncOnceCell`, returning the wrapped value. Returns
+    /// Consumes the `OnceLock`, returning the wrapped value. Returns
     /// `None` if the cell was empty.
     ///
     /// # Examples
@@ -395,12 +423,12 @@ pub(crate) fn get_or_init_pin<F, G>(self: Pin<&Self>, f: F, g: G) -> Pin<&T>
     /// ```
     /// #![feature(once_cell)]
     ///
-    /// use std::lazy::SyncOnceCell;
+    /// use std::sync::OnceLock;
     ///
-    /// let cell: SyncOnceCell<String> = SyncOnceCell::new();
+    /// let cell: OnceLock<String> = OnceLock::new();
     /// assert_eq!(cell.into_inner(), None);
     ///
-    /// let cell = SyncOnceCell::new();
+    /// let cell = OnceLock::new();
     /// cell.set("hello".to_string()).unwrap();
     /// assert_eq!(cell.into_inner(), Some("hello".to_string()));
     /// ```
@@ -409,9 +437,9 @@ pub fn into_inner(mut self) -> Option<T> {
         self.take()
     }
 
-    /// Takes the value out of this `SyncOnceCell`, moving it back to an uninitialized state.
+    /// Takes the value out of this `OnceLock`, moving it back to an uninitialized state.
     ///
-    /// Has no effect and returns `None` if the `SyncOnceCell` hasn't been initialized.
+    /// Has no effect and returns `None` if the `OnceLock` hasn't been initialized.
     ///
     /// Safety is guaranteed by requiring a mutable reference.
     ///
@@ -420,12 +448,12 @@ pub fn into_inner(mut self) -> Option<T> {
     /// ```
     /// #![feature(once_cell)]
     ///
-    /// use std::lazy::SyncOnceCell;
+    /// use std::sync::OnceLock;
     ///
-    /// let mut cell: SyncOnceCell<String> = SyncOnceCell::new();
+    /// let mut cell: OnceLock<String> = OnceLock::new();
     /// assert_eq!(cell.take(), None);
     ///
-    /// let mut cell = SyncOnceCell::new();
+    /// let mut cell = OnceLock::new();
     /// cell.set("hello".to_string()).unwrap();
     /// assert_eq!(cell.take(), Some("hello".to_string()));
     /// assert_eq!(cell.get(), None);
@@ -492,7 +520,7 @@ unsafe fn get_unchecked_mut(&mut self) -> &mut T {
     }
 }
 
-unsafe impl<#[may_dangle] T> Drop for SyncOnceCell<T> {
+unsafe impl<#[may_dangle] T> Drop for OnceLock<T> {
     fn drop(&mut self) {
         if self.is_initialized() {
             // SAFETY: The cell is initialized and being dropped, so it can't
@@ -502,116 +530,3 @@ fn drop(&mut self) {
         }
     }
 }
-
-/// A value which is initialized on the first access.
-///
-/// This type is a thread-safe `Lazy`, and can be used in statics.
-///
-/// # Examples
-///
-/// ```
-/// #![feature(once_cell)]
-///
-/// use std::collections::HashMap;
-///
-/// use std::lazy::SyncLazy;
-///
-/// static HASHMAP: SyncLazy<HashMap<i32, String>> = SyncLazy::new(|| {
-///     println!("initializing");
-///     let mut m = HashMap::new();
-///     m.insert(13, "Spica".to_string());
-///     m.insert(74, "Hoyten".to_string());
-///     m
-/// });
-///
-/// fn main() {
-///     println!("ready");
-///     std::thread::spawn(|| {
-///         println!("{:?}", HASHMAP.get(&13));
-///     }).join().unwrap();
-///     println!("{:?}", HASHMAP.get(&74));
-///
-///     // Prints:
-///     //   ready
-///     //   initializing
-///     //   Some("Spica")
-///     //   Some("Hoyten")
-/// }
-/// ```
-#[unstable(feature = "once_cell", issue = "74465")]
-pub struct SyncLazy<T, F = fn() -> T> {
-    cell: SyncOnceCell<T>,
-    init: Cell<Option<F>>,
-}
-
-#[unstable(feature = "once_cell", issue = "74465")]
-impl<T: fmt::Debug, F> fmt::Debug for SyncLazy<T, F> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Lazy").field("cell", &self.cell).finish_non_exhaustive()
-    }
-}
-
-// We never create a `&F` from a `&SyncLazy<T, F>` so it is fine
-// to not impl `Sync` for `F`
-// we do create a `&mut Option<F>` in `force`, but this is
-// properly synchronized, so it only happens once
-// so it also does not contribute to this impl.
-#[unstable(feature = "once_cell", issue = "74465")]
-unsafe impl<T, F: Send> Sync for SyncLazy<T, F> where SyncOnceCell<T>: Sync {}
-// auto-derived `Send` impl is OK.
-
-#[unstable(feature = "once_cell", issue = "74465")]
-impl<T, F: UnwindSafe> RefUnwindSafe for SyncLazy<T, F> where SyncOnceCell<T>: RefUnwindSafe {}
-#[unstable(feature = "once_cell", issue = "74465")]
-impl<T, F: UnwindSafe> UnwindSafe for SyncLazy<T, F> where SyncOnceCell<T>: UnwindSafe {}
-
-impl<T, F> SyncLazy<T, F> {
-    /// Creates a new lazy value with the given initializing
-    /// function.
-    #[unstable(feature = "once_cell", issue = "74465")]
-    pub const fn new(f: F) -> SyncLazy<T, F> {
-        SyncLazy { cell: SyncOnceCell::new(), init: Cell::new(Some(f)) }
-    }
-}
-
-impl<T, F: FnOnce() -> T> SyncLazy<T, F> {
-    /// Forces the evaluation of this lazy value and
-    /// returns a reference to result. This is equivalent
-    /// to the `Deref` impl, but is explicit.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// #![feature(once_cell)]
-    ///
-    /// use std::lazy::SyncLazy;
-    ///
-    /// let lazy = SyncLazy::new(|| 92);
-    ///
-    /// assert_eq!(SyncLazy::force(&lazy), &92);
-    /// assert_eq!(&*lazy, &92);
-    /// ```
-    #[unstable(feature = "once_cell", issue = "74465")]
-    pub fn force(this: &SyncLazy<T, F>) -> &T {
-        this.cell.get_or_init(|| match this.init.take() {
-            Some(f) => f(),
-            None => panic!("Lazy instance has previously been poisoned"),
-        })
-    }
-}
-
-#[unstable(feature = "once_cell", issue = "74465")]
-impl<T, F: FnOnce() -> T> Deref for SyncLazy<T, F> {
-    type Target = T;
-    fn deref(&self) -> &T {
-        SyncLazy::force(self)
-    }
-}
-
-#[unstable(feature = "once_cell", issue = "74465")]
-impl<T: Default> Default for SyncLazy<T> {
-    /// Creates a new lazy value using `Default` as the initializing function.
-    fn default() -> SyncLazy<T> {
-        SyncLazy::new(T::default)
-    }
-}
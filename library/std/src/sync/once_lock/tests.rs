@@ -1,56 +1,21 @@
 use crate::{
-    lazy::{Lazy, SyncLazy, SyncOnceCell},
     panic,
     sync::{
         atomic::{AtomicUsize, Ordering::SeqCst},
         mpsc::channel,
-        Mutex,
+        OnceLock,
     },
     thread,
 };
 
-#[test]
-fn lazy_default() {
-    static CALLED: AtomicUsize = AtomicUsize::new(0);
-
-    struct Foo(u8);
-    impl Default for Foo {
-        fn default() -> Self {
-            CALLED.fetch_add(1, SeqCst);
-            Foo(42)
-        }
-    }
-
-    let lazy: Lazy<Mutex<Foo>> = <_>::default();
-
-    assert_eq!(CALLED.load(SeqCst), 0);
-
-    assert_eq!(lazy.lock().unwrap().0, 42);
-    assert_eq!(CALLED.load(SeqCst), 1);
-
-    lazy.lock().unwrap().0 = 21;
-
-    assert_eq!(lazy.lock().unwrap().0, 21);
-    assert_eq!(CALLED.load(SeqCst), 1);
-}
-
-#[test]
-fn lazy_poisoning() {
-    let x: Lazy<String> = Lazy::new(|| panic!("kaboom"));
-    for _ in 0..2 {
-        let res = panic::catch_unwind(panic::AssertUnwindSafe(|| x.len()));
-        assert!(res.is_err());
-    }
-}
-
 fn spawn_and_wait<R: Send + 'static>(f: impl FnOnce() -> R + Send + 'static) -> R {
     thread::spawn(f).join().unwrap()
 }
 
 #[test]
 #[cfg_attr(target_os = "emscripten", ignore)]
-fn sync_once_cell() {
-    static ONCE_CELL: SyncOnceCell<i32> = SyncOnceCell::new();
+fn once_lock() {
+    static ONCE_CELL: OnceLock<i32> = OnceLock::new();
 
     assert!(ONCE_CELL.get().is_none());
 
@@ -64,8 +29,8 @@ fn sync_once_cell() {
 }
 
 #[test]
-fn sync_once_cell_get_mut() {
-    let mut c = SyncOnceCell::new();
+fn once_lock_get_mut() {
+    let mut c = OnceLock::new();
     assert!(c.get_mut().is_none());
     c.set(90).unwrap();
     *c.get_mut().unwrap() += 2;
@@ -73,8 +38,8 @@ fn sync_once_cell_get_mut() {
 }
 
 #[test]
-fn sync_once_cell_get_unchecked() {
-    let c = SyncOnceCell::new();
+fn once_lock_get_unchecked() {
+    let c = OnceLock::new();
     c.set(92).unwrap();
     unsafe {
         assert_eq!(c.get_unchecked(), &92);
@@ -83,7 +48,7 @@ fn sync_once_cell_get_unchecked() {
 
 #[test]
 #[cfg_attr(target_os = "emscripten", ignore)]
-fn sync_once_cell_drop() {
+fn once_lock_drop() {
     static DROP_CNT: AtomicUsize = AtomicUsize::new(0);
     struct Dropper;
     impl Drop for Dropper {
@@ -92,7 +57,7 @@ fn drop(&mut self) {
         }
     }
 
-    let x = SyncOnceCell::new();
+    let x = OnceLock::new();
     spawn_and_wait(move || {
         x.get_or_init(|| Dropper);
         assert_eq!(DROP_CNT.load(SeqCst), 0);
@@ -103,14 +68,14 @@ fn drop(&mut self) {
 }
 
 #[test]
-fn sync_once_cell_drop_empty() {
-    let x = SyncOnceCell::<String>::new();
+fn once_lock_drop_empty() {
+    let x = OnceLock::<String>::new();
     drop(x);
 }
 
 #[test]
 fn clone() {
-    let s = SyncOnceCell::new();
+    let s = OnceLock::new();
     let c = s.clone();
     assert!(c.get().is_none());
 
@@ -121,7 +86,7 @@ fn clone() {
 
 #[test]
 fn get_or_try_init() {
-    let cell: SyncOnceCell<String> = SyncOnceCell::new();
+    let cell: OnceLock<String> = OnceLock::new();
     assert!(cell.get().is_none());
 
     let res = panic::catch_unwind(|| cell.get_or_try_init(|| -> Result<_, ()> { panic!() }));
@@ -137,97 +102,32 @@ fn get_or_try_init() {
 
 #[test]
 fn from_impl() {
-    assert_eq!(SyncOnceCell::from("value").get(), Some(&"value"));
-    assert_ne!(SyncOnceCell::from("foo").get(), Some(&"bar"));
+    assert_eq!(OnceLock::from("value").get(), Some(&"value"));
+    assert_ne!(OnceLock::from("foo").get(), Some(&"bar"));
 }
 
 #[test]
 fn partialeq_impl() {
-    assert!(SyncOnceCell::from("value") == SyncOnceCell::from("value"));
-    assert!(SyncOnceCell::from("foo") != SyncOnceCell::from("bar"));
+    assert!(OnceLock::from("value") == OnceLock::from("value"));
+    assert!(OnceLock::from("foo") != OnceLock::from("bar"));
 
-    assert!(SyncOnceCell::<String>::new() == SyncOnceCell::new());
-    assert!(SyncOnceCell::<String>::new() != SyncOnceCell::from("value".to_owned()));
+    assert!(OnceLock::<String>::new() == OnceLock::new());
+    assert!(OnceLock::<String>::new() != OnceLock::from("value".to_owned()));
 }
 
 #[test]
 fn into_inner() {
-    let cell: SyncOnceCell<String> = SyncOnceCell::new();
+    let cell: OnceLock<String> = OnceLock::new();
     assert_eq!(cell.into_inner(), None);
-    let cell = SyncOnceCell::new();
+    let cell = OnceLock::new();
     cell.set("hello".to_string()).unwrap();
     assert_eq!(cell.into_inner(), Some("hello".to_string()));
 }
 
 #[test]
-#[cfg_attr(target_os = "emscripten", ignore)]
-fn sync_lazy_new() {
-    static CALLED: AtomicUsize = AtomicUsize::new(0);
-    static SYNC_LAZY: SyncLazy<i32> = SyncLazy::new(|| {
-        CALLED.fetch_add(1, SeqCst);
-        92
-    });
-
-    assert_eq!(CALLED.load(SeqCst), 0);
-
-    spawn_and_wait(|| {
-        let y = *SYNC_LAZY - 30;
-        assert_eq!(y, 62);
-        assert_eq!(CALLED.load(SeqCst), 1);
-    });
-
-    let y = *SYNC_LAZY - 30;
-    assert_eq!(y, 62);
-    assert_eq!(CALLED.load(SeqCst), 1);
-}
-
-#[test]
-fn sync_lazy_default() {
-    static CALLED: AtomicUsize = AtomicUsize::new(0);
-
-    struct Foo(u8);
-    impl Default for Foo {
-        fn default() -> Self {
-            CALLED.fetch_add(1, SeqCst);
-            Foo(42)
-        }
-    }
-
-    let lazy: SyncLazy<Mutex<Foo>> = <_>::default();
-
-    assert_eq!(CALLED.load(SeqCst), 0);
-
-    assert_eq!(lazy.lock().unwrap().0, 42);
-    assert_eq!(CALLED.load(SeqCst), 1);
-
-    lazy.lock().unwrap().0 = 21;
-
-    assert_eq!(lazy.lock().unwrap().0, 21);
-    assert_eq!(CALLED.load(SeqCst), 1);
-}
-
-#[test]
-#[cfg_attr(target_os = "emscripten", ignore)]
-fn static_sync_lazy() {
-    static XS: SyncLazy<Vec<i32>> = SyncLazy::new(|| {
-        let mut xs = Vec::new();
-        xs.push(1);
-        xs.push(2);
-        xs.push(3);
-        xs
-    });
-
-    spawn_and_wait(|| {
-        assert_eq!(&*XS, &vec![1, 2, 3]);
-    });
-
-    assert_eq!(&*XS, &vec![1, 2, 3]);
-}
-
-#[test]
-fn static_sync_lazy_via_fn() {
+fn static_lazy_lock_via_fn() {
     fn xs() -> &'static Vec<i32> {
-        static XS: SyncOnceCell<Vec<i32>> = SyncOnceCell::new();
+        static XS: OnceLock<Vec<i32>> = OnceLock::new();
         XS.get_or_init(|| {
             let mut xs = Vec::new();
             xs.push(1);
@@ -239,29 +139,13 @@ fn xs() -> &'static Vec<i32> {
     assert_eq!(xs(), &vec![1, 2, 3]);
 }
 
-#[test]
-fn sync_lazy_poisoning() {
-    let x: SyncLazy<String> = SyncLazy::new(|| panic!("kaboom"));
-    for _ in 0..2 {
-        let res = panic::catch_unwind(|| x.len());
-        assert!(res.is_err());
-    }
-}
-
-#[test]
-fn is_sync_send() {
-    fn assert_traits<T: Send + Sync>() {}
-    assert_traits::<SyncOnceCell<String>>();
-    assert_traits::<SyncLazy<String>>();
-}
-
 #[test]
 fn eval_once_macro() {
     macro_rules! eval_once {
         (|| -> $ty:ty {
             $($body:tt)*
         }) => {{
-            static ONCE_CELL: SyncOnceCell<$ty> = SyncOnceCell::new();
+            static ONCE_CELL: OnceLock<$ty> = OnceLock::new();
             fn init() -> $ty {
                 $($body)*
             }
@@ -284,8 +168,8 @@ fn init() -> $ty {
 
 #[test]
 #[cfg_attr(target_os = "emscripten", ignore)]
-fn sync_once_cell_does_not_leak_partially_constructed_boxes() {
-    static ONCE_CELL: SyncOnceCell<String> = SyncOnceCell::new();
+fn once_lock_does_not_leak_partially_constructed_boxes() {
+    static ONCE_CELL: OnceLock<String> = OnceLock::new();
 
     let n_readers = 10;
     let n_writers = 3;
@@ -320,9 +204,42 @@ fn sync_once_cell_does_not_leak_partially_constructed_boxes() {
 
 #[test]
 fn dropck() {
-    let cell = SyncOnceCell::new();
+    let cell = OnceLock::new();
     {
         let s = String::new();
         cell.set(&s).unwrap();
     }
 }
+
+#[test]
+fn is_sync_send() {
+    fn assert_traits<T: Send + Sync>() {}
+    assert_traits::<OnceLock<String>>();
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn wait() {
+    static CELL: OnceLock<i32> = OnceLock::new();
+
+    let waiter = thread::spawn(|| *CELL.wait());
+    CELL.set(92).unwrap();
+    assert_eq!(waiter.join().unwrap(), 92);
+    assert_eq!(*CELL.wait(), 92);
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn wait_survives_failed_init() {
+    static CELL: OnceLock<i32> = OnceLock::new();
+
+    let waiter = thread::spawn(|| *CELL.wait());
+
+    let res = panic::catch_unwind(|| CELL.get_or_init(|| panic!("kaboom")));
+    assert!(res.is_err());
+    assert_eq!(CELL.get_or_try_init(|| Err(())), Err(()));
+    assert!(CELL.get().is_none());
+
+    CELL.get_or_init(|| 92);
+    assert_eq!(waiter.join().unwrap(), 92);
+}
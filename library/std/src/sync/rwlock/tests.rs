@@ -245,3 +245,11 @@ fn test_get_mut_poison() {
         Ok(x) => panic!("get_mut of poisoned RwLock is Ok: {:?}", x),
     }
 }
+
+#[test]
+fn test_const_new() {
+    static L: RwLock<i32> = RwLock::new(0);
+
+    *L.write().unwrap() += 1;
+    assert_eq!(*L.read().unwrap(), 1);
+}
@@ -28,6 +28,8 @@
 pub mod fs;
 #[path = "../unsupported/io.rs"]
 pub mod io;
+#[path = "../unsupported/loaded_modules.rs"]
+pub mod loaded_modules;
 pub mod memchr;
 pub mod mutex;
 pub mod net;
@@ -38,6 +40,8 @@
 pub mod path;
 #[path = "../unsupported/pipe.rs"]
 pub mod pipe;
+#[path = "../unsupported/poller.rs"]
+pub mod poller;
 #[path = "../unsupported/process.rs"]
 pub mod process;
 pub mod rwlock;
@@ -64,16 +68,6 @@ pub fn unsupported_err() -> crate::io::Error {
     )
 }
 
-pub unsafe fn strlen(start: *const c_char) -> usize {
-    let mut str = start;
-
-    while *str != 0 {
-        str = str.offset(1);
-    }
-
-    (str as usize) - (start as usize)
-}
-
 #[no_mangle]
 pub extern "C" fn floor(x: f64) -> f64 {
     unsafe { intrinsics::floorf64(x) }
@@ -166,11 +166,6 @@ pub const fn new() -> Mutex {
         Mutex { inner: Spinlock::new(MutexInner::new()) }
     }
 
-    #[inline]
-    pub unsafe fn init(&mut self) {
-        self.inner = Spinlock::new(MutexInner::new());
-    }
-
     #[inline]
     pub unsafe fn lock(&self) {
         loop {
@@ -207,9 +202,6 @@ pub unsafe fn try_lock(&self) -> bool {
         }
         guard.locked
     }
-
-    #[inline]
-    pub unsafe fn destroy(&self) {}
 }
 
 pub struct ReentrantMutex {
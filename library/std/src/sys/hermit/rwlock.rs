@@ -85,11 +85,11 @@ pub unsafe fn write_unlock(&self) {
         // FIXME: should only wake up one of these some of the time
         self.cond.notify_all();
     }
+}
 
-    #[inline]
-    pub unsafe fn destroy(&self) {
-        self.lock.destroy();
-        self.cond.destroy();
+impl Drop for RWLock {
+    fn drop(&mut self) {
+        unsafe { self.cond.destroy() };
     }
 }
 
@@ -93,20 +93,6 @@ pub unsafe fn get_unchecked(&self) -> (abi::ID, &T) {
         })
     }
 
-    /// Assign the content without checking if it's already initialized or
-    /// being initialized.
-    pub unsafe fn set_unchecked(&self, (id, extra): (abi::ID, T)) {
-        debug_assert!(self.get().is_none());
-
-        // Assumption: A positive `abi::ID` fits in `usize`.
-        debug_assert!(id >= 0);
-        debug_assert!(usize::try_from(id).is_ok());
-        let id = id as usize;
-
-        unsafe { *self.extra.get() = MaybeUninit::new(extra) };
-        self.id.store(id, Ordering::Release);
-    }
-
     /// Gets the contents of the cell, initializing it with `f` if
     /// the cell was empty. If the cell was empty and `f` failed, an
     /// error is returned.
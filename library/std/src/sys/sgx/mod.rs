@@ -5,7 +5,6 @@
 #![deny(unsafe_op_in_unsafe_fn)]
 
 use crate::io::ErrorKind;
-use crate::os::raw::c_char;
 use crate::sync::atomic::{AtomicBool, Ordering};
 
 pub mod abi;
@@ -22,6 +21,8 @@
 pub mod fs;
 #[path = "../unsupported/io.rs"]
 pub mod io;
+#[path = "../unsupported/loaded_modules.rs"]
+pub mod loaded_modules;
 pub mod memchr;
 pub mod mutex;
 pub mod net;
@@ -31,6 +32,8 @@
 pub mod path;
 #[path = "../unsupported/pipe.rs"]
 pub mod pipe;
+#[path = "../unsupported/poller.rs"]
+pub mod poller;
 #[path = "../unsupported/process.rs"]
 pub mod process;
 pub mod rwlock;
@@ -123,15 +126,6 @@ pub fn decode_error_kind(code: i32) -> ErrorKind {
     }
 }
 
-pub unsafe fn strlen(mut s: *const c_char) -> usize {
-    let mut n = 0;
-    while unsafe { *s } != 0 {
-        n += 1;
-        s = unsafe { s.offset(1) };
-    }
-    return n;
-}
-
 pub fn abort_internal() -> ! {
     abi::usercalls::exit(true)
 }
@@ -7,13 +7,20 @@
     try_lock_or_false, NotifiedTcs, SpinMutex, SpinMutexGuard, WaitQueue, WaitVariable,
 };
 use crate::mem;
+use crate::sys_common::lazy_box::{LazyBox, LazyInit};
 
 pub struct RWLock {
     readers: SpinMutex<WaitVariable<Option<NonZeroUsize>>>,
     writer: SpinMutex<WaitVariable<bool>>,
 }
 
-pub type MovableRWLock = Box<RWLock>;
+pub type MovableRWLock = LazyBox<RWLock>;
+
+impl LazyInit for RWLock {
+    fn init() -> Box<Self> {
+        Box::new(Self::new())
+    }
+}
 
 // Check at compile time that RWLock size matches C definition (see test_c_rwlock_initializer below)
 //
@@ -168,9 +175,6 @@ unsafe fn unlock(&self) {
             unsafe { self.__read_unlock(rguard, wguard) };
         }
     }
-
-    #[inline]
-    pub unsafe fn destroy(&self) {}
 }
 
 // The following functions are needed by libunwind. These symbols are named
@@ -7,8 +7,11 @@
 pub const AF_INET6: i32 = 10;
 pub const AF_INET: i32 = 2;
 pub const IPPROTO_IP: i32 = 0;
+pub const IPPROTO_ICMP: i32 = 1;
+pub const IPPROTO_ICMPV6: i32 = 58;
 pub const IPPROTO_IPV6: i32 = 41;
 pub const IPPROTO_TCP: i32 = 6;
+pub const IPPROTO_UDP: i32 = 17;
 pub const IPV6_ADD_MEMBERSHIP: i32 = 12;
 pub const IPV6_DROP_MEMBERSHIP: i32 = 13;
 pub const IPV6_MULTICAST_LOOP: i32 = 19;
@@ -22,12 +25,16 @@
 pub const SHUT_RDWR: i32 = 2;
 pub const SHUT_WR: i32 = 1;
 pub const SOCK_DGRAM: i32 = 2;
+pub const SOCK_RAW: i32 = 3;
 pub const SOCK_STREAM: i32 = 1;
 pub const SOL_SOCKET: i32 = 4095;
 pub const SO_BROADCAST: i32 = 32;
 pub const SO_ERROR: i32 = 4103;
+pub const SO_KEEPALIVE: i32 = 8;
+pub const SO_RCVBUF: i32 = 4098;
 pub const SO_RCVTIMEO: i32 = 4102;
 pub const SO_REUSEADDR: i32 = 4;
+pub const SO_SNDBUF: i32 = 4097;
 pub const SO_SNDTIMEO: i32 = 4101;
 pub const SO_LINGER: i32 = 128;
 pub const TCP_NODELAY: i32 = 1;
@@ -28,6 +28,8 @@ mod itron {
 pub(crate) mod error;
 pub mod fs;
 pub mod io;
+#[path = "../unsupported/loaded_modules.rs"]
+pub mod loaded_modules;
 pub mod net;
 pub mod os;
 #[path = "../unix/os_str.rs"]
@@ -35,6 +37,8 @@ mod itron {
 pub mod path;
 #[path = "../unsupported/pipe.rs"]
 pub mod pipe;
+#[path = "../unsupported/poller.rs"]
+pub mod poller;
 #[path = "../unsupported/process.rs"]
 pub mod process;
 pub mod rwlock;
@@ -93,4 +97,3 @@ pub fn hashmap_random_keys() -> (u64, u64) {
     }
 }
 
-pub use libc::strlen;
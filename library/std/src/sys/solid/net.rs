@@ -2,7 +2,7 @@
 use crate::{
     cmp,
     ffi::CStr,
-    io::{self, ErrorKind, IoSlice, IoSliceMut},
+    io::{self, BorrowedBuf, BorrowedCursor, ErrorKind, IoSlice, IoSliceMut},
     mem,
     net::{Shutdown, SocketAddr},
     ptr, str,
@@ -218,8 +218,12 @@ pub fn new(addr: &SocketAddr, ty: c_int) -> io::Result<Socket> {
     }
 
     pub fn new_raw(fam: c_int, ty: c_int) -> io::Result<Socket> {
+        Socket::new_with_protocol(fam, ty, 0)
+    }
+
+    pub fn new_with_protocol(fam: c_int, ty: c_int, protocol: c_int) -> io::Result<Socket> {
         unsafe {
-            let fd = cvt(netc::socket(fam, ty, 0))?;
+            let fd = cvt(netc::socket(fam, ty, protocol))?;
             let fd = FileDesc::new(fd);
             let socket = Socket(fd);
 
@@ -230,8 +234,8 @@ pub fn new_raw(fam: c_int, ty: c_int) -> io::Result<Socket> {
     pub fn connect_timeout(&self, addr: &SocketAddr, timeout: Duration) -> io::Result<()> {
         self.set_nonblocking(true)?;
         let r = unsafe {
-            let (addrp, len) = addr.into_inner();
-            cvt(netc::connect(self.0.raw(), addrp, len))
+            let (addr, len) = addr.into_inner();
+            cvt(netc::connect(self.0.raw(), addr.as_ptr(), len))
         };
         self.set_nonblocking(false)?;
 
@@ -290,23 +294,50 @@ pub fn accept(&self, storage: *mut sockaddr, len: *mut socklen_t) -> io::Result<
         Ok(Socket(fd))
     }
 
+    pub fn accept_nonblocking(
+        &self,
+        storage: *mut sockaddr,
+        len: *mut socklen_t,
+    ) -> io::Result<Socket> {
+        let socket = self.accept(storage, len)?;
+        socket.set_nonblocking(true)?;
+        Ok(socket)
+    }
+
     pub fn duplicate(&self) -> io::Result<Socket> {
         self.0.duplicate().map(Socket)
     }
 
-    fn recv_with_flags(&self, buf: &mut [u8], flags: c_int) -> io::Result<usize> {
+    fn recv_with_flags(&self, mut buf: BorrowedCursor<'_>, flags: c_int) -> io::Result<()> {
         let ret = cvt(unsafe {
-            netc::recv(self.0.raw(), buf.as_mut_ptr() as *mut c_void, buf.len(), flags)
+            netc::recv(
+                self.0.raw(),
+                buf.as_mut().as_mut_ptr() as *mut c_void,
+                buf.capacity(),
+                flags,
+            )
         })?;
-        Ok(ret as usize)
+        // SAFETY: `ret` bytes were written to the unfilled portion of the buffer
+        unsafe {
+            buf.advance(ret as usize);
+        }
+        Ok(())
     }
 
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
-        self.recv_with_flags(buf, 0)
+        let mut buf = BorrowedBuf::from(buf);
+        self.recv_with_flags(buf.unfilled(), 0)?;
+        Ok(buf.len())
     }
 
     pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
-        self.recv_with_flags(buf, MSG_PEEK)
+        let mut buf = BorrowedBuf::from(buf);
+        self.recv_with_flags(buf.unfilled(), MSG_PEEK)?;
+        Ok(buf.len())
+    }
+
+    pub fn read_buf(&self, buf: BorrowedCursor<'_>) -> io::Result<()> {
+        self.recv_with_flags(buf, 0)
     }
 
     pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
@@ -2,7 +2,7 @@
 
 use crate::ffi::{CStr, CString, OsStr, OsString};
 use crate::fmt;
-use crate::io::{self, Error, IoSlice, IoSliceMut, SeekFrom};
+use crate::io::{self, BorrowedCursor, Error, IoSlice, IoSliceMut, SeekFrom};
 use crate::mem;
 use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd};
 use crate::path::{Path, PathBuf};
@@ -19,7 +19,7 @@
     target_os = "ios",
 ))]
 use crate::sys::weak::syscall;
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "android"))]
 use crate::sys::weak::weak;
 
 use libc::{c_int, mode_t};
@@ -250,6 +250,12 @@ pub struct FilePermissions {
     mode: mode_t,
 }
 
+#[derive(Copy, Clone, Debug, Default)]
+pub struct FileTimes {
+    accessed: Option<SystemTime>,
+    modified: Option<SystemTime>,
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct FileType {
     mode: mode_t,
@@ -415,6 +421,26 @@ pub fn mode(&self) -> u32 {
     }
 }
 
+impl FileTimes {
+    pub fn set_accessed(&mut self, t: SystemTime) {
+        self.accessed = Some(t);
+    }
+
+    pub fn set_modified(&mut self, t: SystemTime) {
+        self.modified = Some(t);
+    }
+
+    // `UTIME_OMIT` in `tv_nsec` makes `futimens` leave the timestamp alone.
+    #[cfg(not(target_os = "redox"))]
+    fn to_timespecs(&self) -> [libc::timespec; 2] {
+        let to_timespec = |time: Option<SystemTime>| match time {
+            Some(time) => libc::timespec::from(time),
+            None => libc::timespec { tv_sec: 0, tv_nsec: libc::UTIME_OMIT as _ },
+        };
+        [to_timespec(self.accessed), to_timespec(self.modified)]
+    }
+}
+
 impl FileType {
     pub fn is_dir(&self) -> bool {
         self.is(libc::S_IFDIR)
@@ -851,6 +877,10 @@ pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
 
+    pub fn read_buf(&self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
+        self.0.read_buf(cursor)
+    }
+
     pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.0.read_vectored(bufs)
     }
@@ -905,6 +935,161 @@ pub fn set_permissions(&self, perm: FilePermissions) -> io::Result<()> {
         cvt_r(|| unsafe { libc::fchmod(self.as_raw_fd(), perm.mode) })?;
         Ok(())
     }
+
+    pub fn set_times(&self, times: FileTimes) -> io::Result<()> {
+        cfg_if::cfg_if! {
+            if #[cfg(target_os = "redox")] {
+                // Redox doesn't appear to support `UTIME_OMIT`.
+                drop(times);
+                Err(io::Error::new_const(
+                    io::ErrorKind::Unsupported,
+                    &"setting file times not supported",
+                ))
+            } else if #[cfg(any(target_os = "android", target_os = "macos"))] {
+                // futimens requires macOS 10.13 and Android API level 19
+                let times = times.to_timespecs();
+                weak!(fn futimens(c_int, *const libc::timespec) -> c_int);
+                match futimens.get() {
+                    Some(futimens) => {
+                        cvt(unsafe { futimens(self.as_raw_fd(), times.as_ptr()) })?;
+                        Ok(())
+                    }
+                    #[cfg(target_os = "macos")]
+                    None => self.set_times_fallback(times),
+                    // futimes requires even newer Android.
+                    #[cfg(target_os = "android")]
+                    None => Err(io::Error::new_const(
+                        io::ErrorKind::Unsupported,
+                        &"setting file times requires Android API level 19",
+                    )),
+                }
+            } else {
+                let times = times.to_timespecs();
+                cvt(unsafe { libc::futimens(self.as_raw_fd(), times.as_ptr()) })?;
+                Ok(())
+            }
+        }
+    }
+
+    // `futimes` has no equivalent of `UTIME_OMIT`, so omitted timestamps are
+    // set to their current value.
+    #[cfg(target_os = "macos")]
+    fn set_times_fallback(&self, times: [libc::timespec; 2]) -> io::Result<()> {
+        let attr = self.file_attr()?;
+        let current: [libc::timespec; 2] = [attr.accessed()?.into(), attr.modified()?.into()];
+        let to_timeval = |ts: &libc::timespec, current: &libc::timespec| {
+            let ts = if ts.tv_nsec == libc::UTIME_OMIT as libc::c_long { current } else { ts };
+            libc::timeval { tv_sec: ts.tv_sec, tv_usec: (ts.tv_nsec / 1000) as _ }
+        };
+        let timevals = [to_timeval(&times[0], &current[0]), to_timeval(&times[1], &current[1])];
+        cvt(unsafe { libc::futimes(self.as_raw_fd(), timevals.as_ptr()) })?;
+        Ok(())
+    }
+
+    pub fn lock(&self) -> io::Result<()> {
+        self.set_lock(FileLock::Exclusive, true).map(drop)
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.set_lock(FileLock::Shared, true).map(drop)
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.set_lock(FileLock::Exclusive, false)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.set_lock(FileLock::Shared, false)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        self.set_lock(FileLock::Unlocked, false).map(drop)
+    }
+
+    /// Calls `flock` to change the lock held on the file, returning
+    /// `Ok(false)` if a non-blocking lock could not be acquired.
+    ///
+    /// `flock` locks belong to the open file description, so they are shared
+    /// by descriptors created with `dup` and inherited across `fork`, and are
+    /// unaffected by closing other descriptors for the same file, unlike
+    /// POSIX record locks.
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    ))]
+    fn set_lock(&self, lock: FileLock, blocking: bool) -> io::Result<bool> {
+        let mut operation = match lock {
+            FileLock::Shared => libc::LOCK_SH,
+            FileLock::Exclusive => libc::LOCK_EX,
+            FileLock::Unlocked => libc::LOCK_UN,
+        };
+        if !blocking {
+            operation |= libc::LOCK_NB;
+        }
+        match cvt_r(|| unsafe { libc::flock(self.as_raw_fd(), operation) }) {
+            Ok(_) => Ok(true),
+            Err(err) if err.raw_os_error() == Some(libc::EWOULDBLOCK) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Solaris and illumos have no `flock`, so fall back to a POSIX record
+    /// lock covering the whole file.
+    ///
+    /// Unlike `flock` locks, these belong to the process: they are not
+    /// inherited across `fork`, and closing *any* descriptor for the file
+    /// releases them. A shared lock also requires the file to be open for
+    /// reading, and an exclusive one requires it to be open for writing.
+    #[cfg(any(target_os = "illumos", target_os = "solaris"))]
+    fn set_lock(&self, lock: FileLock, blocking: bool) -> io::Result<bool> {
+        let mut flock: libc::flock = unsafe { mem::zeroed() };
+        flock.l_type = match lock {
+            FileLock::Shared => libc::F_RDLCK,
+            FileLock::Exclusive => libc::F_WRLCK,
+            FileLock::Unlocked => libc::F_UNLCK,
+        } as _;
+        flock.l_whence = libc::SEEK_SET as _;
+        // `l_start` and `l_len` are zero, locking the whole file even if it grows.
+        let cmd = if blocking { libc::F_SETLKW } else { libc::F_SETLK };
+        match cvt_r(|| unsafe { libc::fcntl(self.as_raw_fd(), cmd, &flock) }) {
+            Ok(_) => Ok(true),
+            Err(err) if matches!(err.raw_os_error(), Some(libc::EAGAIN | libc::EACCES)) => {
+                Ok(false)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "illumos",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+    )))]
+    fn set_lock(&self, _lock: FileLock, _blocking: bool) -> io::Result<bool> {
+        Err(io::Error::new_const(io::ErrorKind::Unsupported, &"file locking is not supported"))
+    }
+}
+
+/// The lock [`File::set_lock`] should leave on the file.
+enum FileLock {
+    Shared,
+    Exclusive,
+    Unlocked,
 }
 
 impl DirBuilder {
@@ -11,8 +11,8 @@ pub mod net {
     #![allow(warnings)]
     use crate::convert::TryFrom;
     use crate::fmt;
-    use crate::io::{self, IoSlice, IoSliceMut};
-    use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
+    use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut};
+    use crate::net::{Domain, Ipv4Addr, Ipv6Addr, Protocol, Shutdown, SocketAddr, Type};
     use crate::sys::fd::FileDesc;
     use crate::sys_common::{AsInner, FromInner, IntoInner};
     use crate::time::Duration;
@@ -54,6 +54,10 @@ pub fn read(&self, _: &mut [u8]) -> io::Result<usize> {
             unimpl!();
         }
 
+        pub fn read_buf(&self, _: BorrowedCursor<'_>) -> io::Result<()> {
+            unimpl!();
+        }
+
         pub fn read_vectored(&self, _: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
             unimpl!();
         }
@@ -186,6 +190,10 @@ pub fn read(&self, _: &mut [u8]) -> io::Result<usize> {
             unimpl!();
         }
 
+        pub fn read_buf(&self, _: BorrowedCursor<'_>) -> io::Result<()> {
+            unimpl!();
+        }
+
         pub fn read_vectored(&self, _: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
             unimpl!();
         }
@@ -483,6 +491,202 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         }
     }
 
+    pub struct GenericSocket {
+        inner: Socket,
+    }
+
+    impl GenericSocket {
+        pub fn new(_: Domain, _: Type, _: Option<Protocol>) -> io::Result<GenericSocket> {
+            unimpl!();
+        }
+
+        pub fn socket(&self) -> &Socket {
+            &self.inner
+        }
+
+        pub fn into_socket(self) -> Socket {
+            self.inner
+        }
+
+        pub fn bind(&self, _: &SocketAddr) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn listen(&self, _: i32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn connect(&self, _: &SocketAddr) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn connect_timeout(&self, _: &SocketAddr, _: Duration) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn accept(&self) -> io::Result<(GenericSocket, SocketAddr)> {
+            unimpl!();
+        }
+
+        pub fn accept_nonblocking(&self) -> io::Result<(GenericSocket, SocketAddr)> {
+            unimpl!();
+        }
+
+        pub fn socket_addr(&self) -> io::Result<SocketAddr> {
+            unimpl!();
+        }
+
+        pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+            unimpl!();
+        }
+
+        pub fn duplicate(&self) -> io::Result<GenericSocket> {
+            unimpl!();
+        }
+
+        pub fn set_reuse_address(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn reuse_address(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_reuse_port(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn reuse_port(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_recv_buffer_size(&self, _: usize) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn recv_buffer_size(&self) -> io::Result<usize> {
+            unimpl!();
+        }
+
+        pub fn set_send_buffer_size(&self, _: usize) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn send_buffer_size(&self) -> io::Result<usize> {
+            unimpl!();
+        }
+
+        pub fn set_keepalive(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_keepalive_idle(&self, _: Duration) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive_idle(&self) -> io::Result<Duration> {
+            unimpl!();
+        }
+
+        pub fn set_keepalive_interval(&self, _: Duration) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive_interval(&self) -> io::Result<Duration> {
+            unimpl!();
+        }
+
+        pub fn set_keepalive_retries(&self, _: u32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn keepalive_retries(&self) -> io::Result<u32> {
+            unimpl!();
+        }
+
+        pub fn set_linger(&self, _: Option<Duration>) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn linger(&self) -> io::Result<Option<Duration>> {
+            unimpl!();
+        }
+
+        pub fn set_nodelay(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn nodelay(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_only_v6(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn only_v6(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_ttl(&self, _: u32) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn ttl(&self) -> io::Result<u32> {
+            unimpl!();
+        }
+
+        pub fn set_broadcast(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn broadcast(&self) -> io::Result<bool> {
+            unimpl!();
+        }
+
+        pub fn set_nonblocking(&self, _: bool) -> io::Result<()> {
+            unimpl!();
+        }
+
+        pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+            unimpl!();
+        }
+    }
+
+    impl FromInner<Socket> for GenericSocket {
+        fn from_inner(socket: Socket) -> GenericSocket {
+            GenericSocket { inner: socket }
+        }
+    }
+
+    impl From<GenericSocket> for TcpStream {
+        fn from(socket: GenericSocket) -> TcpStream {
+            TcpStream { inner: socket.inner }
+        }
+    }
+
+    impl From<GenericSocket> for TcpListener {
+        fn from(socket: GenericSocket) -> TcpListener {
+            TcpListener { inner: socket.inner }
+        }
+    }
+
+    impl From<GenericSocket> for UdpSocket {
+        fn from(socket: GenericSocket) -> UdpSocket {
+            UdpSocket { inner: socket.inner }
+        }
+    }
+
+    impl fmt::Debug for GenericSocket {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "No networking support available on L4Re")
+        }
+    }
+
     pub struct LookupHost {
         original: *mut libc::addrinfo,
         cur: *mut libc::addrinfo,
@@ -0,0 +1,144 @@
+//! Lookup of the executable and shared libraries loaded into this process,
+//! through `dl_iterate_phdr`.
+
+use crate::ffi::{CStr, OsStr};
+use crate::os::unix::ffi::OsStrExt;
+use crate::path::{Path, PathBuf};
+use crate::slice;
+
+#[cfg(target_pointer_width = "32")]
+type Phdr = libc::Elf32_Phdr;
+#[cfg(target_pointer_width = "64")]
+type Phdr = libc::Elf64_Phdr;
+
+const PT_LOAD: u32 = 1;
+const PT_NOTE: u32 = 4;
+const NT_GNU_BUILD_ID: u32 = 3;
+
+pub struct LoadedModule {
+    pub base: usize,
+    pub path: Option<PathBuf>,
+    pub build_id: Option<Vec<u8>>,
+}
+
+/// Returns the module whose loaded segments contain `addr`.
+pub fn module_containing(addr: usize) -> Option<LoadedModule> {
+    let mut found = None;
+    for_each_module(|info| {
+        if !contains(info, addr) {
+            return true;
+        }
+        found = Some(LoadedModule {
+            base: info.dlpi_addr as usize,
+            path: path(info),
+            build_id: build_id(info),
+        });
+        false
+    });
+    found
+}
+
+/// Returns the base address of the loaded module with the given build id,
+/// or, if no build id is given, with the given path.
+pub fn module_base(path: Option<&Path>, build_id: Option<&[u8]>) -> Option<usize> {
+    let mut found = None;
+    for_each_module(|info| {
+        let matches = match (build_id, path) {
+            (Some(id), _) => self::build_id(info).as_deref() == Some(id),
+            (None, Some(p)) => self::path(info).as_deref() == Some(p),
+            (None, None) => false,
+        };
+        if matches {
+            found = Some(info.dlpi_addr as usize);
+        }
+        !matches
+    });
+    found
+}
+
+/// Calls `f` for every loaded module until it returns `false`.
+fn for_each_module<F: FnMut(&libc::dl_phdr_info) -> bool>(mut f: F) {
+    unsafe extern "C" fn callback<F: FnMut(&libc::dl_phdr_info) -> bool>(
+        info: *mut libc::dl_phdr_info,
+        _size: libc::size_t,
+        data: *mut libc::c_void,
+    ) -> libc::c_int {
+        // SAFETY: `data` is the `F` passed to `dl_iterate_phdr` below, and
+        // `info` is valid for the duration of this call.
+        let (f, info) = unsafe { (&mut *(data as *mut F), &*info) };
+        if f(info) { 0 } else { 1 }
+    }
+
+    // SAFETY: `callback::<F>` matches the type of `f`, which outlives the call.
+    unsafe {
+        libc::dl_iterate_phdr(Some(callback::<F>), &mut f as *mut F as *mut libc::c_void);
+    }
+}
+
+fn program_headers(info: &libc::dl_phdr_info) -> &[Phdr] {
+    if info.dlpi_phdr.is_null() {
+        return &[];
+    }
+    // SAFETY: the loader provides `dlpi_phnum` program headers at `dlpi_phdr`.
+    unsafe { slice::from_raw_parts(info.dlpi_phdr, info.dlpi_phnum as usize) }
+}
+
+fn contains(info: &libc::dl_phdr_info, addr: usize) -> bool {
+    program_headers(info).iter().any(|phdr| {
+        let start = (info.dlpi_addr as usize).wrapping_add(phdr.p_vaddr as usize);
+        phdr.p_type == PT_LOAD && addr >= start && addr - start < phdr.p_memsz as usize
+    })
+}
+
+fn path(info: &libc::dl_phdr_info) -> Option<PathBuf> {
+    let name = if info.dlpi_name.is_null() {
+        &[][..]
+    } else {
+        // SAFETY: the loader provides a nul-terminated name.
+        unsafe { CStr::from_ptr(info.dlpi_name) }.to_bytes()
+    };
+    // The main executable is reported without a name.
+    if name.is_empty() {
+        crate::env::current_exe().ok()
+    } else {
+        Some(PathBuf::from(OsStr::from_bytes(name)))
+    }
+}
+
+/// Reads the GNU build id from the module's note segments.
+fn build_id(info: &libc::dl_phdr_info) -> Option<Vec<u8>> {
+    for phdr in program_headers(info) {
+        if phdr.p_type != PT_NOTE {
+            continue;
+        }
+        let align = if phdr.p_align == 8 { 8 } else { 4 };
+        let start = (info.dlpi_addr as usize).wrapping_add(phdr.p_vaddr as usize);
+        // SAFETY: note segments of loaded modules are mapped in memory.
+        let mut notes = unsafe { slice::from_raw_parts(start as *const u8, phdr.p_memsz as usize) };
+        while notes.len() >= 12 {
+            let word = |i: usize| {
+                u32::from_ne_bytes([notes[i], notes[i + 1], notes[i + 2], notes[i + 3]])
+            };
+            let (name_size, desc_size, kind) = (word(0) as usize, word(4) as usize, word(8));
+            // The sizes come straight from the note, so a malformed one could
+            // make these overflow, in particular on 32-bit targets.
+            let name_end = name_size.checked_add(12)?;
+            let desc_start = align_up(name_end, align)?;
+            let desc_end = desc_start.checked_add(desc_size)?;
+            if desc_end > notes.len() {
+                break;
+            }
+            if kind == NT_GNU_BUILD_ID && &notes[12..name_end] == b"GNU\0" {
+                return Some(notes[desc_start..desc_end].to_vec());
+            }
+            notes = &notes[align_up(desc_end, align)?.min(notes.len())..];
+        }
+    }
+    None
+}
+
+/// Rounds `n` up to a multiple of `align`, which must be a power of two.
+/// Returns `None` on overflow.
+fn align_up(n: usize, align: usize) -> Option<usize> {
+    Some(n.checked_add(align - 1)? & !(align - 1))
+}
@@ -0,0 +1,25 @@
+//! Mutexes, condition variables and reader-writer locks.
+//!
+//! On Linux and Android these are small futex-based locks that can be moved
+//! and need neither allocation nor initialization. The other platforms use
+//! pthread primitives, which must stay at a fixed address once used. Those
+//! are boxed lazily, on first use, so they can still be created in a `const`.
+
+cfg_if::cfg_if! {
+    if #[cfg(any(target_os = "linux", target_os = "android"))] {
+        mod futex;
+        mod futex_rwlock;
+        pub use futex::{Condvar, MovableCondvar, MovableMutex, Mutex};
+        pub use futex_rwlock::{MovableRWLock, RWLock};
+    } else {
+        mod pthread_condvar;
+        mod pthread_mutex;
+        mod pthread_rwlock;
+        pub use pthread_condvar::{Condvar, MovableCondvar};
+        pub use pthread_mutex::{MovableMutex, Mutex};
+        pub use pthread_rwlock::{MovableRWLock, RWLock};
+    }
+}
+
+mod pthread_remutex;
+pub use pthread_remutex::ReentrantMutex;
@@ -1,5 +1,6 @@
 use crate::cell::UnsafeCell;
 use crate::sync::atomic::{AtomicUsize, Ordering};
+use crate::sys_common::lazy_box::{LazyBox, LazyInit};
 
 pub struct RWLock {
     inner: UnsafeCell<libc::pthread_rwlock_t>,
@@ -7,11 +8,21 @@ pub struct RWLock {
     num_readers: AtomicUsize,
 }
 
-pub type MovableRWLock = Box<RWLock>;
+pub type MovableRWLock = LazyBox<RWLock>;
 
 unsafe impl Send for RWLock {}
 unsafe impl Sync for RWLock {}
 
+impl LazyInit for RWLock {
+    fn init() -> Box<Self> {
+        Box::new(Self::new())
+    }
+
+    fn destroy(rwlock: Box<Self>) {
+        unsafe { rwlock.destroy() };
+    }
+}
+
 impl RWLock {
     pub const fn new() -> RWLock {
         RWLock {
@@ -3,7 +3,6 @@
 use crate::io::ErrorKind;
 
 pub use self::rand::hashmap_random_keys;
-pub use libc::strlen;
 
 #[cfg(not(target_os = "espidf"))]
 #[macro_use]
@@ -14,7 +13,6 @@
 pub mod args;
 #[path = "../unix/cmath.rs"]
 pub mod cmath;
-pub mod condvar;
 pub mod env;
 pub mod fd;
 pub mod fs;
@@ -24,8 +22,23 @@
 pub mod kernel_copy;
 #[cfg(target_os = "l4re")]
 mod l4re;
+#[cfg(any(
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+pub mod loaded_modules;
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+#[path = "../unsupported/loaded_modules.rs"]
+pub mod loaded_modules;
+pub mod locks;
 pub mod memchr;
-pub mod mutex;
 #[cfg(not(target_os = "l4re"))]
 pub mod net;
 #[cfg(target_os = "l4re")]
@@ -34,9 +47,9 @@
 pub mod os_str;
 pub mod path;
 pub mod pipe;
+pub mod poller;
 pub mod process;
 pub mod rand;
-pub mod rwlock;
 pub mod stack_overflow;
 pub mod stdio;
 pub mod thread;
@@ -44,6 +57,12 @@
 pub mod thread_local_key;
 pub mod time;
 
+// The lock types live in `locks`, but the rest of `sys` looks them up under
+// these names.
+pub use self::locks as condvar;
+pub use self::locks as mutex;
+pub use self::locks as rwlock;
+
 #[cfg(target_os = "espidf")]
 pub fn init(argc: isize, argv: *const *const u8) {}
 
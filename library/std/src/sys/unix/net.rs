@@ -1,6 +1,6 @@
 use crate::cmp;
 use crate::ffi::CStr;
-use crate::io::{self, IoSlice, IoSliceMut};
+use crate::io::{self, BorrowedBuf, BorrowedCursor, IoSlice, IoSliceMut};
 use crate::mem;
 use crate::net::{Shutdown, SocketAddr};
 use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, RawFd};
@@ -68,6 +68,10 @@ pub fn new(addr: &SocketAddr, ty: c_int) -> io::Result<Socket> {
     }
 
     pub fn new_raw(fam: c_int, ty: c_int) -> io::Result<Socket> {
+        Socket::new_with_protocol(fam, ty, 0)
+    }
+
+    pub fn new_with_protocol(fam: c_int, ty: c_int, protocol: c_int) -> io::Result<Socket> {
         unsafe {
             cfg_if::cfg_if! {
                 if #[cfg(any(
@@ -82,10 +86,10 @@ pub fn new_raw(fam: c_int, ty: c_int) -> io::Result<Socket> {
                     // On platforms that support it we pass the SOCK_CLOEXEC
                     // flag to atomically create the socket and set it as
                     // CLOEXEC. On Linux this was added in 2.6.27.
-                    let fd = cvt(libc::socket(fam, ty | libc::SOCK_CLOEXEC, 0))?;
+                    let fd = cvt(libc::socket(fam, ty | libc::SOCK_CLOEXEC, protocol))?;
                     Ok(Socket(FileDesc::from_raw_fd(fd)))
                 } else {
-                    let fd = cvt(libc::socket(fam, ty, 0))?;
+                    let fd = cvt(libc::socket(fam, ty, protocol))?;
                     let fd = FileDesc::from_raw_fd(fd);
                     fd.set_cloexec()?;
                     let socket = Socket(fd);
@@ -139,8 +143,8 @@ pub fn new_pair(_fam: c_int, _ty: c_int) -> io::Result<(Socket, Socket)> {
     pub fn connect_timeout(&self, addr: &SocketAddr, timeout: Duration) -> io::Result<()> {
         self.set_nonblocking(true)?;
         let r = unsafe {
-            let (addrp, len) = addr.into_inner();
-            cvt(libc::connect(self.as_raw_fd(), addrp, len))
+            let (addr, len) = addr.into_inner();
+            cvt(libc::connect(self.as_raw_fd(), addr.as_ptr(), len))
         };
         self.set_nonblocking(false)?;
 
@@ -207,6 +211,23 @@ pub fn connect_timeout(&self, addr: &SocketAddr, timeout: Duration) -> io::Resul
     }
 
     pub fn accept(&self, storage: *mut sockaddr, len: *mut socklen_t) -> io::Result<Socket> {
+        self.accept_with_flags(storage, len, false)
+    }
+
+    pub fn accept_nonblocking(
+        &self,
+        storage: *mut sockaddr,
+        len: *mut socklen_t,
+    ) -> io::Result<Socket> {
+        self.accept_with_flags(storage, len, true)
+    }
+
+    fn accept_with_flags(
+        &self,
+        storage: *mut sockaddr,
+        len: *mut socklen_t,
+        nonblocking: bool,
+    ) -> io::Result<Socket> {
         // Unfortunately the only known way right now to accept a socket and
         // atomically set the CLOEXEC flag is to use the `accept4` syscall on
         // platforms that support it. On Linux, this was added in 2.6.28,
@@ -221,8 +242,13 @@ pub fn accept(&self, storage: *mut sockaddr, len: *mut socklen_t) -> io::Result<
                 target_os = "netbsd",
                 target_os = "openbsd",
             ))] {
+                let flags = if nonblocking {
+                    libc::SOCK_CLOEXEC | libc::SOCK_NONBLOCK
+                } else {
+                    libc::SOCK_CLOEXEC
+                };
                 unsafe {
-                    let fd = cvt_r(|| libc::accept4(self.as_raw_fd(), storage, len, libc::SOCK_CLOEXEC))?;
+                    let fd = cvt_r(|| libc::accept4(self.as_raw_fd(), storage, len, flags))?;
                     Ok(Socket(FileDesc::from_raw_fd(fd)))
                 }
             } else {
@@ -230,7 +256,11 @@ pub fn accept(&self, storage: *mut sockaddr, len: *mut socklen_t) -> io::Result<
                     let fd = cvt_r(|| libc::accept(self.as_raw_fd(), storage, len))?;
                     let fd = FileDesc::from_raw_fd(fd);
                     fd.set_cloexec()?;
-                    Ok(Socket(fd))
+                    let socket = Socket(fd);
+                    if nonblocking {
+                        socket.set_nonblocking(true)?;
+                    }
+                    Ok(socket)
                 }
             }
         }
@@ -240,19 +270,36 @@ pub fn duplicate(&self) -> io::Result<Socket> {
         self.0.duplicate().map(Socket)
     }
 
-    fn recv_with_flags(&self, buf: &mut [u8], flags: c_int) -> io::Result<usize> {
+    fn recv_with_flags(&self, mut buf: BorrowedCursor<'_>, flags: c_int) -> io::Result<()> {
         let ret = cvt(unsafe {
-            libc::recv(self.as_raw_fd(), buf.as_mut_ptr() as *mut c_void, buf.len(), flags)
+            libc::recv(
+                self.as_raw_fd(),
+                buf.as_mut().as_mut_ptr() as *mut c_void,
+                buf.capacity(),
+                flags,
+            )
         })?;
-        Ok(ret as usize)
+        // SAFETY: `ret` bytes were written to the unfilled portion of the buffer
+        unsafe {
+            buf.advance(ret as usize);
+        }
+        Ok(())
     }
 
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
-        self.recv_with_flags(buf, 0)
+        let mut buf = BorrowedBuf::from(buf);
+        self.recv_with_flags(buf.unfilled(), 0)?;
+        Ok(buf.len())
     }
 
     pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
-        self.recv_with_flags(buf, MSG_PEEK)
+        let mut buf = BorrowedBuf::from(buf);
+        self.recv_with_flags(buf.unfilled(), MSG_PEEK)?;
+        Ok(buf.len())
+    }
+
+    pub fn read_buf(&self, buf: BorrowedCursor<'_>) -> io::Result<()> {
+        self.recv_with_flags(buf, 0)
     }
 
     pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
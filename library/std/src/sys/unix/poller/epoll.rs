@@ -0,0 +1,121 @@
+use super::{timeout_ms, RawSource};
+use crate::cmp;
+use crate::fmt;
+use crate::io::{self, Event, Interest, PollMode};
+use crate::os::unix::io::{AsRawFd, FromRawFd};
+use crate::sys::cvt;
+use crate::sys::fd::FileDesc;
+use crate::time::Duration;
+
+pub struct Poller {
+    epoll: FileDesc,
+}
+
+impl Poller {
+    pub fn new() -> io::Result<Poller> {
+        let fd = cvt(unsafe { libc::epoll_create1(libc::EPOLL_CLOEXEC) })?;
+        Ok(Poller { epoll: unsafe { FileDesc::from_raw_fd(fd) } })
+    }
+
+    pub fn add(
+        &self,
+        fd: RawSource,
+        key: usize,
+        interest: Interest,
+        mode: PollMode,
+    ) -> io::Result<()> {
+        self.ctl(libc::EPOLL_CTL_ADD, fd, Some((key, interest, mode)))
+    }
+
+    pub fn modify(
+        &self,
+        fd: RawSource,
+        key: usize,
+        interest: Interest,
+        mode: PollMode,
+    ) -> io::Result<()> {
+        self.ctl(libc::EPOLL_CTL_MOD, fd, Some((key, interest, mode)))
+    }
+
+    pub fn delete(&self, fd: RawSource) -> io::Result<()> {
+        self.ctl(libc::EPOLL_CTL_DEL, fd, None)
+    }
+
+    fn ctl(
+        &self,
+        op: libc::c_int,
+        fd: RawSource,
+        registration: Option<(usize, Interest, PollMode)>,
+    ) -> io::Result<()> {
+        // Kernels before 2.6.9 require a non-null event even for `EPOLL_CTL_DEL`.
+        let mut event = libc::epoll_event { events: 0, u64: 0 };
+        if let Some((key, interest, mode)) = registration {
+            let mut flags = 0;
+            if interest.is_readable() {
+                flags |= libc::EPOLLIN | libc::EPOLLRDHUP;
+            }
+            if interest.is_writable() {
+                flags |= libc::EPOLLOUT;
+            }
+            if mode == PollMode::Edge {
+                flags |= libc::EPOLLET;
+            }
+            event = libc::epoll_event { events: flags as u32, u64: key as u64 };
+        }
+        cvt(unsafe { libc::epoll_ctl(self.epoll.as_raw_fd(), op, fd, &mut event) })?;
+        Ok(())
+    }
+
+    pub fn wait(&self, events: &mut Events, timeout: Option<Duration>) -> io::Result<()> {
+        let capacity = events.list.capacity().min(libc::c_int::MAX as usize) as libc::c_int;
+        let n = cvt(unsafe {
+            libc::epoll_wait(
+                self.epoll.as_raw_fd(),
+                events.list.as_mut_ptr(),
+                capacity,
+                timeout_ms(timeout),
+            )
+        })?;
+        // SAFETY: `epoll_wait` initialized the first `n` events.
+        unsafe { events.list.set_len(n as usize) };
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Poller {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Poller").field("epoll", &self.epoll.as_raw_fd()).finish()
+    }
+}
+
+pub struct Events {
+    list: Vec<libc::epoll_event>,
+}
+
+impl Events {
+    pub fn with_capacity(capacity: usize) -> Events {
+        // `epoll_wait` fails with `EINVAL` when asked for zero events.
+        Events { list: Vec::with_capacity(cmp::max(capacity, 1)) }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.list.capacity()
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear()
+    }
+
+    pub fn get(&self, index: usize) -> Option<Event> {
+        // Copy the fields out, `epoll_event` is packed on some architectures.
+        let (flags, key) = self.list.get(index).map(|event| (event.events as i32, event.u64))?;
+        let closed = flags & (libc::EPOLLERR | libc::EPOLLHUP) != 0;
+        let readable = closed || flags & (libc::EPOLLIN | libc::EPOLLRDHUP) != 0;
+        let writable = closed || flags & libc::EPOLLOUT != 0;
+        Some(Event::new(key as usize, readable, writable))
+    }
+}
@@ -0,0 +1,184 @@
+use super::RawSource;
+use crate::cmp;
+use crate::fmt;
+use crate::io::{self, Event, Interest, PollMode};
+use crate::mem;
+use crate::os::unix::io::{AsRawFd, FromRawFd};
+use crate::ptr;
+use crate::sync::Mutex;
+use crate::sys::cvt;
+use crate::sys::fd::FileDesc;
+use crate::time::Duration;
+
+// kqueue silently updates a filter that is added twice and cannot tell an unknown source apart
+// from a filter that was never added, so the registered sources and their interest are tracked
+// here to report the same errors as the other backends.
+pub struct Poller {
+    kqueue: FileDesc,
+    registrations: Mutex<Vec<(RawSource, Interest)>>,
+}
+
+impl Poller {
+    pub fn new() -> io::Result<Poller> {
+        let fd = cvt(unsafe { libc::kqueue() })?;
+        let kqueue = unsafe { FileDesc::from_raw_fd(fd) };
+        kqueue.set_cloexec()?;
+        Ok(Poller { kqueue, registrations: Mutex::new(Vec::new()) })
+    }
+
+    pub fn add(
+        &self,
+        fd: RawSource,
+        key: usize,
+        interest: Interest,
+        mode: PollMode,
+    ) -> io::Result<()> {
+        let mut registrations = self.registrations.lock().unwrap();
+        if registrations.iter().any(|&(registered, _)| registered == fd) {
+            return Err(io::Error::from_raw_os_error(libc::EEXIST));
+        }
+        if let Err(e) = self.register(fd, key, Interest::NONE, interest, mode) {
+            // Remove the read filter if only the write filter failed.
+            let _ = self.register(fd, 0, interest, Interest::NONE, mode);
+            return Err(e);
+        }
+        registrations.push((fd, interest));
+        Ok(())
+    }
+
+    pub fn modify(
+        &self,
+        fd: RawSource,
+        key: usize,
+        interest: Interest,
+        mode: PollMode,
+    ) -> io::Result<()> {
+        let mut registrations = self.registrations.lock().unwrap();
+        let registration = registrations
+            .iter_mut()
+            .find(|(registered, _)| *registered == fd)
+            .ok_or_else(|| io::Error::from_raw_os_error(libc::ENOENT))?;
+        let result = self.register(fd, key, registration.1, interest, mode);
+        // The source keeps its previous interest if one of the filters could not be changed.
+        if result.is_ok() {
+            registration.1 = interest;
+        }
+        result
+    }
+
+    pub fn delete(&self, fd: RawSource) -> io::Result<()> {
+        let mut registrations = self.registrations.lock().unwrap();
+        let index = registrations
+            .iter()
+            .position(|&(registered, _)| registered == fd)
+            .ok_or_else(|| io::Error::from_raw_os_error(libc::ENOENT))?;
+        let (_, interest) = registrations.swap_remove(index);
+        self.register(fd, 0, interest, Interest::NONE, PollMode::Level)
+    }
+
+    // Readiness for reading and writing is tracked by two separate filters, which are added,
+    // updated or removed depending on the previous and the new interest.
+    fn register(
+        &self,
+        fd: RawSource,
+        key: usize,
+        old: Interest,
+        new: Interest,
+        mode: PollMode,
+    ) -> io::Result<()> {
+        let add = if mode == PollMode::Edge { libc::EV_ADD | libc::EV_CLEAR } else { libc::EV_ADD };
+        let filters = [
+            (libc::EVFILT_READ, old.is_readable(), new.is_readable()),
+            (libc::EVFILT_WRITE, old.is_writable(), new.is_writable()),
+        ];
+        for (filter, was_enabled, enabled) in filters {
+            if enabled {
+                self.change(fd, filter, add, key)?;
+            } else if was_enabled {
+                self.change(fd, filter, libc::EV_DELETE, 0)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn change<F, G>(&self, fd: RawSource, filter: F, flags: G, key: usize) -> io::Result<()>
+    where
+        F: Into<i64>,
+        G: Into<u32>,
+    {
+        let mut change: libc::kevent = unsafe { mem::zeroed() };
+        change.ident = fd as _;
+        change.filter = filter.into() as _;
+        change.flags = flags.into() as _;
+        change.udata = key as _;
+        let res = cvt(unsafe {
+            libc::kevent(self.kqueue.as_raw_fd(), &change, 1, ptr::null_mut(), 0, ptr::null())
+        });
+        match res {
+            Ok(_) => Ok(()),
+            // Some platforms refuse to add a write filter to a pipe whose read end is closed.
+            Err(ref e) if e.raw_os_error() == Some(libc::EPIPE) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn wait(&self, events: &mut Events, timeout: Option<Duration>) -> io::Result<()> {
+        let timeout = timeout.map(|timeout| libc::timespec {
+            tv_sec: cmp::min(timeout.as_secs(), libc::time_t::MAX as u64) as libc::time_t,
+            tv_nsec: timeout.subsec_nanos() as _,
+        });
+        let timeout_ptr = timeout.as_ref().map_or(ptr::null(), |timeout| timeout as *const _);
+        let capacity = cmp::min(events.list.capacity(), libc::c_int::MAX as usize);
+        let n = cvt(unsafe {
+            libc::kevent(
+                self.kqueue.as_raw_fd(),
+                ptr::null(),
+                0,
+                events.list.as_mut_ptr(),
+                capacity as _,
+                timeout_ptr,
+            )
+        })?;
+        // SAFETY: `kevent` initialized the first `n` events.
+        unsafe { events.list.set_len(n as usize) };
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Poller {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Poller").field("kqueue", &self.kqueue.as_raw_fd()).finish()
+    }
+}
+
+pub struct Events {
+    list: Vec<libc::kevent>,
+}
+
+impl Events {
+    pub fn with_capacity(capacity: usize) -> Events {
+        // `kevent` returns immediately instead of waiting when asked for zero
+        // events.
+        Events { list: Vec::with_capacity(cmp::max(capacity, 1)) }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.list.capacity()
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear()
+    }
+
+    pub fn get(&self, index: usize) -> Option<Event> {
+        let event = self.list.get(index)?;
+        let error = event.flags & libc::EV_ERROR != 0;
+        let readable = error || event.filter == libc::EVFILT_READ;
+        let writable = error || event.filter == libc::EVFILT_WRITE;
+        Some(Event::new(event.udata as usize, readable, writable))
+    }
+}
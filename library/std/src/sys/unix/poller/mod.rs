@@ -0,0 +1,42 @@
+//! Readiness polling backends for `std::io::Poller`.
+
+use crate::cmp;
+use crate::os::unix::io::RawFd;
+use crate::time::Duration;
+
+pub type RawSource = RawFd;
+
+cfg_if::cfg_if! {
+    if #[cfg(any(target_os = "android", target_os = "linux"))] {
+        mod epoll;
+        pub use self::epoll::{Events, Poller};
+    } else if #[cfg(any(
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    ))] {
+        mod kqueue;
+        pub use self::kqueue::{Events, Poller};
+    } else {
+        mod poll;
+        pub use self::poll::{Events, Poller};
+    }
+}
+
+/// Converts a timeout into the milliseconds expected by `epoll_wait` and `poll`.
+///
+/// Sub-millisecond timeouts are rounded up so that a short, non-zero timeout
+/// does not degrade into busy polling. Unused by the `kqueue` backend.
+#[allow(dead_code)]
+fn timeout_ms(timeout: Option<Duration>) -> libc::c_int {
+    match timeout {
+        None => -1,
+        Some(timeout) => {
+            let ms = timeout.as_millis() + (timeout.subsec_nanos() % 1_000_000 != 0) as u128;
+            cmp::min(ms, libc::c_int::MAX as u128) as libc::c_int
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use super::{timeout_ms, RawSource};
+use crate::fmt;
+use crate::io::{self, Event, Interest, PollMode};
+use crate::sync::Mutex;
+use crate::sys::cvt;
+use crate::time::Duration;
+
+struct Registration {
+    fd: RawSource,
+    key: usize,
+    interest: Interest,
+}
+
+// `poll(2)` has no kernel-side registrations, so the sources are kept in a list that is turned
+// into an array of `pollfd`s by every call to `wait`.
+pub struct Poller {
+    registrations: Mutex<Vec<Registration>>,
+}
+
+impl Poller {
+    pub fn new() -> io::Result<Poller> {
+        Ok(Poller { registrations: Mutex::new(Vec::new()) })
+    }
+
+    pub fn add(
+        &self,
+        fd: RawSource,
+        key: usize,
+        interest: Interest,
+        mode: PollMode,
+    ) -> io::Result<()> {
+        check_mode(mode)?;
+        let mut registrations = self.registrations.lock().unwrap();
+        if registrations.iter().any(|r| r.fd == fd) {
+            return Err(io::Error::from_raw_os_error(libc::EEXIST));
+        }
+        registrations.push(Registration { fd, key, interest });
+        Ok(())
+    }
+
+    pub fn modify(
+        &self,
+        fd: RawSource,
+        key: usize,
+        interest: Interest,
+        mode: PollMode,
+    ) -> io::Result<()> {
+        check_mode(mode)?;
+        let mut registrations = self.registrations.lock().unwrap();
+        match registrations.iter_mut().find(|r| r.fd == fd) {
+            Some(registration) => {
+                *registration = Registration { fd, key, interest };
+                Ok(())
+            }
+            None => Err(io::Error::from_raw_os_error(libc::ENOENT)),
+        }
+    }
+
+    pub fn delete(&self, fd: RawSource) -> io::Result<()> {
+        let mut registrations = self.registrations.lock().unwrap();
+        match registrations.iter().position(|r| r.fd == fd) {
+            Some(index) => {
+                registrations.swap_remove(index);
+                Ok(())
+            }
+            None => Err(io::Error::from_raw_os_error(libc::ENOENT)),
+        }
+    }
+
+    pub fn wait(&self, events: &mut Events, timeout: Option<Duration>) -> io::Result<()> {
+        let (mut fds, keys): (Vec<_>, Vec<_>) = self
+            .registrations
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|r| {
+                let mut flags = 0;
+                if r.interest.is_readable() {
+                    flags |= libc::POLLIN;
+                }
+                if r.interest.is_writable() {
+                    flags |= libc::POLLOUT;
+                }
+                (libc::pollfd { fd: r.fd, events: flags, revents: 0 }, r.key)
+            })
+            .unzip();
+
+        let n = cvt(unsafe {
+            libc::poll(fds.as_mut_ptr(), fds.len() as libc::nfds_t, timeout_ms(timeout))
+        })?;
+        if n == 0 {
+            return Ok(());
+        }
+
+        let ready = fds.iter().zip(keys).filter(|(fd, _)| fd.revents != 0);
+        for (fd, key) in ready.take(events.capacity) {
+            let closed = fd.revents & (libc::POLLERR | libc::POLLHUP | libc::POLLNVAL) != 0;
+            let readable = closed || fd.revents & libc::POLLIN != 0;
+            let writable = closed || fd.revents & libc::POLLOUT != 0;
+            events.list.push(Event::new(key, readable, writable));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Poller {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Poller").finish_non_exhaustive()
+    }
+}
+
+fn check_mode(mode: PollMode) -> io::Result<()> {
+    match mode {
+        PollMode::Level => Ok(()),
+        PollMode::Edge => Err(io::Error::new_const(
+            io::ErrorKind::Unsupported,
+            &"edge-triggered polling is not supported on this platform",
+        )),
+    }
+}
+
+pub struct Events {
+    list: Vec<Event>,
+    capacity: usize,
+}
+
+impl Events {
+    pub fn with_capacity(capacity: usize) -> Events {
+        Events { list: Vec::with_capacity(capacity), capacity }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear()
+    }
+
+    pub fn get(&self, index: usize) -> Option<Event> {
+        self.list.get(index).copied()
+    }
+}
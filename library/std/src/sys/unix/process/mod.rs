@@ -4,6 +4,8 @@
 pub use crate::sys_common::process::CommandEnvs;
 
 mod process_common;
+#[cfg(target_os = "linux")]
+pub mod pidfd;
 
 cfg_if::cfg_if! {
     if #[cfg(target_os = "fuchsia")] {
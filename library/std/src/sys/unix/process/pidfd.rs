@@ -0,0 +1,110 @@
+//! Process handles backed by Linux pidfds.
+//!
+//! A pidfd keeps referring to the same process for as long as it is open, even after the
+//! process has exited and its PID has been reused, so signalling and waiting through it is
+//! free of the races inherent to the PID based `kill(2)` and `waitpid(2)`.
+
+use crate::io;
+use crate::mem;
+use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, RawFd};
+use crate::sys::cvt;
+use crate::sys::fd::FileDesc;
+use crate::sys::process::ExitStatus;
+use crate::sys::weak::syscall;
+use crate::sys_common::{AsInner, FromInner, IntoInner};
+use libc::{c_int, c_uint};
+
+// Not yet exported by the `libc` version we depend on.
+const P_PIDFD: libc::idtype_t = 3;
+
+#[derive(Debug)]
+pub struct PidFd(FileDesc);
+
+impl PidFd {
+    pub fn kill(&self) -> io::Result<()> {
+        self.send_signal(libc::SIGKILL)
+    }
+
+    pub fn send_signal(&self, signal: i32) -> io::Result<()> {
+        syscall! {
+            fn pidfd_send_signal(
+                pidfd: c_int,
+                sig: c_int,
+                info: *const libc::siginfo_t,
+                flags: c_uint
+            ) -> c_int
+        }
+        cvt(unsafe { pidfd_send_signal(self.0.as_raw_fd(), signal, crate::ptr::null(), 0) })
+            .map(drop)
+    }
+
+    pub fn wait(&self) -> io::Result<ExitStatus> {
+        let status = self.waitid(libc::WEXITED)?;
+        Ok(status.expect("waitid without WNOHANG returned no status"))
+    }
+
+    pub fn try_wait(&self) -> io::Result<Option<ExitStatus>> {
+        self.waitid(libc::WEXITED | libc::WNOHANG)
+    }
+
+    fn waitid(&self, options: c_int) -> io::Result<Option<ExitStatus>> {
+        let mut siginfo: libc::siginfo_t = unsafe { mem::zeroed() };
+        loop {
+            match cvt(unsafe {
+                libc::waitid(P_PIDFD, self.0.as_raw_fd() as libc::id_t, &mut siginfo, options)
+            }) {
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+                Ok(_) => break,
+            }
+        }
+        // With `WNOHANG`, a zeroed `siginfo` means that the process has not changed state yet.
+        if unsafe { siginfo.si_pid() } == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(ExitStatus::from_waitid_siginfo(siginfo)))
+        }
+    }
+}
+
+impl AsInner<FileDesc> for PidFd {
+    fn as_inner(&self) -> &FileDesc {
+        &self.0
+    }
+}
+
+impl IntoInner<FileDesc> for PidFd {
+    fn into_inner(self) -> FileDesc {
+        self.0
+    }
+}
+
+impl FromInner<FileDesc> for PidFd {
+    fn from_inner(fd: FileDesc) -> PidFd {
+        PidFd(fd)
+    }
+}
+
+impl AsRawFd for PidFd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0.as_raw_fd()
+    }
+}
+
+impl FromRawFd for PidFd {
+    unsafe fn from_raw_fd(fd: RawFd) -> PidFd {
+        PidFd(FileDesc::from_raw_fd(fd))
+    }
+}
+
+impl IntoRawFd for PidFd {
+    fn into_raw_fd(self) -> RawFd {
+        self.0.into_raw_fd()
+    }
+}
+
+impl AsFd for PidFd {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.0.as_fd()
+    }
+}
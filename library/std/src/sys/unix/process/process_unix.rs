@@ -11,6 +11,8 @@
 
 #[cfg(target_os = "linux")]
 use crate::os::linux::process::PidFd;
+#[cfg(target_os = "linux")]
+use crate::sys::process::pidfd;
 
 #[cfg(target_os = "linux")]
 use crate::sys::weak::syscall;
@@ -563,7 +565,7 @@ unsafe fn new(pid: pid_t, pidfd: pid_t) -> Self {
         use crate::os::unix::io::FromRawFd;
         use crate::sys_common::FromInner;
         // Safety: If `pidfd` is nonnegative, we assume it's valid and otherwise unowned.
-        let pidfd = (pidfd >= 0).then(|| PidFd::from_inner(sys::fd::FileDesc::from_raw_fd(pidfd)));
+        let pidfd = (pidfd >= 0).then(|| PidFd::from_inner(pidfd::PidFd::from_raw_fd(pidfd)));
         Process { pid, status: None, pidfd }
     }
 
@@ -576,6 +578,15 @@ pub fn id(&self) -> u32 {
         self.pid as u32
     }
 
+    // Waiting on a pidfd with `waitid(P_PIDFD, ..)` requires Linux 5.4, while pidfds are
+    // already handed out by `clone3` on Linux 5.3. `wait` and `try_wait` therefore fall back
+    // to `waitpid` when the kernel rejects the id type with `EINVAL`.
+    #[cfg(target_os = "linux")]
+    fn pidfd(&self) -> Option<&pidfd::PidFd> {
+        use crate::sys_common::AsInner;
+        self.pidfd.as_ref().map(|pidfd| pidfd.as_inner())
+    }
+
     pub fn kill(&mut self) -> io::Result<()> {
         // If we've already waited on this process then the pid can be recycled
         // and used for another process, and we probably shouldn't be killing
@@ -586,6 +597,10 @@ pub fn kill(&mut self) -> io::Result<()> {
                 &"invalid argument: can't kill an exited process",
             ))
         } else {
+            #[cfg(target_os = "linux")]
+            if let Some(pidfd) = self.pidfd() {
+                return pidfd.kill();
+            }
             cvt(unsafe { libc::kill(self.pid, libc::SIGKILL) }).map(drop)
         }
     }
@@ -595,6 +610,17 @@ pub fn wait(&mut self) -> io::Result<ExitStatus> {
         if let Some(status) = self.status {
             return Ok(status);
         }
+        #[cfg(target_os = "linux")]
+        if let Some(pidfd) = self.pidfd() {
+            match pidfd.wait() {
+                Err(ref e) if e.raw_os_error() == Some(libc::EINVAL) => {}
+                result => {
+                    let status = result?;
+                    self.status = Some(status);
+                    return Ok(status);
+                }
+            }
+        }
         let mut status = 0 as c_int;
         cvt_r(|| unsafe { libc::waitpid(self.pid, &mut status, 0) })?;
         self.status = Some(ExitStatus::new(status));
@@ -605,6 +631,17 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         if let Some(status) = self.status {
             return Ok(Some(status));
         }
+        #[cfg(target_os = "linux")]
+        if let Some(pidfd) = self.pidfd() {
+            match pidfd.try_wait() {
+                Err(ref e) if e.raw_os_error() == Some(libc::EINVAL) => {}
+                result => {
+                    let status = result?;
+                    self.status = status;
+                    return Ok(status);
+                }
+            }
+        }
         let mut status = 0 as c_int;
         let pid = cvt(unsafe { libc::waitpid(self.pid, &mut status, libc::WNOHANG) })?;
         if pid == 0 {
@@ -670,6 +707,20 @@ pub fn continued(&self) -> bool {
     pub fn into_raw(&self) -> c_int {
         self.0
     }
+
+    /// Converts the `siginfo_t` filled in by `waitid` into the equivalent wait status.
+    #[cfg(target_os = "linux")]
+    pub(crate) fn from_waitid_siginfo(siginfo: libc::siginfo_t) -> ExitStatus {
+        let status = unsafe { siginfo.si_status() };
+        match siginfo.si_code {
+            libc::CLD_EXITED => ExitStatus((status & 0xff) << 8),
+            libc::CLD_KILLED => ExitStatus(status),
+            libc::CLD_DUMPED => ExitStatus(status | 0x80),
+            libc::CLD_CONTINUED => ExitStatus(0xffff),
+            libc::CLD_STOPPED | libc::CLD_TRAPPED => ExitStatus(((status & 0xff) << 8) | 0x7f),
+            _ => unreachable!("waitid() should only return the above codes"),
+        }
+    }
 }
 
 /// Converts a raw `c_int` to a type-safe `ExitStatus` by wrapping it without copying.
@@ -55,3 +55,59 @@ fn test_command_fork_no_unwind() {
     let signal = status.signal().expect("expected child process to die of signal");
     assert!(signal == libc::SIGABRT || signal == libc::SIGILL || signal == libc::SIGTRAP);
 }
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_pidfd() {
+    use crate::os::linux::process::{ChildExt, CommandExt};
+    use crate::os::unix::io::AsRawFd;
+
+    fn is_readable(fd: &impl AsRawFd, timeout: libc::c_int) -> bool {
+        let mut pollfd = libc::pollfd { fd: fd.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+        let n = unsafe { libc::poll(&mut pollfd, 1, timeout) };
+        assert!(n >= 0, "poll failed: {}", crate::io::Error::last_os_error());
+        n == 1 && pollfd.revents & libc::POLLIN != 0
+    }
+
+    let mut child = Command::new("sleep").arg("1000").create_pidfd(true).spawn().unwrap();
+    let pidfd = match child.pidfd() {
+        Ok(pidfd) => pidfd,
+        // clone3 is unavailable, so no pidfd was created
+        Err(_) => {
+            child.kill().unwrap();
+            child.wait().unwrap();
+            return;
+        }
+    };
+    match pidfd.try_wait() {
+        Ok(status) => assert_eq!(status, None),
+        // waitid(P_PIDFD) requires Linux 5.4
+        Err(e) if e.raw_os_error() == Some(libc::EINVAL) => {
+            child.kill().unwrap();
+            child.wait().unwrap();
+            return;
+        }
+        Err(e) => panic!("try_wait failed: {}", e),
+    }
+    assert!(!is_readable(pidfd, 0));
+
+    // `Child::kill` and `Child::wait` go through the pidfd.
+    child.kill().unwrap();
+    let status = child.wait().unwrap();
+    assert_eq!(status.signal(), Some(libc::SIGKILL));
+
+    let mut child = Command::new("sleep").arg("1000").create_pidfd(true).spawn().unwrap();
+    let pidfd = child.take_pidfd().unwrap();
+    pidfd.send_signal(libc::SIGTERM).unwrap();
+    assert!(is_readable(&pidfd, -1));
+    let status = pidfd.try_wait().unwrap().expect("the child should have exited");
+    assert_eq!(status.signal(), Some(libc::SIGTERM));
+
+    // The process has been reaped through the pidfd.
+    assert_eq!(pidfd.wait().unwrap_err().raw_os_error(), Some(libc::ECHILD));
+    assert_eq!(pidfd.kill().unwrap_err().raw_os_error(), Some(libc::ESRCH));
+
+    let mut child = Command::new("true").create_pidfd(true).spawn().unwrap();
+    let status = child.take_pidfd().unwrap().wait().unwrap();
+    assert!(status.success());
+}
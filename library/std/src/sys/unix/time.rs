@@ -215,6 +215,12 @@ fn from(t: libc::timespec) -> SystemTime {
         }
     }
 
+    impl From<SystemTime> for libc::timespec {
+        fn from(t: SystemTime) -> libc::timespec {
+            t.t.t
+        }
+    }
+
     impl fmt::Debug for SystemTime {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             f.debug_struct("SystemTime")
@@ -353,6 +359,12 @@ fn from(t: libc::timespec) -> SystemTime {
         }
     }
 
+    impl From<SystemTime> for libc::timespec {
+        fn from(t: SystemTime) -> libc::timespec {
+            t.t.t
+        }
+    }
+
     impl fmt::Debug for SystemTime {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             f.debug_struct("SystemTime")
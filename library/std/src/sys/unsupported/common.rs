@@ -6,7 +6,6 @@ pub mod memchr {
 
 // This is not necessarily correct. May want to consider making it part of the
 // spec definition?
-use crate::os::raw::c_char;
 
 // SAFETY: must be called only once during runtime initialization.
 // NOTE: this is not guaranteed to run, for example when Rust code is called externally.
@@ -38,15 +37,3 @@ pub fn abort_internal() -> ! {
 pub fn hashmap_random_keys() -> (u64, u64) {
     (1, 2)
 }
-
-pub unsafe fn strlen(mut s: *const c_char) -> usize {
-    // SAFETY: The caller must guarantee `s` points to a valid 0-terminated string.
-    unsafe {
-        let mut n = 0;
-        while *s != 0 {
-            n += 1;
-            s = s.offset(1);
-        }
-        n
-    }
-}
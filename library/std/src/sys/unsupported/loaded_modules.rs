@@ -0,0 +1,15 @@
+use crate::path::{Path, PathBuf};
+
+pub struct LoadedModule {
+    pub base: usize,
+    pub path: Option<PathBuf>,
+    pub build_id: Option<Vec<u8>>,
+}
+
+pub fn module_containing(_addr: usize) -> Option<LoadedModule> {
+    None
+}
+
+pub fn module_base(_path: Option<&Path>, _build_id: Option<&[u8]>) -> Option<usize> {
+    None
+}
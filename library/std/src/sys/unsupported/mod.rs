@@ -8,6 +8,7 @@
 pub mod env;
 pub mod fs;
 pub mod io;
+pub mod loaded_modules;
 pub mod mutex;
 pub mod net;
 pub mod os;
@@ -16,6 +17,7 @@
 #[path = "../unix/path.rs"]
 pub mod path;
 pub mod pipe;
+pub mod poller;
 pub mod process;
 pub mod rwlock;
 pub mod stdio;
@@ -0,0 +1,60 @@
+use crate::fmt;
+use crate::io::{self, Event, Interest, PollMode};
+use crate::sys::unsupported;
+use crate::time::Duration;
+
+pub type RawSource = !;
+
+pub struct Poller(!);
+
+impl Poller {
+    pub fn new() -> io::Result<Poller> {
+        unsupported()
+    }
+
+    pub fn add(&self, source: RawSource, _: usize, _: Interest, _: PollMode) -> io::Result<()> {
+        source
+    }
+
+    pub fn modify(&self, source: RawSource, _: usize, _: Interest, _: PollMode) -> io::Result<()> {
+        source
+    }
+
+    pub fn delete(&self, source: RawSource) -> io::Result<()> {
+        source
+    }
+
+    pub fn wait(&self, _events: &mut Events, _timeout: Option<Duration>) -> io::Result<()> {
+        self.0
+    }
+}
+
+impl fmt::Debug for Poller {
+    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0
+    }
+}
+
+pub struct Events {
+    capacity: usize,
+}
+
+impl Events {
+    pub fn with_capacity(capacity: usize) -> Events {
+        Events { capacity }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        0
+    }
+
+    pub fn clear(&mut self) {}
+
+    pub fn get(&self, _index: usize) -> Option<Event> {
+        None
+    }
+}
@@ -28,6 +28,8 @@
 pub mod fd;
 pub mod fs;
 pub mod io;
+#[path = "../unsupported/loaded_modules.rs"]
+pub mod loaded_modules;
 #[path = "../unsupported/mutex.rs"]
 pub mod mutex;
 pub mod net;
@@ -38,6 +40,8 @@
 pub mod path;
 #[path = "../unsupported/pipe.rs"]
 pub mod pipe;
+#[path = "../unsupported/poller.rs"]
+pub mod poller;
 #[path = "../unsupported/process.rs"]
 pub mod process;
 #[path = "../unsupported/rwlock.rs"]
@@ -37,11 +37,6 @@ pub const fn new() -> Condvar {
         Condvar { cnt: AtomicUsize::new(0) }
     }
 
-    #[inline]
-    pub unsafe fn init(&mut self) {
-        // nothing to do
-    }
-
     pub unsafe fn notify_one(&self) {
         self.cnt.fetch_add(1, SeqCst);
         // SAFETY: ptr() is always valid
@@ -89,11 +84,6 @@ pub unsafe fn wait_timeout(&self, mutex: &Mutex, dur: Duration) -> bool {
         return ret;
     }
 
-    #[inline]
-    pub unsafe fn destroy(&self) {
-        // nothing to do
-    }
-
     #[inline]
     fn ptr(&self) -> *mut i32 {
         assert_eq!(mem::size_of::<usize>(), mem::size_of::<i32>());
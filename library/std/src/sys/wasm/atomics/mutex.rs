@@ -21,11 +21,6 @@ pub const fn new() -> Mutex {
         Mutex { locked: AtomicUsize::new(0) }
     }
 
-    #[inline]
-    pub unsafe fn init(&mut self) {
-        // nothing to do
-    }
-
     pub unsafe fn lock(&self) {
         while !self.try_lock() {
             // SAFETY: the caller must uphold the safety contract for `memory_atomic_wait32`.
@@ -53,11 +48,6 @@ pub unsafe fn try_lock(&self) -> bool {
         self.locked.compare_exchange(0, 1, SeqCst, SeqCst).is_ok()
     }
 
-    #[inline]
-    pub unsafe fn destroy(&self) {
-        // nothing to do
-    }
-
     #[inline]
     fn ptr(&self) -> *mut i32 {
         assert_eq!(mem::size_of::<usize>(), mem::size_of::<i32>());
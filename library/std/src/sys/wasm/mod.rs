@@ -26,6 +26,8 @@
 pub mod fs;
 #[path = "../unsupported/io.rs"]
 pub mod io;
+#[path = "../unsupported/loaded_modules.rs"]
+pub mod loaded_modules;
 #[path = "../unsupported/net.rs"]
 pub mod net;
 #[path = "../unsupported/os.rs"]
@@ -36,6 +38,8 @@
 pub mod path;
 #[path = "../unsupported/pipe.rs"]
 pub mod pipe;
+#[path = "../unsupported/poller.rs"]
+pub mod poller;
 #[path = "../unsupported/process.rs"]
 pub mod process;
 #[path = "../unsupported/stdio.rs"]
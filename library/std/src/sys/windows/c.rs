@@ -5,7 +5,7 @@
 #![unstable(issue = "none", feature = "windows_c")]
 
 use crate::os::raw::NonZero_c_ulong;
-use crate::os::raw::{c_char, c_int, c_long, c_longlong, c_uint, c_ulong, c_ushort};
+use crate::os::raw::{c_char, c_int, c_long, c_longlong, c_short, c_uint, c_ulong, c_ushort};
 use crate::ptr;
 
 use libc::{c_void, size_t, wchar_t};
@@ -195,15 +195,26 @@ fn clone(&self) -> Self {
 pub const SD_SEND: c_int = 1;
 pub const SOCK_DGRAM: c_int = 2;
 pub const SOCK_STREAM: c_int = 1;
+pub const SOCK_RAW: c_int = 3;
 pub const SOCKET_ERROR: c_int = -1;
 pub const SOL_SOCKET: c_int = 0xffff;
 pub const SO_LINGER: c_int = 0x0080;
 pub const SO_RCVTIMEO: c_int = 0x1006;
 pub const SO_SNDTIMEO: c_int = 0x1005;
+pub const SO_REUSEADDR: c_int = 0x0004;
+pub const SO_KEEPALIVE: c_int = 0x0008;
+pub const SO_SNDBUF: c_int = 0x1001;
+pub const SO_RCVBUF: c_int = 0x1002;
 pub const IPPROTO_IP: c_int = 0;
+pub const IPPROTO_ICMP: c_int = 1;
 pub const IPPROTO_TCP: c_int = 6;
+pub const IPPROTO_UDP: c_int = 17;
 pub const IPPROTO_IPV6: c_int = 41;
+pub const IPPROTO_ICMPV6: c_int = 58;
 pub const TCP_NODELAY: c_int = 0x0001;
+pub const TCP_KEEPALIVE: c_int = 3;
+pub const TCP_KEEPCNT: c_int = 16;
+pub const TCP_KEEPINTVL: c_int = 17;
 pub const IP_TTL: c_int = 4;
 pub const IPV6_V6ONLY: c_int = 27;
 pub const SO_ERROR: c_int = 0x1007;
@@ -256,8 +267,17 @@ pub struct ipv6_mreq {
 pub const PIPE_REJECT_REMOTE_CLIENTS: DWORD = 0x00000008;
 pub const PIPE_READMODE_BYTE: DWORD = 0x00000000;
 
+pub const LOCKFILE_FAIL_IMMEDIATELY: DWORD = 0x00000001;
+pub const LOCKFILE_EXCLUSIVE_LOCK: DWORD = 0x00000002;
+
 pub const FD_SETSIZE: usize = 64;
 
+pub const POLLERR: c_short = 0x0001;
+pub const POLLHUP: c_short = 0x0002;
+pub const POLLNVAL: c_short = 0x0004;
+pub const POLLWRNORM: c_short = 0x0010;
+pub const POLLRDNORM: c_short = 0x0100;
+
 pub const STACK_SIZE_PARAM_IS_A_RESERVATION: DWORD = 0x00010000;
 
 pub const STATUS_SUCCESS: NTSTATUS = 0x00000000;
@@ -618,6 +638,14 @@ pub struct timeval {
     pub tv_usec: c_long,
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct WSAPOLLFD {
+    pub fd: SOCKET,
+    pub events: c_short,
+    pub revents: c_short,
+}
+
 // Desktop specific functions & types
 cfg_if::cfg_if! {
 if #[cfg(not(target_vendor = "uwp"))] {
@@ -929,6 +957,12 @@ pub fn CreateNamedPipeW(
         nDefaultTimeOut: DWORD,
         lpSecurityAttributes: LPSECURITY_ATTRIBUTES,
     ) -> HANDLE;
+    pub fn CreatePipe(
+        hReadPipe: LPHANDLE,
+        hWritePipe: LPHANDLE,
+        lpPipeAttributes: LPSECURITY_ATTRIBUTES,
+        nSize: DWORD,
+    ) -> BOOL;
     pub fn CancelIo(handle: HANDLE) -> BOOL;
     pub fn GetOverlappedResult(
         hFile: HANDLE,
@@ -953,6 +987,27 @@ pub fn SetFileInformationByHandle(
         lpFileInformation: LPVOID,
         dwBufferSize: DWORD,
     ) -> BOOL;
+    pub fn SetFileTime(
+        hFile: HANDLE,
+        lpCreationTime: *const FILETIME,
+        lpLastAccessTime: *const FILETIME,
+        lpLastWriteTime: *const FILETIME,
+    ) -> BOOL;
+    pub fn LockFileEx(
+        hFile: HANDLE,
+        dwFlags: DWORD,
+        dwReserved: DWORD,
+        nNumberOfBytesToLockLow: DWORD,
+        nNumberOfBytesToLockHigh: DWORD,
+        lpOverlapped: LPOVERLAPPED,
+    ) -> BOOL;
+    pub fn UnlockFile(
+        hFile: HANDLE,
+        dwFileOffsetLow: DWORD,
+        dwFileOffsetHigh: DWORD,
+        nNumberOfBytesToUnlockLow: DWORD,
+        nNumberOfBytesToUnlockHigh: DWORD,
+    ) -> BOOL;
     pub fn SleepConditionVariableSRW(
         ConditionVariable: PCONDITION_VARIABLE,
         SRWLock: PSRWLOCK,
@@ -1076,6 +1131,7 @@ pub fn select(
         exceptfds: *mut fd_set,
         timeout: *const timeval,
     ) -> c_int;
+    pub fn WSAPoll(fdArray: *mut WSAPOLLFD, fds: c_ulong, timeout: c_int) -> c_int;
 }
 
 #[link(name = "bcrypt")]
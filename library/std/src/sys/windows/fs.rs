@@ -2,7 +2,7 @@
 
 use crate::ffi::OsString;
 use crate::fmt;
-use crate::io::{self, Error, IoSlice, IoSliceMut, SeekFrom};
+use crate::io::{self, BorrowedCursor, Error, IoSlice, IoSliceMut, SeekFrom};
 use crate::mem;
 use crate::os::windows::io::{AsHandle, BorrowedHandle};
 use crate::path::{Path, PathBuf};
@@ -21,6 +21,12 @@ pub struct File {
     handle: Handle,
 }
 
+#[derive(Copy, Clone, Debug, Default)]
+pub struct FileTimes {
+    accessed: Option<SystemTime>,
+    modified: Option<SystemTime>,
+}
+
 #[derive(Clone)]
 pub struct FileAttr {
     attributes: c::DWORD,
@@ -407,6 +413,10 @@ pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.handle.read(buf)
     }
 
+    pub fn read_buf(&self, cursor: BorrowedCursor<'_>) -> io::Result<()> {
+        self.handle.read_buf(cursor)
+    }
+
     pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.handle.read_vectored(bufs)
     }
@@ -543,6 +553,94 @@ pub fn set_permissions(&self, perm: FilePermissions) -> io::Result<()> {
         })?;
         Ok(())
     }
+
+    pub fn set_times(&self, times: FileTimes) -> io::Result<()> {
+        // A zero `FILETIME` leaves the timestamp unchanged and all bits set
+        // stops the system from updating it, so neither can be used as an
+        // actual time.
+        let to_filetime = |time: Option<SystemTime>| -> io::Result<Option<c::FILETIME>> {
+            let time = match time {
+                Some(time) => time.into_inner(),
+                None => return Ok(None),
+            };
+            let raw = (time.dwHighDateTime as u64) << 32 | time.dwLowDateTime as u64;
+            if raw == 0 || raw == u64::MAX {
+                return Err(io::Error::new_const(
+                    io::ErrorKind::InvalidInput,
+                    &"cannot set file timestamp to this value",
+                ));
+            }
+            Ok(Some(time))
+        };
+        let accessed = to_filetime(times.accessed)?;
+        let modified = to_filetime(times.modified)?;
+        let as_ptr = |time: &Option<c::FILETIME>| match time {
+            Some(time) => time as *const c::FILETIME,
+            None => ptr::null(),
+        };
+        cvt(unsafe {
+            c::SetFileTime(
+                self.handle.as_raw_handle(),
+                ptr::null(),
+                as_ptr(&accessed),
+                as_ptr(&modified),
+            )
+        })?;
+        Ok(())
+    }
+
+    pub fn lock(&self) -> io::Result<()> {
+        self.lock_file(c::LOCKFILE_EXCLUSIVE_LOCK).map(drop)
+    }
+
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.lock_file(0).map(drop)
+    }
+
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.lock_file(c::LOCKFILE_EXCLUSIVE_LOCK | c::LOCKFILE_FAIL_IMMEDIATELY)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.lock_file(c::LOCKFILE_FAIL_IMMEDIATELY)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        // A handle may hold both a shared and an exclusive lock on the whole
+        // file, each of which has to be released separately.
+        for _ in 0..2 {
+            let result = cvt(unsafe {
+                c::UnlockFile(self.handle.as_raw_handle(), 0, 0, c::DWORD::MAX, c::DWORD::MAX)
+            });
+            match result {
+                Ok(_) => {}
+                Err(err) if err.raw_os_error() == Some(c::ERROR_NOT_LOCKED as i32) => break,
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+
+    /// Locks the whole file with `LockFileEx`, returning `Ok(false)` if
+    /// `LOCKFILE_FAIL_IMMEDIATELY` was given and the lock is held elsewhere.
+    fn lock_file(&self, flags: c::DWORD) -> io::Result<bool> {
+        let mut overlapped: c::OVERLAPPED = unsafe { mem::zeroed() };
+        let result = cvt(unsafe {
+            c::LockFileEx(
+                self.handle.as_raw_handle(),
+                flags,
+                0,
+                c::DWORD::MAX,
+                c::DWORD::MAX,
+                &mut overlapped,
+            )
+        });
+        match result {
+            Ok(_) => Ok(true),
+            Err(err) if err.raw_os_error() == Some(c::ERROR_LOCK_VIOLATION as i32) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
 }
 
 impl AsInner<Handle> for File {
@@ -671,6 +769,16 @@ pub fn set_readonly(&mut self, readonly: bool) {
     }
 }
 
+impl FileTimes {
+    pub fn set_accessed(&mut self, t: SystemTime) {
+        self.accessed = Some(t);
+    }
+
+    pub fn set_modified(&mut self, t: SystemTime) {
+        self.modified = Some(t);
+    }
+}
+
 impl FileType {
     fn new(attrs: c::DWORD, reparse_tag: c::DWORD) -> FileType {
         FileType { attributes: attrs, reparse_tag }
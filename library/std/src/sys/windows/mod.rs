@@ -7,7 +7,6 @@
 use crate::time::Duration;
 
 pub use self::rand::hashmap_random_keys;
-pub use libc::strlen;
 
 #[macro_use]
 pub mod compat;
@@ -21,6 +20,8 @@
 pub mod fs;
 pub mod handle;
 pub mod io;
+#[path = "../unsupported/loaded_modules.rs"]
+pub mod loaded_modules;
 pub mod memchr;
 pub mod mutex;
 pub mod net;
@@ -28,6 +29,7 @@
 pub mod os_str;
 pub mod path;
 pub mod pipe;
+pub mod poller;
 pub mod process;
 pub mod rand;
 pub mod rwlock;
@@ -9,10 +9,11 @@
 use crate::sync::atomic::AtomicUsize;
 use crate::sync::atomic::Ordering::SeqCst;
 use crate::sys::c;
+use crate::sys::cvt;
 use crate::sys::fs::{File, OpenOptions};
 use crate::sys::handle::Handle;
 use crate::sys::hashmap_random_keys;
-use crate::sys_common::IntoInner;
+use crate::sys_common::{FromInner, IntoInner};
 
 ////////////////////////////////////////////////////////////////////////////////
 // Anonymous pipes
@@ -28,6 +29,12 @@ fn into_inner(self) -> Handle {
     }
 }
 
+impl FromInner<Handle> for AnonPipe {
+    fn from_inner(inner: Handle) -> AnonPipe {
+        AnonPipe { inner }
+    }
+}
+
 pub struct Pipes {
     pub ours: AnonPipe,
     pub theirs: AnonPipe,
@@ -162,6 +169,23 @@ pub fn anon_pipe(ours_readable: bool, their_handle_inheritable: bool) -> io::Res
     }
 }
 
+/// Creates a pipe for `std::io::pipe`.
+///
+/// Unlike `anon_pipe`, neither end is opened in overlapped mode, so both can
+/// be used for regular blocking I/O in this process or handed to any number
+/// of child processes. Neither handle is inheritable.
+pub fn anon_pipe_pair() -> io::Result<(AnonPipe, AnonPipe)> {
+    let mut read = ptr::null_mut();
+    let mut write = ptr::null_mut();
+    unsafe {
+        cvt(c::CreatePipe(&mut read, &mut write, ptr::null_mut(), 0))?;
+        Ok((
+            AnonPipe { inner: Handle::from_raw_handle(read) },
+            AnonPipe { inner: Handle::from_raw_handle(write) },
+        ))
+    }
+}
+
 fn random_number() -> usize {
     static N: AtomicUsize = AtomicUsize::new(0);
     loop {
@@ -181,6 +205,11 @@ pub fn into_handle(self) -> Handle {
         self.inner
     }
 
+    pub fn try_clone(&self) -> io::Result<AnonPipe> {
+        let inner = self.inner.duplicate(0, false, c::DUPLICATE_SAME_ACCESS)?;
+        Ok(AnonPipe { inner })
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.inner.read(buf)
     }
@@ -0,0 +1,176 @@
+//! Readiness polling for `std::io::Poller`, based on `WSAPoll`.
+
+use crate::cmp;
+use crate::fmt;
+use crate::io::{self, Event, Interest, PollMode};
+use crate::os::windows::io::RawSocket;
+use crate::sync::Mutex;
+use crate::sys::c;
+use crate::sys::net::{cvt, init};
+use crate::thread;
+use crate::time::Duration;
+
+use libc::c_int;
+
+pub type RawSource = RawSocket;
+
+struct Registration {
+    socket: RawSource,
+    key: usize,
+    interest: Interest,
+}
+
+// `WSAPoll` has no kernel-side registrations, so the sources are kept in a list that is turned
+// into an array of `WSAPOLLFD`s by every call to `wait`.
+pub struct Poller {
+    registrations: Mutex<Vec<Registration>>,
+}
+
+impl Poller {
+    pub fn new() -> io::Result<Poller> {
+        init();
+        Ok(Poller { registrations: Mutex::new(Vec::new()) })
+    }
+
+    pub fn add(
+        &self,
+        socket: RawSource,
+        key: usize,
+        interest: Interest,
+        mode: PollMode,
+    ) -> io::Result<()> {
+        check_mode(mode)?;
+        let mut registrations = self.registrations.lock().unwrap();
+        if registrations.iter().any(|r| r.socket == socket) {
+            return Err(io::Error::new_const(
+                io::ErrorKind::AlreadyExists,
+                &"socket is already registered with this poller",
+            ));
+        }
+        registrations.push(Registration { socket, key, interest });
+        Ok(())
+    }
+
+    pub fn modify(
+        &self,
+        socket: RawSource,
+        key: usize,
+        interest: Interest,
+        mode: PollMode,
+    ) -> io::Result<()> {
+        check_mode(mode)?;
+        let mut registrations = self.registrations.lock().unwrap();
+        match registrations.iter_mut().find(|r| r.socket == socket) {
+            Some(registration) => {
+                *registration = Registration { socket, key, interest };
+                Ok(())
+            }
+            None => Err(not_registered()),
+        }
+    }
+
+    pub fn delete(&self, socket: RawSource) -> io::Result<()> {
+        let mut registrations = self.registrations.lock().unwrap();
+        match registrations.iter().position(|r| r.socket == socket) {
+            Some(index) => {
+                registrations.swap_remove(index);
+                Ok(())
+            }
+            None => Err(not_registered()),
+        }
+    }
+
+    pub fn wait(&self, events: &mut Events, timeout: Option<Duration>) -> io::Result<()> {
+        let (mut fds, keys): (Vec<_>, Vec<_>) = self
+            .registrations
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|r| {
+                let mut flags = 0;
+                if r.interest.is_readable() {
+                    flags |= c::POLLRDNORM;
+                }
+                if r.interest.is_writable() {
+                    flags |= c::POLLWRNORM;
+                }
+                (c::WSAPOLLFD { fd: r.socket as c::SOCKET, events: flags, revents: 0 }, r.key)
+            })
+            .unzip();
+
+        // `WSAPoll` fails when it is given no sockets, unlike `poll` which simply waits for the
+        // timeout to elapse.
+        if fds.is_empty() {
+            thread::sleep(timeout.unwrap_or(Duration::MAX));
+            return Ok(());
+        }
+
+        let timeout = match timeout {
+            None => -1,
+            Some(timeout) => {
+                let ms = timeout.as_millis() + (timeout.subsec_nanos() % 1_000_000 != 0) as u128;
+                cmp::min(ms, c_int::MAX as u128) as c_int
+            }
+        };
+        let n = cvt(unsafe { c::WSAPoll(fds.as_mut_ptr(), fds.len() as c::ULONG, timeout) })?;
+        if n == 0 {
+            return Ok(());
+        }
+
+        let ready = fds.iter().zip(keys).filter(|(fd, _)| fd.revents != 0);
+        for (fd, key) in ready.take(events.capacity) {
+            let closed = fd.revents & (c::POLLERR | c::POLLHUP | c::POLLNVAL) != 0;
+            let readable = closed || fd.revents & c::POLLRDNORM != 0;
+            let writable = closed || fd.revents & c::POLLWRNORM != 0;
+            events.list.push(Event::new(key, readable, writable));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Poller {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Poller").finish_non_exhaustive()
+    }
+}
+
+fn check_mode(mode: PollMode) -> io::Result<()> {
+    match mode {
+        PollMode::Level => Ok(()),
+        PollMode::Edge => Err(io::Error::new_const(
+            io::ErrorKind::Unsupported,
+            &"edge-triggered polling is not supported on this platform",
+        )),
+    }
+}
+
+fn not_registered() -> io::Error {
+    io::Error::new_const(io::ErrorKind::NotFound, &"socket is not registered with this poller")
+}
+
+pub struct Events {
+    list: Vec<Event>,
+    capacity: usize,
+}
+
+impl Events {
+    pub fn with_capacity(capacity: usize) -> Events {
+        Events { list: Vec::with_capacity(capacity), capacity }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.list.clear()
+    }
+
+    pub fn get(&self, index: usize) -> Option<Event> {
+        self.list.get(index).copied()
+    }
+}
@@ -5,13 +5,14 @@
 use crate::convert::{TryFrom, TryInto};
 use crate::ffi::CString;
 use crate::fmt;
-use crate::io::{self, Error, ErrorKind, IoSlice, IoSliceMut};
+use crate::io::{self, BorrowedCursor, Error, ErrorKind, IoSlice, IoSliceMut};
 use crate::mem;
-use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
+use crate::net::{Domain, Ipv4Addr, Ipv6Addr, Protocol, Shutdown, SocketAddr, Type};
+use crate::net::{SocketAddrV4, SocketAddrV6};
 use crate::ptr;
 use crate::sys::net::netc as c;
 use crate::sys::net::{cvt, cvt_gai, cvt_r, init, wrlen_t, Socket};
-use crate::sys_common::{AsInner, FromInner, IntoInner};
+use crate::sys_common::{FromInner, IntoInner};
 use crate::time::Duration;
 
 use libc::{c_int, c_void};
@@ -54,6 +55,41 @@
     }
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_os = "linux", target_os = "android",
+        target_os = "dragonfly", target_os = "freebsd",
+        target_os = "openbsd", target_os = "netbsd",
+        target_os = "ios", target_os = "macos",
+        target_os = "fuchsia"))] {
+        const SO_REUSEPORT: Option<c_int> = Some(libc::SO_REUSEPORT);
+    } else {
+        const SO_REUSEPORT: Option<c_int> = None;
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(target_os = "ios", target_os = "macos"))] {
+        const TCP_KEEPIDLE: Option<c_int> = Some(libc::TCP_KEEPALIVE);
+        const TCP_KEEPINTVL: Option<c_int> = Some(libc::TCP_KEEPINTVL);
+        const TCP_KEEPCNT: Option<c_int> = Some(libc::TCP_KEEPCNT);
+    } else if #[cfg(any(
+        target_os = "linux", target_os = "android",
+        target_os = "freebsd", target_os = "fuchsia"))] {
+        const TCP_KEEPIDLE: Option<c_int> = Some(libc::TCP_KEEPIDLE);
+        const TCP_KEEPINTVL: Option<c_int> = Some(libc::TCP_KEEPINTVL);
+        const TCP_KEEPCNT: Option<c_int> = Some(libc::TCP_KEEPCNT);
+    } else if #[cfg(windows)] {
+        const TCP_KEEPIDLE: Option<c_int> = Some(c::TCP_KEEPALIVE);
+        const TCP_KEEPINTVL: Option<c_int> = Some(c::TCP_KEEPINTVL);
+        const TCP_KEEPCNT: Option<c_int> = Some(c::TCP_KEEPCNT);
+    } else {
+        const TCP_KEEPIDLE: Option<c_int> = None;
+        const TCP_KEEPINTVL: Option<c_int> = None;
+        const TCP_KEEPCNT: Option<c_int> = None;
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // sockaddr and misc bindings
 ////////////////////////////////////////////////////////////////////////////////
@@ -116,6 +152,110 @@ fn to_ipv6mr_interface(value: u32) -> libc::c_uint {
     value as libc::c_uint
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Conversions between the address types and their C representations
+////////////////////////////////////////////////////////////////////////////////
+
+impl IntoInner<c::in_addr> for Ipv4Addr {
+    #[inline]
+    fn into_inner(self) -> c::in_addr {
+        // `s_addr` is stored as BE on all machines and the array is in BE order.
+        // So the native endian conversion method is used so that it's never swapped.
+        c::in_addr { s_addr: u32::from_ne_bytes(self.octets()) }
+    }
+}
+
+impl FromInner<c::in_addr> for Ipv4Addr {
+    #[inline]
+    fn from_inner(addr: c::in_addr) -> Ipv4Addr {
+        Ipv4Addr::from(addr.s_addr.to_ne_bytes())
+    }
+}
+
+impl IntoInner<c::in6_addr> for Ipv6Addr {
+    #[inline]
+    fn into_inner(self) -> c::in6_addr {
+        c::in6_addr { s6_addr: self.octets() }
+    }
+}
+
+impl FromInner<c::in6_addr> for Ipv6Addr {
+    #[inline]
+    fn from_inner(addr: c::in6_addr) -> Ipv6Addr {
+        Ipv6Addr::from(addr.s6_addr)
+    }
+}
+
+impl IntoInner<c::sockaddr_in> for SocketAddrV4 {
+    fn into_inner(self) -> c::sockaddr_in {
+        c::sockaddr_in {
+            sin_family: c::AF_INET as c::sa_family_t,
+            sin_port: self.port().to_be(),
+            sin_addr: self.ip().into_inner(),
+            ..unsafe { mem::zeroed() }
+        }
+    }
+}
+
+impl FromInner<c::sockaddr_in> for SocketAddrV4 {
+    fn from_inner(addr: c::sockaddr_in) -> SocketAddrV4 {
+        SocketAddrV4::new(Ipv4Addr::from_inner(addr.sin_addr), u16::from_be(addr.sin_port))
+    }
+}
+
+impl IntoInner<c::sockaddr_in6> for SocketAddrV6 {
+    fn into_inner(self) -> c::sockaddr_in6 {
+        c::sockaddr_in6 {
+            sin6_family: c::AF_INET6 as c::sa_family_t,
+            sin6_port: self.port().to_be(),
+            sin6_addr: self.ip().into_inner(),
+            sin6_flowinfo: self.flowinfo(),
+            sin6_scope_id: self.scope_id(),
+            ..unsafe { mem::zeroed() }
+        }
+    }
+}
+
+impl FromInner<c::sockaddr_in6> for SocketAddrV6 {
+    fn from_inner(addr: c::sockaddr_in6) -> SocketAddrV6 {
+        SocketAddrV6::new(
+            Ipv6Addr::from_inner(addr.sin6_addr),
+            u16::from_be(addr.sin6_port),
+            addr.sin6_flowinfo,
+            addr.sin6_scope_id,
+        )
+    }
+}
+
+/// A `sockaddr_in` or `sockaddr_in6` built from a [`SocketAddr`], to be passed to
+/// the system by pointer together with its length.
+#[repr(C)]
+pub union SocketAddrCRepr {
+    v4: c::sockaddr_in,
+    v6: c::sockaddr_in6,
+}
+
+impl SocketAddrCRepr {
+    pub fn as_ptr(&self) -> *const c::sockaddr {
+        self as *const _ as *const c::sockaddr
+    }
+}
+
+impl<'a> IntoInner<(SocketAddrCRepr, c::socklen_t)> for &'a SocketAddr {
+    fn into_inner(self) -> (SocketAddrCRepr, c::socklen_t) {
+        match *self {
+            SocketAddr::V4(ref a) => {
+                let sockaddr = SocketAddrCRepr { v4: a.into_inner() };
+                (sockaddr, mem::size_of::<c::sockaddr_in>() as c::socklen_t)
+            }
+            SocketAddr::V6(ref a) => {
+                let sockaddr = SocketAddrCRepr { v6: a.into_inner() };
+                (sockaddr, mem::size_of::<c::sockaddr_in6>() as c::socklen_t)
+            }
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // get_host_addresses
 ////////////////////////////////////////////////////////////////////////////////
@@ -210,8 +350,8 @@ pub fn connect(addr: io::Result<&SocketAddr>) -> io::Result<TcpStream> {
 
         let sock = Socket::new(addr, c::SOCK_STREAM)?;
 
-        let (addrp, len) = addr.into_inner();
-        cvt_r(|| unsafe { c::connect(sock.as_raw(), addrp, len) })?;
+        let (addr, len) = addr.into_inner();
+        cvt_r(|| unsafe { c::connect(sock.as_raw(), addr.as_ptr(), len) })?;
         Ok(TcpStream { inner: sock })
     }
 
@@ -255,6 +395,10 @@ pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.inner.read(buf)
     }
 
+    pub fn read_buf(&self, buf: BorrowedCursor<'_>) -> io::Result<()> {
+        self.inner.read_buf(buf)
+    }
+
     pub fn read_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
         self.inner.read_vectored(bufs)
     }
@@ -381,8 +525,8 @@ pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
         setsockopt(&sock, c::SOL_SOCKET, c::SO_REUSEADDR, 1 as c_int)?;
 
         // Bind our new socket
-        let (addrp, len) = addr.into_inner();
-        cvt(unsafe { c::bind(sock.as_raw(), addrp, len as _) })?;
+        let (addr, len) = addr.into_inner();
+        cvt(unsafe { c::bind(sock.as_raw(), addr.as_ptr(), len as _) })?;
 
         // Start listening
         cvt(unsafe { c::listen(sock.as_raw(), 128) })?;
@@ -474,8 +618,8 @@ pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
         init();
 
         let sock = Socket::new(addr, c::SOCK_DGRAM)?;
-        let (addrp, len) = addr.into_inner();
-        cvt(unsafe { c::bind(sock.as_raw(), addrp, len as _) })?;
+        let (addr, len) = addr.into_inner();
+        cvt(unsafe { c::bind(sock.as_raw(), addr.as_ptr(), len as _) })?;
         Ok(UdpSocket { inner: sock })
     }
 
@@ -505,14 +649,14 @@ pub fn peek_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
 
     pub fn send_to(&self, buf: &[u8], dst: &SocketAddr) -> io::Result<usize> {
         let len = cmp::min(buf.len(), <wrlen_t>::MAX as usize) as wrlen_t;
-        let (dstp, dstlen) = dst.into_inner();
+        let (dst, dstlen) = dst.into_inner();
         let ret = cvt(unsafe {
             c::sendto(
                 self.inner.as_raw(),
                 buf.as_ptr() as *const c_void,
                 len,
                 MSG_NOSIGNAL,
-                dstp,
+                dst.as_ptr(),
                 dstlen,
             )
         })?;
@@ -595,7 +739,7 @@ pub fn join_multicast_v4(&self, multiaddr: &Ipv4Addr, interface: &Ipv4Addr) -> i
 
     pub fn join_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32) -> io::Result<()> {
         let mreq = c::ipv6_mreq {
-            ipv6mr_multiaddr: *multiaddr.as_inner(),
+            ipv6mr_multiaddr: multiaddr.into_inner(),
             ipv6mr_interface: to_ipv6mr_interface(interface),
         };
         setsockopt(&self.inner, c::IPPROTO_IPV6, IPV6_ADD_MEMBERSHIP, mreq)
@@ -611,7 +755,7 @@ pub fn leave_multicast_v4(&self, multiaddr: &Ipv4Addr, interface: &Ipv4Addr) ->
 
     pub fn leave_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32) -> io::Result<()> {
         let mreq = c::ipv6_mreq {
-            ipv6mr_multiaddr: *multiaddr.as_inner(),
+            ipv6mr_multiaddr: multiaddr.into_inner(),
             ipv6mr_interface: to_ipv6mr_interface(interface),
         };
         setsockopt(&self.inner, c::IPPROTO_IPV6, IPV6_DROP_MEMBERSHIP, mreq)
@@ -651,8 +795,8 @@ pub fn send(&self, buf: &[u8]) -> io::Result<usize> {
     }
 
     pub fn connect(&self, addr: io::Result<&SocketAddr>) -> io::Result<()> {
-        let (addrp, len) = addr?.into_inner();
-        cvt_r(|| unsafe { c::connect(self.inner.as_raw(), addrp, len) }).map(drop)
+        let (addr, len) = addr?.into_inner();
+        cvt_r(|| unsafe { c::connect(self.inner.as_raw(), addr.as_ptr(), len) }).map(drop)
     }
 }
 
@@ -674,3 +818,274 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         res.field(name, &self.inner.as_raw()).finish()
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Generic sockets
+////////////////////////////////////////////////////////////////////////////////
+
+pub struct GenericSocket {
+    inner: Socket,
+}
+
+impl GenericSocket {
+    pub fn new(domain: Domain, ty: Type, protocol: Option<Protocol>) -> io::Result<GenericSocket> {
+        init();
+
+        let family = match domain {
+            Domain::Ipv4 => c::AF_INET,
+            Domain::Ipv6 => c::AF_INET6,
+        };
+        let ty = match ty {
+            Type::Stream => c::SOCK_STREAM,
+            Type::Datagram => c::SOCK_DGRAM,
+            Type::Raw => c::SOCK_RAW,
+        };
+        let protocol = match protocol {
+            None => 0,
+            Some(Protocol::Tcp) => c::IPPROTO_TCP,
+            Some(Protocol::Udp) => c::IPPROTO_UDP,
+            Some(Protocol::Icmpv4) => c::IPPROTO_ICMP,
+            Some(Protocol::Icmpv6) => c::IPPROTO_ICMPV6,
+        };
+        let sock = Socket::new_with_protocol(family, ty, protocol)?;
+        Ok(GenericSocket { inner: sock })
+    }
+
+    pub fn socket(&self) -> &Socket {
+        &self.inner
+    }
+
+    pub fn into_socket(self) -> Socket {
+        self.inner
+    }
+
+    pub fn bind(&self, addr: &SocketAddr) -> io::Result<()> {
+        let (addr, len) = addr.into_inner();
+        cvt(unsafe { c::bind(self.inner.as_raw(), addr.as_ptr(), len as _) }).map(drop)
+    }
+
+    pub fn listen(&self, backlog: i32) -> io::Result<()> {
+        cvt(unsafe { c::listen(self.inner.as_raw(), backlog as _) }).map(drop)
+    }
+
+    pub fn connect(&self, addr: &SocketAddr) -> io::Result<()> {
+        let (addr, len) = addr.into_inner();
+        cvt_r(|| unsafe { c::connect(self.inner.as_raw(), addr.as_ptr(), len) }).map(drop)
+    }
+
+    pub fn connect_timeout(&self, addr: &SocketAddr, timeout: Duration) -> io::Result<()> {
+        self.inner.connect_timeout(addr, timeout)
+    }
+
+    pub fn accept(&self) -> io::Result<(GenericSocket, SocketAddr)> {
+        let mut storage: c::sockaddr_storage = unsafe { mem::zeroed() };
+        let mut len = mem::size_of_val(&storage) as c::socklen_t;
+        let sock = self.inner.accept(&mut storage as *mut _ as *mut _, &mut len)?;
+        let addr = sockaddr_to_addr(&storage, len as usize)?;
+        Ok((GenericSocket { inner: sock }, addr))
+    }
+
+    pub fn accept_nonblocking(&self) -> io::Result<(GenericSocket, SocketAddr)> {
+        let mut storage: c::sockaddr_storage = unsafe { mem::zeroed() };
+        let mut len = mem::size_of_val(&storage) as c::socklen_t;
+        let sock = self.inner.accept_nonblocking(&mut storage as *mut _ as *mut _, &mut len)?;
+        let addr = sockaddr_to_addr(&storage, len as usize)?;
+        Ok((GenericSocket { inner: sock }, addr))
+    }
+
+    pub fn socket_addr(&self) -> io::Result<SocketAddr> {
+        sockname(|buf, len| unsafe { c::getsockname(self.inner.as_raw(), buf, len) })
+    }
+
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        sockname(|buf, len| unsafe { c::getpeername(self.inner.as_raw(), buf, len) })
+    }
+
+    pub fn duplicate(&self) -> io::Result<GenericSocket> {
+        self.inner.duplicate().map(|s| GenericSocket { inner: s })
+    }
+
+    pub fn set_reuse_address(&self, reuse: bool) -> io::Result<()> {
+        setsockopt(&self.inner, c::SOL_SOCKET, c::SO_REUSEADDR, reuse as c_int)
+    }
+
+    pub fn reuse_address(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(&self.inner, c::SOL_SOCKET, c::SO_REUSEADDR)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_reuse_port(&self, reuse: bool) -> io::Result<()> {
+        setsockopt(&self.inner, c::SOL_SOCKET, supported(SO_REUSEPORT)?, reuse as c_int)
+    }
+
+    pub fn reuse_port(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(&self.inner, c::SOL_SOCKET, supported(SO_REUSEPORT)?)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        let size = cmp::min(size, c_int::MAX as usize) as c_int;
+        setsockopt(&self.inner, c::SOL_SOCKET, c::SO_RCVBUF, size)
+    }
+
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        let raw: c_int = getsockopt(&self.inner, c::SOL_SOCKET, c::SO_RCVBUF)?;
+        Ok(raw as usize)
+    }
+
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        let size = cmp::min(size, c_int::MAX as usize) as c_int;
+        setsockopt(&self.inner, c::SOL_SOCKET, c::SO_SNDBUF, size)
+    }
+
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        let raw: c_int = getsockopt(&self.inner, c::SOL_SOCKET, c::SO_SNDBUF)?;
+        Ok(raw as usize)
+    }
+
+    pub fn set_keepalive(&self, keepalive: bool) -> io::Result<()> {
+        setsockopt(&self.inner, c::SOL_SOCKET, c::SO_KEEPALIVE, keepalive as c_int)
+    }
+
+    pub fn keepalive(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(&self.inner, c::SOL_SOCKET, c::SO_KEEPALIVE)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_keepalive_idle(&self, idle: Duration) -> io::Result<()> {
+        setsockopt(&self.inner, c::IPPROTO_TCP, supported(TCP_KEEPIDLE)?, keepalive_secs(idle)?)
+    }
+
+    pub fn keepalive_idle(&self) -> io::Result<Duration> {
+        let raw: c_int = getsockopt(&self.inner, c::IPPROTO_TCP, supported(TCP_KEEPIDLE)?)?;
+        Ok(Duration::from_secs(raw as u64))
+    }
+
+    pub fn set_keepalive_interval(&self, interval: Duration) -> io::Result<()> {
+        let secs = keepalive_secs(interval)?;
+        setsockopt(&self.inner, c::IPPROTO_TCP, supported(TCP_KEEPINTVL)?, secs)
+    }
+
+    pub fn keepalive_interval(&self) -> io::Result<Duration> {
+        let raw: c_int = getsockopt(&self.inner, c::IPPROTO_TCP, supported(TCP_KEEPINTVL)?)?;
+        Ok(Duration::from_secs(raw as u64))
+    }
+
+    pub fn set_keepalive_retries(&self, retries: u32) -> io::Result<()> {
+        let retries = cmp::min(retries, c_int::MAX as u32) as c_int;
+        setsockopt(&self.inner, c::IPPROTO_TCP, supported(TCP_KEEPCNT)?, retries)
+    }
+
+    pub fn keepalive_retries(&self) -> io::Result<u32> {
+        let raw: c_int = getsockopt(&self.inner, c::IPPROTO_TCP, supported(TCP_KEEPCNT)?)?;
+        Ok(raw as u32)
+    }
+
+    pub fn set_linger(&self, linger: Option<Duration>) -> io::Result<()> {
+        self.inner.set_linger(linger)
+    }
+
+    pub fn linger(&self) -> io::Result<Option<Duration>> {
+        self.inner.linger()
+    }
+
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        self.inner.set_nodelay(nodelay)
+    }
+
+    pub fn nodelay(&self) -> io::Result<bool> {
+        self.inner.nodelay()
+    }
+
+    pub fn set_only_v6(&self, only_v6: bool) -> io::Result<()> {
+        setsockopt(&self.inner, c::IPPROTO_IPV6, c::IPV6_V6ONLY, only_v6 as c_int)
+    }
+
+    pub fn only_v6(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(&self.inner, c::IPPROTO_IPV6, c::IPV6_V6ONLY)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_ttl(&self, ttl: u32) -> io::Result<()> {
+        setsockopt(&self.inner, c::IPPROTO_IP, c::IP_TTL, ttl as c_int)
+    }
+
+    pub fn ttl(&self) -> io::Result<u32> {
+        let raw: c_int = getsockopt(&self.inner, c::IPPROTO_IP, c::IP_TTL)?;
+        Ok(raw as u32)
+    }
+
+    pub fn set_broadcast(&self, broadcast: bool) -> io::Result<()> {
+        setsockopt(&self.inner, c::SOL_SOCKET, c::SO_BROADCAST, broadcast as c_int)
+    }
+
+    pub fn broadcast(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(&self.inner, c::SOL_SOCKET, c::SO_BROADCAST)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.inner.set_nonblocking(nonblocking)
+    }
+
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.inner.take_error()
+    }
+}
+
+fn supported(option: Option<c_int>) -> io::Result<c_int> {
+    option.ok_or_else(|| {
+        Error::new_const(ErrorKind::Unsupported, &"socket option not supported on this platform")
+    })
+}
+
+fn keepalive_secs(dur: Duration) -> io::Result<c_int> {
+    match dur.as_secs() {
+        0 => Err(Error::new_const(
+            ErrorKind::InvalidInput,
+            &"keepalive times need to be at least one second",
+        )),
+        secs => Ok(cmp::min(secs, c_int::MAX as u64) as c_int),
+    }
+}
+
+impl FromInner<Socket> for GenericSocket {
+    fn from_inner(socket: Socket) -> GenericSocket {
+        GenericSocket { inner: socket }
+    }
+}
+
+impl From<GenericSocket> for TcpStream {
+    fn from(socket: GenericSocket) -> TcpStream {
+        TcpStream { inner: socket.inner }
+    }
+}
+
+impl From<GenericSocket> for TcpListener {
+    fn from(socket: GenericSocket) -> TcpListener {
+        TcpListener { inner: socket.inner }
+    }
+}
+
+impl From<GenericSocket> for UdpSocket {
+    fn from(socket: GenericSocket) -> UdpSocket {
+        UdpSocket { inner: socket.inner }
+    }
+}
+
+impl fmt::Debug for GenericSocket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut res = f.debug_struct("Socket");
+
+        if let Ok(addr) = self.socket_addr() {
+            res.field("addr", &addr);
+        }
+
+        if let Ok(peer) = self.peer_addr() {
+            res.field("peer", &peer);
+        }
+
+        let name = if cfg!(windows) { "socket" } else { "fd" };
+        res.field(name, &self.inner.as_raw()).finish()
+    }
+}
@@ -3,6 +3,7 @@
 use crate::mem;
 use crate::result;
 use crate::sync::{
+    atomic::{AtomicUsize, Ordering},
     mpsc::{channel, Sender},
     Arc, Barrier,
 };
@@ -294,3 +295,59 @@ fn test_thread_id_not_equal() {
 
 // NOTE: the corresponding test for stderr is in ui/thread-stderr, due
 // to the test harness apparently interfering with stderr configuration.
+
+#[test]
+fn test_scoped_threads_borrow() {
+    let mut a = vec![1, 2, 3];
+    let mut x = 0;
+    thread::scope(|s| {
+        s.spawn(|| assert_eq!(a.len(), 3));
+        s.spawn(|| x += a[0] + a[2]);
+    });
+    a.push(4);
+    assert_eq!(x, a.len());
+}
+
+#[test]
+fn test_scoped_threads_join_returns_value() {
+    let data = [1, 2, 3, 4];
+    let sum = thread::scope(|s| {
+        let handles: Vec<_> =
+            data.chunks(2).map(|c| s.spawn(move || c.iter().sum::<i32>())).collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum::<i32>()
+    });
+    assert_eq!(sum, 10);
+}
+
+#[test]
+fn test_scoped_threads_joined_panic_is_handled() {
+    let result = thread::scope(|s| {
+        let t = s.spawn(|| panic!("oh no"));
+        t.join().is_err()
+    });
+    assert!(result);
+}
+
+#[test]
+fn test_scoped_threads_unjoined_panic_propagates() {
+    let result = crate::panic::catch_unwind(|| {
+        thread::scope(|s| {
+            s.spawn(|| panic!("oh no"));
+        });
+    });
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_scoped_threads_all_joined_before_return() {
+    let counter = AtomicUsize::new(0);
+    thread::scope(|s| {
+        for _ in 0..8 {
+            s.spawn(|| {
+                thread::sleep(Duration::from_millis(10));
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+    });
+    assert_eq!(counter.load(Ordering::SeqCst), 8);
+}
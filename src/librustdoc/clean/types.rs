@@ -2,10 +2,9 @@
 use std::default::Default;
 use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
-use std::lazy::SyncOnceCell as OnceCell;
 use std::path::PathBuf;
 use std::rc::Rc;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock as OnceCell};
 use std::{slice, vec};
 
 use arrayvec::ArrayVec;
@@ -52,6 +52,14 @@
         }
     }
 
+    // `CStr` is defined in core, but its allocating methods are added by a lang item impl in
+    // alloc, so they have to be inlined like the primitive impls above.
+    if let Some(def_id) = cx.tcx.lang_items().c_str_alloc_impl() {
+        if !def_id.is_local() {
+            inline::build_impl(cx, None, def_id, None, &mut new_items);
+        }
+    }
+
     let mut cleaner = BadImplStripper { prims, items: crate_items };
     let mut type_did_to_deref_target: FxHashMap<DefId, &Type> = FxHashMap::default();
 
@@ -0,0 +1,77 @@
+#![no_std]
+#![feature(allocator_api)]
+#![feature(new_uninit)]
+#![feature(try_alloc)]
+
+extern crate alloc;
+
+use alloc::alloc::AllocError;
+use alloc::collections::{
+    BTreeMap, BTreeSet, BinaryHeap, LinkedList, TryCollect, TryReserveError, VecDeque,
+};
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+pub fn shout(words: &[&str]) -> Result<Vec<String>, TryReserveError> {
+    let mut out = Vec::try_with_capacity(words.len())?;
+    for word in words {
+        let mut s = String::try_with_capacity(word.len() + 1)?;
+        s.try_push_str(word)?;
+        s.try_push('!')?;
+        out.try_push(s).map_err(|(_, e)| e)?;
+    }
+    Ok(out)
+}
+
+pub fn bytes(chunks: &[&[u8]]) -> Result<Vec<u8>, TryReserveError> {
+    let mut out = Vec::try_from_iter(chunks.iter().map(|chunk| chunk.len() as u8))?;
+    for chunk in chunks {
+        out.try_extend_from_slice(chunk)?;
+    }
+    out.try_insert(0, 0).map_err(|(_, e)| e)?;
+    Ok(out)
+}
+
+pub fn queue(items: &[u32]) -> Result<VecDeque<u32>, TryReserveError> {
+    let mut out = VecDeque::try_with_capacity(items.len())?;
+    for &item in items {
+        out.try_push_back(item).map_err(|(_, e)| e)?;
+    }
+    out.try_push_front(0).map_err(|(_, e)| e)?;
+    Ok(out)
+}
+
+pub fn lists(items: &[u32]) -> Result<(LinkedList<u32>, BinaryHeap<u32>), TryReserveError> {
+    let mut list = LinkedList::new();
+    let mut heap = BinaryHeap::try_with_capacity(items.len())?;
+    for &item in items {
+        list.try_push_back(item).map_err(|(_, e)| e)?;
+        heap.try_push(item).map_err(|(_, e)| e)?;
+    }
+    list.try_push_front(0).map_err(|(_, e)| e)?;
+    Ok((list, heap))
+}
+
+pub fn trees(items: &[u32]) -> Result<(BTreeMap<u32, usize>, BTreeSet<u32>), TryReserveError> {
+    let mut map = BTreeMap::new();
+    let mut set = BTreeSet::new();
+    for (i, &item) in items.iter().enumerate() {
+        map.try_insert(item, i).map_err(|(_, e)| e)?;
+        set.try_insert(item).map_err(|(_, e)| e)?;
+    }
+    Ok((map, set))
+}
+
+pub fn collected(items: &[u32]) -> Result<(Vec<u32>, BTreeSet<u32>), TryReserveError> {
+    Ok((items.iter().copied().try_collect()?, items.iter().copied().try_collect()?))
+}
+
+pub fn shared(len: usize) -> Result<(Rc<[u32]>, Arc<usize>), AllocError> {
+    let zeroed = Rc::<[u32]>::try_new_zeroed_slice(len)?;
+    let mut arc = Arc::try_new(0)?;
+    *Arc::try_make_mut(&mut arc)? = len;
+    // SAFETY: all-zero bytes are a valid `u32`.
+    Ok((unsafe { zeroed.assume_init() }, arc))
+}
@@ -0,0 +1,18 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+pub fn push(v: &mut Vec<u8>) {
+    v.push(0);
+}
+
+pub fn insert(map: &mut BTreeMap<u8, u8>) {
+    map.insert(0, 0);
+}
+
+pub fn collect(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().copied().collect()
+}
@@ -0,0 +1,17 @@
+// run-pass
+// edition:2021
+
+#![feature(c_str_literals)]
+
+use std::ffi::CStr;
+
+const HELLO: &CStr = c"hello";
+
+fn main() {
+    assert_eq!(HELLO.to_bytes_with_nul(), b"hello\0");
+    assert_eq!(c"".to_bytes_with_nul(), b"\0");
+    assert_eq!(c"\xff\u{e9}\n".to_bytes(), b"\xff\xc3\xa9\n");
+    assert_eq!(cr"\x00".to_bytes(), b"\\x00");
+    assert_eq!(cr#"a "quoted" string"#.to_bytes(), b"a \"quoted\" string");
+    assert_eq!(HELLO, CStr::from_bytes_with_nul(b"hello\0").unwrap());
+}
@@ -0,0 +1,13 @@
+// check-pass
+// edition:2018
+
+// Before Rust 2021, `c"..."` is the identifier `c` followed by a string literal.
+
+macro_rules! m {
+    ($prefix:ident $lit:literal) => {};
+}
+
+m!(c"hello");
+m!(cr"hello");
+
+fn main() {}
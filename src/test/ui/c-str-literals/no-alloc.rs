@@ -0,0 +1,16 @@
+// check-pass
+// edition:2021
+
+// `c"..."` literals only need `core`, so they work in crates without `alloc`.
+
+#![feature(c_str_literals, core_c_str)]
+#![no_std]
+#![crate_type = "lib"]
+
+use core::ffi::CStr;
+
+pub const HELLO: &CStr = c"hello";
+
+pub fn hello_len() -> usize {
+    HELLO.to_bytes().len()
+}
@@ -0,0 +1,8 @@
+// edition:2021
+
+#![feature(c_str_literals)]
+
+fn main() {
+    let _ = c"\0"; //~ ERROR null characters in C string literals are not supported
+    let _ = c"a\x00b"; //~ ERROR null characters in C string literals are not supported
+}
@@ -0,0 +1,6 @@
+// edition:2021
+
+fn main() {
+    let _ = c"hello"; //~ ERROR `c".."` literals are experimental
+    let _ = cr#"hello"#; //~ ERROR `c".."` literals are experimental
+}
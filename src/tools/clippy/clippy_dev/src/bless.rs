@@ -3,13 +3,13 @@
 
 use std::ffi::OsStr;
 use std::fs;
-use std::lazy::SyncLazy;
 use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
 use walkdir::WalkDir;
 
 use crate::clippy_project_root;
 
-static CLIPPY_BUILD_TIME: SyncLazy<Option<std::time::SystemTime>> = SyncLazy::new(|| {
+static CLIPPY_BUILD_TIME: LazyLock<Option<std::time::SystemTime>> = LazyLock::new(|| {
     let mut path = std::env::current_exe().unwrap();
     path.set_file_name("cargo-clippy");
     fs::metadata(path).ok()?.modified().ok()
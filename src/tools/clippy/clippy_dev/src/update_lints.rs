@@ -3,8 +3,8 @@
 use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::fs;
-use std::lazy::SyncLazy;
 use std::path::Path;
+use std::sync::LazyLock;
 use walkdir::WalkDir;
 
 use crate::clippy_project_root;
@@ -13,7 +13,7 @@
      // Use that command to update this file and do not edit by hand.\n\
      // Manual edits will be overwritten.\n\n";
 
-static DEC_CLIPPY_LINT_RE: SyncLazy<Regex> = SyncLazy::new(|| {
+static DEC_CLIPPY_LINT_RE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(
         r#"(?x)
     declare_clippy_lint!\s*[\{(]
@@ -26,7 +26,7 @@
     .unwrap()
 });
 
-static DEC_DEPRECATED_LINT_RE: SyncLazy<Regex> = SyncLazy::new(|| {
+static DEC_DEPRECATED_LINT_RE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(
         r#"(?x)
     declare_deprecated_lint!\s*[{(]\s*
@@ -37,7 +37,7 @@
     )
     .unwrap()
 });
-static NL_ESCAPE_RE: SyncLazy<Regex> = SyncLazy::new(|| Regex::new(r#"\\\n\s*"#).unwrap());
+static NL_ESCAPE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"\\\n\s*"#).unwrap());
 
 static DOCS_LINK: &str = "https://rust-lang.github.io/rust-clippy/master/index.html";
 
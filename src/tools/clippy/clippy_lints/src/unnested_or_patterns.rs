@@ -394,7 +394,7 @@ fn drain_matching(
 ) -> Vec<P<Pat>> {
     let mut tail_or = vec![];
     let mut idx = 0;
-    for pat in alternatives.drain_filter(|p| {
+    for pat in alternatives.extract_if(.., |p| {
         // Check if we should extract, but only if `idx >= start`.
         idx += 1;
         idx > start && predicate(&p.kind)
@@ -303,6 +303,11 @@ fn visit_expr(&mut self, expr: &Expr<'_>) {
                         println!("    if let LitKind::ByteStr(ref {}) = {}.node;", vec_pat, lit_pat);
                         println!("    if let [{:?}] = **{};", vec, vec_pat);
                     },
+                    LitKind::CStr(ref vec) => {
+                        let vec_pat = self.next("vec");
+                        println!("    if let LitKind::CStr(ref {}) = {}.node;", vec_pat, lit_pat);
+                        println!("    if let [{:?}] = **{};", vec, vec_pat);
+                    },
                     LitKind::Str(ref text, _) => {
                         let str_pat = self.next("s");
                         println!("    if let LitKind::Str(ref {}, _) = {}.node;", str_pat, lit_pat);